@@ -6,8 +6,8 @@ use crate::engine_constants::{BulletData, EngineConstants};
 use crate::npc::list::NPCList;
 use crate::npc::NPC;
 use crate::physics::{OFF_X, OFF_Y, PhysicalEntity};
-use crate::player::TargetPlayer;
-use crate::rng::RNG;
+use crate::player::{Player, TargetPlayer};
+use crate::rng::{RNG, Xoroshiro32PlusPlus};
 use crate::shared_game_state::SharedGameState;
 use crate::stage::Stage;
 
@@ -27,17 +27,20 @@ impl BulletManager {
         self.bullets.push(Bullet::new(x, y, btype, owner, direction, constants));
     }
 
-    pub fn tick_bullets(&mut self, state: &mut SharedGameState, players: [&dyn PhysicalEntity; 2], npc_list: &NPCList, stage: &mut Stage) {
+    pub fn tick_bullets(&mut self, state: &mut SharedGameState, players: [&Player; 2], npc_list: &NPCList, stage: &mut Stage) {
+        let mut new_bullets = Vec::new();
+
         for bullet in self.bullets.iter_mut() {
             if bullet.life < 1 {
                 bullet.cond.set_alive(false);
                 continue;
             }
 
-            bullet.tick(state, players, npc_list);
+            bullet.tick(state, players, npc_list, &mut new_bullets);
             bullet.tick_map_collisions(state, npc_list, stage);
         }
 
+        self.bullets.append(&mut new_bullets);
         self.bullets.retain(|b| !b.is_dead());
     }
 
@@ -46,7 +49,7 @@ impl BulletManager {
     }
 
     pub fn count_bullets_type_idx_all(&self, type_idx: u16) -> usize {
-        self.bullets.iter().filter(|b| (b.btype.saturating_sub(2) / 3) == type_idx).count()
+        self.bullets.iter().filter(|b| (b.btype + 2) / 3 == type_idx).count()
     }
 
     pub fn count_bullets_multi(&self, btypes: [u16; 3], player_id: TargetPlayer) -> usize {
@@ -255,7 +258,7 @@ impl Bullet {
         }
     }
 
-    fn tick_fireball(&mut self, state: &mut SharedGameState, players: [&dyn PhysicalEntity; 2], npc_list: &NPCList) {
+    fn tick_fireball(&mut self, state: &mut SharedGameState, players: [&Player; 2], npc_list: &NPCList) {
         self.action_counter += 1;
         if self.action_counter > self.lifetime {
             self.cond.set_alive(false);
@@ -374,8 +377,763 @@ impl Bullet {
         }
     }
 
-    pub fn tick(&mut self, state: &mut SharedGameState, players: [&dyn PhysicalEntity; 2], npc_list: &NPCList) {
-        if self.lifetime == 0 {
+    fn tick_machine_gun(&mut self, state: &mut SharedGameState, npc_list: &NPCList) {
+        self.action_counter += 1;
+        if self.action_counter > self.lifetime {
+            self.cond.set_alive(false);
+            state.create_caret(self.x, self.y, CaretType::Shoot, Direction::Left);
+            return;
+        }
+
+        if self.action_num == 0 {
+            self.action_num = 1;
+
+            match self.direction {
+                Direction::Left => {
+                    self.vel_x = -0x1000;
+                    self.vel_y = state.game_rng.range(-0xaa..0xaa);
+                }
+                Direction::Up => {
+                    self.vel_x = state.game_rng.range(-0xaa..0xaa);
+                    self.vel_y = -0x1000;
+                }
+                Direction::Right => {
+                    self.vel_x = 0x1000;
+                    self.vel_y = state.game_rng.range(-0xaa..0xaa);
+                }
+                Direction::Bottom => {
+                    self.vel_x = state.game_rng.range(-0xaa..0xaa);
+                    self.vel_y = 0x1000;
+                }
+                Direction::FacingPlayer => unreachable!(),
+            }
+        } else {
+            self.x += self.vel_x;
+            self.y += self.vel_y;
+
+            match self.btype {
+                10 => {
+                    self.anim_rect = state.constants.weapon.bullet_rects.b010_machine_gun_l1[self.direction as usize];
+                }
+                11 => {
+                    self.anim_rect = state.constants.weapon.bullet_rects.b011_machine_gun_l2[self.direction as usize];
+
+                    let mut npc = NPC::create(127, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y;
+                    npc.direction = if self.direction == Direction::Up || self.direction == Direction::Bottom {
+                        Direction::Up
+                    } else {
+                        Direction::Left
+                    };
+
+                    let _ = npc_list.spawn(0x100, npc);
+                }
+                12 => {
+                    self.anim_rect = state.constants.weapon.bullet_rects.b012_machine_gun_l3[self.direction as usize];
+
+                    let mut npc = NPC::create(128, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y;
+                    npc.direction = self.direction;
+
+                    let _ = npc_list.spawn(0x100, npc);
+                }
+                _ => { unreachable!() }
+            }
+        }
+    }
+
+    fn tick_missile(&mut self, state: &mut SharedGameState, players: [&Player; 2]) {
+        let is_super = self.btype >= 28;
+        let level = if is_super { self.btype - 27 } else { self.btype - 12 };
+
+        self.action_counter += 1;
+        if self.action_counter > self.lifetime {
+            self.cond.set_alive(false);
+            state.create_caret(self.x, self.y, CaretType::Shoot, Direction::Left);
+            return;
+        }
+
+        // missiles lose life when they hit an npc
+        let hit = self.life != 10
+            || (self.direction == Direction::Left && (self.flags.hit_left_wall() || self.flags.hit_left_slope() || self.flags.flag_x80()))
+            || (self.direction == Direction::Right && (self.flags.hit_right_wall() || self.flags.hit_right_slope() || self.flags.flag_x40()))
+            || (self.direction == Direction::Up && self.flags.hit_top_wall())
+            || (self.direction == Direction::Bottom && self.flags.hit_bottom_wall());
+
+        if hit {
+            let btype = if is_super { 30 + level } else { 15 + level };
+
+            *self = Bullet::new(self.x, self.y, btype, self.owner, Direction::Left, &state.constants);
+            return;
+        }
+
+        if self.action_num == 0 {
+            self.action_num = 1;
+
+            match self.direction {
+                Direction::Left | Direction::Right => self.target_y = self.y,
+                Direction::Up | Direction::Bottom => self.target_x = self.x,
+                Direction::FacingPlayer => unreachable!(),
+            }
+
+            // acceleration is kept in anim_num
+            self.anim_num = if is_super { 0x200 } else { 0x80 };
+
+            if level == 3 {
+                let player = players[self.owner.index()];
+
+                match self.direction {
+                    Direction::Left | Direction::Right => {
+                        self.vel_y = if player.y() < self.y { 0x100 } else { -0x100 };
+                        self.vel_x = state.game_rng.range(-0x200..0x200);
+                    }
+                    Direction::Up | Direction::Bottom => {
+                        self.vel_x = if player.x() < self.x { 0x100 } else { -0x100 };
+                        self.vel_y = state.game_rng.range(-0x200..0x200);
+                    }
+                    Direction::FacingPlayer => unreachable!(),
+                }
+
+                self.anim_num = match state.game_rng.range(0..2) {
+                    0 => self.anim_num,
+                    1 => self.anim_num / 2,
+                    _ => self.anim_num * 2 / 5,
+                };
+            }
+        }
+
+        match self.direction {
+            Direction::Left => self.vel_x -= self.anim_num as i32,
+            Direction::Up => self.vel_y -= self.anim_num as i32,
+            Direction::Right => self.vel_x += self.anim_num as i32,
+            Direction::Bottom => self.vel_y += self.anim_num as i32,
+            Direction::FacingPlayer => unreachable!(),
+        }
+
+        if level == 3 {
+            let wobble = if is_super { 0x40 } else { 0x20 };
+
+            match self.direction {
+                Direction::Left | Direction::Right => {
+                    self.vel_y += if self.y < self.target_y { wobble } else { -wobble };
+                }
+                Direction::Up | Direction::Bottom => {
+                    self.vel_x += if self.x < self.target_x { wobble } else { -wobble };
+                }
+                Direction::FacingPlayer => unreachable!(),
+            }
+        }
+
+        let max_speed = if is_super { 0x1400 } else { 0xa00 };
+        self.vel_x = clamp(self.vel_x, -max_speed, max_speed);
+        self.vel_y = clamp(self.vel_y, -max_speed, max_speed);
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.anim_counter += 1;
+        if self.anim_counter > 2 {
+            self.anim_counter = 0;
+
+            match self.direction {
+                Direction::Left => state.create_caret(self.x + 8 * 0x200, self.y, CaretType::Exhaust, Direction::Right),
+                Direction::Up => state.create_caret(self.x, self.y + 8 * 0x200, CaretType::Exhaust, Direction::Bottom),
+                Direction::Right => state.create_caret(self.x - 8 * 0x200, self.y, CaretType::Exhaust, Direction::Left),
+                Direction::Bottom => state.create_caret(self.x, self.y - 8 * 0x200, CaretType::Exhaust, Direction::Up),
+                Direction::FacingPlayer => unreachable!(),
+            }
+        }
+
+        let rects = match self.btype {
+            13 => &state.constants.weapon.bullet_rects.b013_missile_l1,
+            14 => &state.constants.weapon.bullet_rects.b014_missile_l2,
+            15 => &state.constants.weapon.bullet_rects.b015_missile_l3,
+            28 => &state.constants.weapon.bullet_rects.b028_super_missile_l1,
+            29 => &state.constants.weapon.bullet_rects.b029_super_missile_l2,
+            _ => &state.constants.weapon.bullet_rects.b030_super_missile_l3,
+        };
+
+        self.anim_rect = rects[self.direction as usize];
+    }
+
+    fn tick_bubble_1(&mut self, state: &mut SharedGameState) {
+        if self.flags.0 & 0x2ff != 0 {
+            self.cond.set_alive(false);
+            state.create_caret(self.x, self.y, CaretType::ProjectileDissipation, Direction::Left);
+            return;
+        }
+
+        self.action_counter += 1;
+        if self.action_counter > self.lifetime {
+            self.cond.set_alive(false);
+            state.create_caret(self.x, self.y, CaretType::ProjectileDissipation, Direction::Left);
+            return;
+        }
+
+        if self.action_num == 0 {
+            self.action_num = 1;
+
+            match self.direction {
+                Direction::Left => self.vel_x = -0x600,
+                Direction::Up => self.vel_y = -0x600,
+                Direction::Right => self.vel_x = 0x600,
+                Direction::Bottom => self.vel_y = 0x600,
+                Direction::FacingPlayer => unreachable!(),
+            }
+        }
+
+        match self.direction {
+            Direction::Left => self.vel_x += 0x2a,
+            Direction::Up => self.vel_y += 0x2a,
+            Direction::Right => self.vel_x -= 0x2a,
+            Direction::Bottom => self.vel_y -= 0x2a,
+            Direction::FacingPlayer => unreachable!(),
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.anim_counter += 1;
+        if self.anim_counter > 3 {
+            self.anim_counter = 0;
+            self.anim_num += 1;
+        }
+
+        if self.anim_num > 3 {
+            self.anim_num = 3;
+        }
+
+        self.anim_rect = state.constants.weapon.bullet_rects.b019_bubble_l1[self.anim_num as usize];
+    }
+
+    fn tick_bubble_2(&mut self, state: &mut SharedGameState) {
+        let hit_wall = match self.direction {
+            Direction::Left => self.flags.hit_left_wall(),
+            Direction::Up => self.flags.hit_top_wall(),
+            Direction::Right => self.flags.hit_right_wall(),
+            Direction::Bottom => self.flags.hit_bottom_wall(),
+            Direction::FacingPlayer => unreachable!(),
+        };
+
+        if hit_wall {
+            self.cond.set_alive(false);
+            state.create_caret(self.x, self.y, CaretType::ProjectileDissipation, Direction::Left);
+            return;
+        }
+
+        self.action_counter += 1;
+        if self.action_counter > self.lifetime {
+            self.cond.set_alive(false);
+            state.create_caret(self.x, self.y, CaretType::ProjectileDissipation, Direction::Left);
+            return;
+        }
+
+        if self.action_num == 0 {
+            self.action_num = 1;
+
+            match self.direction {
+                Direction::Left => {
+                    self.vel_x = -0x600;
+                    self.vel_y = state.game_rng.range(-0x100..0x100);
+                }
+                Direction::Up => {
+                    self.vel_x = state.game_rng.range(-0x100..0x100);
+                    self.vel_y = -0x600;
+                }
+                Direction::Right => {
+                    self.vel_x = 0x600;
+                    self.vel_y = state.game_rng.range(-0x100..0x100);
+                }
+                Direction::Bottom => {
+                    self.vel_x = state.game_rng.range(-0x100..0x100);
+                    self.vel_y = 0x600;
+                }
+                Direction::FacingPlayer => unreachable!(),
+            }
+        }
+
+        match self.direction {
+            Direction::Left => self.vel_x += 0x10,
+            Direction::Up => self.vel_y += 0x10,
+            Direction::Right => self.vel_x -= 0x10,
+            Direction::Bottom => self.vel_y -= 0x10,
+            Direction::FacingPlayer => unreachable!(),
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.anim_counter += 1;
+        if self.anim_counter > 3 {
+            self.anim_counter = 0;
+            self.anim_num += 1;
+        }
+
+        if self.anim_num > 2 {
+            self.anim_num = 2;
+        }
+
+        self.anim_rect = state.constants.weapon.bullet_rects.b020_bubble_l2[self.anim_num as usize];
+    }
+
+    fn tick_bubble_3(&mut self, state: &mut SharedGameState, players: [&Player; 2], new_bullets: &mut Vec<Bullet>) {
+        let player = players[self.owner.index()];
+
+        // bubbles keep circling the player until the trigger is released, then burst into spines
+        self.action_counter += 1;
+        if self.action_counter > 100 || !player.controller.shoot() {
+            self.cond.set_alive(false);
+            state.create_caret(self.x, self.y, CaretType::ProjectileDissipation, Direction::Left);
+            state.sound_manager.play_sfx(100);
+
+            let direction = if player.up {
+                Direction::Up
+            } else if player.down {
+                Direction::Bottom
+            } else {
+                player.direction
+            };
+
+            new_bullets.push(Bullet::new(self.x, self.y, 22, self.owner, direction, &state.constants));
+            return;
+        }
+
+        if self.action_num == 0 {
+            self.action_num = 1;
+
+            match self.direction {
+                Direction::Left => self.vel_x = state.game_rng.range(-0x400..-0x200),
+                Direction::Up => self.vel_y = state.game_rng.range(-0x400..-0x200),
+                Direction::Right => self.vel_x = state.game_rng.range(0x200..0x400),
+                Direction::Bottom => self.vel_y = state.game_rng.range(0x80..0x100),
+                Direction::FacingPlayer => unreachable!(),
+            }
+        }
+
+        if player.x > self.x {
+            self.vel_x += 0x20;
+        }
+        if player.x < self.x {
+            self.vel_x -= 0x20;
+        }
+        if player.y > self.y {
+            self.vel_y += 0x20;
+        }
+        if player.y < self.y {
+            self.vel_y -= 0x20;
+        }
+
+        if self.vel_x < 0 && self.flags.hit_left_wall() {
+            self.vel_x = 0x400;
+        }
+        if self.vel_x > 0 && self.flags.hit_right_wall() {
+            self.vel_x = -0x400;
+        }
+        if self.vel_y < 0 && self.flags.hit_top_wall() {
+            self.vel_y = 0x400;
+        }
+        if self.vel_y > 0 && self.flags.hit_bottom_wall() {
+            self.vel_y = -0x400;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.anim_counter += 1;
+        if self.anim_counter > 3 {
+            self.anim_counter = 0;
+            self.anim_num += 1;
+        }
+
+        if self.anim_num > 3 {
+            self.anim_num = 3;
+        }
+
+        self.anim_rect = state.constants.weapon.bullet_rects.b021_bubble_l3[self.anim_num as usize];
+    }
+
+    fn tick_bubble_spines(&mut self, state: &mut SharedGameState) {
+        self.action_counter += 1;
+        if self.action_counter > self.lifetime || self.flags.hit_bottom_wall() {
+            self.cond.set_alive(false);
+            state.create_caret(self.x, self.y, CaretType::Shoot, Direction::Left);
+            return;
+        }
+
+        if self.action_num == 0 {
+            self.action_num = 1;
+
+            let speed = state.game_rng.range(10..16) * 0x200 / 2;
+
+            match self.direction {
+                Direction::Left => self.vel_x = -speed,
+                Direction::Up => self.vel_y = -speed,
+                Direction::Right => self.vel_x = speed,
+                Direction::Bottom => self.vel_y = speed,
+                Direction::FacingPlayer => unreachable!(),
+            }
+        } else {
+            self.x += self.vel_x;
+            self.y += self.vel_y;
+        }
+
+        self.anim_counter += 1;
+        if self.anim_counter > 1 {
+            self.anim_counter = 0;
+            self.anim_num += 1;
+        }
+
+        if self.anim_num > 1 {
+            self.anim_num = 0;
+        }
+
+        let dir_offset = if self.direction == Direction::Up || self.direction == Direction::Bottom { 2 } else { 0 };
+
+        self.anim_rect = state.constants.weapon.bullet_rects.b022_bubble_spines[self.anim_num as usize + dir_offset];
+    }
+
+    fn tick_blade_slash(&mut self, state: &mut SharedGameState) {
+        if self.action_num == 0 {
+            self.action_num = 1;
+            self.y -= 12 * 0x200;
+            self.x += if self.direction == Direction::Left { 16 * 0x200 } else { -16 * 0x200 };
+        }
+
+        self.anim_counter += 1;
+        if self.anim_counter > 2 {
+            self.anim_counter = 0;
+            self.anim_num += 1;
+        }
+
+        self.x += if self.direction == Direction::Left { -0x400 } else { 0x400 };
+        self.y += 0x400;
+
+        self.damage = if self.anim_num == 1 { 2 } else { 1 };
+
+        if self.anim_num > 4 {
+            self.cond.set_alive(false);
+            return;
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 5 };
+
+        self.anim_rect = state.constants.weapon.bullet_rects.b023_blade_slash[self.anim_num as usize + dir_offset];
+    }
+
+    fn tick_blade_1(&mut self, state: &mut SharedGameState) {
+        self.action_counter += 1;
+        if self.action_counter > self.lifetime {
+            self.cond.set_alive(false);
+            state.create_caret(self.x, self.y, CaretType::ProjectileDissipation, Direction::Left);
+            return;
+        }
+
+        // the blade is thrown from behind the player, so it can't hit walls right away
+        if self.action_counter == 3 {
+            self.weapon_flags.set_flag_x04(false);
+        }
+
+        if self.action_counter % 5 == 1 {
+            state.sound_manager.play_sfx(34);
+        }
+
+        if self.action_num == 0 {
+            self.action_num = 1;
+
+            match self.direction {
+                Direction::Left => self.vel_x = -0x800,
+                Direction::Up => self.vel_y = -0x800,
+                Direction::Right => self.vel_x = 0x800,
+                Direction::Bottom => self.vel_y = 0x800,
+                Direction::FacingPlayer => unreachable!(),
+            }
+        } else {
+            self.x += self.vel_x;
+            self.y += self.vel_y;
+        }
+
+        self.anim_counter += 1;
+        if self.anim_counter > 1 {
+            self.anim_counter = 0;
+            self.anim_num += 1;
+        }
+
+        if self.anim_num > 3 {
+            self.anim_num = 0;
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 4 };
+
+        if self.btype == 25 {
+            self.anim_rect = state.constants.weapon.bullet_rects.b025_blade_l1[self.anim_num as usize + dir_offset];
+        } else {
+            self.anim_rect = state.constants.weapon.bullet_rects.b026_blade_l2[self.anim_num as usize + dir_offset];
+        }
+    }
+
+    fn tick_blade_3(&mut self, state: &mut SharedGameState, new_bullets: &mut Vec<Bullet>) {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.vel_x = 0;
+                    self.vel_y = 0;
+                }
+
+                match self.direction {
+                    Direction::Left => self.vel_x = -0x800,
+                    Direction::Up => self.vel_y = -0x800,
+                    Direction::Right => self.vel_x = 0x800,
+                    Direction::Bottom => self.vel_y = 0x800,
+                    Direction::FacingPlayer => unreachable!(),
+                }
+
+                // the king's spirit gets stuck in the first enemy it hits
+                if self.life != 100 {
+                    self.action_num = 2;
+                    self.anim_num = 1;
+                    self.anim_counter = 0;
+                    self.damage = 0;
+                }
+
+                self.anim_counter += 1;
+                if self.anim_counter % 4 == 1 {
+                    state.sound_manager.play_sfx(106);
+
+                    self.action_counter += 1;
+                    let direction = if self.action_counter % 2 != 0 { Direction::Left } else { Direction::Right };
+
+                    new_bullets.push(Bullet::new(self.x, self.y, 23, self.owner, direction, &state.constants));
+                }
+
+                self.action_counter += 1;
+                if self.action_counter == 5 {
+                    self.weapon_flags.set_flag_x04(false);
+                }
+
+                if self.action_counter > self.lifetime {
+                    self.cond.set_alive(false);
+                    state.create_caret(self.x, self.y, CaretType::Shoot, Direction::Left);
+                    return;
+                }
+            }
+            2 => {
+                self.vel_x = 0;
+                self.vel_y = 0;
+
+                self.anim_counter += 1;
+
+                if state.game_rng.range(-1..1) == 0 {
+                    state.sound_manager.play_sfx(106);
+
+                    let x = self.x + state.game_rng.range(-0x40..0x40) * 0x200;
+                    let y = self.y + state.game_rng.range(-0x40..0x40) * 0x200;
+
+                    new_bullets.push(Bullet::new(x, y, 23, self.owner, Direction::Right, &state.constants));
+                }
+
+                if self.anim_counter > 50 {
+                    self.cond.set_alive(false);
+                }
+            }
+            _ => {}
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.anim_rect = state.constants.weapon.bullet_rects.b027_blade_l3[self.direction as usize * 2 + self.anim_num as usize];
+    }
+
+    fn tick_explosion(&mut self, state: &mut SharedGameState, npc_list: &NPCList) {
+        let radius = match self.btype {
+            16 | 31 => 16,
+            17 | 32 => 32,
+            _ => 40,
+        };
+
+        if self.action_num == 0 {
+            self.action_num = 1;
+            self.action_counter = match self.btype {
+                16 | 31 => 10,
+                17 | 32 => 15,
+                _ => 5,
+            };
+
+            state.sound_manager.play_sfx(44);
+        }
+
+        // the explosion itself is drawn by the smoke and explosion carets
+        if self.action_counter % 3 == 0 {
+            let x = self.x + state.game_rng.range(-radius..radius) * 0x200;
+            let y = self.y + state.game_rng.range(-radius..radius) * 0x200;
+            let rng = Xoroshiro32PlusPlus::new(state.game_rng.next() as u32);
+
+            npc_list.create_death_smoke_up(x, y, self.enemy_hit_width as usize, 2, state, &rng);
+        }
+
+        if self.action_counter == 0 {
+            self.cond.set_alive(false);
+            return;
+        }
+
+        self.action_counter -= 1;
+    }
+
+    fn tick_nemesis(&mut self, state: &mut SharedGameState, npc_list: &NPCList) {
+        self.action_counter += 1;
+        if self.action_counter > self.lifetime {
+            self.cond.set_alive(false);
+            state.create_caret(self.x, self.y, CaretType::ProjectileDissipation, Direction::Left);
+            return;
+        }
+
+        if self.action_num == 0 {
+            self.action_num = 1;
+            self.action_counter = 0;
+
+            match self.direction {
+                Direction::Left => self.vel_x = -0x1000,
+                Direction::Up => self.vel_y = -0x1000,
+                Direction::Right => self.vel_x = 0x1000,
+                Direction::Bottom => self.vel_y = 0x1000,
+                Direction::FacingPlayer => unreachable!(),
+            }
+
+            // nemesis gets weaker as it levels up
+            if self.btype == 36 {
+                self.vel_x /= 4;
+                self.vel_y /= 4;
+            }
+        } else {
+            self.x += self.vel_x;
+            self.y += self.vel_y;
+        }
+
+        if self.btype == 34 && self.action_counter % 4 == 0 {
+            let mut npc = NPC::create(4, &state.npc_table);
+            npc.cond.set_alive(true);
+            npc.x = self.x;
+            npc.y = self.y;
+
+            match self.direction {
+                Direction::Left => {
+                    npc.vel_y = state.game_rng.range(-0x200..0x200);
+                    npc.direction = Direction::Right;
+                }
+                Direction::Right => {
+                    npc.vel_y = state.game_rng.range(-0x200..0x200);
+                    npc.direction = Direction::Left;
+                }
+                Direction::Up | Direction::Bottom => {
+                    npc.vel_x = state.game_rng.range(-0x200..0x200);
+                    npc.direction = Direction::Right;
+                }
+                Direction::FacingPlayer => unreachable!(),
+            }
+
+            let _ = npc_list.spawn(0x100, npc);
+        }
+
+        self.anim_num += 1;
+        if self.anim_num > 1 {
+            self.anim_num = 0;
+        }
+
+        let rects = match self.btype {
+            34 | 43 => &state.constants.weapon.bullet_rects.b034_nemesis_l1,
+            35 => &state.constants.weapon.bullet_rects.b035_nemesis_l2,
+            _ => &state.constants.weapon.bullet_rects.b036_nemesis_l3,
+        };
+
+        self.anim_rect = rects[self.direction as usize * 2 + self.anim_num as usize];
+    }
+
+    fn tick_spur(&mut self, state: &mut SharedGameState, new_bullets: &mut Vec<Bullet>) {
+        self.action_counter += 1;
+        if self.action_counter > self.lifetime {
+            self.cond.set_alive(false);
+            state.create_caret(self.x, self.y, CaretType::Shoot, Direction::Left);
+            return;
+        }
+
+        if self.action_num == 0 {
+            self.action_num = 1;
+
+            match self.direction {
+                Direction::Left => self.vel_x = -0x1000,
+                Direction::Up => self.vel_y = -0x1000,
+                Direction::Right => self.vel_x = 0x1000,
+                Direction::Bottom => self.vel_y = 0x1000,
+                Direction::FacingPlayer => unreachable!(),
+            }
+
+            let size = match self.btype {
+                37 => 0x400,
+                38 => 0x800,
+                _ => 0,
+            };
+
+            // level 3 uses default values
+            if size != 0 {
+                match self.direction {
+                    Direction::Left | Direction::Right => self.enemy_hit_height = size,
+                    Direction::Up | Direction::Bottom => self.enemy_hit_width = size,
+                    Direction::FacingPlayer => unreachable!(),
+                }
+            }
+        } else {
+            self.x += self.vel_x;
+            self.y += self.vel_y;
+        }
+
+        let vertical = self.direction == Direction::Up || self.direction == Direction::Bottom;
+
+        let (rects, trail_type) = match self.btype {
+            37 => (&state.constants.weapon.bullet_rects.b037_spur_l1, 40),
+            38 => (&state.constants.weapon.bullet_rects.b038_spur_l2, 41),
+            _ => (&state.constants.weapon.bullet_rects.b039_spur_l3, 42),
+        };
+
+        self.anim_rect = rects[vertical as usize];
+
+        new_bullets.push(Bullet::new(self.x, self.y, trail_type, self.owner, self.direction, &state.constants));
+    }
+
+    fn tick_spur_trail(&mut self, state: &mut SharedGameState) {
+        self.action_counter += 1;
+        if self.action_counter > 20 {
+            self.anim_num = self.action_counter - 20;
+        }
+
+        if self.anim_num > 2 {
+            self.cond.set_alive(false);
+            return;
+        }
+
+        // each piece of the trail can only hit once
+        if self.damage != 0 && self.life != 100 {
+            self.damage = 0;
+        }
+
+        let dir_offset = if self.direction == Direction::Up || self.direction == Direction::Bottom { 3 } else { 0 };
+
+        let rects = match self.btype {
+            40 => &state.constants.weapon.bullet_rects.b040_spur_trail_l1,
+            41 => &state.constants.weapon.bullet_rects.b041_spur_trail_l2,
+            _ => &state.constants.weapon.bullet_rects.b042_spur_trail_l3,
+        };
+
+        self.anim_rect = rects[self.anim_num as usize + dir_offset];
+    }
+
+    pub fn tick(&mut self, state: &mut SharedGameState, players: [&Player; 2], npc_list: &NPCList, new_bullets: &mut Vec<Bullet>) {
+        // blade slashes have no lifetime, they disappear once their animation ends
+        if self.lifetime == 0 && self.btype != 23 {
             self.cond.set_alive(false);
             return;
         }
@@ -384,6 +1142,21 @@ impl Bullet {
             1 => self.tick_snake_1(state),
             4 | 5 | 6 => self.tick_polar_star(state),
             7 | 8 | 9 => self.tick_fireball(state, players, npc_list),
+            10 | 11 | 12 => self.tick_machine_gun(state, npc_list),
+            13 | 14 | 15 => self.tick_missile(state, players),
+            16 | 17 | 18 => self.tick_explosion(state, npc_list),
+            19 => self.tick_bubble_1(state),
+            20 => self.tick_bubble_2(state),
+            21 => self.tick_bubble_3(state, players, new_bullets),
+            22 => self.tick_bubble_spines(state),
+            23 => self.tick_blade_slash(state),
+            25 | 26 => self.tick_blade_1(state),
+            27 => self.tick_blade_3(state, new_bullets),
+            28 | 29 | 30 => self.tick_missile(state, players),
+            31 | 32 | 33 => self.tick_explosion(state, npc_list),
+            34 | 35 | 36 | 43 => self.tick_nemesis(state, npc_list),
+            37 | 38 | 39 => self.tick_spur(state, new_bullets),
+            40 | 41 | 42 => self.tick_spur_trail(state),
             _ => self.cond.set_alive(false),
         }
     }
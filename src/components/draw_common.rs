@@ -10,9 +10,14 @@ pub enum Alignment {
 }
 
 pub fn draw_number(x: f32, y: f32, val: usize, align: Alignment, state: &mut SharedGameState, ctx: &mut Context) -> GameResult {
+    draw_number_zeros(x, y, val, align, 0, state, ctx)
+}
+
+/// Draws a number, padded with leading zeros to given amount of digits.
+pub fn draw_number_zeros(x: f32, y: f32, val: usize, align: Alignment, zeros: usize, state: &mut SharedGameState, ctx: &mut Context) -> GameResult {
     let batch = state.texture_set.get_or_load_batch(ctx, &state.constants, "TextBox")?;
 
-    let n = val.to_string();
+    let n = format!("{:01$}", val, zeros);
     let align_offset = if align == Alignment::Right { n.len() as f32 * 8.0 } else { 0.0 };
 
     for (offset, chr) in n.chars().enumerate() {
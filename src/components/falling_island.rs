@@ -0,0 +1,122 @@
+use ggez::{Context, GameResult};
+
+use crate::common::Rect;
+use crate::entity::GameEntity;
+use crate::frame::Frame;
+use crate::shared_game_state::SharedGameState;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum FallingIslandMode {
+    /// The island crashes into the ground.
+    Crash,
+    /// The island slows down and stops before hitting the ground.
+    Saved,
+}
+
+/// The falling island cutscene shown by `<XX1` in both endings.
+pub struct FallingIsland {
+    mode: FallingIslandMode,
+    visible: bool,
+    tick: usize,
+    island_x: i32,
+    island_y: i32,
+}
+
+impl FallingIsland {
+    pub fn new() -> FallingIsland {
+        FallingIsland {
+            mode: FallingIslandMode::Crash,
+            visible: false,
+            tick: 0,
+            island_x: 0,
+            island_y: 0,
+        }
+    }
+
+    pub fn visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn start(&mut self, mode: FallingIslandMode) {
+        self.mode = mode;
+        self.visible = true;
+        self.tick = 0;
+        self.island_x = 168 * 0x200;
+        self.island_y = 64 * 0x200;
+    }
+}
+
+impl GameEntity<()> for FallingIsland {
+    fn tick(&mut self, _state: &mut SharedGameState, _custom: ()) -> GameResult {
+        if !self.visible {
+            return Ok(());
+        }
+
+        match self.mode {
+            FallingIslandMode::Crash => {
+                self.island_y += 0x33;
+            }
+            FallingIslandMode::Saved => {
+                match self.tick {
+                    0..=349 => self.island_y += 0x33,
+                    350..=499 => self.island_y += 0x19,
+                    500..=599 => self.island_y += 0x0c,
+                    750 => self.visible = false,
+                    _ => {}
+                }
+            }
+        }
+
+        self.tick += 1;
+        if self.tick >= 900 {
+            self.visible = false;
+        }
+
+        Ok(())
+    }
+
+    fn draw(&self, state: &mut SharedGameState, ctx: &mut Context, _frame: &Frame) -> GameResult {
+        if !self.visible {
+            return Ok(());
+        }
+
+        state.texture_set.draw_rect(Rect::new_size(0, 0,
+                                                   (state.canvas_size.0 * state.scale) as isize,
+                                                   (state.canvas_size.1 * state.scale) as isize),
+                                    [0.0, 0.0, 0.0, 1.0], ctx)?;
+
+        // the scene was laid out for a 320 pixels wide screen, everything is clipped to the 160x80 window in the middle
+        let offset_x = ((state.canvas_size.0 - 320.0) / 2.0).floor();
+        let clip = Rect::new(80.0 + offset_x, 80.0, 240.0 + offset_x, 160.0f32);
+
+        let batch = state.texture_set.get_or_load_batch(ctx, &state.constants, &state.npc_table.tex_npc1_name)?;
+
+        // sky
+        batch.add_rect(80.0 + offset_x, 80.0, &Rect::new(0, 0, 160, 80));
+
+        // island, only the part that's inside the window is visible
+        let island_x = offset_x + (self.island_x / 0x200) as f32 - 20.0;
+        let mut island_y = (self.island_y / 0x200) as f32 - 12.0;
+        let mut rect = Rect::new(160u16, 0, 200, 24);
+
+        if island_y < clip.top {
+            rect.top = (rect.top as f32 + clip.top - island_y).min(rect.bottom as f32) as u16;
+            island_y = clip.top;
+        }
+
+        if island_y + rect.height() as f32 > clip.bottom {
+            rect.bottom = (clip.bottom - island_y + rect.top as f32).max(rect.top as f32) as u16;
+        }
+
+        if rect.bottom > rect.top {
+            batch.add_rect(island_x, island_y, &rect);
+        }
+
+        // ground
+        batch.add_rect(80.0 + offset_x, 128.0, &Rect::new(160, 48, 320, 80));
+
+        batch.draw(ctx)?;
+
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@ use ggez::{Context, GameResult};
 
 use crate::common::Rect;
 use crate::components::draw_common::{Alignment, draw_number};
+use crate::components::nikumaru::NikumaruCounter;
 use crate::entity::GameEntity;
 use crate::frame::Frame;
 use crate::inventory::Inventory;
@@ -13,6 +14,8 @@ pub struct HUD {
     pub weapon_x_pos: usize,
     pub visible: bool,
     pub has_player2: bool,
+    /// Ticked by the scene for player 1 only.
+    pub nikumaru_counter: NikumaruCounter,
     ammo: u16,
     max_ammo: u16,
     xp: u16,
@@ -37,6 +40,7 @@ impl HUD {
             weapon_x_pos: 16,
             visible: false,
             has_player2: false,
+            nikumaru_counter: NikumaruCounter::new(),
             ammo: 0,
             max_ammo: 0,
             xp: 0,
@@ -157,7 +161,7 @@ impl GameEntity<(&Player, &mut Inventory)> for HUD {
         Ok(())
     }
 
-    fn draw(&self, state: &mut SharedGameState, ctx: &mut Context, _frame: &Frame) -> GameResult {
+    fn draw(&self, state: &mut SharedGameState, ctx: &mut Context, frame: &Frame) -> GameResult {
         if !self.visible {
             return Ok(());
         }
@@ -279,6 +283,8 @@ impl GameEntity<(&Player, &mut Inventory)> for HUD {
         draw_number(num_offset + weap_x + 24.0, 32.0, self.current_level, Alignment::Right, state, ctx)?;
         draw_number(num_offset + 40.0, 40.0, self.life_bar as usize, Alignment::Right, state, ctx)?;
 
+        self.nikumaru_counter.draw(state, ctx, frame)?;
+
         Ok(())
     }
 }
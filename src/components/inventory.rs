@@ -0,0 +1,399 @@
+use ggez::{Context, GameResult};
+
+use crate::common::Rect;
+use crate::components::draw_common::{Alignment, draw_number};
+use crate::entity::GameEntity;
+use crate::frame::Frame;
+use crate::input::touch_controls::TouchControlType;
+use crate::inventory::Inventory;
+use crate::player::Player;
+use crate::shared_game_state::SharedGameState;
+use crate::text_script::ScriptMode;
+use crate::weapon::WeaponLevel;
+
+const ITEMS_PER_ROW: usize = 6;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+enum InventoryFocus {
+    Weapons,
+    Items,
+}
+
+#[derive(Copy, Clone)]
+struct InventoryWeaponData {
+    wtype: u8,
+    level: u8,
+    ammo: u16,
+    max_ammo: u16,
+    xp: u16,
+    max_xp: u16,
+    max_level: bool,
+}
+
+pub struct InventoryUI {
+    text_y_pos: usize,
+    tick: usize,
+    focus: InventoryFocus,
+    selected_weapon: usize,
+    selected_item: usize,
+    weapon_count: usize,
+    weapon_data: [InventoryWeaponData; 16],
+    item_data: Vec<u16>,
+}
+
+impl InventoryUI {
+    pub fn new() -> InventoryUI {
+        InventoryUI {
+            text_y_pos: 24,
+            tick: 0,
+            focus: InventoryFocus::Weapons,
+            selected_weapon: 0,
+            selected_item: 0,
+            weapon_count: 0,
+            weapon_data: [InventoryWeaponData { wtype: 0, level: 0, ammo: 0, max_ammo: 0, xp: 0, max_xp: 0, max_level: false }; 16],
+            item_data: Vec::with_capacity(16),
+        }
+    }
+
+    /// Opens the inventory and starts the description event of currently selected weapon or item.
+    pub fn init(&mut self, state: &mut SharedGameState, inventory: &Inventory) {
+        self.text_y_pos = 24;
+        self.tick = 0;
+
+        state.textscript_vm.set_mode(ScriptMode::Inventory);
+
+        if let Some(weapon) = inventory.get_current_weapon() {
+            self.focus = InventoryFocus::Weapons;
+            state.textscript_vm.start_script(1000 + weapon.wtype as u16);
+        } else if let Some(item) = inventory.get_item_idx(inventory.current_item as usize) {
+            self.focus = InventoryFocus::Items;
+            state.textscript_vm.start_script(5000 + item.0);
+        } else {
+            self.focus = InventoryFocus::Items;
+            state.textscript_vm.start_script(5000);
+        }
+    }
+
+    fn exit(&self, state: &mut SharedGameState) {
+        state.textscript_vm.set_mode(ScriptMode::Map);
+        state.control_flags.set_tick_world(true);
+        state.control_flags.set_control_enabled(true);
+        state.control_flags.set_interactions_disabled(false);
+    }
+
+    fn start_description(&self, state: &mut SharedGameState) {
+        let event_num = match self.focus {
+            InventoryFocus::Weapons => 1000 + self.weapon_data.get(self.selected_weapon).map_or(0, |weapon| weapon.wtype as u16),
+            InventoryFocus::Items => 5000 + self.item_data.get(self.selected_item).copied().unwrap_or(0),
+        };
+
+        state.textscript_vm.start_script(event_num);
+    }
+
+    fn update_data(&mut self, state: &SharedGameState, inventory: &Inventory) {
+        self.weapon_count = inventory.get_weapon_count().min(self.weapon_data.len());
+
+        for (idx, data) in self.weapon_data.iter_mut().enumerate() {
+            *data = if let Some(weapon) = inventory.get_weapon(idx) {
+                let (max_xp, max_level) = if weapon.level == WeaponLevel::None {
+                    (0, false)
+                } else {
+                    let max_xp = state.constants.weapon.level_table[weapon.wtype as usize][weapon.level as usize - 1];
+                    (max_xp, weapon.level == WeaponLevel::Level3 && weapon.experience == max_xp)
+                };
+
+                InventoryWeaponData {
+                    wtype: weapon.wtype as u8,
+                    level: weapon.level as u8,
+                    ammo: weapon.ammo,
+                    max_ammo: weapon.max_ammo,
+                    xp: weapon.experience,
+                    max_xp,
+                    max_level,
+                }
+            } else {
+                InventoryWeaponData { wtype: 0, level: 0, ammo: 0, max_ammo: 0, xp: 0, max_xp: 0, max_level: false }
+            };
+        }
+
+        self.item_data.clear();
+        for idx in 0..inventory.get_item_count() {
+            if let Some(item) = inventory.get_item_idx(idx) {
+                self.item_data.push(item.0);
+            }
+        }
+
+        self.selected_weapon = inventory.current_weapon as usize;
+        self.selected_item = inventory.current_item as usize;
+    }
+}
+
+impl GameEntity<(&Player, &mut Inventory)> for InventoryUI {
+    fn tick(&mut self, state: &mut SharedGameState, (player, inventory): (&Player, &mut Inventory)) -> GameResult {
+        self.update_data(state, inventory);
+
+        if self.text_y_pos > 16 {
+            self.text_y_pos -= 1;
+        }
+
+        self.tick = self.tick.wrapping_add(1);
+
+        // inventory can be only navigated if there's no item event in progress
+        if !state.control_flags.control_enabled() {
+            return Ok(());
+        }
+
+        state.touch_controls.control_type = TouchControlType::None;
+
+        let item_count = self.item_data.len();
+        let mut ok_pressed = player.controller.trigger_jump() || player.controller.trigger_menu_ok();
+        let mut cancel_pressed = player.controller.trigger_shoot() || player.controller.trigger_inventory();
+
+        match self.focus {
+            InventoryFocus::Weapons => {
+                let prev_weapon = inventory.current_weapon;
+
+                if player.controller.trigger_left() {
+                    inventory.prev_weapon();
+                } else if player.controller.trigger_right() {
+                    inventory.next_weapon();
+                }
+
+                if prev_weapon != inventory.current_weapon {
+                    state.sound_manager.play_sfx(4);
+                    self.selected_weapon = inventory.current_weapon as usize;
+                    self.start_description(state);
+                }
+
+                if player.controller.trigger_up() || player.controller.trigger_down() {
+                    self.focus = InventoryFocus::Items;
+                    state.sound_manager.play_sfx(1);
+                    self.start_description(state);
+                }
+            }
+            InventoryFocus::Items if item_count != 0 => {
+                let mut selected = self.selected_item.min(item_count - 1);
+
+                if player.controller.trigger_left() {
+                    if selected % ITEMS_PER_ROW == 0 {
+                        selected = (selected + ITEMS_PER_ROW - 1).min(item_count - 1);
+                    } else {
+                        selected -= 1;
+                    }
+                } else if player.controller.trigger_right() {
+                    if selected + 1 == item_count {
+                        selected = selected / ITEMS_PER_ROW * ITEMS_PER_ROW;
+                    } else if selected % ITEMS_PER_ROW == ITEMS_PER_ROW - 1 {
+                        selected -= ITEMS_PER_ROW - 1;
+                    } else {
+                        selected += 1;
+                    }
+                }
+
+                if player.controller.trigger_up() {
+                    if selected < ITEMS_PER_ROW {
+                        self.focus = InventoryFocus::Weapons;
+                    } else {
+                        selected -= ITEMS_PER_ROW;
+                    }
+                } else if player.controller.trigger_down() {
+                    if selected / ITEMS_PER_ROW == (item_count - 1) / ITEMS_PER_ROW {
+                        self.focus = InventoryFocus::Weapons;
+                    } else {
+                        selected = (selected + ITEMS_PER_ROW).min(item_count - 1);
+                    }
+                }
+
+                if self.focus == InventoryFocus::Weapons {
+                    state.sound_manager.play_sfx(1);
+                    self.start_description(state);
+                } else if selected != self.selected_item {
+                    state.sound_manager.play_sfx(1);
+                    self.selected_item = selected;
+                    inventory.current_item = selected as u16;
+                    self.start_description(state);
+                }
+            }
+            InventoryFocus::Items => {
+                if player.controller.trigger_up() || player.controller.trigger_down() {
+                    self.focus = InventoryFocus::Weapons;
+                    state.sound_manager.play_sfx(1);
+                    self.start_description(state);
+                }
+            }
+        }
+
+        if state.settings.touch_controls {
+            let (center_x, center_y) = ((state.canvas_size.0 / 2.0).floor() as isize, (state.canvas_size.1 / 2.0).floor() as isize);
+
+            for idx in 0..self.weapon_count {
+                let slot_rect = Rect::new_size(center_x - 112 + idx as isize * 40, center_y - 96, 40, 40);
+
+                if state.touch_controls.consume_click_in(slot_rect) {
+                    if self.focus == InventoryFocus::Weapons && self.selected_weapon == idx {
+                        ok_pressed = true;
+                    } else {
+                        state.sound_manager.play_sfx(4);
+                        self.focus = InventoryFocus::Weapons;
+                        self.selected_weapon = idx;
+                        inventory.current_weapon = idx as u16;
+                        self.start_description(state);
+                    }
+
+                    break;
+                }
+            }
+
+            for idx in 0..item_count {
+                let slot_rect = Rect::new_size(center_x - 112 + (idx % ITEMS_PER_ROW) as isize * 32,
+                                               center_y - 36 + (idx / ITEMS_PER_ROW) as isize * 16, 32, 16);
+
+                if state.touch_controls.consume_click_in(slot_rect) {
+                    if self.focus == InventoryFocus::Items && self.selected_item == idx {
+                        ok_pressed = true;
+                    } else {
+                        state.sound_manager.play_sfx(1);
+                        self.focus = InventoryFocus::Items;
+                        self.selected_item = idx;
+                        inventory.current_item = idx as u16;
+                        self.start_description(state);
+                    }
+
+                    break;
+                }
+            }
+
+            let close_rect = Rect::new_size(state.canvas_size.0 as isize - 34, 8, 26, 26);
+
+            if state.touch_controls.consume_click_in(close_rect) {
+                state.sound_manager.play_sfx(5);
+                cancel_pressed = true;
+            }
+        }
+
+        match self.focus {
+            InventoryFocus::Weapons if ok_pressed || cancel_pressed => {
+                self.exit(state);
+            }
+            InventoryFocus::Items if cancel_pressed => {
+                self.exit(state);
+            }
+            InventoryFocus::Items if ok_pressed => {
+                if let Some(&item_id) = self.item_data.get(self.selected_item) {
+                    state.textscript_vm.start_script(6000 + item_id);
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn draw(&self, state: &mut SharedGameState, ctx: &mut Context, _frame: &Frame) -> GameResult {
+        let (center_x, center_y) = ((state.canvas_size.0 / 2.0).floor(), (state.canvas_size.1 / 2.0).floor());
+        let flash = self.tick / 2 % 2;
+
+        let batch = state.texture_set.get_or_load_batch(ctx, &state.constants, "TextBox")?;
+
+        batch.add_rect(center_x - 122.0, center_y - 112.0, &state.constants.textscript.textbox_rect_top);
+        for i in 1..19 {
+            batch.add_rect(center_x - 122.0, center_y - 112.0 + i as f32 * 8.0, &state.constants.textscript.textbox_rect_middle);
+        }
+        batch.add_rect(center_x - 122.0, center_y - 112.0 + 19.0 * 8.0, &state.constants.textscript.textbox_rect_bottom);
+
+        let title_y = center_y - 120.0 + self.text_y_pos as f32;
+        batch.add_rect(center_x - 112.0, title_y, &state.constants.textscript.inventory_text_arms);
+        batch.add_rect(center_x - 112.0, title_y + 60.0, &state.constants.textscript.inventory_text_item);
+
+        if self.weapon_count != 0 {
+            let cursor = if self.focus == InventoryFocus::Weapons { flash } else { 1 };
+            batch.add_rect(center_x - 112.0 + self.selected_weapon as f32 * 40.0, center_y - 96.0,
+                           &state.constants.textscript.cursor_inventory_weapon[cursor]);
+        }
+
+        if !self.item_data.is_empty() {
+            let cursor = if self.focus == InventoryFocus::Items { flash } else { 1 };
+            batch.add_rect(center_x - 112.0 + (self.selected_item % ITEMS_PER_ROW) as f32 * 32.0,
+                           center_y - 36.0 + (self.selected_item / ITEMS_PER_ROW) as f32 * 16.0,
+                           &state.constants.textscript.cursor_inventory_item[cursor]);
+        }
+
+        for (idx, weapon) in self.weapon_data.iter().take(self.weapon_count).enumerate() {
+            let x = center_x - 112.0 + idx as f32 * 40.0;
+
+            // lv
+            batch.add_rect(x, center_y - 80.0, &Rect::new_size(80, 80, 16, 8));
+            // per
+            batch.add_rect(x, center_y - 64.0, &Rect::new_size(72, 48, 8, 8));
+
+            if weapon.max_ammo == 0 {
+                batch.add_rect(x + 16.0, center_y - 72.0, &Rect::new_size(80, 48, 16, 8));
+                batch.add_rect(x + 16.0, center_y - 64.0, &Rect::new_size(80, 48, 16, 8));
+            }
+
+            // xp box
+            batch.add_rect(x, center_y - 56.0, &Rect::new_size(0, 72, 40, 8));
+
+            if weapon.max_level {
+                batch.add_rect(x, center_y - 56.0, &Rect::new_size(40, 72, 40, 8));
+            } else if weapon.max_xp > 0 {
+                let bar_width = (weapon.xp as f32 / weapon.max_xp as f32 * 40.0) as u16;
+
+                batch.add_rect(x, center_y - 56.0, &Rect::new_size(0, 80, bar_width, 8));
+            }
+        }
+
+        batch.draw(ctx)?;
+
+        if self.weapon_count != 0 {
+            let batch = state.texture_set.get_or_load_batch(ctx, &state.constants, "ArmsImage")?;
+            let mut rect = Rect::new(0, 0, 0, 16);
+
+            for (idx, weapon) in self.weapon_data.iter().take(self.weapon_count).enumerate() {
+                rect.left = weapon.wtype as u16 * 16;
+                rect.right = rect.left + 16;
+                batch.add_rect(center_x - 112.0 + idx as f32 * 40.0, center_y - 96.0, &rect);
+            }
+
+            batch.draw(ctx)?;
+        }
+
+        if !self.item_data.is_empty() {
+            let batch = state.texture_set.get_or_load_batch(ctx, &state.constants, "ItemImage")?;
+            let mut rect = Rect::new(0, 0, 0, 0);
+
+            for (idx, &item_id) in self.item_data.iter().enumerate() {
+                rect.left = (item_id % 8) * 32;
+                rect.top = (item_id / 8) * 16;
+                rect.right = rect.left + 32;
+                rect.bottom = rect.top + 16;
+
+                batch.add_rect(center_x - 112.0 + (idx % ITEMS_PER_ROW) as f32 * 32.0,
+                               center_y - 36.0 + (idx / ITEMS_PER_ROW) as f32 * 16.0, &rect);
+            }
+
+            batch.draw(ctx)?;
+        }
+
+        for (idx, weapon) in self.weapon_data.iter().take(self.weapon_count).enumerate() {
+            let x = center_x - 112.0 + idx as f32 * 40.0;
+
+            draw_number(x + 32.0, center_y - 80.0, weapon.level as usize, Alignment::Right, state, ctx)?;
+
+            if weapon.max_ammo != 0 {
+                draw_number(x + 32.0, center_y - 72.0, weapon.ammo as usize, Alignment::Right, state, ctx)?;
+                draw_number(x + 32.0, center_y - 64.0, weapon.max_ammo as usize, Alignment::Right, state, ctx)?;
+            }
+        }
+
+        if state.settings.touch_controls {
+            let close_rect = Rect { left: 110, top: 110, right: 128, bottom: 128 };
+            let batch = state.texture_set.get_or_load_batch(ctx, &state.constants, "builtin/touch")?;
+
+            batch.add_rect(state.canvas_size.0 - 30.0, 12.0, &close_rect);
+            batch.draw(ctx)?;
+        }
+
+        Ok(())
+    }
+}
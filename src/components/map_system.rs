@@ -0,0 +1,168 @@
+use ggez::{Context, GameResult};
+
+use crate::common::Rect;
+use crate::entity::GameEntity;
+use crate::frame::Frame;
+use crate::player::Player;
+use crate::shared_game_state::SharedGameState;
+use crate::stage::Stage;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+enum MapSystemState {
+    Hidden,
+    FadeIn(u16),
+    Visible,
+    FadeOut(u16),
+}
+
+pub struct MapSystem {
+    state: MapSystemState,
+    tick: usize,
+    width: usize,
+    height: usize,
+    /// Color index for each tile of current stage, in row-major order.
+    tiles: Vec<u8>,
+    /// Number of rows that have been revealed so far.
+    rows_visible: usize,
+    player_pos: (usize, usize),
+    stage_name: String,
+}
+
+impl MapSystem {
+    pub fn new() -> MapSystem {
+        MapSystem {
+            state: MapSystemState::Hidden,
+            tick: 0,
+            width: 0,
+            height: 0,
+            tiles: Vec::new(),
+            rows_visible: 0,
+            player_pos: (0, 0),
+            stage_name: String::new(),
+        }
+    }
+
+    pub fn visible(&self) -> bool {
+        self.state != MapSystemState::Hidden
+    }
+
+    pub fn open(&mut self, stage: &Stage, player: &Player) {
+        self.state = MapSystemState::FadeIn(0);
+        self.tick = 0;
+        self.rows_visible = 0;
+        self.width = stage.map.width as usize;
+        self.height = stage.map.height as usize;
+        self.stage_name = stage.data.name.clone();
+
+        self.tiles.clear();
+        for y in 0..self.height {
+            for x in 0..self.width {
+                self.tiles.push(Self::tile_color(stage.map.get_attribute(x, y)));
+            }
+        }
+
+        self.player_pos = (
+            ((player.x / 0x200 + 8) / 16).max(0) as usize,
+            ((player.y / 0x200 + 8) / 16).max(0) as usize,
+        );
+    }
+
+    fn tile_color(attrib: u8) -> u8 {
+        match attrib {
+            0 => 0,
+            0x01 | 0x02 | 0x40 | 0x44 | 0x51 | 0x52 | 0x55 | 0x56 | 0x60 | 0x71 | 0x72 | 0x75 | 0x76
+            | 0x80..=0x83 | 0xa0..=0xa3 => 1,
+            0x43 | 0x50 | 0x53 | 0x54 | 0x57 | 0x63 | 0x70 | 0x73 | 0x74 | 0x77 => 2,
+            _ => 3,
+        }
+    }
+
+    /// Returns the map size scaled for the current expanding/shrinking animation frame.
+    fn current_size(&self) -> (f32, f32) {
+        let (width, height) = (self.width as f32 + 2.0, self.height as f32 + 2.0);
+
+        match self.state {
+            MapSystemState::Hidden => (0.0, 0.0),
+            MapSystemState::FadeIn(counter) | MapSystemState::FadeOut(counter) => {
+                ((width * counter as f32 / 8.0).floor(), (height * counter as f32 / 8.0).floor())
+            }
+            MapSystemState::Visible => (width, height),
+        }
+    }
+}
+
+impl GameEntity<(&Player, &Player)> for MapSystem {
+    fn tick(&mut self, state: &mut SharedGameState, (player1, player2): (&Player, &Player)) -> GameResult {
+        self.tick = self.tick.wrapping_add(1);
+
+        match self.state {
+            MapSystemState::Hidden => {}
+            MapSystemState::FadeIn(counter) => {
+                self.state = if counter >= 8 { MapSystemState::Visible } else { MapSystemState::FadeIn(counter + 1) };
+            }
+            MapSystemState::Visible => {
+                self.rows_visible = (self.rows_visible + 2).min(self.height);
+
+                let mut close_pressed = player1.controller.trigger_jump() || player1.controller.trigger_shoot()
+                    || player1.controller.trigger_map() || player2.controller.trigger_jump()
+                    || player2.controller.trigger_shoot() || player2.controller.trigger_map();
+
+                if state.settings.touch_controls {
+                    close_pressed |= state.touch_controls.consume_click_in(Rect::new_size(0, 0, state.canvas_size.0 as isize, state.canvas_size.1 as isize));
+                }
+
+                if close_pressed {
+                    self.state = MapSystemState::FadeOut(8);
+                }
+            }
+            MapSystemState::FadeOut(counter) => {
+                self.state = if counter == 0 { MapSystemState::Hidden } else { MapSystemState::FadeOut(counter - 1) };
+            }
+        }
+
+        Ok(())
+    }
+
+    fn draw(&self, state: &mut SharedGameState, ctx: &mut Context, _frame: &Frame) -> GameResult {
+        if !self.visible() {
+            return Ok(());
+        }
+
+        let (width, height) = self.current_size();
+        let left = ((state.canvas_size.0 - width) / 2.0).floor();
+        let top = ((state.canvas_size.1 - height) / 2.0).floor();
+
+        state.texture_set.draw_rect(Rect::new_size((left * state.scale) as isize, (top * state.scale) as isize,
+                                                   (width * state.scale) as isize, (height * state.scale) as isize),
+                                    [0.0, 0.0, 0.0, 1.0], ctx)?;
+
+        if self.state != MapSystemState::Visible {
+            return Ok(());
+        }
+
+        let name_width = state.font.text_width(self.stage_name.chars(), &state.constants);
+        state.font.draw_text(self.stage_name.chars(), ((state.canvas_size.0 - name_width) / 2.0).floor(),
+                             (top - state.font.line_height(&state.constants) - 4.0).max(0.0),
+                             &state.constants, &mut state.texture_set, ctx)?;
+
+        let batch = state.texture_set.get_or_load_batch(ctx, &state.constants, "TextBox")?;
+
+        for y in 0..self.rows_visible {
+            for x in 0..self.width {
+                let color = self.tiles[y * self.width + x] as u16;
+
+                batch.add_rect(left + 1.0 + x as f32, top + 1.0 + y as f32,
+                               &Rect::new_size(240 + color, 24, 1, 1));
+            }
+        }
+
+        if (self.tick / 8) % 2 != 0 {
+            batch.add_rect(left + 1.0 + self.player_pos.0 as f32, top + 1.0 + self.player_pos.1 as f32,
+                           &Rect::new_size(0, 57, 1, 1));
+        }
+
+        batch.draw(ctx)?;
+
+        Ok(())
+    }
+}
@@ -1,4 +1,8 @@
 pub mod boss_life_bar;
 pub mod draw_common;
+pub mod falling_island;
 pub mod hud;
+pub mod inventory;
+pub mod map_system;
+pub mod nikumaru;
 pub mod stage_select;
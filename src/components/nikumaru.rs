@@ -0,0 +1,162 @@
+use std::io;
+use std::io::{Read, Write};
+
+use byteorder::{LE, ReadBytesExt, WriteBytesExt};
+use ggez::{Context, filesystem, GameResult};
+use ggez::filesystem::OpenOptions;
+
+use crate::common::Rect;
+use crate::components::draw_common::{Alignment, draw_number, draw_number_zeros};
+use crate::entity::GameEntity;
+use crate::frame::Frame;
+use crate::player::Player;
+use crate::rng::RNG;
+use crate::shared_game_state::SharedGameState;
+
+/// Tick rate the times in 290.rec are stored at.
+const RECORD_TPS: usize = 50;
+
+pub struct NikumaruCounter {
+    pub tick: usize,
+    pub shown: bool,
+}
+
+impl NikumaruCounter {
+    pub fn new() -> NikumaruCounter {
+        NikumaruCounter {
+            tick: 0,
+            shown: false,
+        }
+    }
+
+    fn tps(state: &SharedGameState) -> usize {
+        match state.timing_mode.get_tps() {
+            0 => RECORD_TPS,
+            tps => tps,
+        }
+    }
+
+    /// Loads the best time from 290.rec, converted to ticks at current timing mode.
+    /// Returns 0 if there's no record or it's been tampered with.
+    pub fn load_counter(state: &SharedGameState, ctx: &mut Context) -> GameResult<usize> {
+        if let Ok(data) = filesystem::user_open(ctx, "/290.rec") {
+            // truncated or otherwise unreadable files are treated as no record
+            let (mut counters, random) = match NikumaruCounter::read_record(data) {
+                Ok(record) => record,
+                Err(_) => return Ok(0),
+            };
+
+            for (counter, &key) in counters.iter_mut().zip(random.iter()) {
+                let mut bytes = counter.to_le_bytes();
+                bytes[0] = bytes[0].wrapping_sub(key);
+                bytes[1] = bytes[1].wrapping_sub(key);
+                bytes[2] = bytes[2].wrapping_sub(key);
+                bytes[3] = bytes[3].wrapping_sub(key / 2);
+                *counter = u32::from_le_bytes(bytes);
+            }
+
+            if counters.iter().all(|&counter| counter == counters[0]) {
+                return Ok(counters[0] as usize * NikumaruCounter::tps(state) / RECORD_TPS);
+            }
+        }
+
+        Ok(0)
+    }
+
+    fn read_record<R: Read>(mut data: R) -> io::Result<([u32; 4], [u8; 4])> {
+        let mut counters = [0u32; 4];
+        let mut random = [0u8; 4];
+
+        for counter in counters.iter_mut() {
+            *counter = data.read_u32::<LE>()?;
+        }
+        data.read_exact(&mut random)?;
+
+        Ok((counters, random))
+    }
+
+    /// Saves current time to 290.rec if it's better than the stored one. Returns true if a new record was set.
+    pub fn save_counter(&self, state: &mut SharedGameState, player: &Player, ctx: &mut Context) -> GameResult<bool> {
+        if !player.equip.has_nikumaru() || self.tick == 0 {
+            return Ok(false);
+        }
+
+        let record = NikumaruCounter::load_counter(state, ctx)?;
+        if record != 0 && record < self.tick {
+            return Ok(false);
+        }
+
+        let time = (self.tick * RECORD_TPS / NikumaruCounter::tps(state)) as u32;
+        let mut counters = [time; 4];
+        let mut random = [0u8; 4];
+
+        for (idx, (counter, key)) in counters.iter_mut().zip(random.iter_mut()).enumerate() {
+            *key = (state.effect_rng.range(0..250) + idx as i32) as u8;
+
+            let mut bytes = counter.to_le_bytes();
+            bytes[0] = bytes[0].wrapping_add(*key);
+            bytes[1] = bytes[1].wrapping_add(*key);
+            bytes[2] = bytes[2].wrapping_add(*key);
+            bytes[3] = bytes[3].wrapping_add(*key / 2);
+            *counter = u32::from_le_bytes(bytes);
+        }
+
+        if let Ok(mut data) = filesystem::open_options(ctx, "/290.rec", OpenOptions::new().write(true).create(true).truncate(true)) {
+            for &counter in counters.iter() {
+                data.write_u32::<LE>(counter)?;
+            }
+            data.write_all(&random)?;
+        } else {
+            log::warn!("Cannot open 290.rec file.");
+        }
+
+        Ok(true)
+    }
+}
+
+impl GameEntity<&Player> for NikumaruCounter {
+    fn tick(&mut self, state: &mut SharedGameState, player: &Player) -> GameResult {
+        if !player.equip.has_nikumaru() {
+            self.tick = 0;
+            self.shown = false;
+            return Ok(());
+        }
+
+        self.shown = true;
+
+        // stops at 100 minutes, just like the original
+        if state.control_flags.control_enabled() && self.tick < NikumaruCounter::tps(state) * 60 * 100 {
+            self.tick += 1;
+        }
+
+        Ok(())
+    }
+
+    fn draw(&self, state: &mut SharedGameState, ctx: &mut Context, _frame: &Frame) -> GameResult {
+        if !self.shown {
+            return Ok(());
+        }
+
+        let tps = NikumaruCounter::tps(state);
+        let (x, y) = (16.0, 8.0);
+
+        let batch = state.texture_set.get_or_load_batch(ctx, &state.constants, "TextBox")?;
+
+        // clock
+        if state.control_flags.control_enabled() && self.tick % 30 <= 10 {
+            batch.add_rect(x, y, &Rect::new_size(120, 104, 8, 8));
+        } else {
+            batch.add_rect(x, y, &Rect::new_size(112, 104, 8, 8));
+        }
+        // ' and "
+        batch.add_rect(x + 30.0, y, &Rect::new_size(128, 104, 32, 8));
+
+        batch.draw(ctx)?;
+
+        draw_number(x + 32.0, y, self.tick / (60 * tps), Alignment::Right, state, ctx)?;
+        draw_number_zeros(x + 52.0, y, self.tick / tps % 60, Alignment::Right, 2, state, ctx)?;
+        draw_number(x + 64.0, y, self.tick / (tps / 10) % 10, Alignment::Right, state, ctx)?;
+
+        Ok(())
+    }
+}
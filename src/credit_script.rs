@@ -0,0 +1,367 @@
+use std::collections::HashMap;
+use std::io;
+use std::io::Cursor;
+
+use ggez::{Context, GameResult};
+use ggez::GameError::ParseError;
+
+use crate::encoding::{read_cur_shift_jis, read_cur_wtf8};
+use crate::engine_constants::EngineConstants;
+use crate::shared_game_state::SharedGameState;
+use crate::str;
+use crate::text_script::TextScriptEncoding;
+
+#[derive(Debug, Clone)]
+pub enum CreditOpCode {
+    /// `/`, Stops execution of the script.
+    StopCredits,
+    /// `[text]xxxx`, Pushes a line of text with cast sprite xxxx.
+    PushLine(u16, String),
+    /// `-xxxx`, Waits for xxxx frames.
+    Wait(u16),
+    /// `+xxxx`, Sets the X position of next lines to xxxx.
+    SetTextXOffset(i32),
+    /// `!xxxx`, Changes the music to song xxxx.
+    ChangeMusic(u16),
+    /// `~`, Fades out the music.
+    FadeMusic,
+    /// `jxxxx`, Jumps to label xxxx.
+    JumpLabel(u16),
+    /// `fxxxx:yyyy`, Jumps to label yyyy if flag xxxx is set.
+    JumpFlag(u16, u16),
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum CreditScriptExecutionState {
+    Ended,
+    Running(usize),
+    WaitTicks(usize, u16),
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum IllustrationState {
+    Hidden,
+    Visible,
+    FadeIn,
+    FadeOut,
+}
+
+pub struct CreditScriptLine {
+    pub pos_x: i32,
+    pub pos_y: i32,
+    pub cast_id: u16,
+    pub text: String,
+}
+
+pub struct CreditScript {
+    opcodes: Vec<CreditOpCode>,
+    labels: HashMap<u16, usize>,
+}
+
+impl Default for CreditScript {
+    fn default() -> Self {
+        CreditScript::new()
+    }
+}
+
+impl CreditScript {
+    pub fn new() -> CreditScript {
+        CreditScript {
+            opcodes: Vec::new(),
+            labels: HashMap::new(),
+        }
+    }
+
+    /// Loads, decrypts and compiles a credit script from specified stream.
+    pub fn load_from<R: io::Read>(mut data: R, constants: &EngineConstants) -> GameResult<CreditScript> {
+        let mut buf = Vec::new();
+        data.read_to_end(&mut buf)?;
+
+        if constants.textscript.encrypted && !buf.is_empty() {
+            let half = buf.len() / 2;
+            let key = if let Some(0) = buf.get(half) {
+                0xf9
+            } else {
+                (-(*buf.get(half).unwrap() as isize)) as u8
+            };
+
+            for (idx, byte) in buf.iter_mut().enumerate() {
+                if idx == half {
+                    continue;
+                }
+
+                *byte = byte.wrapping_add(key);
+            }
+        }
+
+        CreditScript::compile(&buf, constants.textscript.encoding)
+    }
+
+    /// Compiles a decrypted credit script.
+    pub fn compile(data: &[u8], encoding: TextScriptEncoding) -> GameResult<CreditScript> {
+        let mut opcodes = Vec::new();
+        let mut labels = HashMap::new();
+        let mut pos = 0;
+
+        while let Some(&chr) = data.get(pos) {
+            pos += 1;
+
+            match chr {
+                b'[' => {
+                    let mut cursor = Cursor::new(data);
+                    cursor.set_position(pos as u64);
+                    let mut text = String::new();
+
+                    loop {
+                        let remaining = data.len().saturating_sub(cursor.position() as usize) as u32;
+                        if remaining == 0 {
+                            return Err(ParseError(str!("Script unexpectedly ended.")));
+                        }
+
+                        let (_, chr) = match encoding {
+                            TextScriptEncoding::UTF8 => read_cur_wtf8(&mut cursor, remaining),
+                            TextScriptEncoding::ShiftJIS => read_cur_shift_jis(&mut cursor, remaining),
+                        };
+
+                        if chr == ']' {
+                            break;
+                        }
+
+                        text.push(chr);
+                    }
+
+                    pos = cursor.position() as usize;
+                    let cast_id = CreditScript::read_number(data, &mut pos)? as u16;
+
+                    opcodes.push(CreditOpCode::PushLine(cast_id, text));
+                }
+                b'-' => {
+                    let ticks = CreditScript::read_number(data, &mut pos)? as u16;
+                    opcodes.push(CreditOpCode::Wait(ticks));
+                }
+                b'+' => {
+                    let offset = CreditScript::read_number(data, &mut pos)?;
+                    opcodes.push(CreditOpCode::SetTextXOffset(offset));
+                }
+                b'!' => {
+                    let song_id = CreditScript::read_number(data, &mut pos)? as u16;
+                    opcodes.push(CreditOpCode::ChangeMusic(song_id));
+                }
+                b'~' => {
+                    opcodes.push(CreditOpCode::FadeMusic);
+                }
+                b'/' => {
+                    opcodes.push(CreditOpCode::StopCredits);
+                }
+                b'l' => {
+                    let label = CreditScript::read_number(data, &mut pos)? as u16;
+                    labels.insert(label, opcodes.len());
+                }
+                b'j' => {
+                    let label = CreditScript::read_number(data, &mut pos)? as u16;
+                    opcodes.push(CreditOpCode::JumpLabel(label));
+                }
+                b'f' => {
+                    let flag = CreditScript::read_number(data, &mut pos)? as u16;
+                    pos += 1; // ':'
+                    let label = CreditScript::read_number(data, &mut pos)? as u16;
+                    opcodes.push(CreditOpCode::JumpFlag(flag, label));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(CreditScript {
+            opcodes,
+            labels,
+        })
+    }
+
+    /// Reads a 4 digit number, no range checking is done, just like in TSC.
+    fn read_number(data: &[u8], pos: &mut usize) -> GameResult<i32> {
+        let digits = data.get(*pos..*pos + 4)
+            .ok_or_else(|| ParseError(str!("Script unexpectedly ended.")))?;
+        *pos += 4;
+
+        Ok(digits.iter().fold(0, |acc, &digit| acc * 10 + digit.wrapping_sub(b'0') as i32))
+    }
+}
+
+pub struct CreditScriptVM {
+    pub state: CreditScriptExecutionState,
+    pub script: CreditScript,
+    pub lines: Vec<CreditScriptLine>,
+    pub text_offset: i32,
+    pub illustration: u16,
+    pub illustration_state: IllustrationState,
+    pub illustration_pos: i32,
+}
+
+impl Default for CreditScriptVM {
+    fn default() -> Self {
+        CreditScriptVM::new()
+    }
+}
+
+impl CreditScriptVM {
+    pub fn new() -> CreditScriptVM {
+        CreditScriptVM {
+            state: CreditScriptExecutionState::Ended,
+            script: CreditScript::new(),
+            lines: Vec::new(),
+            text_offset: 0,
+            illustration: 0,
+            illustration_state: IllustrationState::Hidden,
+            illustration_pos: -160 * 0x200,
+        }
+    }
+
+    pub fn set_script(&mut self, script: CreditScript) {
+        self.script = script;
+    }
+
+    pub fn start(&mut self) {
+        self.state = CreditScriptExecutionState::Running(0);
+        self.lines.clear();
+        self.text_offset = 0;
+        self.illustration_state = IllustrationState::Hidden;
+        self.illustration_pos = -160 * 0x200;
+    }
+
+    pub fn set_illustration(&mut self, id: u16) {
+        self.illustration = id;
+        self.illustration_state = IllustrationState::FadeIn;
+    }
+
+    pub fn hide_illustration(&mut self) {
+        self.illustration_state = IllustrationState::FadeOut;
+    }
+
+    pub fn run(state: &mut SharedGameState, ctx: &mut Context) -> GameResult {
+        if !state.control_flags.credits_running() {
+            return Ok(());
+        }
+
+        let vm = &mut state.creditscript_vm;
+
+        for line in vm.lines.iter_mut() {
+            line.pos_y -= 0x100;
+        }
+        vm.lines.retain(|line| line.pos_y > -16 * 0x200);
+
+        match vm.illustration_state {
+            IllustrationState::FadeIn => {
+                vm.illustration_pos += 40 * 0x200;
+                if vm.illustration_pos >= 0 {
+                    vm.illustration_pos = 0;
+                    vm.illustration_state = IllustrationState::Visible;
+                }
+            }
+            IllustrationState::FadeOut => {
+                vm.illustration_pos -= 40 * 0x200;
+                if vm.illustration_pos <= -160 * 0x200 {
+                    vm.illustration_pos = -160 * 0x200;
+                    vm.illustration_state = IllustrationState::Hidden;
+                }
+            }
+            IllustrationState::Hidden | IllustrationState::Visible => {}
+        }
+
+        match state.creditscript_vm.state {
+            CreditScriptExecutionState::Ended => {}
+            CreditScriptExecutionState::WaitTicks(ip, ticks) => {
+                state.creditscript_vm.state = if ticks <= 1 {
+                    CreditScriptExecutionState::Running(ip)
+                } else {
+                    CreditScriptExecutionState::WaitTicks(ip, ticks - 1)
+                };
+            }
+            CreditScriptExecutionState::Running(ip) => {
+                state.creditscript_vm.state = CreditScriptVM::execute(ip, state, ctx)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn execute(ip: usize, state: &mut SharedGameState, ctx: &mut Context) -> GameResult<CreditScriptExecutionState> {
+        let op = if let Some(op) = state.creditscript_vm.script.opcodes.get(ip) {
+            op.clone()
+        } else {
+            state.control_flags.set_credits_running(false);
+            return Ok(CreditScriptExecutionState::Ended);
+        };
+
+        let mut exec_state = CreditScriptExecutionState::Running(ip + 1);
+
+        match op {
+            CreditOpCode::StopCredits => {
+                state.control_flags.set_credits_running(false);
+                exec_state = CreditScriptExecutionState::Ended;
+            }
+            CreditOpCode::PushLine(cast_id, text) => {
+                let vm = &mut state.creditscript_vm;
+
+                vm.lines.push(CreditScriptLine {
+                    pos_x: vm.text_offset,
+                    pos_y: (state.canvas_size.1 as i32 + 8) * 0x200,
+                    cast_id,
+                    text,
+                });
+            }
+            CreditOpCode::Wait(ticks) => {
+                exec_state = CreditScriptExecutionState::WaitTicks(ip + 1, ticks);
+            }
+            CreditOpCode::SetTextXOffset(offset) => {
+                state.creditscript_vm.text_offset = offset * 0x200;
+            }
+            CreditOpCode::ChangeMusic(song_id) => {
+                state.sound_manager.play_song(song_id as usize, &state.constants, ctx)?;
+            }
+            CreditOpCode::FadeMusic => {
+                state.sound_manager.fade_out()?;
+            }
+            CreditOpCode::JumpLabel(label) => {
+                if let Some(&target) = state.creditscript_vm.script.labels.get(&label) {
+                    exec_state = CreditScriptExecutionState::Running(target);
+                } else {
+                    log::warn!("Unknown credits label: {}", label);
+                }
+            }
+            CreditOpCode::JumpFlag(flag, label) => {
+                if let Some(true) = state.game_flags.get(flag as usize) {
+                    if let Some(&target) = state.creditscript_vm.script.labels.get(&label) {
+                        exec_state = CreditScriptExecutionState::Running(target);
+                    } else {
+                        log::warn!("Unknown credits label: {}", label);
+                    }
+                }
+            }
+        }
+
+        Ok(exec_state)
+    }
+}
+
+#[test]
+fn test_credit_script_compile() {
+    let script = CreditScript::compile(b"+0160\n[Cave Story]0012-0100\nl0001\nf0500:0001j0002\n/", TextScriptEncoding::UTF8).unwrap();
+
+    assert_eq!(script.opcodes.len(), 6);
+    assert!(matches!(script.opcodes[0], CreditOpCode::SetTextXOffset(160)));
+    assert!(matches!(&script.opcodes[1], CreditOpCode::PushLine(12, text) if text == "Cave Story"));
+    assert!(matches!(script.opcodes[2], CreditOpCode::Wait(100)));
+    assert!(matches!(script.opcodes[3], CreditOpCode::JumpFlag(500, 1)));
+    assert!(matches!(script.opcodes[4], CreditOpCode::JumpLabel(2)));
+    assert!(matches!(script.opcodes[5], CreditOpCode::StopCredits));
+    assert_eq!(script.labels.get(&1), Some(&3));
+}
+
+#[test]
+fn test_credit_script_load_empty() {
+    let mut constants = EngineConstants::defaults();
+    constants.textscript.encrypted = true;
+
+    let script = CreditScript::load_from(&[][..], &constants).unwrap();
+    assert!(script.opcodes.is_empty());
+}
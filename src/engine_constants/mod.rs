@@ -115,6 +115,26 @@ pub struct BulletRects {
     pub b006_polar_star_l3: [Rect<u16>; 2],
     pub b007_fireball_l1: [Rect<u16>; 8],
     pub b008_009_fireball_l2_3: [Rect<u16>; 6],
+    pub b010_machine_gun_l1: [Rect<u16>; 4],
+    pub b011_machine_gun_l2: [Rect<u16>; 4],
+    pub b012_machine_gun_l3: [Rect<u16>; 4],
+    pub b013_missile_l1: [Rect<u16>; 4],
+    pub b014_missile_l2: [Rect<u16>; 4],
+    pub b015_missile_l3: [Rect<u16>; 4],
+    pub b019_bubble_l1: [Rect<u16>; 4],
+    pub b020_bubble_l2: [Rect<u16>; 3],
+    pub b021_bubble_l3: [Rect<u16>; 4],
+    pub b022_bubble_spines: [Rect<u16>; 4],
+    pub b023_blade_slash: [Rect<u16>; 10],
+    pub b025_blade_l1: [Rect<u16>; 8],
+    pub b026_blade_l2: [Rect<u16>; 8],
+    pub b027_blade_l3: [Rect<u16>; 8],
+    pub b028_super_missile_l1: [Rect<u16>; 4],
+    pub b029_super_missile_l2: [Rect<u16>; 4],
+    pub b030_super_missile_l3: [Rect<u16>; 4],
+    pub b034_nemesis_l1: [Rect<u16>; 8],
+    pub b035_nemesis_l2: [Rect<u16>; 8],
+    pub b036_nemesis_l3: [Rect<u16>; 8],
     pub b037_spur_l1: [Rect<u16>; 2],
     pub b038_spur_l2: [Rect<u16>; 2],
     pub b039_spur_l3: [Rect<u16>; 2],
@@ -161,7 +181,11 @@ pub struct TextScriptConsts {
     pub get_item_right: Rect<u16>,
     pub get_item_bottom_right: Rect<u16>,
     pub stage_select_text: Rect<u16>,
+    pub inventory_text_arms: Rect<u16>,
+    pub inventory_text_item: Rect<u16>,
     pub cursor: [Rect<u16>; 2],
+    pub cursor_inventory_weapon: [Rect<u16>; 2],
+    pub cursor_inventory_item: [Rect<u16>; 2],
 }
 
 
@@ -533,6 +557,155 @@ impl EngineConstants {
                         Rect { left: 208, top: 16, right: 224, bottom: 32 },
                         Rect { left: 192, top: 16, right: 208, bottom: 32 },
                     ],
+                    b010_machine_gun_l1: [
+                        Rect { left: 64, top: 0, right: 80, bottom: 16 }, // left
+                        Rect { left: 80, top: 0, right: 96, bottom: 16 }, // up
+                        Rect { left: 96, top: 0, right: 112, bottom: 16 }, // right
+                        Rect { left: 112, top: 0, right: 128, bottom: 16 }, // down
+                    ],
+                    b011_machine_gun_l2: [
+                        Rect { left: 64, top: 16, right: 80, bottom: 32 }, // left
+                        Rect { left: 80, top: 16, right: 96, bottom: 32 }, // up
+                        Rect { left: 96, top: 16, right: 112, bottom: 32 }, // right
+                        Rect { left: 112, top: 16, right: 128, bottom: 32 }, // down
+                    ],
+                    b012_machine_gun_l3: [
+                        Rect { left: 64, top: 32, right: 80, bottom: 48 }, // left
+                        Rect { left: 80, top: 32, right: 96, bottom: 48 }, // up
+                        Rect { left: 96, top: 32, right: 112, bottom: 48 }, // right
+                        Rect { left: 112, top: 32, right: 128, bottom: 48 }, // down
+                    ],
+                    b013_missile_l1: [
+                        Rect { left: 0, top: 16, right: 16, bottom: 32 }, // left
+                        Rect { left: 16, top: 16, right: 32, bottom: 32 }, // up
+                        Rect { left: 32, top: 16, right: 48, bottom: 32 }, // right
+                        Rect { left: 48, top: 16, right: 64, bottom: 32 }, // down
+                    ],
+                    b014_missile_l2: [
+                        Rect { left: 0, top: 32, right: 16, bottom: 48 }, // left
+                        Rect { left: 16, top: 32, right: 32, bottom: 48 }, // up
+                        Rect { left: 32, top: 32, right: 48, bottom: 48 }, // right
+                        Rect { left: 48, top: 32, right: 64, bottom: 48 }, // down
+                    ],
+                    b015_missile_l3: [
+                        Rect { left: 0, top: 48, right: 16, bottom: 64 }, // left
+                        Rect { left: 16, top: 48, right: 32, bottom: 64 }, // up
+                        Rect { left: 32, top: 48, right: 48, bottom: 64 }, // right
+                        Rect { left: 48, top: 48, right: 64, bottom: 64 }, // down
+                    ],
+                    b019_bubble_l1: [
+                        Rect { left: 192, top: 0, right: 200, bottom: 8 },
+                        Rect { left: 200, top: 0, right: 208, bottom: 8 },
+                        Rect { left: 208, top: 0, right: 216, bottom: 8 },
+                        Rect { left: 216, top: 0, right: 224, bottom: 8 },
+                    ],
+                    b020_bubble_l2: [
+                        Rect { left: 192, top: 8, right: 200, bottom: 16 },
+                        Rect { left: 200, top: 8, right: 208, bottom: 16 },
+                        Rect { left: 208, top: 8, right: 216, bottom: 16 },
+                    ],
+                    b021_bubble_l3: [
+                        Rect { left: 240, top: 16, right: 248, bottom: 24 },
+                        Rect { left: 248, top: 16, right: 256, bottom: 24 },
+                        Rect { left: 240, top: 24, right: 248, bottom: 32 },
+                        Rect { left: 248, top: 24, right: 256, bottom: 32 },
+                    ],
+                    b022_bubble_spines: [
+                        Rect { left: 224, top: 0, right: 232, bottom: 8 }, // horizontal
+                        Rect { left: 232, top: 0, right: 240, bottom: 8 },
+                        Rect { left: 224, top: 16, right: 232, bottom: 24 }, // vertical
+                        Rect { left: 232, top: 16, right: 240, bottom: 24 },
+                    ],
+                    b023_blade_slash: [
+                        Rect { left: 0, top: 64, right: 24, bottom: 88 }, // left
+                        Rect { left: 24, top: 64, right: 48, bottom: 88 },
+                        Rect { left: 48, top: 64, right: 72, bottom: 88 },
+                        Rect { left: 72, top: 64, right: 96, bottom: 88 },
+                        Rect { left: 96, top: 64, right: 120, bottom: 88 },
+                        Rect { left: 0, top: 88, right: 24, bottom: 112 }, // right
+                        Rect { left: 24, top: 88, right: 48, bottom: 112 },
+                        Rect { left: 48, top: 88, right: 72, bottom: 112 },
+                        Rect { left: 72, top: 88, right: 96, bottom: 112 },
+                        Rect { left: 96, top: 88, right: 120, bottom: 112 },
+                    ],
+                    b025_blade_l1: [
+                        Rect { left: 0, top: 48, right: 16, bottom: 64 }, // left
+                        Rect { left: 16, top: 48, right: 32, bottom: 64 },
+                        Rect { left: 32, top: 48, right: 48, bottom: 64 },
+                        Rect { left: 48, top: 48, right: 64, bottom: 64 },
+                        Rect { left: 64, top: 48, right: 80, bottom: 64 }, // right
+                        Rect { left: 80, top: 48, right: 96, bottom: 64 },
+                        Rect { left: 96, top: 48, right: 112, bottom: 64 },
+                        Rect { left: 112, top: 48, right: 128, bottom: 64 },
+                    ],
+                    b026_blade_l2: [
+                        Rect { left: 160, top: 48, right: 184, bottom: 72 }, // left
+                        Rect { left: 184, top: 48, right: 208, bottom: 72 },
+                        Rect { left: 208, top: 48, right: 232, bottom: 72 },
+                        Rect { left: 232, top: 48, right: 256, bottom: 72 },
+                        Rect { left: 160, top: 72, right: 184, bottom: 96 }, // right
+                        Rect { left: 184, top: 72, right: 208, bottom: 96 },
+                        Rect { left: 208, top: 72, right: 232, bottom: 96 },
+                        Rect { left: 232, top: 72, right: 256, bottom: 96 },
+                    ],
+                    b027_blade_l3: [
+                        Rect { left: 272, top: 0, right: 296, bottom: 24 }, // left
+                        Rect { left: 296, top: 0, right: 320, bottom: 24 },
+                        Rect { left: 272, top: 48, right: 296, bottom: 72 }, // up
+                        Rect { left: 296, top: 0, right: 320, bottom: 24 },
+                        Rect { left: 272, top: 24, right: 296, bottom: 48 }, // right
+                        Rect { left: 296, top: 24, right: 320, bottom: 48 },
+                        Rect { left: 296, top: 48, right: 320, bottom: 72 }, // down
+                        Rect { left: 296, top: 24, right: 320, bottom: 48 },
+                    ],
+                    b028_super_missile_l1: [
+                        Rect { left: 120, top: 96, right: 136, bottom: 112 }, // left
+                        Rect { left: 136, top: 96, right: 152, bottom: 112 }, // up
+                        Rect { left: 152, top: 96, right: 168, bottom: 112 }, // right
+                        Rect { left: 168, top: 96, right: 184, bottom: 112 }, // down
+                    ],
+                    b029_super_missile_l2: [
+                        Rect { left: 184, top: 96, right: 200, bottom: 112 }, // left
+                        Rect { left: 200, top: 96, right: 216, bottom: 112 }, // up
+                        Rect { left: 216, top: 96, right: 232, bottom: 112 }, // right
+                        Rect { left: 232, top: 96, right: 248, bottom: 112 }, // down
+                    ],
+                    b030_super_missile_l3: [
+                        Rect { left: 120, top: 96, right: 136, bottom: 112 }, // left
+                        Rect { left: 136, top: 96, right: 152, bottom: 112 }, // up
+                        Rect { left: 152, top: 96, right: 168, bottom: 112 }, // right
+                        Rect { left: 168, top: 96, right: 184, bottom: 112 }, // down
+                    ],
+                    b034_nemesis_l1: [
+                        Rect { left: 0, top: 112, right: 32, bottom: 128 }, // left
+                        Rect { left: 0, top: 128, right: 32, bottom: 144 },
+                        Rect { left: 32, top: 112, right: 48, bottom: 144 }, // up
+                        Rect { left: 48, top: 112, right: 64, bottom: 144 },
+                        Rect { left: 64, top: 112, right: 96, bottom: 128 }, // right
+                        Rect { left: 64, top: 128, right: 96, bottom: 144 },
+                        Rect { left: 96, top: 112, right: 112, bottom: 144 }, // down
+                        Rect { left: 112, top: 112, right: 128, bottom: 144 },
+                    ],
+                    b035_nemesis_l2: [
+                        Rect { left: 128, top: 112, right: 160, bottom: 128 }, // left
+                        Rect { left: 128, top: 128, right: 160, bottom: 144 },
+                        Rect { left: 160, top: 112, right: 176, bottom: 144 }, // up
+                        Rect { left: 176, top: 112, right: 192, bottom: 144 },
+                        Rect { left: 192, top: 112, right: 224, bottom: 128 }, // right
+                        Rect { left: 192, top: 128, right: 224, bottom: 144 },
+                        Rect { left: 224, top: 112, right: 240, bottom: 144 }, // down
+                        Rect { left: 240, top: 112, right: 256, bottom: 144 },
+                    ],
+                    b036_nemesis_l3: [
+                        Rect { left: 0, top: 144, right: 32, bottom: 160 }, // left
+                        Rect { left: 0, top: 160, right: 32, bottom: 176 },
+                        Rect { left: 32, top: 144, right: 48, bottom: 176 }, // up
+                        Rect { left: 48, top: 144, right: 64, bottom: 176 },
+                        Rect { left: 64, top: 144, right: 96, bottom: 160 }, // right
+                        Rect { left: 64, top: 160, right: 96, bottom: 176 },
+                        Rect { left: 96, top: 144, right: 112, bottom: 176 }, // down
+                        Rect { left: 112, top: 144, right: 128, bottom: 176 },
+                    ],
                     b037_spur_l1: [
                         Rect { left: 128, top: 32, right: 144, bottom: 48 }, // horizontal
                         Rect { left: 144, top: 32, right: 160, bottom: 48 }, // vertical
@@ -721,10 +894,20 @@ impl EngineConstants {
                 get_item_right: Rect { left: 240, top: 8, right: 244, bottom: 16 },
                 get_item_bottom_right: Rect { left: 240, top: 16, right: 244, bottom: 24 },
                 stage_select_text: Rect { left: 80, top: 64, right: 144, bottom: 72 },
+                inventory_text_arms: Rect { left: 80, top: 48, right: 144, bottom: 56 },
+                inventory_text_item: Rect { left: 80, top: 56, right: 144, bottom: 64 },
                 cursor: [
                     Rect { left: 80, top: 88, right: 112, bottom: 104 },
                     Rect { left: 80, top: 104, right: 112, bottom: 120 },
                 ],
+                cursor_inventory_weapon: [
+                    Rect { left: 0, top: 88, right: 40, bottom: 128 },
+                    Rect { left: 40, top: 88, right: 80, bottom: 128 },
+                ],
+                cursor_inventory_item: [
+                    Rect { left: 80, top: 88, right: 112, bottom: 104 },
+                    Rect { left: 80, top: 104, right: 112, bottom: 120 },
+                ],
             },
             title: TitleConsts {
                 intro_text: "Studio Pixel presents".to_string(),
@@ -459,9 +459,15 @@ pub struct NPCConsts {
 
     // pub n152_shutter_stuck: () // Defined in code
 
+    #[serde(default = "default_n153_gaudi")]
+    pub n153_gaudi: [Rect<u16>; 14],
+
     #[serde(default = "default_n154_gaudi_dead")]
     pub n154_gaudi_dead: [Rect<u16>; 6],
 
+    #[serde(default = "default_n155_gaudi_flying")]
+    pub n155_gaudi_flying: [Rect<u16>; 8],
+
     #[serde(default = "default_n156_gaudi_projectile")]
     pub n156_gaudi_projectile: [Rect<u16>; 3],
 
@@ -1073,6 +1079,24 @@ pub struct NPCConsts {
 
     #[serde(default = "default_b03_monster_x")]
     pub b03_monster_x: [Rect<u16>; 29],
+
+    #[serde(default = "default_b04_core")]
+    pub b04_core: [Rect<u16>; 10],
+
+    #[serde(default = "default_b05_ironhead")]
+    pub b05_ironhead: [Rect<u16>; 18],
+
+    #[serde(default = "default_b06_twins")]
+    pub b06_twins: [Rect<u16>; 14],
+
+    #[serde(default = "default_b07_undead_core")]
+    pub b07_undead_core: [Rect<u16>; 14],
+
+    #[serde(default = "default_b08_press")]
+    pub b08_press: [Rect<u16>; 6],
+
+    #[serde(default = "default_b09_ballos")]
+    pub b09_ballos: [Rect<u16>; 14],
 }
 
 fn default_n001_experience() -> [Rect<u16>; 6] {
@@ -2821,6 +2845,25 @@ fn default_n151_blue_robot_standing() -> [Rect<u16>; 4] {
     ]
 }
 
+fn default_n153_gaudi() -> [Rect<u16>; 14] {
+    [
+        Rect { left: 0, top: 0, right: 24, bottom: 24 },
+        Rect { left: 24, top: 0, right: 48, bottom: 24 },
+        Rect { left: 48, top: 0, right: 72, bottom: 24 },
+        Rect { left: 0, top: 0, right: 24, bottom: 24 },
+        Rect { left: 72, top: 0, right: 96, bottom: 24 },
+        Rect { left: 0, top: 0, right: 24, bottom: 24 },
+        Rect { left: 288, top: 0, right: 312, bottom: 24 },
+        Rect { left: 0, top: 24, right: 24, bottom: 48 },
+        Rect { left: 24, top: 24, right: 48, bottom: 48 },
+        Rect { left: 48, top: 24, right: 72, bottom: 48 },
+        Rect { left: 0, top: 24, right: 24, bottom: 48 },
+        Rect { left: 72, top: 24, right: 96, bottom: 48 },
+        Rect { left: 0, top: 24, right: 24, bottom: 48 },
+        Rect { left: 288, top: 24, right: 312, bottom: 48 },
+    ]
+}
+
 fn default_n154_gaudi_dead() -> [Rect<u16>; 6] {
     [
         Rect { left: 168, top: 24, right: 192, bottom: 48 },
@@ -2832,6 +2875,19 @@ fn default_n154_gaudi_dead() -> [Rect<u16>; 6] {
     ]
 }
 
+fn default_n155_gaudi_flying() -> [Rect<u16>; 8] {
+    [
+        Rect { left: 0, top: 48, right: 24, bottom: 72 },
+        Rect { left: 24, top: 48, right: 48, bottom: 72 },
+        Rect { left: 288, top: 0, right: 312, bottom: 24 },
+        Rect { left: 24, top: 48, right: 48, bottom: 72 },
+        Rect { left: 0, top: 72, right: 24, bottom: 96 },
+        Rect { left: 24, top: 72, right: 48, bottom: 96 },
+        Rect { left: 288, top: 24, right: 312, bottom: 48 },
+        Rect { left: 24, top: 72, right: 48, bottom: 96 },
+    ]
+}
+
 fn default_n156_gaudi_projectile() -> [Rect<u16>; 3] {
     [
         Rect { left: 96, top: 112, right: 112, bottom: 128 },
@@ -4824,3 +4880,111 @@ fn default_b03_monster_x() -> [Rect<u16>; 29] {
         Rect { left: 48, top: 208, right: 64, bottom: 224 },
     ]
 }
+
+fn default_b04_core() -> [Rect<u16>; 10] {
+    [
+        Rect { left: 0, top: 0, right: 72, bottom: 112 }, // face
+        Rect { left: 0, top: 112, right: 72, bottom: 224 },
+        Rect { left: 160, top: 0, right: 232, bottom: 112 },
+        Rect { left: 0, top: 0, right: 0, bottom: 0 },
+        Rect { left: 72, top: 0, right: 160, bottom: 112 }, // tail
+        Rect { left: 72, top: 112, right: 160, bottom: 224 },
+        Rect { left: 0, top: 0, right: 0, bottom: 0 },
+        Rect { left: 256, top: 0, right: 320, bottom: 40 }, // small head
+        Rect { left: 256, top: 40, right: 320, bottom: 80 },
+        Rect { left: 256, top: 80, right: 320, bottom: 120 },
+    ]
+}
+
+fn default_b05_ironhead() -> [Rect<u16>; 18] {
+    [
+        Rect { left: 0, top: 0, right: 64, bottom: 24 }, // normal
+        Rect { left: 64, top: 0, right: 128, bottom: 24 },
+        Rect { left: 128, top: 0, right: 192, bottom: 24 },
+        Rect { left: 64, top: 0, right: 128, bottom: 24 },
+        Rect { left: 0, top: 0, right: 64, bottom: 24 },
+        Rect { left: 192, top: 0, right: 256, bottom: 24 },
+        Rect { left: 256, top: 0, right: 320, bottom: 24 },
+        Rect { left: 192, top: 0, right: 256, bottom: 24 },
+        Rect { left: 256, top: 48, right: 320, bottom: 72 },
+        Rect { left: 0, top: 24, right: 64, bottom: 48 }, // hurt
+        Rect { left: 64, top: 24, right: 128, bottom: 48 },
+        Rect { left: 128, top: 24, right: 192, bottom: 48 },
+        Rect { left: 64, top: 24, right: 128, bottom: 48 },
+        Rect { left: 0, top: 24, right: 64, bottom: 48 },
+        Rect { left: 192, top: 24, right: 256, bottom: 48 },
+        Rect { left: 256, top: 24, right: 320, bottom: 48 },
+        Rect { left: 192, top: 24, right: 256, bottom: 48 },
+        Rect { left: 256, top: 48, right: 320, bottom: 72 },
+    ]
+}
+
+fn default_b06_twins() -> [Rect<u16>; 14] {
+    [
+        // body
+        Rect { left: 0, top: 0, right: 40, bottom: 40 },
+        Rect { left: 40, top: 0, right: 80, bottom: 40 },
+        Rect { left: 80, top: 0, right: 120, bottom: 40 },
+        Rect { left: 0, top: 40, right: 40, bottom: 80 },
+        Rect { left: 40, top: 40, right: 80, bottom: 80 },
+        Rect { left: 80, top: 40, right: 120, bottom: 80 },
+        // head
+        Rect { left: 0, top: 80, right: 40, bottom: 112 },
+        Rect { left: 40, top: 80, right: 80, bottom: 112 },
+        Rect { left: 80, top: 80, right: 120, bottom: 112 },
+        Rect { left: 120, top: 80, right: 160, bottom: 112 },
+        Rect { left: 0, top: 112, right: 40, bottom: 144 },
+        Rect { left: 40, top: 112, right: 80, bottom: 144 },
+        Rect { left: 80, top: 112, right: 120, bottom: 144 },
+        Rect { left: 120, top: 112, right: 160, bottom: 144 },
+    ]
+}
+
+fn default_b07_undead_core() -> [Rect<u16>; 14] {
+    [
+        Rect { left: 0, top: 0, right: 0, bottom: 0 }, // face
+        Rect { left: 160, top: 112, right: 232, bottom: 152 },
+        Rect { left: 160, top: 152, right: 232, bottom: 192 },
+        Rect { left: 160, top: 192, right: 232, bottom: 232 },
+        Rect { left: 248, top: 160, right: 320, bottom: 200 },
+        Rect { left: 0, top: 0, right: 72, bottom: 112 }, // head
+        Rect { left: 0, top: 112, right: 72, bottom: 224 },
+        Rect { left: 160, top: 0, right: 232, bottom: 112 },
+        Rect { left: 72, top: 0, right: 160, bottom: 112 }, // tail
+        Rect { left: 72, top: 112, right: 160, bottom: 224 },
+        Rect { left: 232, top: 0, right: 320, bottom: 112 },
+        Rect { left: 256, top: 0, right: 320, bottom: 40 }, // small head
+        Rect { left: 256, top: 40, right: 320, bottom: 80 },
+        Rect { left: 256, top: 80, right: 320, bottom: 120 },
+    ]
+}
+
+fn default_b08_press() -> [Rect<u16>; 6] {
+    [
+        Rect { left: 0, top: 0, right: 80, bottom: 120 },
+        Rect { left: 80, top: 0, right: 160, bottom: 120 },
+        Rect { left: 160, top: 0, right: 240, bottom: 120 },
+        Rect { left: 0, top: 120, right: 80, bottom: 240 }, // damage
+        Rect { left: 80, top: 120, right: 160, bottom: 240 },
+        Rect { left: 160, top: 120, right: 240, bottom: 240 },
+    ]
+}
+
+fn default_b09_ballos() -> [Rect<u16>; 14] {
+    [
+        Rect { left: 272, top: 0, right: 296, bottom: 16 }, // left eye
+        Rect { left: 272, top: 16, right: 296, bottom: 32 },
+        Rect { left: 272, top: 32, right: 296, bottom: 48 },
+        Rect { left: 0, top: 0, right: 0, bottom: 0 },
+        Rect { left: 240, top: 16, right: 264, bottom: 32 },
+        Rect { left: 296, top: 0, right: 320, bottom: 16 }, // right eye
+        Rect { left: 296, top: 16, right: 320, bottom: 32 },
+        Rect { left: 296, top: 32, right: 320, bottom: 48 },
+        Rect { left: 0, top: 0, right: 0, bottom: 0 },
+        Rect { left: 240, top: 32, right: 264, bottom: 48 },
+        Rect { left: 0, top: 0, right: 120, bottom: 120 }, // body
+        Rect { left: 120, top: 0, right: 240, bottom: 120 },
+        Rect { left: 0, top: 120, right: 120, bottom: 240 },
+        Rect { left: 120, top: 120, right: 240, bottom: 240 },
+    ]
+}
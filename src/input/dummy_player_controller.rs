@@ -92,6 +92,14 @@ impl PlayerController for DummyPlayerController {
         false
     }
 
+    fn trigger_inventory(&self) -> bool {
+        false
+    }
+
+    fn trigger_map(&self) -> bool {
+        false
+    }
+
     fn trigger_menu_ok(&self) -> bool {
         false
     }
@@ -149,6 +149,14 @@ impl PlayerController for KeyboardController {
         self.trigger.skip()
     }
 
+    fn trigger_inventory(&self) -> bool {
+        self.trigger.inventory()
+    }
+
+    fn trigger_map(&self) -> bool {
+        self.trigger.map()
+    }
+
     fn trigger_menu_ok(&self) -> bool {
         self.trigger.jump() || self.trigger.enter()
     }
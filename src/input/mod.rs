@@ -2,5 +2,6 @@ pub mod combined_menu_controller;
 pub mod dummy_player_controller;
 pub mod keyboard_player_controller;
 pub mod player_controller;
+pub mod replay_player_controller;
 pub mod touch_controls;
 pub mod touch_player_controller;
@@ -52,6 +52,10 @@ pub trait PlayerController: PlayerControllerClone {
 
     fn trigger_skip(&self) -> bool;
 
+    fn trigger_inventory(&self) -> bool;
+
+    fn trigger_map(&self) -> bool;
+
     fn trigger_menu_ok(&self) -> bool;
 
     fn trigger_menu_back(&self) -> bool;
@@ -0,0 +1,219 @@
+use ggez::{Context, GameResult};
+
+use crate::input::keyboard_player_controller::KeyState;
+use crate::input::player_controller::PlayerController;
+use crate::replay::ReplayState;
+use crate::shared_game_state::SharedGameState;
+
+#[derive(Clone)]
+enum ReplayMode {
+    /// Passes through inputs of wrapped controller, storing them in `SharedGameState::replay` while recording.
+    Record(Box<dyn PlayerController>),
+    /// Feeds inputs stored in `SharedGameState::replay`.
+    Playback,
+}
+
+/// Records or plays back player inputs, one `KeyState` per tick.
+#[derive(Clone)]
+pub struct ReplayController {
+    mode: ReplayMode,
+    state: KeyState,
+    old_state: KeyState,
+    trigger: KeyState,
+}
+
+impl ReplayController {
+    pub fn new_recorder(controller: Box<dyn PlayerController>) -> ReplayController {
+        ReplayController::new(ReplayMode::Record(controller))
+    }
+
+    pub fn new_playback() -> ReplayController {
+        ReplayController::new(ReplayMode::Playback)
+    }
+
+    fn new(mode: ReplayMode) -> ReplayController {
+        ReplayController {
+            mode,
+            state: KeyState(0),
+            old_state: KeyState(0),
+            trigger: KeyState(0),
+        }
+    }
+}
+
+impl PlayerController for ReplayController {
+    fn update(&mut self, state: &mut SharedGameState, ctx: &mut Context) -> GameResult {
+        match &mut self.mode {
+            ReplayMode::Record(controller) => {
+                controller.update(state, ctx)?;
+                controller.update_trigger();
+
+                // buttons that are only exposed as triggers are recorded for a single tick
+                self.state.set_left(controller.move_left());
+                self.state.set_right(controller.move_right());
+                self.state.set_up(controller.move_up());
+                self.state.set_down(controller.move_down());
+                self.state.set_map(controller.trigger_map());
+                self.state.set_inventory(controller.trigger_inventory());
+                self.state.set_jump(controller.jump());
+                self.state.set_shoot(controller.shoot());
+                self.state.set_next_weapon(controller.next_weapon());
+                self.state.set_prev_weapon(controller.prev_weapon());
+                self.state.set_escape(controller.trigger_menu_pause());
+                self.state.set_enter(controller.trigger_menu_ok() && !controller.trigger_jump());
+                self.state.set_skip(controller.skip());
+
+                state.replay.record(self.state.0);
+            }
+            ReplayMode::Playback => {
+                self.state = KeyState(state.replay.next_keys());
+
+                // give the control back to player once the replay is over
+                if state.replay.state != ReplayState::Playback {
+                    self.mode = ReplayMode::Record(state.settings.create_player1_controller());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn update_trigger(&mut self) {
+        let mut trigger = self.state.0 ^ self.old_state.0;
+        trigger &= self.state.0;
+        self.old_state = self.state;
+        self.trigger = KeyState(trigger);
+    }
+
+    fn move_up(&self) -> bool {
+        self.state.up()
+    }
+
+    fn move_left(&self) -> bool {
+        self.state.left()
+    }
+
+    fn move_down(&self) -> bool {
+        self.state.down()
+    }
+
+    fn move_right(&self) -> bool {
+        self.state.right()
+    }
+
+    fn prev_weapon(&self) -> bool {
+        self.state.prev_weapon()
+    }
+
+    fn next_weapon(&self) -> bool {
+        self.state.next_weapon()
+    }
+
+    fn jump(&self) -> bool {
+        self.state.jump()
+    }
+
+    fn shoot(&self) -> bool {
+        self.state.shoot()
+    }
+
+    fn skip(&self) -> bool {
+        self.state.skip()
+    }
+
+    fn trigger_up(&self) -> bool {
+        self.trigger.up()
+    }
+
+    fn trigger_left(&self) -> bool {
+        self.trigger.left()
+    }
+
+    fn trigger_down(&self) -> bool {
+        self.trigger.down()
+    }
+
+    fn trigger_right(&self) -> bool {
+        self.trigger.right()
+    }
+
+    fn trigger_prev_weapon(&self) -> bool {
+        self.trigger.prev_weapon()
+    }
+
+    fn trigger_next_weapon(&self) -> bool {
+        self.trigger.next_weapon()
+    }
+
+    fn trigger_jump(&self) -> bool {
+        self.trigger.jump()
+    }
+
+    fn trigger_shoot(&self) -> bool {
+        self.trigger.shoot()
+    }
+
+    fn trigger_skip(&self) -> bool {
+        self.trigger.skip()
+    }
+
+    fn trigger_inventory(&self) -> bool {
+        self.trigger.inventory()
+    }
+
+    fn trigger_map(&self) -> bool {
+        self.trigger.map()
+    }
+
+    fn trigger_menu_ok(&self) -> bool {
+        self.trigger.jump() || self.trigger.enter()
+    }
+
+    fn trigger_menu_back(&self) -> bool {
+        self.trigger.shoot() || self.trigger.escape()
+    }
+
+    fn trigger_menu_pause(&self) -> bool {
+        self.trigger.escape()
+    }
+
+    fn look_up(&self) -> bool {
+        self.state.up()
+    }
+
+    fn look_left(&self) -> bool {
+        self.state.left()
+    }
+
+    fn look_down(&self) -> bool {
+        self.state.down()
+    }
+
+    fn look_right(&self) -> bool {
+        self.state.right()
+    }
+
+    fn move_analog_x(&self) -> f64 {
+        if self.state.left() && self.state.right() {
+            0.0
+        } else if self.state.left() {
+            -1.0
+        } else if self.state.right() {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    fn move_analog_y(&self) -> f64 {
+        if self.state.up() && self.state.down() {
+            0.0
+        } else if self.state.up() {
+            -1.0
+        } else if self.state.down() {
+            1.0
+        } else {
+            0.0
+        }
+    }
+}
@@ -175,6 +175,14 @@ impl PlayerController for TouchPlayerController {
         false
     }
 
+    fn trigger_inventory(&self) -> bool {
+        self.trigger.inventory()
+    }
+
+    fn trigger_map(&self) -> bool {
+        self.trigger.map()
+    }
+
     fn trigger_menu_ok(&self) -> bool {
         self.trigger.jump()
     }
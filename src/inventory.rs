@@ -74,6 +74,10 @@ impl Inventory {
         self.items.get(idx)
     }
 
+    pub fn get_item_count(&self) -> usize {
+        self.items.len()
+    }
+
     pub fn has_item(&self, item_id: u16) -> bool {
         self.items.iter().any(|item| item.0 == item_id)
     }
@@ -106,6 +110,16 @@ impl Inventory {
         self.weapons.retain(|weapon| weapon.wtype != wtype);
     }
 
+    /// Replaces a weapon with another one in the same slot, adding `max_ammo` to its ammo.
+    /// Level and experience are kept.
+    pub fn trade_weapon(&mut self, old: WeaponType, new: WeaponType, max_ammo: u16) {
+        if let Some(weapon) = self.get_weapon_by_type_mut(old) {
+            weapon.wtype = new;
+            weapon.max_ammo = weapon.max_ammo.saturating_add(max_ammo);
+            weapon.ammo = weapon.ammo.saturating_add(max_ammo);
+        }
+    }
+
     pub fn get_weapon(&self, idx: usize) -> Option<&Weapon> {
         self.weapons.get(idx)
     }
@@ -286,3 +300,24 @@ fn inventory_test() {
     assert_eq!(inventory.has_item_amount(4, Ordering::Equal, 4), true);
     assert_eq!(inventory.has_item_amount(4, Ordering::Less, 2), false);
 }
+
+#[test]
+fn trade_weapon_test() {
+    let mut inventory = Inventory::new();
+
+    inventory.add_weapon(WeaponType::Snake, 0);
+    inventory.add_weapon(WeaponType::PolarStar, 0);
+    let polar_star = inventory.get_weapon_by_type_mut(WeaponType::PolarStar).unwrap();
+    polar_star.level = WeaponLevel::Level2;
+    polar_star.experience = 10;
+
+    inventory.trade_weapon(WeaponType::PolarStar, WeaponType::Spur, 0);
+
+    assert_eq!(inventory.has_weapon(WeaponType::PolarStar), false);
+    assert_eq!(inventory.get_weapon(1).unwrap().wtype, WeaponType::Spur);
+    assert_eq!(inventory.get_weapon(1).unwrap().level, WeaponLevel::Level2);
+    assert_eq!(inventory.get_weapon(1).unwrap().experience, 10);
+
+    inventory.trade_weapon(WeaponType::Fireball, WeaponType::Snake, 0);
+    assert_eq!(inventory.get_weapon_count(), 2);
+}
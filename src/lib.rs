@@ -40,6 +40,7 @@ mod bullet;
 mod caret;
 mod common;
 mod components;
+mod credit_script;
 mod difficulty_modifier;
 mod encoding;
 mod engine_constants;
@@ -55,8 +56,10 @@ mod npc;
 mod physics;
 mod player;
 mod profile;
+mod replay;
 mod rng;
 mod scene;
+mod screen_effect;
 #[cfg(feature = "scripting")]
 mod scripting;
 mod settings;
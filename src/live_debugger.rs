@@ -2,6 +2,7 @@ use ggez::{Context, GameResult};
 use imgui::{CollapsingHeader, Condition, im_str, ImStr, ImString, Slider, Window};
 use itertools::Itertools;
 
+use crate::replay::ReplayState;
 use crate::scene::game_scene::GameScene;
 use crate::shared_game_state::SharedGameState;
 use crate::text_script::TextScriptExecutionState;
@@ -59,7 +60,7 @@ impl LiveDebugger {
             .resizable(false)
             .collapsed(true, Condition::FirstUseEver)
             .position([5.0, 5.0], Condition::FirstUseEver)
-            .size([400.0, 170.0], Condition::FirstUseEver)
+            .size([400.0, 190.0], Condition::FirstUseEver)
             .build(ui, || {
                 ui.text(format!(
                     "Player position: ({:.1},{:.1}), velocity: ({:.1},{:.1})",
@@ -129,6 +130,41 @@ impl LiveDebugger {
                 } else if ui.button(im_str!("Add Player 2"), [0.0, 0.0]) {
                     game_scene.add_player2();
                 }
+
+                let replay_result = match state.replay.state {
+                    ReplayState::None => {
+                        let record = ui.button(im_str!("Record Replay"), [0.0, 0.0]);
+                        ui.same_line(0.0);
+                        let play = ui.button(im_str!("Play Replay"), [0.0, 0.0]);
+
+                        if record {
+                            state.start_replay_recording(game_scene, ctx)
+                        } else if play {
+                            state.start_replay_playback(ctx)
+                        } else {
+                            Ok(())
+                        }
+                    }
+                    ReplayState::Recording => {
+                        if ui.button(im_str!("Stop Recording"), [0.0, 0.0]) {
+                            state.stop_replay(ctx)
+                        } else {
+                            Ok(())
+                        }
+                    }
+                    ReplayState::Playback => {
+                        if ui.button(im_str!("Stop Playback"), [0.0, 0.0]) {
+                            state.stop_replay(ctx)
+                        } else {
+                            Ok(())
+                        }
+                    }
+                };
+
+                if let Err(err) = replay_result {
+                    log::error!("Replay error: {:?}", err);
+                    self.error = Some(ImString::new(err.to_string()));
+                }
             });
 
         if self.map_selector_visible {
@@ -157,6 +193,7 @@ impl LiveDebugger {
                             Ok(mut scene) => {
                                 scene.inventory_player1 = game_scene.inventory_player1.clone();
                                 scene.inventory_player2 = game_scene.inventory_player2.clone();
+                                scene.hud_player1.nikumaru_counter.tick = game_scene.hud_player1.nikumaru_counter.tick;
 
                                 scene.player1 = game_scene.player1.clone();
                                 scene.player1.x = scene.stage.map.width as i32 / 2 * 16 * 0x200;
@@ -294,6 +331,16 @@ impl LiveDebugger {
                         ui.checkbox_flags(im_str!("Whimsical Star"), &mut game_scene.player1.equip.0, 128);
                         ui.checkbox_flags(im_str!("Nikumaru Counter"), &mut game_scene.player1.equip.0, 256);
                     }
+
+                    if CollapsingHeader::new(im_str!("Skip flags")).default_open(false).build(&ui) {
+                        for idx in 0..state.skip_flags.len() {
+                            let mut value = state.skip_flags[idx];
+
+                            if ui.checkbox(&im_str!("Skip flag {}", idx), &mut value) {
+                                state.skip_flags.set(idx, value);
+                            }
+                        }
+                    }
                 });
         }
 
@@ -5,7 +5,12 @@ use crate::input::combined_menu_controller::CombinedMenuController;
 use crate::shared_game_state::SharedGameState;
 
 pub struct MenuSaveInfo {
-
+    pub stage_name: String,
+    pub life: u16,
+    pub max_life: u16,
+    pub weapons: Vec<u32>,
+    /// Play time in ticks.
+    pub play_time: u32,
 }
 
 pub enum MenuEntry {
@@ -205,6 +210,31 @@ impl Menu {
 
                     state.font.draw_text(value_text.chars(), self.x as f32 + self.width as f32 - val_text_len, y, &state.constants, &mut state.texture_set, ctx)?;
                 }
+                MenuEntry::SaveData(info) => {
+                    state.font.draw_text(info.stage_name.chars(), self.x as f32 + 20.0, y, &state.constants, &mut state.texture_set, ctx)?;
+
+                    let tps = match state.timing_mode.get_tps() {
+                        0 => 50,
+                        tps => tps as u32,
+                    };
+                    let seconds = info.play_time / tps;
+                    let time_text = format!("{}:{:02}:{:02}", seconds / 3600, seconds / 60 % 60, seconds % 60);
+                    let time_text_len = state.font.text_width(time_text.chars(), &state.constants);
+                    state.font.draw_text(time_text.chars(), self.x as f32 + self.width as f32 - time_text_len, y, &state.constants, &mut state.texture_set, ctx)?;
+
+                    let life_text = format!("Life: {}/{}", info.life, info.max_life);
+                    state.font.draw_text(life_text.chars(), self.x as f32 + 20.0, y + 14.0, &state.constants, &mut state.texture_set, ctx)?;
+
+                    let batch = state.texture_set.get_or_load_batch(ctx, &state.constants, "ArmsImage")?;
+                    for (idx, &weapon_id) in info.weapons.iter().enumerate() {
+                        batch.add_rect(self.x as f32 + 100.0 + idx as f32 * 16.0, y + 12.0,
+                                       &Rect::new_size(weapon_id as u16 * 16, 0, 16, 16));
+                    }
+                    batch.draw(ctx)?;
+                }
+                MenuEntry::NewSave => {
+                    state.font.draw_text("New Save".chars(), self.x as f32 + 20.0, y + 7.0, &state.constants, &mut state.texture_set, ctx)?;
+                }
                 MenuEntry::Hidden => {}
                 _ => {}
             }
@@ -240,6 +270,8 @@ impl Menu {
                     match entry {
                         MenuEntry::Active(_) => { break; }
                         MenuEntry::Toggle(_, _) => { break; }
+                        MenuEntry::SaveData(_) => { break; }
+                        MenuEntry::NewSave => { break; }
                         _ => {}
                     }
                 } else {
@@ -267,7 +299,7 @@ impl Menu {
             }
 
             match entry {
-                MenuEntry::Active(_) | MenuEntry::Toggle(_, _) => {
+                MenuEntry::Active(_) | MenuEntry::Toggle(_, _) | MenuEntry::SaveData(_) | MenuEntry::NewSave => {
                     self.selected = idx;
                     state.sound_manager.play_sfx(18);
                     return MenuSelectionResult::Selected(idx, entry);
@@ -0,0 +1,332 @@
+use ggez::GameResult;
+use num_traits::clamp;
+
+use crate::common::{CDEG_RAD, Direction};
+use crate::npc::list::NPCList;
+use crate::npc::NPC;
+use crate::player::Player;
+use crate::rng::RNG;
+use crate::shared_game_state::SharedGameState;
+
+impl NPC {
+    pub(crate) fn tick_n184_shutter(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.x += 8 * 0x200;
+                    self.y += 8 * 0x200;
+                }
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.anim_num = 1;
+                    self.anim_counter = 0;
+                    self.npc_flags.set_ignore_solidity(true);
+                }
+
+                match self.direction {
+                    Direction::Left => self.x -= 0x80,
+                    Direction::Up => self.y -= 0x80,
+                    Direction::Right => self.x += 0x80,
+                    Direction::Bottom => self.y += 0x80,
+                    Direction::FacingPlayer => {}
+                }
+
+                self.action_counter += 1;
+                if self.action_counter % 8 == 0 {
+                    state.sound_manager.play_sfx(26);
+                }
+
+                state.quake_counter = 20;
+            }
+            20 => {
+                self.action_num = 21;
+
+                for _ in 0..4 {
+                    let mut npc = NPC::create(4, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x + self.rng.range(-12..12) * 0x200;
+                    npc.y = self.y + 16 * 0x200;
+                    npc.vel_x = self.rng.range(-0x155..0x155);
+                    npc.vel_y = self.rng.range(-0x600..0);
+
+                    let _ = npc_list.spawn(0x100, npc);
+                }
+            }
+            _ => {}
+        }
+
+        if self.action_num == 11 {
+            self.animate(10, 1, 3);
+        } else {
+            self.anim_num = 0;
+        }
+
+        self.anim_rect = state.constants.npc.n184_shutter[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n185_small_shutter(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.y += 8 * 0x200;
+                }
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.action_counter = 0;
+                    self.npc_flags.set_ignore_solidity(true);
+                }
+
+                match self.direction {
+                    Direction::Up => self.y -= 0x80,
+                    Direction::Bottom => self.y += 0x80,
+                    _ => {}
+                }
+
+                self.action_counter += 1;
+                if self.action_counter == 64 {
+                    self.action_num = 1;
+                    self.action_counter = 0;
+                    self.npc_flags.set_ignore_solidity(false);
+                }
+            }
+            _ => {}
+        }
+
+        self.anim_rect = state.constants.npc.n185_small_shutter;
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n186_lift_block(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                }
+
+                self.anim_num = 0;
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                    self.anim_counter = 0;
+                    self.npc_flags.set_ignore_solidity(true);
+                }
+
+                match self.direction {
+                    Direction::Up => self.y -= 0x80,
+                    Direction::Bottom => self.y += 0x80,
+                    _ => {}
+                }
+
+                self.animate(2, 1, 3);
+
+                self.action_counter += 1;
+                if self.action_counter == 32 {
+                    self.action_num = 1;
+                    self.action_counter = 0;
+                    self.npc_flags.set_ignore_solidity(false);
+                }
+            }
+            _ => {}
+        }
+
+        self.anim_rect = state.constants.npc.n186_lift_block[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n187_fuzz_core(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.target_x = self.x;
+                    self.target_y = self.y;
+                    self.action_counter = self.rng.range(0..50) as u16;
+
+                    // vel_x2 holds the angle the fuzz orbit around the core at
+                    self.vel_x2 = 120;
+
+                    let mut npc = NPC::create(188, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y;
+                    npc.parent_id = self.id;
+
+                    for i in 0..5 {
+                        npc.vel_x2 = i * 51;
+
+                        let _ = npc_list.spawn(0x100, npc.clone());
+                    }
+                }
+
+                self.action_counter += 1;
+                if self.action_counter >= 50 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.vel_y = 0x300;
+                }
+            }
+            2 => {
+                self.vel_x2 = (self.vel_x2 + 4) & 0xff;
+
+                let player = self.get_closest_player_mut(players);
+                self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+
+                self.vel_y += if self.y > self.target_y { -0x10 } else { 0x10 };
+                self.vel_y = clamp(self.vel_y, -0x355, 0x355);
+            }
+            _ => {}
+        }
+
+        self.y += self.vel_y;
+
+        self.animate(2, 0, 1);
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 2 };
+        self.anim_rect = state.constants.npc.n187_fuzz_core[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n188_fuzz(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                }
+
+                if let Some(parent) = self.get_parent_ref_mut(npc_list) {
+                    if parent.npc_type == 187 && parent.cond.alive() {
+                        let angle = ((self.vel_x2 + parent.vel_x2) & 0xff) as f64 * CDEG_RAD;
+
+                        self.x = parent.x + (angle.sin() * 20.0 * 512.0) as i32;
+                        self.y = parent.y + (angle.cos() * 32.0 * 512.0) as i32;
+                    } else {
+                        self.action_num = 10;
+                    }
+                } else {
+                    self.action_num = 10;
+                }
+
+                // the core is gone, scatter
+                if self.action_num == 10 {
+                    self.vel_x = self.rng.range(-0x200..0x200);
+                    self.vel_y = self.rng.range(-0x200..0x200);
+                }
+            }
+            10 => {
+                self.vel_x += if self.x > player.x { -0x20 } else { 0x20 };
+                self.vel_y += if self.y > player.y { -0x20 } else { 0x20 };
+
+                self.vel_x = clamp(self.vel_x, -0x800, 0x800);
+                self.vel_y = clamp(self.vel_y, -0x200, 0x200);
+
+                self.x += self.vel_x;
+                self.y += self.vel_y;
+            }
+            _ => {}
+        }
+
+        self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+
+        self.animate(2, 0, 1);
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 2 };
+        self.anim_rect = state.constants.npc.n188_fuzz[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n190_broken_robot(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 => {
+                self.anim_num = 0;
+            }
+            10 => {
+                state.sound_manager.play_sfx(72);
+                npc_list.create_death_smoke(self.x, self.y, 16 * 0x200, 8, state, &self.rng);
+
+                self.cond.set_alive(false);
+                return Ok(());
+            }
+            20 | 21 => {
+                if self.action_num == 20 {
+                    self.action_num = 21;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                    self.target_x = self.x;
+                }
+
+                self.action_counter += 1;
+                self.x = self.target_x + if self.action_counter / 2 % 2 != 0 { 0x200 } else { 0 };
+            }
+            _ => {}
+        }
+
+        self.anim_rect = state.constants.npc.n190_broken_robot[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n191_water_level(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 | 10 => {
+                if self.action_num == 0 {
+                    self.action_num = 10;
+                    self.target_y = self.y;
+                    self.vel_y = 0x200;
+                }
+
+                self.vel_y += if self.y < self.target_y { 4 } else { -4 };
+                self.vel_y = clamp(self.vel_y, -0x100, 0x100);
+            }
+            20 | 21 => {
+                if self.action_num == 20 {
+                    self.action_num = 21;
+                    self.action_counter = 0;
+                }
+
+                self.vel_y += if self.y < self.target_y { 4 } else { -4 };
+                self.vel_y = clamp(self.vel_y, -0x200, 0x200);
+
+                self.action_counter += 1;
+                if self.action_counter > 1000 {
+                    self.action_num = 22;
+                }
+            }
+            22 => {
+                self.vel_y += if self.y < 0 { 4 } else { -4 };
+                self.vel_y = clamp(self.vel_y, -0x200, 0x200);
+
+                if self.y < 64 * 0x200 || state.npc_super_pos.1 != 0 {
+                    self.action_num = 21;
+                    self.action_counter = 0;
+                }
+            }
+            30 => {
+                self.vel_y += if self.y < 0 { 4 } else { -4 };
+                self.vel_y = clamp(self.vel_y, -0x200, 0x200);
+            }
+            _ => {}
+        }
+
+        self.y += self.vel_y;
+        state.water_level = self.y;
+
+        Ok(())
+    }
+}
@@ -983,5 +983,275 @@ impl NPC {
 
         Ok(())
     }
-}
 
+    pub(crate) fn tick_n169_balrog_shooting_missiles(&mut self, state: &mut SharedGameState, mut players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                    self.action_counter = 30;
+
+                    let player = self.get_closest_player_mut(players);
+                    self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+                }
+
+                if self.action_counter > 0 {
+                    self.action_counter -= 1;
+                } else {
+                    self.action_num = 2;
+                    self.action_counter2 += 1;
+                }
+            }
+            2 | 3 => {
+                if self.action_num == 2 {
+                    self.action_num = 3;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                    self.anim_counter = 0;
+                }
+
+                self.anim_counter += 1;
+                if self.anim_counter > 3 {
+                    self.anim_counter = 0;
+                    self.anim_num += 1;
+
+                    if self.anim_num == 2 || self.anim_num == 4 {
+                        state.sound_manager.play_sfx(23);
+                    }
+
+                    if self.anim_num > 4 {
+                        self.anim_num = 1;
+                    }
+                }
+
+                self.vel_x += 0x20 * self.direction.vector_x();
+
+                let pi = self.get_closest_player_idx_mut(&players);
+                if self.action_counter >= 8 && (players[pi].x - self.x).abs() < 12 * 0x200
+                    && self.y - 12 * 0x200 < players[pi].y && self.y + 8 * 0x200 > players[pi].y {
+                    self.action_num = 10;
+                    self.anim_num = 5;
+                    self.vel_y2 = pi as i32;
+                    players[pi].cond.set_hidden(true);
+                    players[pi].damage(5, state, npc_list);
+                } else {
+                    self.action_counter += 1;
+
+                    // vel_x2 counts the ticks spent stuck against a wall
+                    if self.flags.hit_left_wall() || self.flags.hit_right_wall() {
+                        self.vel_x2 += 1;
+                    } else {
+                        self.vel_x2 = 0;
+                    }
+
+                    if self.action_counter > 75 {
+                        self.action_num = 9;
+                        self.anim_num = 0;
+                    } else if self.vel_x2 > 5 || (self.action_counter2 % 2 == 0 && self.action_counter > 25) {
+                        self.vel_x2 = 0;
+                        self.action_num = 4;
+                        self.action_counter = 0;
+                        self.anim_num = 7;
+                        self.vel_y = -0x5ff;
+                    }
+                }
+            }
+            4 => {
+                let player = self.get_closest_player_mut(players);
+                self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+
+                self.action_counter += 1;
+                if self.action_counter < 30 && self.action_counter % 6 == 1 {
+                    let mut npc = NPC::create(170, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y;
+                    npc.direction = self.direction;
+
+                    let _ = npc_list.spawn(0x100, npc);
+
+                    state.sound_manager.play_sfx(39);
+                }
+
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 9;
+                    self.anim_num = 8;
+
+                    state.quake_counter = 30;
+                    state.sound_manager.play_sfx(26);
+                }
+            }
+            9 => {
+                self.vel_x = self.vel_x * 4 / 5;
+
+                if self.vel_x == 0 {
+                    self.action_num = 0;
+                }
+            }
+            10 => {
+                let player = &mut players[self.vel_y2 as usize];
+                player.x = self.x;
+                player.y = self.y;
+
+                self.vel_x = self.vel_x * 4 / 5;
+
+                if self.vel_x == 0 {
+                    self.action_num = 11;
+                    self.action_counter = 0;
+                    self.anim_num = 5;
+                    self.anim_counter = 0;
+                }
+            }
+            11 => {
+                let player = &mut players[self.vel_y2 as usize];
+                player.x = self.x;
+                player.y = self.y;
+
+                self.anim_counter += 1;
+                if self.anim_counter > 2 {
+                    self.anim_counter = 0;
+                    self.anim_num += 1;
+
+                    if self.anim_num > 6 {
+                        self.anim_num = 5;
+                    }
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 100 {
+                    self.action_num = 20;
+                }
+            }
+            20 | 21 => {
+                if self.action_num == 20 {
+                    let player = &mut players[self.vel_y2 as usize];
+                    state.sound_manager.play_sfx(25);
+                    player.cond.set_hidden(false);
+
+                    self.direction = self.direction.opposite();
+
+                    player.direction = self.direction;
+                    player.x += 4 * 0x200 * self.direction.vector_x();
+                    player.y -= 8 * 0x200;
+                    player.vel_x = 0x5ff * self.direction.vector_x();
+                    player.vel_y = -0x200;
+
+                    self.action_num = 21;
+                    self.action_counter = 0;
+                    self.anim_num = 7;
+                }
+
+                self.action_counter += 1;
+                if self.action_counter >= 50 {
+                    self.action_num = 0;
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_x = clamp(self.vel_x, -0x300, 0x300);
+        self.vel_y += 0x20;
+
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 9 };
+        self.anim_rect = state.constants.npc.n169_missile[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n170_balrog_missile(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        if (self.direction == Direction::Left && self.flags.hit_left_wall())
+            || (self.direction == Direction::Right && self.flags.hit_right_wall()) {
+            state.sound_manager.play_sfx(44);
+            npc_list.create_death_smoke(self.x, self.y, 0, 3, state, &self.rng);
+            self.vanish(state);
+            return Ok(());
+        }
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+
+                    // launched backwards first, then accelerates towards the player
+                    if self.direction == Direction::Left {
+                        self.vel_x = self.rng.range(1..2) * 0x200;
+                    } else {
+                        self.vel_x = self.rng.range(-2..-1) * 0x200;
+                    }
+
+                    self.vel_y = self.rng.range(-2..0) * 0x200;
+                }
+
+                self.action_counter2 += 1;
+                self.vel_x += self.direction.vector_x() * 0x20;
+
+                if self.action_counter2 % 3 == 1 {
+                    state.create_caret(self.x - self.direction.vector_x() * 8 * 0x200, self.y, CaretType::Exhaust, self.direction.opposite());
+                }
+
+                if self.action_counter2 < 50 {
+                    let player = self.get_closest_player_mut(players);
+                    self.vel_y += if self.y < player.y { 0x20 } else { -0x20 };
+                } else {
+                    self.vel_y = 0;
+                }
+
+                self.anim_num += 1;
+                if self.anim_num > 1 {
+                    self.anim_num = 0;
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_x = clamp(self.vel_x, -0x400, 0x400);
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 2 };
+        self.anim_rect = state.constants.npc.n170_balrog_missile[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n306_balrog_nurse(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                    self.y += 4 * 0x200;
+                }
+
+                if self.rng.range(0..120) == 10 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 8 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            _ => {}
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 2 };
+        self.anim_rect = state.constants.npc.n306_balrog_nurse[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+}
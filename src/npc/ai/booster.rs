@@ -1,6 +1,7 @@
 use ggez::GameResult;
 
 use crate::common::Direction;
+use crate::npc::list::NPCList;
 use crate::npc::NPC;
 use crate::rng::RNG;
 use crate::shared_game_state::SharedGameState;
@@ -92,4 +93,60 @@ impl NPC {
 
         Ok(())
     }
+
+    pub(crate) fn tick_n167_booster_falling(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 => {
+                self.action_num = 1;
+                self.anim_num = 1;
+            }
+            10 => {
+                self.anim_num = 0;
+
+                self.vel_y += 0x40;
+                if self.vel_y > 0x5ff {
+                    self.vel_y = 0x5ff;
+                }
+
+                self.y += self.vel_y;
+            }
+            20 | 21 => {
+                if self.action_num == 20 {
+                    self.action_num = 21;
+                    self.action_counter = 0;
+                    self.anim_num = 0;
+
+                    state.sound_manager.play_sfx(29);
+                }
+
+                self.anim_num += 1;
+                if self.anim_num > 2 {
+                    self.anim_num = 1;
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 100 {
+                    let mut npc = NPC::create(4, &state.npc_table);
+                    npc.cond.set_alive(true);
+
+                    for _ in 0..4 {
+                        npc.x = self.x + self.rng.range(-12..12) * 0x200;
+                        npc.y = self.y + self.rng.range(-12..12) * 0x200;
+                        npc.vel_x = self.rng.range(-341..341);
+                        npc.vel_y = self.rng.range(-0x600..0);
+
+                        let _ = npc_list.spawn(0x100, npc.clone());
+                    }
+
+                    self.cond.set_alive(false);
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+
+        self.anim_rect = state.constants.npc.n167_booster_falling[self.anim_num as usize];
+
+        Ok(())
+    }
 }
@@ -2,6 +2,7 @@ use ggez::GameResult;
 use num_traits::{abs, clamp};
 
 use crate::common::Direction;
+use crate::npc::list::NPCList;
 use crate::npc::NPC;
 use crate::player::Player;
 use crate::rng::RNG;
@@ -87,7 +88,7 @@ impl NPC {
         Ok(())
     }
 
-    pub(crate) fn tick_n061_king(&mut self, state: &mut SharedGameState) -> GameResult {
+    pub(crate) fn tick_n061_king(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
         match self.action_num {
             0 | 1 => {
                 if self.action_num == 0 {
@@ -183,7 +184,17 @@ impl NPC {
                     self.vel_x = 0x400;
                 }
             }
-            // todo: 20 - king's sword
+            20 => {
+                let mut npc = NPC::create(145, &state.npc_table);
+                npc.cond.set_alive(true);
+                npc.direction = Direction::Right;
+                npc.parent_id = self.id;
+
+                let _ = npc_list.spawn(0x100, npc);
+
+                self.anim_num = 0;
+                self.action_num = 0;
+            }
             // todo: 30,31 - pre misery attack
             // todo: 40,42 - dying
             // todo: 60,61 - leap
@@ -370,4 +381,26 @@ impl NPC {
 
         Ok(())
     }
+
+    pub(crate) fn tick_n145_king_sword(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        if self.action_num == 0 {
+            let parent = match self.get_parent_ref_mut(npc_list) {
+                Some(parent) => parent,
+                None => {
+                    self.cond.set_alive(false);
+                    return Ok(());
+                }
+            };
+
+            // held out in front of the king
+            self.direction = parent.direction;
+            self.x = parent.x + parent.direction.vector_x() * 10 * 0x200;
+            self.y = parent.y;
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 1 };
+        self.anim_rect = state.constants.npc.n145_king_sword[dir_offset];
+
+        Ok(())
+    }
 }
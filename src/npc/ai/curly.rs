@@ -0,0 +1,785 @@
+use ggez::GameResult;
+use num_traits::{abs, clamp};
+
+use crate::bullet::BulletManager;
+use crate::caret::CaretType;
+use crate::common::{Direction, Rect};
+use crate::npc::list::NPCList;
+use crate::npc::NPC;
+use crate::player::{Player, TargetPlayer};
+use crate::rng::RNG;
+use crate::shared_game_state::SharedGameState;
+
+impl NPC {
+    pub(crate) fn tick_n117_curly(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    if self.direction == Direction::FacingPlayer {
+                        self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+                    }
+
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                }
+
+                self.vel_x = 0;
+                self.vel_y += 0x40;
+            }
+            3 | 4 => {
+                if self.action_num == 3 {
+                    self.action_num = 4;
+                    self.anim_num = 1;
+                    self.anim_counter = 0;
+                }
+
+                self.animate(4, 1, 4);
+
+                self.vel_y += 0x40;
+                self.vel_x = self.direction.vector_x() * 0x200;
+            }
+            5 => {
+                self.action_num = 6;
+                self.anim_num = 5;
+
+                npc_list.create_death_smoke(self.x, self.y, self.display_bounds.right, 8, state, &self.rng);
+            }
+            6 => {
+                self.anim_num = 5;
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.anim_num = 1;
+                    self.anim_counter = 0;
+
+                    self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+                }
+
+                self.animate(4, 1, 4);
+
+                self.x += self.direction.vector_x() * 0x200;
+
+                if abs(player.x - self.x) < 20 * 0x200 {
+                    self.action_num = 0;
+                }
+            }
+            20 => {
+                self.vel_x = 0;
+                self.anim_num = 6;
+            }
+            21 => {
+                self.vel_x = 0;
+                self.anim_num = 9;
+            }
+            30 | 31 => {
+                if self.action_num == 30 {
+                    self.action_num = 31;
+                    self.action_counter = 0;
+                    self.vel_y = -0x400;
+                }
+
+                self.anim_num = 7;
+                self.vel_x = self.direction.vector_x() * 0x200;
+                self.vel_y += 0x40;
+
+                if self.action_counter > 0 && self.flags.hit_bottom_wall() {
+                    self.action_num = 32;
+                }
+
+                self.action_counter += 1;
+            }
+            32 => {
+                self.vel_y += 0x40;
+                self.anim_num = 8;
+                self.vel_x = 0;
+            }
+            70 | 71 => {
+                if self.action_num == 70 {
+                    self.action_num = 71;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                    self.anim_counter = 0;
+                }
+
+                // walks backwards
+                self.x -= self.direction.vector_x() * 0x100;
+
+                self.animate(8, 1, 4);
+            }
+            _ => {}
+        }
+
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 10 };
+        self.anim_rect = state.constants.npc.n117_curly[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n118_curly_boss(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList, bullet_manager: &BulletManager) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 => {
+                self.action_num = 1;
+                self.anim_num = 0;
+                self.anim_counter = 0;
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.action_counter = self.rng.range(50..100) as u16;
+                    self.anim_num = 0;
+                    self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+                    self.npc_flags.set_shootable(true);
+                    self.npc_flags.set_invulnerable(false);
+                }
+
+                if self.action_counter > 0 {
+                    self.action_counter -= 1;
+                } else {
+                    self.action_num = 13;
+                }
+            }
+            13 | 14 => {
+                if self.action_num == 13 {
+                    self.action_num = 14;
+                    self.anim_num = 3;
+                    self.action_counter = self.rng.range(50..100) as u16;
+                    self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+                }
+
+                self.animate(2, 3, 6);
+
+                self.vel_x += self.direction.vector_x() * 0x40;
+
+                if self.action_counter > 0 {
+                    self.action_counter -= 1;
+                } else {
+                    self.npc_flags.set_shootable(true);
+                    self.action_num = 20;
+                    self.action_counter = 0;
+
+                    state.sound_manager.play_sfx(103);
+                }
+            }
+            20 => {
+                self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+                self.vel_x = 8 * self.vel_x / 9;
+
+                self.anim_num += 1;
+                if self.anim_num > 1 {
+                    self.anim_num = 0;
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 50 {
+                    self.action_num = 21;
+                    self.action_counter = 0;
+                }
+            }
+            21 => {
+                self.action_counter += 1;
+                if self.action_counter % 4 == 1 {
+                    let shoot_up = abs(player.x - self.x) < 48 * 0x200 && player.y < self.y - 32 * 0x200;
+
+                    let mut npc = NPC::create(123, &state.npc_table);
+                    npc.cond.set_alive(true);
+
+                    if shoot_up {
+                        self.anim_num = 2;
+
+                        npc.x = self.x;
+                        npc.y = self.y - 8 * 0x200;
+                        npc.direction = Direction::Up;
+                    } else {
+                        self.anim_num = 0;
+
+                        npc.x = self.x + self.direction.vector_x() * 8 * 0x200;
+                        npc.y = self.y + 4 * 0x200;
+                        npc.direction = self.direction;
+
+                        // recoil
+                        self.x -= self.direction.vector_x() * 0x200;
+                    }
+
+                    let _ = npc_list.spawn(0x100, npc);
+                }
+
+                if self.action_counter > 30 {
+                    self.action_num = 10;
+                }
+            }
+            30 => {
+                self.vel_x = 8 * self.vel_x / 9;
+
+                self.action_counter += 1;
+                if self.action_counter > 30 {
+                    self.action_num = 10;
+                }
+            }
+            _ => {}
+        }
+
+        // shields herself from missile explosions
+        if self.action_num > 10 && self.action_num < 30 && bullet_manager.count_bullets_type_idx_all(6) > 0 {
+            self.action_counter = 0;
+            self.action_num = 30;
+            self.anim_num = 8;
+            self.vel_x = 0;
+            self.npc_flags.set_shootable(false);
+            self.npc_flags.set_invulnerable(true);
+        }
+
+        self.vel_y += 0x20;
+
+        self.vel_x = clamp(self.vel_x, -0x1ff, 0x1ff);
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 9 };
+        self.anim_rect = state.constants.npc.n118_curly_boss[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n123_curly_boss_bullet(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 => {
+                self.action_num = 1;
+
+                state.create_caret(self.x, self.y, CaretType::Shoot, Direction::Left);
+                state.sound_manager.play_sfx(32);
+
+                match self.direction {
+                    Direction::Left => {
+                        self.vel_x = -0x1000;
+                        self.vel_y = self.rng.range(-0x80..0x80);
+                    }
+                    Direction::Up => {
+                        self.vel_x = self.rng.range(-0x80..0x80);
+                        self.vel_y = -0x1000;
+                    }
+                    Direction::Right => {
+                        self.vel_x = 0x1000;
+                        self.vel_y = self.rng.range(-0x80..0x80);
+                    }
+                    Direction::Bottom => {
+                        self.vel_x = self.rng.range(-0x80..0x80);
+                        self.vel_y = 0x1000;
+                    }
+                    Direction::FacingPlayer => {}
+                }
+            }
+            1 => {
+                let hit_wall = match self.direction {
+                    Direction::Left => self.flags.hit_left_wall(),
+                    Direction::Up => self.flags.hit_top_wall(),
+                    Direction::Right => self.flags.hit_right_wall(),
+                    Direction::Bottom => self.flags.hit_bottom_wall(),
+                    Direction::FacingPlayer => false,
+                };
+
+                if hit_wall {
+                    state.create_caret(self.x, self.y, CaretType::ProjectileDissipation, Direction::Right);
+                    state.sound_manager.play_sfx(28);
+                    self.cond.set_alive(false);
+                    return Ok(());
+                }
+
+                self.x += self.vel_x;
+                self.y += self.vel_y;
+            }
+            _ => {}
+        }
+
+        self.anim_rect = state.constants.npc.n123_curly_boss_bullet[self.direction as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n165_curly_collapsed(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.y += 10 * 0x200;
+                }
+
+                let player = self.get_closest_player_mut(players);
+
+                // she looks up when the player comes close
+                self.anim_num = if self.direction == Direction::Left {
+                    0
+                } else if abs(player.x - self.x) < 32 * 0x200 && abs(player.y - self.y) < 16 * 0x200 {
+                    2
+                } else {
+                    1
+                };
+            }
+            _ => {}
+        }
+
+        self.anim_rect = state.constants.npc.n165_curly_collapsed[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n180_curly_ai(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        if self.action_num >= 100 {
+            if state.npc_curly_counter > 0 {
+                state.npc_curly_counter -= 1;
+                self.target_x = state.npc_curly_target.0;
+                self.target_y = state.npc_curly_target.1;
+            } else {
+                self.target_x = player.x;
+                self.target_y = player.y;
+            }
+
+            // every now and then she jumps while shooting
+            if state.npc_curly_counter == 60 && self.flags.hit_bottom_wall() && self.rng.range(0..2) != 0 {
+                self.action_counter2 = 0;
+                self.vel_y = -0x600;
+                self.anim_num = 1;
+                self.action_num = if self.x > self.target_x { 210 } else { 310 };
+
+                state.sound_manager.play_sfx(15);
+            }
+        }
+
+        match self.action_num {
+            0 => {
+                self.action_num = 1;
+                self.target_x = self.x;
+                self.target_y = self.y;
+            }
+            20 => {
+                self.x = player.x;
+                self.y = player.y;
+                self.action_num = 100;
+                self.anim_num = 0;
+
+                self.tick_n180_curly_ai_spawn_equipment(state, npc_list);
+            }
+            40 | 41 => {
+                if self.action_num == 40 {
+                    self.action_num = 41;
+                    self.action_counter = 0;
+                    self.anim_num = 10;
+                }
+
+                self.action_counter += 1;
+                if self.action_counter == 750 {
+                    self.npc_flags.set_interactable(false);
+                    self.anim_num = 0;
+                }
+
+                if self.action_counter > 1000 {
+                    self.action_num = 100;
+                    self.anim_num = 0;
+
+                    self.tick_n180_curly_ai_spawn_equipment(state, npc_list);
+                }
+            }
+            100 => {
+                self.anim_num = 0;
+                self.vel_x = 7 * self.vel_x / 8;
+                self.action_counter2 = 0;
+
+                if self.x > self.target_x + 16 * 0x200 {
+                    self.action_num = 300;
+                    self.anim_num = 1;
+                    self.direction = Direction::Left;
+                    self.action_counter = self.rng.range(20..60) as u16;
+                } else if self.x < self.target_x - 16 * 0x200 {
+                    self.action_num = 200;
+                    self.anim_num = 1;
+                    self.direction = Direction::Right;
+                    self.action_counter = self.rng.range(20..60) as u16;
+                }
+            }
+            200 => {
+                self.vel_x += 0x20;
+                self.direction = Direction::Right;
+
+                if self.flags.hit_right_wall() {
+                    self.action_counter2 += 1;
+                } else {
+                    self.action_counter2 = 0;
+                }
+            }
+            210 => {
+                self.vel_x += 0x20;
+                self.direction = Direction::Right;
+
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 100;
+                }
+            }
+            300 => {
+                self.vel_x -= 0x20;
+                self.direction = Direction::Left;
+
+                if self.flags.hit_left_wall() {
+                    self.action_counter2 += 1;
+                } else {
+                    self.action_counter2 = 0;
+                }
+            }
+            310 => {
+                self.vel_x -= 0x20;
+                self.direction = Direction::Left;
+
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 100;
+                }
+            }
+            _ => {}
+        }
+
+        let dist_x = abs(self.x - self.target_x);
+        let dist_y = self.y - self.target_y;
+        let looking_up = dist_x + 2 * 0x200 < dist_y;
+
+        match self.action_num {
+            100 => {
+                self.anim_num = if looking_up { 5 } else { 0 };
+            }
+            210 | 310 => {
+                self.anim_num = if looking_up { 6 } else { 1 };
+            }
+            200 | 300 => {
+                self.anim_counter += 1;
+
+                let frame = self.anim_counter / 4 % 4;
+                self.anim_num = if looking_up { 6 + frame } else { 1 + frame };
+
+                if self.action_counter > 0 {
+                    self.action_counter -= 1;
+
+                    // jump over obstacles she keeps running into
+                    if self.flags.hit_bottom_wall() && self.action_counter2 > 10 {
+                        self.action_counter2 = 0;
+                        self.vel_y = -0x600;
+                        self.action_num += 10;
+                        self.anim_num = 1;
+
+                        state.sound_manager.play_sfx(15);
+                    }
+                } else {
+                    self.action_num = 100;
+                    self.anim_num = 0;
+                }
+            }
+            _ => {}
+        }
+
+        if self.action_num >= 100 && self.action_num < 500 {
+            if self.flags.in_water() && abs(self.x - player.x) > 80 * 0x200 {
+                self.vel_y += 0x10;
+            } else {
+                self.vel_y += 0x33;
+            }
+        }
+
+        self.vel_x = clamp(self.vel_x, -0x300, 0x300);
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        if self.action_num >= 100 && !self.flags.hit_bottom_wall() {
+            self.anim_num = if looking_up { 6 } else { 1 };
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 11 };
+        self.anim_rect = state.constants.npc.n180_curly_ai[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    fn tick_n180_curly_ai_spawn_equipment(&mut self, state: &mut SharedGameState, npc_list: &NPCList) {
+        let mut npc = NPC::create(183, &state.npc_table);
+        npc.cond.set_alive(true);
+        npc.x = self.x;
+        npc.y = self.y;
+        npc.parent_id = self.id;
+
+        let _ = npc_list.spawn(0x100, npc);
+
+        // she carries the polar star if the player traded the machine gun away
+        let weapon_type = if let Some(true) = state.game_flags.get(563) { 182 } else { 181 };
+
+        let mut npc = NPC::create(weapon_type, &state.npc_table);
+        npc.cond.set_alive(true);
+        npc.x = self.x;
+        npc.y = self.y;
+        npc.parent_id = self.id;
+
+        let _ = npc_list.spawn(0x100, npc);
+    }
+
+    pub(crate) fn tick_n181_curly_ai_machine_gun(&mut self, state: &mut SharedGameState, npc_list: &NPCList, bullet_manager: &mut BulletManager) -> GameResult {
+        self.tick_curly_ai_weapon(state, npc_list, bullet_manager, 12, 6)?;
+        self.anim_rect = state.constants.npc.n181_curly_ai_machine_gun[self.anim_num as usize + self.direction as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n182_curly_ai_polar_star(&mut self, state: &mut SharedGameState, npc_list: &NPCList, bullet_manager: &mut BulletManager) -> GameResult {
+        self.tick_curly_ai_weapon(state, npc_list, bullet_manager, 6, 12)?;
+        self.anim_rect = state.constants.npc.n182_curly_ai_polar_star[self.anim_num as usize + self.direction as usize];
+
+        Ok(())
+    }
+
+    fn tick_curly_ai_weapon(&mut self, state: &mut SharedGameState, npc_list: &NPCList, bullet_manager: &mut BulletManager, btype: u16, interval: u16) -> GameResult {
+        let parent = match self.get_parent_ref_mut(npc_list) {
+            Some(parent) if parent.npc_type == 180 => parent,
+            _ => {
+                self.cond.set_alive(false);
+                return Ok(());
+            }
+        };
+
+        self.direction = parent.direction;
+
+        if parent.anim_num < 5 {
+            self.anim_num = 0;
+            self.x = parent.x + parent.direction.vector_x() * 8 * 0x200;
+            self.y = parent.y;
+        } else {
+            self.anim_num = 1;
+            self.x = parent.x;
+            self.y = parent.y - 10 * 0x200;
+        }
+
+        if [1, 3, 6, 8].contains(&parent.anim_num) {
+            self.y -= 0x200;
+        }
+
+        match self.action_num {
+            0 => {
+                if state.npc_curly_counter > 0 {
+                    self.action_num = 10;
+                    self.action_counter = 0;
+                }
+            }
+            10 => {
+                self.action_counter += 1;
+                if self.action_counter % interval == 1 {
+                    let (x, y, direction) = if self.anim_num == 0 {
+                        (self.x + self.direction.vector_x() * 4 * 0x200, self.y + 3 * 0x200, self.direction)
+                    } else {
+                        (self.x + self.direction.vector_x() * 2 * 0x200, self.y - 4 * 0x200, Direction::Up)
+                    };
+
+                    bullet_manager.create_bullet(x, y, btype, TargetPlayer::Player1, direction, &state.constants);
+                    state.create_caret(x, y, CaretType::Shoot, Direction::Left);
+
+                    if btype == 6 {
+                        state.sound_manager.play_sfx(49);
+                    }
+                }
+
+                if self.action_counter == 60 {
+                    self.action_num = 0;
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n183_curly_air_tank_bubble(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        let parent = match self.get_parent_ref_mut(npc_list) {
+            Some(parent) => parent,
+            None => {
+                self.cond.set_alive(false);
+                return Ok(());
+            }
+        };
+
+        if self.action_num == 0 {
+            self.action_num = 1;
+            self.x = parent.x;
+            self.y = parent.y;
+        }
+
+        self.x += (parent.x - self.x) / 2;
+        self.y += (parent.y - self.y) / 2;
+
+        let in_water = parent.flags.in_water();
+
+        self.animate(1, 0, 1);
+
+        self.anim_rect = if in_water {
+            state.constants.npc.n183_curly_air_tank_bubble[self.anim_num as usize]
+        } else {
+            Rect::new(0, 0, 0, 0)
+        };
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n259_curly_unconcious(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.npc_flags.set_interactable(false);
+                }
+
+                let player = self.get_closest_player_mut(players);
+
+                self.direction = player.direction;
+                self.x = player.x - self.direction.vector_x() * 3 * 0x200;
+                self.y = player.y - 4 * 0x200;
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.vel_x = 0x40;
+                    self.vel_y = -0x20;
+                    self.direction = Direction::Left;
+                }
+
+                if self.y < 64 * 0x200 {
+                    self.vel_y = 0x20;
+                }
+
+                self.x += self.vel_x;
+                self.y += self.vel_y;
+            }
+            20 => {
+                npc_list.create_death_smoke_up(self.x, self.y, 16 * 0x200, 64, state, &self.rng);
+                self.vanish(state);
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 1 };
+        self.anim_rect = state.constants.npc.n259_curly_unconcious[dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n303_curly_machine_gun(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        let parent = match self.get_parent_ref_mut(npc_list) {
+            Some(parent) => parent,
+            None => {
+                self.cond.set_alive(false);
+                return Ok(());
+            }
+        };
+
+        self.direction = parent.direction;
+        self.x = parent.x + parent.direction.vector_x() * 8 * 0x200;
+        self.y = parent.y;
+
+        if parent.anim_num == 3 || parent.anim_num == 5 {
+            self.y -= 0x200;
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 2 };
+        self.anim_rect = state.constants.npc.n303_curly_machine_gun[dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n320_curly_carried(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        if self.action_num == 0 {
+            self.action_num = 1;
+            self.x = player.x;
+            self.y = player.y;
+
+            let mut npc = NPC::create(321, &state.npc_table);
+            npc.cond.set_alive(true);
+            npc.x = self.x;
+            npc.y = self.y;
+            npc.parent_id = self.id;
+
+            let _ = npc_list.spawn(0x100, npc);
+        }
+
+        // she rides on the player's back and aims where the player is looking
+        if player.up {
+            self.anim_num = 1;
+        } else if player.down && !player.flags.hit_bottom_wall() {
+            self.anim_num = 2;
+        } else {
+            self.anim_num = 0;
+        }
+
+        self.direction = player.direction;
+
+        self.target_x = player.x - player.direction.vector_x() * 6 * 0x200;
+        self.target_y = player.y - if self.anim_num == 1 { 8 * 0x200 } else { 16 * 0x200 };
+
+        self.x += (self.target_x - self.x) / 2;
+        self.y += (self.target_y - self.y) / 2;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 3 };
+        self.anim_rect = state.constants.npc.n320_curly_carried[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n321_curly_nemesis(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList, bullet_manager: &mut BulletManager) -> GameResult {
+        let parent = match self.get_parent_ref_mut(npc_list) {
+            Some(parent) => parent,
+            None => {
+                self.cond.set_alive(false);
+                return Ok(());
+            }
+        };
+
+        self.direction = parent.direction;
+        self.anim_num = parent.anim_num;
+
+        let (bullet_direction, off_x, off_y) = match self.anim_num {
+            1 => (Direction::Up, 0, -10 * 0x200),
+            2 => (Direction::Bottom, 0, 10 * 0x200),
+            _ => (self.direction, self.direction.vector_x() * 8 * 0x200, 0),
+        };
+
+        self.x = parent.x + off_x;
+        self.y = parent.y + off_y;
+
+        let player = self.get_closest_player_mut(players);
+
+        // fires alongside the player
+        if player.controller.trigger_shoot() && bullet_manager.count_bullets(43, TargetPlayer::Player1) < 2 {
+            bullet_manager.create_bullet(self.x, self.y, 43, TargetPlayer::Player1, bullet_direction, &state.constants);
+            state.create_caret(self.x, self.y, CaretType::Shoot, Direction::Left);
+            state.sound_manager.play_sfx(117);
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 3 };
+        self.anim_rect = state.constants.npc.n321_curly_nemesis[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+}
@@ -0,0 +1,804 @@
+use ggez::GameResult;
+use num_traits::{abs, clamp};
+
+use crate::common::{CDEG_RAD, Direction};
+use crate::npc::list::NPCList;
+use crate::npc::NPC;
+use crate::player::Player;
+use crate::rng::RNG;
+use crate::shared_game_state::SharedGameState;
+use crate::stage::Stage;
+
+impl NPC {
+    pub(crate) fn tick_n139_doctor(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.vel_x = 0;
+                    self.vel_y = 0;
+                    self.y -= 8 * 0x200;
+                }
+
+                self.anim_num = if self.flags.hit_bottom_wall() { 0 } else { 2 };
+                self.vel_y += 0x40;
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.anim_num = 1;
+                    self.anim_counter = 0;
+                    self.action_counter2 = 0;
+                }
+
+                self.anim_counter += 1;
+                if self.anim_counter > 6 {
+                    self.anim_counter = 0;
+                    self.anim_num += 1;
+                }
+
+                if self.anim_num > 1 {
+                    self.anim_num = 0;
+
+                    self.action_counter2 += 1;
+                    if self.action_counter2 > 7 {
+                        self.anim_num = 0;
+                        self.action_num = 1;
+                    }
+                }
+            }
+            20 | 21 => {
+                if self.action_num == 20 {
+                    self.action_num = 21;
+                    self.action_counter = 0;
+                    self.anim_num = 2;
+                    self.target_y = self.y - 32 * 0x200;
+                }
+
+                self.vel_y += if self.y < self.target_y { 0x20 } else { -0x20 };
+                self.vel_y = clamp(self.vel_y, -0x200, 0x200);
+            }
+            30 | 31 => {
+                if self.action_num == 30 {
+                    self.action_num = 31;
+                    self.vel_x = 0;
+                    self.vel_y = 0;
+                    self.action_counter = (self.anim_rect.bottom - self.anim_rect.top) * 2;
+
+                    state.sound_manager.play_sfx(29);
+                }
+
+                self.anim_num = 0;
+
+                if self.action_counter > 0 {
+                    self.action_counter -= 1;
+                } else {
+                    self.cond.set_alive(false);
+                    return Ok(());
+                }
+            }
+            40 | 41 => {
+                if self.action_num == 40 {
+                    self.action_num = 41;
+                    self.action_counter = 0;
+                    self.vel_x = 0;
+                    self.vel_y = 0;
+
+                    state.sound_manager.play_sfx(29);
+                }
+
+                self.anim_num = 2;
+
+                self.action_counter += 1;
+                if self.action_counter >= 64 {
+                    self.action_num = 20;
+                }
+            }
+            _ => {}
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 3 };
+        self.anim_rect = state.constants.npc.n139_doctor[self.anim_num as usize + dir_offset];
+
+        if self.action_num == 31 || self.action_num == 41 {
+            // teleporting in and out
+            self.anim_rect.bottom = self.anim_rect.top + self.action_counter / 2;
+
+            if self.action_counter / 2 % 2 != 0 {
+                self.anim_rect.left += 1;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n256_doctor_facing_away(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.y -= 8 * 0x200;
+
+                    state.npc_super_pos = (0, 0);
+                }
+
+                self.anim_num = 0;
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.anim_counter = 0;
+                    self.anim_num = 0;
+                    self.action_counter2 = 0;
+                }
+
+                self.anim_counter += 1;
+                if self.anim_counter > 5 {
+                    self.anim_counter = 0;
+                    self.anim_num += 1;
+                }
+
+                if self.anim_num > 1 {
+                    self.anim_num = 0;
+
+                    self.action_counter2 += 1;
+                    if self.action_counter2 > 5 {
+                        self.action_num = 1;
+                    }
+                }
+            }
+            20 | 21 => {
+                if self.action_num == 20 {
+                    self.action_num = 21;
+                }
+
+                self.anim_num = 2;
+            }
+            40 | 41 => {
+                if self.action_num == 40 {
+                    self.action_num = 41;
+
+                    let mut npc = NPC::create(257, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x - 14 * 0x200;
+                    npc.y = self.y - 16 * 0x200;
+
+                    let _ = npc_list.spawn(0x100, npc.clone());
+
+                    npc.direction = Direction::Right;
+
+                    let _ = npc_list.spawn(0xaa, npc);
+                }
+
+                self.anim_num = 4;
+            }
+            50 | 51 => {
+                if self.action_num == 50 {
+                    self.action_num = 51;
+                    self.anim_num = 4;
+                    self.anim_counter = 0;
+                    self.action_counter2 = 0;
+                }
+
+                self.anim_counter += 1;
+                if self.anim_counter > 5 {
+                    self.anim_counter = 0;
+                    self.anim_num += 1;
+                }
+
+                if self.anim_num > 5 {
+                    self.anim_num = 4;
+
+                    self.action_counter2 += 1;
+                    if self.action_counter2 > 5 {
+                        self.action_num = 41;
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        self.anim_rect = state.constants.npc.n256_doctor_facing_away[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n257_red_crystal(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                }
+
+                if state.npc_super_pos.0 != 0 {
+                    self.action_num = 10;
+                }
+            }
+            10 => {
+                // orbits around whoever is holding the crystal
+                self.vel_x += if self.x < state.npc_super_pos.0 { 0x55 } else { -0x55 };
+                self.vel_y += if self.y < state.npc_super_pos.1 { 0x55 } else { -0x55 };
+
+                self.x += self.vel_x;
+                self.y += self.vel_y;
+            }
+            _ => {}
+        }
+
+        self.animate(3, 0, 1);
+
+        // hidden while passing behind the holder
+        if (self.direction == Direction::Left && self.vel_x > 0) || (self.direction == Direction::Right && self.vel_x < 0) {
+            self.anim_num = 2;
+        }
+
+        self.anim_rect = state.constants.npc.n257_red_crystal[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n263_doctor_boss(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList, stage: &Stage) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 => {
+                self.action_num = 1;
+                self.y += 8 * 0x200;
+                self.anim_num = 3;
+            }
+            2 => {
+                self.action_counter += 1;
+                self.anim_num = if self.action_counter / 2 % 2 != 0 { 0 } else { 3 };
+
+                if self.action_counter > 50 {
+                    self.action_num = 10;
+                }
+            }
+            10 => {
+                self.vel_y += 0x80;
+                self.npc_flags.set_shootable(true);
+                self.damage = 3;
+
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 20;
+                    self.action_counter = 0;
+                    self.anim_num = 0;
+                    self.vel_y2 = self.life as i32;
+
+                    self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+                }
+            }
+            20 => {
+                self.action_counter += 1;
+
+                // taking enough damage makes him attack sooner
+                if self.action_counter < 50 && (self.life as i32) < self.vel_y2 - 20 {
+                    self.action_counter = 50;
+                }
+
+                if self.action_counter == 50 {
+                    self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+                    self.anim_num = 4;
+                }
+
+                if self.action_counter == 80 || self.action_counter == 130 {
+                    self.anim_num = if self.action_counter == 80 { 5 } else { 6 };
+
+                    state.sound_manager.play_sfx(25);
+
+                    let mut npc = NPC::create(264, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x + self.direction.vector_x() * 32 * 0x200;
+                    npc.y = self.y;
+                    npc.direction = self.direction;
+
+                    let _ = npc_list.spawn(0x100, npc.clone());
+
+                    // the second wave runs on the opposite phase
+                    npc.vel_x2 = 0x80;
+
+                    let _ = npc_list.spawn(0x100, npc);
+                }
+
+                if self.action_counter == 120 || self.action_counter == 160 {
+                    self.anim_num = 4;
+                }
+
+                if self.action_counter > 180 {
+                    self.action_counter2 += 1;
+                    self.action_num = if self.action_counter2 % 2 == 0 { 30 } else { 100 };
+                }
+            }
+            30 | 31 => {
+                if self.action_num == 30 {
+                    self.action_num = 31;
+                    self.action_counter = 0;
+                    self.anim_num = 6;
+                    self.vel_x = 0;
+
+                    self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+                }
+
+                self.action_counter += 1;
+                if self.action_counter % 20 == 10 && self.action_counter < 70 {
+                    let mut npc = NPC::create(266, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x + self.direction.vector_x() * 16 * 0x200;
+                    npc.y = self.y;
+                    npc.vel_x = self.direction.vector_x() * 0x400;
+                    npc.vel_y = self.rng.range(-0x200..0x200);
+
+                    let _ = npc_list.spawn(0x100, npc);
+
+                    state.sound_manager.play_sfx(12);
+                }
+
+                if self.action_counter > 90 {
+                    self.action_num = 100;
+                }
+            }
+            100 | 101 => {
+                if self.action_num == 100 {
+                    self.action_num = 101;
+                    self.action_counter = 0;
+                    self.npc_flags.set_shootable(false);
+                    self.damage = 0;
+
+                    state.sound_manager.play_sfx(29);
+                }
+
+                self.action_counter += 1;
+                self.anim_num = if self.action_counter / 2 % 2 != 0 { 0 } else { 3 };
+
+                if self.action_counter > 16 {
+                    self.action_num = 102;
+                    self.action_counter = 0;
+                    self.anim_num = 3;
+
+                    // reappears somewhere in the room, near the player
+                    let map_width = stage.map.width as i32 * 16 * 0x200;
+                    self.x = clamp(player.x + self.rng.range(-6..6) * 16 * 0x200, 5 * 16 * 0x200, map_width - 5 * 16 * 0x200);
+                    self.y = 8 * 16 * 0x200;
+                }
+            }
+            102 => {
+                self.action_counter += 1;
+                if self.action_counter > 40 {
+                    self.action_num = 103;
+                    self.action_counter = 0;
+
+                    state.sound_manager.play_sfx(29);
+                }
+            }
+            103 => {
+                self.action_counter += 1;
+                self.anim_num = if self.action_counter / 2 % 2 != 0 { 0 } else { 3 };
+
+                if self.action_counter > 16 {
+                    self.action_num = 10;
+                    self.anim_num = 0;
+                }
+            }
+            500 | 501 => {
+                if self.action_num == 500 {
+                    self.action_num = 501;
+                    self.action_counter = 0;
+                    self.anim_num = 8;
+                    self.npc_flags.set_shootable(false);
+                    self.damage = 0;
+                    self.target_x = self.x;
+
+                    npc_list.create_death_smoke(self.x, self.y, 16 * 0x200, 16, state, &self.rng);
+                }
+
+                self.vel_y += 0x20;
+
+                self.action_counter += 1;
+                self.x = self.target_x + if self.action_counter / 2 % 2 != 0 { 0x200 } else { 0 };
+            }
+            _ => {}
+        }
+
+        if self.action_num >= 10 && self.action_num != 102 {
+            if self.vel_y > 0x5ff {
+                self.vel_y = 0x5ff;
+            }
+
+            self.x += self.vel_x;
+            self.y += self.vel_y;
+        }
+
+        // the red crystal follows the doctor around
+        state.npc_super_pos = (self.x, self.y - 24 * 0x200);
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 9 };
+        self.anim_rect = state.constants.npc.n263_doctor_boss[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n264_doctor_boss_red_wave_projectile(&mut self, state: &mut SharedGameState, npc_list: &NPCList, stage: &Stage) -> GameResult {
+        if self.x < 0 || self.x > stage.map.width as i32 * 16 * 0x200 {
+            self.vanish(state);
+            return Ok(());
+        }
+
+        if self.action_num == 0 {
+            self.action_num = 1;
+            self.target_x = self.x;
+            self.target_y = self.y;
+        }
+
+        // vel_x2 is the phase of the sine wave
+        self.vel_x2 = (self.vel_x2 + 6) & 0xff;
+        self.target_x += self.direction.vector_x() * 0x300;
+
+        self.x = self.target_x;
+        self.y = self.target_y + ((self.vel_x2 as f64 * CDEG_RAD).sin() * 24.0 * 512.0) as i32;
+
+        self.action_counter += 1;
+        if self.action_counter % 4 == 1 {
+            let mut npc = NPC::create(265, &state.npc_table);
+            npc.cond.set_alive(true);
+            npc.x = self.x;
+            npc.y = self.y;
+
+            let _ = npc_list.spawn(0x100, npc);
+        }
+
+        self.anim_rect = state.constants.npc.n264_doctor_boss_red_wave_projectile;
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n265_doctor_boss_red_ball_projectile(&mut self, state: &mut SharedGameState) -> GameResult {
+        self.anim_counter += 1;
+        if self.anim_counter > 3 {
+            self.anim_counter = 0;
+            self.anim_num += 1;
+        }
+
+        if self.anim_num > 2 {
+            self.cond.set_alive(false);
+            return Ok(());
+        }
+
+        self.anim_rect = state.constants.npc.n265_doctor_boss_red_ball_projectile[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n266_doctor_boss_red_ball_projectile_bouncing(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        if self.flags.hit_left_wall() || self.flags.hit_right_wall() {
+            self.vel_x = -self.vel_x;
+        }
+
+        if self.flags.hit_top_wall() {
+            self.vel_y = 0x200;
+        }
+
+        if self.flags.hit_bottom_wall() {
+            self.vel_y = -0x200;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.anim_num += 1;
+        if self.anim_num > 1 {
+            self.anim_num = 0;
+        }
+
+        self.action_counter += 1;
+        if self.action_counter % 4 == 1 {
+            let mut npc = NPC::create(265, &state.npc_table);
+            npc.cond.set_alive(true);
+            npc.x = self.x;
+            npc.y = self.y;
+
+            let _ = npc_list.spawn(0x100, npc);
+        }
+
+        if self.action_counter > 250 {
+            self.vanish(state);
+            return Ok(());
+        }
+
+        self.anim_rect = state.constants.npc.n266_doctor_boss_red_ball_projectile_bouncing[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n267_muscle_doctor(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = 1;
+                    self.y -= 8 * 0x200;
+                }
+
+                self.vel_y += 0x40;
+            }
+            5 | 6 => {
+                // powering up with the red crystal's energy
+                if self.action_num == 5 {
+                    self.action_num = 6;
+                    self.action_counter = 0;
+                    self.anim_num = 9;
+                }
+
+                self.action_counter += 1;
+                if self.action_counter % 4 == 1 {
+                    let mut npc = NPC::create(270, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x + self.rng.range(-32..32) * 0x200;
+                    npc.y = self.y + self.rng.range(-32..32) * 0x200;
+                    npc.direction = Direction::Bottom;
+                    npc.parent_id = self.id;
+
+                    let _ = npc_list.spawn(0x100, npc);
+                }
+
+                self.anim_num = if self.action_counter / 2 % 2 != 0 { 9 } else { 1 };
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                    self.anim_counter = 0;
+                    self.npc_flags.set_shootable(true);
+                    self.damage = 5;
+                }
+
+                self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+                self.vel_x = 0;
+
+                self.animate(10, 1, 2);
+
+                self.action_counter += 1;
+                if self.action_counter > 30 {
+                    self.action_counter2 += 1;
+
+                    self.action_num = match self.action_counter2 % 4 {
+                        1 => 30,
+                        3 => 40,
+                        _ => 20,
+                    };
+                }
+            }
+            20 | 21 | 22 => {
+                if self.action_num == 20 {
+                    self.action_num = 21;
+                    self.action_counter = 0;
+                    self.anim_num = 3;
+                }
+
+                if self.action_num == 21 {
+                    self.action_counter += 1;
+                    if self.action_counter > 10 {
+                        self.action_num = 22;
+                        self.action_counter = 0;
+                        self.anim_num = 4;
+                        self.vel_y = -0x600;
+                        self.vel_x = self.direction.vector_x() * 0x400;
+
+                        state.sound_manager.play_sfx(25);
+                    }
+                } else {
+                    self.action_counter += 1;
+                    if self.action_counter > 2 && self.flags.hit_bottom_wall() {
+                        self.action_num = 10;
+                        self.vel_x = 0;
+
+                        state.quake_counter = 30;
+                        state.sound_manager.play_sfx(26);
+                    }
+                }
+            }
+            30 | 31 | 32 => {
+                if self.action_num == 30 {
+                    self.action_num = 31;
+                    self.action_counter = 0;
+                    self.anim_num = 5;
+                }
+
+                if self.action_num == 31 {
+                    self.action_counter += 1;
+                    if self.action_counter > 20 {
+                        self.action_num = 32;
+                        self.action_counter = 0;
+                        self.anim_num = 6;
+                        self.damage = 10;
+
+                        state.sound_manager.play_sfx(25);
+                    }
+                } else {
+                    self.vel_x = self.direction.vector_x() * 0x5ff;
+
+                    self.anim_counter += 1;
+                    if self.anim_counter > 1 {
+                        self.anim_counter = 0;
+                        self.anim_num = if self.anim_num == 7 { 8 } else { 7 };
+                    }
+
+                    self.action_counter += 1;
+                    if self.action_counter > 30 || self.flags.hit_left_wall() || self.flags.hit_right_wall() {
+                        self.action_num = 10;
+                        self.vel_x = 0;
+                    }
+                }
+            }
+            40 | 41 => {
+                if self.action_num == 40 {
+                    self.action_num = 41;
+                    self.action_counter = 0;
+                    self.anim_num = 9;
+                }
+
+                self.action_counter += 1;
+                if self.action_counter == 20 || self.action_counter == 30 || self.action_counter == 40 {
+                    let mut npc = NPC::create(269, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y - 8 * 0x200;
+                    npc.vel_x = self.direction.vector_x() * 0x400;
+                    npc.vel_y = self.rng.range(-0x200..0x200);
+                    npc.direction = self.direction;
+
+                    let _ = npc_list.spawn(0x100, npc);
+
+                    state.sound_manager.play_sfx(39);
+                }
+
+                if self.action_counter > 60 {
+                    self.action_num = 10;
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y += 0x40;
+
+        self.vel_x = clamp(self.vel_x, -0x5ff, 0x5ff);
+        self.vel_y = clamp(self.vel_y, -0x5ff, 0x5ff);
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 10 };
+        self.anim_rect = state.constants.npc.n267_muscle_doctor[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n269_red_bat_bouncing(&mut self, state: &mut SharedGameState) -> GameResult {
+        if self.action_num == 0 {
+            self.action_num = 1;
+            self.vel_x2 = self.vel_x;
+            self.vel_y2 = self.vel_y;
+        }
+
+        if (self.vel_x2 < 0 && self.flags.hit_left_wall()) || (self.vel_x2 > 0 && self.flags.hit_right_wall()) {
+            self.vel_x2 = -self.vel_x2;
+        }
+
+        if (self.vel_y2 < 0 && self.flags.hit_top_wall()) || (self.vel_y2 > 0 && self.flags.hit_bottom_wall()) {
+            self.vel_y2 = -self.vel_y2;
+        }
+
+        self.x += self.vel_x2;
+        self.y += self.vel_y2;
+
+        self.direction = if self.vel_x2 < 0 { Direction::Left } else { Direction::Right };
+
+        self.animate(2, 0, 2);
+
+        self.action_counter += 1;
+        if self.action_counter > 300 {
+            self.vanish(state);
+            return Ok(());
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 3 };
+        self.anim_rect = state.constants.npc.n269_red_bat_bouncing[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n270_doctor_red_energy(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        self.action_counter += 1;
+
+        if self.direction == Direction::Up || self.direction == Direction::Bottom {
+            // drawn into the parent
+            let (parent_x, parent_y) = match self.get_parent_ref_mut(npc_list) {
+                Some(parent) => (parent.x, parent.y),
+                None => {
+                    self.cond.set_alive(false);
+                    return Ok(());
+                }
+            };
+
+            self.vel_x += if self.x < parent_x { 0x40 } else { -0x40 };
+            self.vel_y += if self.y < parent_y { 0x40 } else { -0x40 };
+
+            self.vel_x = clamp(self.vel_x, -0x400, 0x400);
+            self.vel_y = clamp(self.vel_y, -0x400, 0x400);
+
+            if (abs(parent_x - self.x) < 8 * 0x200 && abs(parent_y - self.y) < 8 * 0x200) || self.action_counter > 300 {
+                self.cond.set_alive(false);
+                return Ok(());
+            }
+        } else {
+            self.vel_y += 0x20;
+
+            if self.action_counter > 50 || (self.flags.0 & 0xff) != 0 {
+                self.cond.set_alive(false);
+                return Ok(());
+            }
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.anim_num = self.rng.range(0..1) as u16;
+        self.anim_rect = state.constants.npc.n270_doctor_red_energy[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n281_doctor_energy_form(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 => {
+                self.action_num = 1;
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.action_counter = 0;
+                }
+
+                self.action_counter += 1;
+
+                let mut npc = NPC::create(270, &state.npc_table);
+                npc.cond.set_alive(true);
+                npc.x = self.x + self.rng.range(-64..64) * 0x200;
+                npc.y = self.y + 128 * 0x200;
+                npc.direction = Direction::Bottom;
+                npc.parent_id = self.id;
+
+                let _ = npc_list.spawn(0x100, npc);
+
+                if self.action_counter > 150 {
+                    self.action_num = 12;
+                }
+            }
+            20 | 21 => {
+                if self.action_num == 20 {
+                    self.action_num = 21;
+                    self.action_counter = 0;
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 250 {
+                    for npc in npc_list.iter_alive() {
+                        if npc.npc_type == 270 {
+                            npc.cond.set_alive(false);
+                        }
+                    }
+
+                    self.action_num = 22;
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
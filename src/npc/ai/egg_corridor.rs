@@ -684,4 +684,641 @@ impl NPC {
 
         Ok(())
     }
+
+    pub(crate) fn tick_n202_zombie_dragon_projectile(&mut self, state: &mut SharedGameState) -> GameResult {
+        if (self.flags.0 & 0xff) != 0 {
+            state.create_caret(self.x, self.y, CaretType::ProjectileDissipation, Direction::Left);
+            self.cond.set_alive(false);
+            return Ok(());
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.anim_num += 1;
+        if self.anim_num > 2 {
+            self.anim_num = 0;
+        }
+
+        self.anim_rect = state.constants.npc.n202_zombie_dragon_projectile[self.anim_num as usize];
+
+        self.action_counter2 += 1;
+        if self.action_counter2 > 300 {
+            state.create_caret(self.x, self.y, CaretType::ProjectileDissipation, Direction::Left);
+            self.cond.set_alive(false);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n200_zombie_dragon(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        if self.action_num < 100 && self.life < 950 {
+            state.sound_manager.play_sfx(72);
+            npc_list.create_death_smoke(self.x, self.y, self.display_bounds.right, 8, state, &self.rng);
+            self.create_xp_drop(state, npc_list);
+
+            self.action_num = 100;
+            self.npc_flags.set_shootable(false);
+            self.damage = 0;
+        }
+
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 10 => {
+                if self.action_num == 0 {
+                    self.action_num = 10;
+                    self.action_counter2 = 0;
+                }
+
+                self.animate(30, 0, 1);
+
+                if self.action_counter2 > 0 {
+                    self.action_counter2 -= 1;
+                }
+
+                if self.action_counter2 == 0 && abs(player.x - self.x) < 112 * 0x200 {
+                    self.action_num = 20;
+                }
+            }
+            20 | 21 => {
+                if self.action_num == 20 {
+                    self.action_num = 21;
+                    self.action_counter = 0;
+                }
+
+                self.anim_counter += 1;
+                if self.anim_counter > 2 {
+                    self.anim_counter = 0;
+                    self.anim_num += 1;
+                }
+
+                if self.anim_num > 3 || self.anim_num < 2 {
+                    self.anim_num = 2;
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 30 {
+                    self.action_num = 30;
+                }
+
+                self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+            }
+            30 | 31 => {
+                if self.action_num == 30 {
+                    self.action_num = 31;
+                    self.action_counter = 0;
+                    self.target_x = player.x;
+                    self.target_y = player.y;
+                    self.action_counter2 = 200;
+
+                    state.sound_manager.play_sfx(102);
+                }
+
+                self.anim_num = 4;
+
+                self.action_counter += 1;
+                if self.action_counter < 40 && self.action_counter % 8 == 1 {
+                    let angle = f64::atan2((self.y - self.target_y) as f64, (self.x - self.target_x) as f64)
+                        + self.rng.range(-6..6) as f64 * CDEG_RAD;
+
+                    let mut npc = NPC::create(202, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y;
+                    npc.vel_x = (angle.cos() * -1536.0) as i32;
+                    npc.vel_y = (angle.sin() * -1536.0) as i32;
+
+                    let _ = npc_list.spawn(0x100, npc);
+
+                    state.sound_manager.play_sfx(33);
+                }
+
+                if self.action_counter > 40 {
+                    self.action_num = 10;
+                    self.anim_num = 0;
+                }
+            }
+            100 => {
+                self.anim_num = 5;
+            }
+            _ => {}
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 6 };
+        self.anim_rect = state.constants.npc.n200_zombie_dragon[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n201_zombie_dragon_dead(&mut self, state: &mut SharedGameState) -> GameResult {
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 1 };
+        self.anim_rect = state.constants.npc.n201_zombie_dragon_dead[dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n203_critter_destroyed_egg_corridor(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.y += 3 * 0x200;
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+
+                let player = self.get_closest_player_mut(players);
+
+                self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+
+                if self.target_x < 100 {
+                    self.target_x += 1;
+                }
+
+                if self.action_counter >= 8 && abs(player.x - self.x) < 112 * 0x200 && abs(player.y - self.y) < 80 * 0x200 {
+                    self.anim_num = 1;
+                } else {
+                    if self.action_counter < 8 {
+                        self.action_counter += 1;
+                    }
+
+                    self.anim_num = 0;
+                }
+
+                if self.shock > 0 || (self.action_counter >= 8 && self.target_x >= 100
+                    && abs(player.x - self.x) < 64 * 0x200 && abs(player.y - self.y) < 80 * 0x200) {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 0;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 8 {
+                    self.action_num = 3;
+                    self.anim_num = 2;
+                    self.vel_y = -0x5ff;
+                    self.vel_x = self.direction.vector_x() * 0x100;
+
+                    state.sound_manager.play_sfx(30);
+                }
+            }
+            3 => {
+                if self.flags.hit_bottom_wall() {
+                    self.vel_x = 0;
+                    self.action_counter = 0;
+                    self.action_num = 1;
+                    self.anim_num = 0;
+
+                    state.sound_manager.play_sfx(23);
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y += 0x40;
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 3 };
+        self.anim_rect = state.constants.npc.n203_critter_destroyed_egg_corridor[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n204_small_falling_spike(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.target_x = self.x;
+                }
+
+                if abs(player.x - self.x) < 12 * 0x200 && player.y > self.y {
+                    self.action_num = 2;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                self.x = if self.action_counter / 6 % 2 != 0 { self.target_x - 0x200 } else { self.target_x };
+
+                if self.action_counter > 30 {
+                    self.action_num = 3;
+                    self.anim_num = 1;
+                }
+            }
+            3 => {
+                self.vel_y += 0x20;
+
+                if (self.flags.0 & 0xff) != 0 {
+                    if !player.cond.hidden() {
+                        state.sound_manager.play_sfx(12);
+                    }
+
+                    npc_list.create_death_smoke(self.x, self.y, self.display_bounds.right, 4, state, &self.rng);
+                    self.cond.set_alive(false);
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+
+        if self.vel_y > 0xc00 {
+            self.vel_y = 0xc00;
+        }
+
+        self.y += self.vel_y;
+
+        self.anim_rect = state.constants.npc.n204_small_falling_spike[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n205_large_falling_spike(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.target_x = self.x;
+                    self.y += 4 * 0x200;
+                }
+
+                if abs(player.x - self.x) < 12 * 0x200 && player.y > self.y {
+                    self.action_num = 2;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                self.x = if self.action_counter / 6 % 2 != 0 { self.target_x - 0x200 } else { self.target_x };
+
+                if self.action_counter > 30 {
+                    self.action_num = 3;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+            }
+            3 => {
+                self.vel_y += 0x20;
+
+                // only deadly from below, can be stood on otherwise
+                if player.y > self.y {
+                    self.npc_flags.set_solid_hard(false);
+                    self.damage = 127;
+                } else {
+                    self.npc_flags.set_solid_hard(true);
+                    self.damage = 0;
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 8 && (self.flags.0 & 0xff) != 0 {
+                    self.npc_flags.set_solid_hard(true);
+                    self.action_num = 4;
+                    self.action_counter = 0;
+                    self.vel_y = 0;
+                    self.damage = 0;
+
+                    state.sound_manager.play_sfx(12);
+                    npc_list.create_death_smoke(self.x, self.y, self.display_bounds.right, 4, state, &self.rng);
+                }
+            }
+            4 => {
+                self.action_counter += 1;
+                if self.action_counter > 4 {
+                    self.action_num = 5;
+                    self.npc_flags.set_shootable(true);
+                }
+            }
+            _ => {}
+        }
+
+        if self.vel_y > 0xc00 {
+            self.vel_y = 0xc00;
+        }
+
+        self.y += self.vel_y;
+
+        self.anim_rect = state.constants.npc.n205_large_falling_spike[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n206_counter_bomb(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.target_x = self.x;
+                    self.target_y = self.y;
+                    self.action_counter2 = self.rng.range(0..50) as u16;
+                }
+
+                if self.action_counter2 > 0 {
+                    self.action_counter2 -= 1;
+                } else {
+                    self.action_num = 2;
+                    self.vel_y = 0x300;
+                }
+            }
+            2 => {
+                let player = self.get_closest_player_mut(players);
+
+                if abs(player.x - self.x) < 80 * 0x200 || self.shock > 0 {
+                    self.action_num = 3;
+                    self.action_counter = 0;
+                    self.action_counter2 = 0;
+                }
+            }
+            3 => {
+                // counts down from five, then blows up
+                if self.action_counter % 60 == 0 {
+                    if self.action_counter2 < 5 {
+                        let mut npc = NPC::create(207, &state.npc_table);
+                        npc.cond.set_alive(true);
+                        npc.x = self.x + 16 * 0x200;
+                        npc.y = self.y;
+                        npc.tsc_direction = self.action_counter2;
+
+                        let _ = npc_list.spawn(0x180, npc);
+
+                        self.action_counter2 += 1;
+                    } else {
+                        self.action_num = 4;
+                        self.action_counter = 0;
+                    }
+                }
+
+                self.action_counter += 1;
+            }
+            4 => {
+                self.npc_flags.set_shootable(false);
+                self.display_bounds = Rect { left: 96 * 0x200, top: 96 * 0x200, right: 96 * 0x200, bottom: 96 * 0x200 };
+                self.hit_bounds = Rect { left: 96 * 0x200, top: 96 * 0x200, right: 96 * 0x200, bottom: 96 * 0x200 };
+                self.damage = 30;
+
+                self.action_counter += 1;
+                if self.action_counter == 1 {
+                    state.quake_counter = 20;
+                    state.sound_manager.play_sfx(35);
+
+                    npc_list.create_death_smoke(self.x, self.y, 48 * 0x200, 40, state, &self.rng);
+                }
+
+                if self.action_counter > 2 {
+                    self.cond.set_alive(false);
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+
+        if self.action_num == 2 || self.action_num == 3 {
+            self.vel_y += if self.y > self.target_y { -0x10 } else { 0x10 };
+            self.vel_y = clamp(self.vel_y, -0x100, 0x100);
+
+            self.y += self.vel_y;
+        }
+
+        self.animate(4, 0, 2);
+
+        self.anim_rect = state.constants.npc.n206_counter_bomb[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n207_counter_bomb_countdown(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = self.tsc_direction.min(4);
+
+                    state.sound_manager.play_sfx(43);
+                }
+
+                self.x += 0x200;
+
+                self.action_counter += 1;
+                if self.action_counter > 8 {
+                    self.action_counter = 0;
+                    self.action_num = 2;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 30 {
+                    self.cond.set_alive(false);
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+
+        self.anim_rect = state.constants.npc.n207_counter_bomb_countdown[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n208_basu_destroyed_egg_corridor(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 => {
+                if player.x < self.x + 16 * 0x200 && player.x > self.x - 16 * 0x200 {
+                    self.target_x = self.x;
+                    self.target_y = self.y;
+                    self.action_num = 1;
+                    self.action_counter = 0;
+                    self.action_counter2 = 0;
+                    self.damage = 6;
+                    self.vel_y = -0x100;
+                    self.tsc_direction = self.direction as u16;
+                    self.npc_flags.set_shootable(true);
+
+                    self.x = player.x + self.direction.vector_x() * 16 * 16 * 0x200;
+                    self.vel_x = self.direction.vector_x() * 0x2ff;
+                } else {
+                    self.anim_rect = Rect::new(0, 0, 0, 0);
+                    self.damage = 0;
+                    self.vel_x = 0;
+                    self.vel_y = 0;
+                    self.npc_flags.set_shootable(false);
+                }
+
+                return Ok(());
+            }
+            1 => {
+                if self.x > player.x {
+                    self.direction = Direction::Left;
+                    self.vel_x -= 0x10;
+                } else {
+                    self.direction = Direction::Right;
+                    self.vel_x += 0x10;
+                }
+
+                if self.flags.hit_left_wall() {
+                    self.vel_x = 0x200;
+                }
+
+                if self.flags.hit_right_wall() {
+                    self.vel_x = -0x200;
+                }
+
+                self.vel_y += ((self.target_y - self.y).signum() | 1) * 0x08;
+
+                self.vel_x = clamp(self.vel_x, -0x2ff, 0x2ff);
+                self.vel_y = clamp(self.vel_y, -0x100, 0x100);
+
+                if self.shock > 0 {
+                    self.x += self.vel_x / 2;
+                    self.y += self.vel_y / 2;
+                } else {
+                    self.x += self.vel_x;
+                    self.y += self.vel_y;
+                }
+
+                if player.x > self.x + 400 * 0x200 || player.x < self.x - 400 * 0x200 {
+                    self.action_num = 0;
+                    self.vel_x = 0;
+                    self.x = self.target_x;
+                    self.damage = 0;
+                    self.direction = Direction::from_int_facing(self.tsc_direction as usize)
+                        .unwrap_or(Direction::Left);
+                    self.anim_rect = Rect::new(0, 0, 0, 0);
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+
+        if self.action_counter < 150 {
+            self.action_counter += 1;
+        } else {
+            self.action_counter2 += 1;
+            if (self.action_counter2 % 8) == 0 && abs(self.x - player.x) < 160 * 0x200 {
+                let angle = f64::atan2((self.y - player.y) as f64, (self.x - player.x) as f64)
+                    + self.rng.range(-6..6) as f64 * CDEG_RAD;
+
+                let mut npc = NPC::create(209, &state.npc_table);
+                npc.cond.set_alive(true);
+                npc.x = self.x;
+                npc.y = self.y;
+                npc.vel_x = (angle.cos() * -1024.0) as i32;
+                npc.vel_y = (angle.sin() * -1024.0) as i32;
+
+                let _ = npc_list.spawn(0x100, npc);
+                state.sound_manager.play_sfx(39);
+            }
+
+            if self.action_counter2 > 8 {
+                self.action_counter = 0;
+                self.action_counter2 = 0;
+            }
+        }
+
+        self.animate(1, 0, 1);
+
+        if self.action_counter > 120 && self.action_counter / 2 % 2 == 1 && self.anim_num == 1 {
+            self.anim_num = 2;
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 3 };
+        self.anim_rect = state.constants.npc.n208_basu_destroyed_egg_corridor[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n209_basu_projectile_destroyed_egg_corridor(&mut self, state: &mut SharedGameState) -> GameResult {
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.animate(2, 0, 3);
+
+        self.anim_rect = state.constants.npc.n209_basu_projectile_destroyed_egg_corridor[self.anim_num as usize];
+
+        self.action_counter2 += 1;
+        if self.flags.0 != 0 || self.action_counter2 > 300 {
+            state.create_caret(self.x, self.y, CaretType::ProjectileDissipation, Direction::Left);
+            self.cond.set_alive(false);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n210_destroyed_egg_corridor(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 => {
+                if player.x < self.x + 16 * 0x200 && player.x > self.x - 16 * 0x200 {
+                    self.target_x = self.x;
+                    self.target_y = self.y;
+                    self.action_num = 1;
+                    self.damage = 2;
+                    self.vel_y = -0x200;
+                    self.tsc_direction = self.direction as u16;
+                    self.npc_flags.set_shootable(true);
+
+                    self.x = player.x + self.direction.vector_x() * 16 * 16 * 0x200;
+                    self.vel_x = self.direction.vector_x() * 0x2ff;
+                } else {
+                    self.anim_rect = Rect::new(0, 0, 0, 0);
+                    self.damage = 0;
+                    self.vel_x = 0;
+                    self.vel_y = 0;
+                    self.npc_flags.set_shootable(false);
+                }
+
+                return Ok(());
+            }
+            1 => {
+                if self.x > player.x {
+                    self.direction = Direction::Left;
+                    self.vel_x -= 0x10;
+                } else {
+                    self.direction = Direction::Right;
+                    self.vel_x += 0x10;
+                }
+
+                self.vel_y += if self.y < self.target_y { 8 } else { -8 };
+
+                self.vel_x = clamp(self.vel_x, -0x2ff, 0x2ff);
+                self.vel_y = clamp(self.vel_y, -0x100, 0x100);
+
+                if self.shock > 0 {
+                    self.x += self.vel_x / 2;
+                    self.y += self.vel_y / 2;
+                } else {
+                    self.x += self.vel_x;
+                    self.y += self.vel_y;
+                }
+
+                if player.x > self.x + 400 * 0x200 || player.x < self.x - 400 * 0x200 {
+                    self.action_num = 0;
+                    self.vel_x = 0;
+                    self.x = self.target_x;
+                    self.damage = 0;
+                    self.direction = Direction::from_int_facing(self.tsc_direction as usize)
+                        .unwrap_or(Direction::Left);
+                    self.anim_rect = Rect::new(0, 0, 0, 0);
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+
+        self.animate(1, 0, 1);
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 2 };
+        self.anim_rect = state.constants.npc.n210_destroyed_egg_corridor[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
 }
@@ -0,0 +1,1410 @@
+use ggez::GameResult;
+use num_traits::{abs, clamp};
+
+use crate::caret::CaretType;
+use crate::common::{CDEG_RAD, Direction};
+use crate::npc::list::NPCList;
+use crate::npc::NPC;
+use crate::player::Player;
+use crate::rng::RNG;
+use crate::shared_game_state::SharedGameState;
+use crate::stage::Stage;
+
+impl NPC {
+    pub(crate) fn tick_n309_bute(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                }
+
+                if abs(player.x - self.x) < 320 * 0x200 && abs(player.y - self.y) < 240 * 0x200 {
+                    self.action_num = 10;
+                }
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.npc_flags.set_shootable(true);
+                    self.damage = 5;
+                }
+
+                if self.x > player.x {
+                    self.direction = Direction::Left;
+                    self.vel_x2 -= 0x10;
+                } else {
+                    self.direction = Direction::Right;
+                    self.vel_x2 += 0x10;
+                }
+
+                if self.y > player.y {
+                    self.vel_y2 -= 0x10;
+                } else {
+                    self.vel_y2 += 0x10;
+                }
+
+                if (self.vel_x2 < 0 && self.flags.hit_left_wall()) || (self.vel_x2 > 0 && self.flags.hit_right_wall()) {
+                    self.vel_x2 = -self.vel_x2;
+                }
+
+                if (self.vel_y2 < 0 && self.flags.hit_top_wall()) || (self.vel_y2 > 0 && self.flags.hit_bottom_wall()) {
+                    self.vel_y2 = -self.vel_y2;
+                }
+
+                self.vel_x2 = clamp(self.vel_x2, -0x5ff, 0x5ff);
+                self.vel_y2 = clamp(self.vel_y2, -0x5ff, 0x5ff);
+
+                self.x += self.vel_x2;
+                self.y += self.vel_y2;
+
+                self.animate(1, 0, 1);
+            }
+            _ => {}
+        }
+
+        // butes don't die from damage, they fall down once they've been hit
+        if self.life <= 996 {
+            self.npc_type = 316;
+            self.action_num = 0;
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 2 };
+
+        self.anim_rect = state.constants.npc.n309_bute[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n316_bute_dead(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 => {
+                self.action_num = 1;
+                self.anim_num = 0;
+                self.damage = 0;
+                self.npc_flags.set_shootable(false);
+                self.npc_flags.set_ignore_solidity(false);
+                self.display_bounds.left = 12 * 0x200;
+                self.display_bounds.right = 12 * 0x200;
+                self.display_bounds.top = 12 * 0x200;
+                self.vel_y = -0x200;
+                self.vel_x = if self.direction == Direction::Left { 0x100 } else { -0x100 };
+
+                state.sound_manager.play_sfx(50);
+            }
+            1 => {
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                    self.anim_counter = 0;
+                }
+            }
+            2 => {
+                self.vel_x = 8 * self.vel_x / 9;
+
+                self.animate(3, 1, 2);
+
+                self.action_counter += 1;
+                if self.action_counter > 50 {
+                    self.cond.set_explode_die(true);
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y += 0x20;
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 3 };
+
+        self.anim_rect = state.constants.npc.n316_bute_dead[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n323_bute_spinning(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        self.anim_counter += 1;
+        if self.anim_counter > 3 {
+            self.anim_counter = 0;
+            self.anim_num += 1;
+            if self.anim_num > 3 {
+                self.anim_num = 0;
+            }
+        }
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+
+                    match self.direction {
+                        Direction::Left => self.vel_x = -0x600,
+                        Direction::Up => self.vel_y = -0x600,
+                        Direction::Right => self.vel_x = 0x600,
+                        Direction::Bottom => self.vel_y = 0x600,
+                        Direction::FacingPlayer => {}
+                    }
+                }
+
+                self.action_counter += 1;
+                if self.action_counter == 16 {
+                    self.npc_flags.set_ignore_solidity(false);
+                }
+
+                self.x += self.vel_x;
+                self.y += self.vel_y;
+
+                if (self.flags.0 & 0xff) != 0 {
+                    self.action_num = 10;
+                }
+
+                if self.action_counter > 20 {
+                    let player = self.get_closest_player_mut(players);
+
+                    let reached = match self.direction {
+                        Direction::Left => self.x <= player.x + 32 * 0x200,
+                        Direction::Up => self.y <= player.y + 32 * 0x200,
+                        Direction::Right => self.x >= player.x - 32 * 0x200,
+                        Direction::Bottom => self.y >= player.y - 32 * 0x200,
+                        Direction::FacingPlayer => false,
+                    };
+
+                    if reached {
+                        self.action_num = 10;
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        if self.action_num == 10 {
+            let mut npc = NPC::create(309, &state.npc_table);
+            npc.cond.set_alive(true);
+            npc.x = self.x;
+            npc.y = self.y;
+
+            let _ = npc_list.spawn(0x100, npc);
+
+            state.create_caret(self.x, self.y, CaretType::ProjectileDissipation, Direction::Left);
+            self.cond.set_alive(false);
+            return Ok(());
+        }
+
+        self.anim_rect = state.constants.npc.n323_bute_spinning[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n325_heavy_press_lighting(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    state.sound_manager.play_sfx(29);
+                }
+
+                self.animate(0, 0, 2);
+
+                self.action_counter += 1;
+                if self.action_counter > 50 {
+                    self.action_num = 10;
+                    self.anim_counter = 0;
+                    self.anim_num = 3;
+                    self.damage = 10;
+                    self.display_bounds.left = 8 * 0x200;
+                    self.display_bounds.top = 12 * 0x200;
+
+                    state.sound_manager.play_sfx(101);
+                    npc_list.create_death_smoke(self.x, self.y + 84 * 0x200, 0, 3, state, &self.rng);
+                }
+            }
+            10 => {
+                self.anim_counter += 1;
+                if self.anim_counter > 2 {
+                    self.anim_counter = 0;
+                    self.anim_num += 1;
+                }
+
+                if self.anim_num > 6 {
+                    self.cond.set_alive(false);
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+
+        self.anim_rect = state.constants.npc.n325_heavy_press_lighting[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n308_stumpy(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                }
+
+                if abs(player.x - self.x) < 240 * 0x200 && abs(player.y - self.y) < 192 * 0x200 {
+                    self.action_num = 10;
+                }
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.action_counter = 0;
+                    self.vel_x2 = 0;
+                    self.vel_y2 = 0;
+                    self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 50 {
+                    self.action_num = 20;
+                }
+
+                self.animate(1, 0, 1);
+
+                if abs(player.x - self.x) > 320 * 0x200 || abs(player.y - self.y) > 240 * 0x200 {
+                    self.action_num = 0;
+                }
+            }
+            20 | 21 => {
+                if self.action_num == 20 {
+                    self.action_num = 21;
+                    self.action_counter = 0;
+
+                    let angle = f64::atan2((self.y - player.y) as f64, (self.x - player.x) as f64)
+                        + self.rng.range(-3..3) as f64 * CDEG_RAD;
+
+                    self.vel_x2 = (angle.cos() * -1024.0) as i32;
+                    self.vel_y2 = (angle.sin() * -1024.0) as i32;
+
+                    self.direction = if self.vel_x2 < 0 { Direction::Left } else { Direction::Right };
+                }
+
+                if (self.vel_x2 < 0 && self.flags.hit_left_wall()) || (self.vel_x2 > 0 && self.flags.hit_right_wall()) {
+                    self.vel_x2 = -self.vel_x2;
+                    self.direction = self.direction.opposite();
+                }
+
+                if (self.vel_y2 < 0 && self.flags.hit_top_wall()) || (self.vel_y2 > 0 && self.flags.hit_bottom_wall()) {
+                    self.vel_y2 = -self.vel_y2;
+                }
+
+                self.x += self.vel_x2;
+                self.y += self.vel_y2;
+
+                self.action_counter += 1;
+                if self.action_counter > 50 {
+                    self.action_num = 10;
+                }
+
+                self.animate(0, 0, 1);
+            }
+            _ => {}
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 2 };
+
+        self.anim_rect = state.constants.npc.n308_stumpy[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n310_bute_sword(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.npc_flags.set_shootable(false);
+                    self.npc_flags.set_invulnerable(true);
+                    self.damage = 0;
+                }
+
+                if abs(player.x - self.x) < 128 * 0x200 && self.y - 128 * 0x200 < player.y && self.y + 16 * 0x200 > player.y {
+                    self.action_num = 10;
+                }
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.action_counter = 0;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                    self.npc_flags.set_shootable(true);
+                    self.npc_flags.set_invulnerable(false);
+                }
+
+                self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+                self.vel_x = self.direction.vector_x() * 0x400;
+
+                self.animate(3, 0, 1);
+
+                self.action_counter += 1;
+                if self.action_counter > 10 && abs(player.x - self.x) < 40 * 0x200 {
+                    self.action_num = 20;
+                }
+            }
+            20 | 21 => {
+                if self.action_num == 20 {
+                    self.action_num = 21;
+                    self.action_counter = 0;
+                    self.anim_num = 2;
+                }
+
+                self.vel_x = 8 * self.vel_x / 9;
+
+                self.action_counter += 1;
+                if self.action_counter > 10 {
+                    self.action_num = 30;
+                }
+            }
+            30 | 31 => {
+                if self.action_num == 30 {
+                    self.action_num = 31;
+                    self.action_counter = 0;
+                    self.anim_num = 3;
+                    self.vel_y = -0x5ff;
+                    self.vel_x = self.direction.vector_x() * 0x400;
+                    self.damage = 9;
+
+                    state.sound_manager.play_sfx(30);
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 8 {
+                    self.anim_num = 4;
+                }
+
+                if self.flags.hit_bottom_wall() && self.vel_y >= 0 {
+                    self.action_num = 32;
+                    self.action_counter = 0;
+                    self.anim_num = 2;
+                    self.damage = 3;
+                    self.vel_x = 0;
+
+                    state.sound_manager.play_sfx(23);
+                }
+            }
+            32 => {
+                self.action_counter += 1;
+                if self.action_counter > 20 {
+                    self.action_num = 10;
+                    self.damage = 0;
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y += 0x20;
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        if self.life <= 996 {
+            self.npc_type = 316;
+            self.action_num = 0;
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 5 };
+
+        self.anim_rect = state.constants.npc.n310_bute_sword[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n311_bute_archer(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                }
+
+                if abs(player.x - self.x) < 160 * 0x200 && abs(player.y - self.y) < 160 * 0x200 {
+                    self.action_num = 10;
+                }
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.action_counter = 0;
+                    self.anim_num = 0;
+                }
+
+                self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+
+                // keeps some distance before drawing the bow
+                let target_x = player.x - self.direction.vector_x() * 96 * 0x200;
+                self.vel_x += if self.x < target_x { 0x20 } else { -0x20 };
+                self.vel_x = clamp(self.vel_x, -0x200, 0x200);
+
+                self.animate(3, 0, 1);
+
+                self.action_counter += 1;
+                if self.action_counter > 60 {
+                    self.action_num = 20;
+                }
+            }
+            20 | 21 => {
+                if self.action_num == 20 {
+                    self.action_num = 21;
+                    self.action_counter = 0;
+                    self.anim_num = 2;
+                }
+
+                self.vel_x = 4 * self.vel_x / 5;
+
+                self.action_counter += 1;
+                if self.action_counter > 20 {
+                    self.action_num = 30;
+                    self.action_counter = 0;
+                    self.anim_num = 3;
+                }
+            }
+            30 => {
+                self.anim_num = if self.action_counter / 2 % 2 != 0 { 3 } else { 4 };
+
+                self.action_counter += 1;
+                if self.action_counter > 30 {
+                    self.action_num = 40;
+                    self.action_counter = 0;
+                    self.anim_num = 5;
+
+                    let mut npc = NPC::create(312, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y;
+                    npc.vel_x = self.direction.vector_x() * 0x600;
+                    npc.direction = self.direction;
+
+                    let _ = npc_list.spawn(0x100, npc);
+                }
+            }
+            40 => {
+                self.action_counter += 1;
+                if self.action_counter > 2 {
+                    self.anim_num = 6;
+                }
+
+                if self.action_counter > 40 {
+                    self.action_num = 10;
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y += 0x20;
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        if self.life <= 992 {
+            self.npc_type = 316;
+            self.action_num = 0;
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 7 };
+
+        self.anim_rect = state.constants.npc.n311_bute_archer[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n312_bute_arrow_projectile(&mut self, state: &mut SharedGameState) -> GameResult {
+        if self.action_num > 0 && self.action_num < 20 && (self.flags.0 & 0xff) != 0 {
+            self.action_num = 20;
+        }
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.action_counter = 0;
+                    self.direction = if self.vel_x < 0 { Direction::Left } else { Direction::Right };
+                    self.anim_num = 0;
+                }
+
+                self.action_counter += 1;
+                if self.action_counter == 4 {
+                    self.npc_flags.set_ignore_solidity(false);
+                }
+
+                if self.action_counter > 10 {
+                    self.action_num = 10;
+                }
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.action_counter = 0;
+                    self.vel_x = 3 * self.vel_x / 4;
+                    self.vel_y = 0;
+                }
+
+                self.vel_y += 0x20;
+
+                // the arrow tilts down as it loses speed
+                self.anim_num = clamp(self.vel_y / 0x100 + 1, 1, 4) as u16;
+
+                self.action_counter += 1;
+                if self.action_counter > 30 {
+                    self.action_num = 20;
+                }
+            }
+            20 | 21 => {
+                if self.action_num == 20 {
+                    self.action_num = 21;
+                    self.action_counter = 0;
+                    self.vel_x = 0;
+                    self.vel_y = 0;
+                    self.damage = 0;
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 30 {
+                    self.action_num = 30;
+                    self.action_counter = 0;
+                }
+            }
+            30 => {
+                self.action_counter += 1;
+                if self.action_counter > 30 {
+                    self.cond.set_alive(false);
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 5 };
+
+        self.anim_rect = state.constants.npc.n312_bute_arrow_projectile[self.anim_num as usize + dir_offset];
+
+        // blinks out before disappearing
+        if self.action_num == 30 && self.action_counter / 2 % 2 != 0 {
+            self.anim_rect.right = self.anim_rect.left;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n317_mesa(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.y -= 8 * 0x200;
+                    self.action_counter = 0;
+                    self.anim_num = 0;
+                }
+
+                self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+
+                self.animate(40, 0, 1);
+
+                self.action_counter += 1;
+                if self.action_counter > 50 {
+                    self.action_num = 20;
+                }
+            }
+            20 | 21 => {
+                if self.action_num == 20 {
+                    self.action_num = 21;
+                    self.action_counter = 0;
+                    self.anim_num = 2;
+
+                    let mut npc = NPC::create(319, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y;
+                    npc.direction = self.direction;
+                    npc.parent_id = self.id;
+
+                    let _ = npc_list.spawn(0x100, npc);
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 50 {
+                    self.action_num = 22;
+                    self.action_counter = 0;
+                    self.anim_num = 3;
+
+                    state.sound_manager.play_sfx(39);
+                }
+            }
+            22 => {
+                self.action_counter += 1;
+                if self.action_counter > 20 {
+                    self.action_num = 1;
+                    self.action_counter = 0;
+                    self.anim_num = 0;
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y += 0x55;
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.y += self.vel_y;
+
+        // same as butes, mesas crumble instead of dying
+        if self.life <= 936 {
+            self.npc_type = 318;
+            self.action_num = 0;
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 4 };
+
+        self.anim_rect = state.constants.npc.n317_mesa[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n318_mesa_dead(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 => {
+                self.action_num = 1;
+                self.anim_num = 0;
+                self.damage = 0;
+                self.npc_flags.set_shootable(false);
+                self.npc_flags.set_ignore_solidity(false);
+                self.vel_y = -0x200;
+                self.vel_x = if self.direction == Direction::Left { 0x40 } else { -0x40 };
+
+                state.sound_manager.play_sfx(54);
+            }
+            1 => {
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                    self.anim_counter = 0;
+                }
+            }
+            2 => {
+                self.vel_x = 8 * self.vel_x / 9;
+
+                self.animate(3, 1, 2);
+
+                self.action_counter += 1;
+                if self.action_counter > 50 {
+                    self.cond.set_explode_die(true);
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y += 0x20;
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 3 };
+
+        self.anim_rect = state.constants.npc.n318_mesa_dead[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n319_mesa_block(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                }
+
+                // held above mesa's head until it's thrown
+                if let Some(parent) = self.get_parent_ref_mut(npc_list) {
+                    self.x = parent.x + parent.direction.vector_x() * 7 * 0x200;
+                    self.y = parent.y - 8 * 0x200;
+
+                    if parent.anim_num == 3 {
+                        self.action_num = 2;
+                        self.anim_num = 2;
+
+                        let player = self.get_closest_player_mut(players);
+                        self.vel_x = clamp((player.x - self.x) / 32, -0x400, 0x400);
+                        self.vel_y = -0x400;
+                    }
+                } else {
+                    self.cond.set_alive(false);
+                    return Ok(());
+                }
+
+                self.animate(0, 0, 1);
+            }
+            2 => {
+                self.vel_y += 0x2a;
+                if self.vel_y > 0x5ff {
+                    self.vel_y = 0x5ff;
+                }
+
+                self.x += self.vel_x;
+                self.y += self.vel_y;
+
+                if (self.flags.0 & 0xff) != 0 {
+                    state.sound_manager.play_sfx(12);
+                    npc_list.create_death_smoke(self.x, self.y, 0, 3, state, &self.rng);
+
+                    self.cond.set_alive(false);
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+
+        self.anim_rect = state.constants.npc.n319_mesa_block[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n322_deleet(&mut self, state: &mut SharedGameState, npc_list: &NPCList, stage: &mut Stage) -> GameResult {
+        if self.action_num < 2 && self.life <= 968 {
+            self.action_num = 2;
+            self.action_counter = 0;
+            self.action_counter2 = 0;
+            self.npc_flags.set_shootable(false);
+            self.npc_flags.set_invulnerable(true);
+
+            state.sound_manager.play_sfx(22);
+        }
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+
+                    if self.direction == Direction::Left {
+                        self.y += 8 * 0x200;
+                    } else {
+                        self.x += 8 * 0x200;
+                    }
+                }
+
+                self.anim_num = if self.shock > 0 { 1 } else { 0 };
+            }
+            2 => {
+                self.anim_num = 2;
+
+                // counts down from five, then blows up the surrounding blocks
+                if self.action_counter % 50 == 0 {
+                    if self.action_counter2 < 5 {
+                        let mut npc = NPC::create(207, &state.npc_table);
+                        npc.cond.set_alive(true);
+                        npc.x = self.x + 4 * 0x200;
+                        npc.y = self.y;
+                        npc.tsc_direction = self.action_counter2;
+
+                        let _ = npc_list.spawn(0x180, npc);
+
+                        self.action_counter2 += 1;
+                    } else {
+                        self.action_num = 3;
+                    }
+                }
+
+                self.action_counter += 1;
+            }
+            3 => {
+                let x = (self.x / (16 * 0x200)).max(1) as usize;
+                let y = (self.y / (16 * 0x200)).max(1) as usize;
+
+                let tiles: [(usize, usize); 5] = if self.direction == Direction::Left {
+                    [(x, y), (x - 1, y), (x + 1, y), (x - 1, y - 1), (x, y - 1)]
+                } else {
+                    [(x, y), (x - 1, y - 1), (x - 1, y), (x, y - 1), (x, y + 1)]
+                };
+
+                let mut npc = NPC::create(4, &state.npc_table);
+                npc.cond.set_alive(true);
+
+                for &(tx, ty) in tiles.iter() {
+                    if stage.change_tile(tx, ty, 0) {
+                        npc.x = tx as i32 * 16 * 0x200;
+                        npc.y = ty as i32 * 16 * 0x200;
+
+                        let _ = npc_list.spawn(0x100, npc.clone());
+                    }
+                }
+
+                npc_list.create_death_smoke(self.x, self.y, 16 * 0x200, 8, state, &self.rng);
+
+                state.quake_counter = 10;
+                state.sound_manager.play_sfx(35);
+
+                self.cond.set_alive(false);
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        self.anim_rect = state.constants.npc.n322_deleet[self.anim_num as usize];
+
+        if self.action_num == 2 && self.action_counter / 2 % 2 != 0 {
+            self.anim_rect.left += 1;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n324_bute_generator(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.action_counter = 0;
+                }
+
+                if self.action_counter % 50 == 0 {
+                    let mut npc = NPC::create(323, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y;
+                    npc.direction = self.direction;
+
+                    let _ = npc_list.spawn(0x100, npc);
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 351 {
+                    self.action_num = 0;
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n330_rolling(&mut self, state: &mut SharedGameState, npc_list: &NPCList, stage: &mut Stage) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+
+                    // the placeholder tile it was spawned on is removed
+                    let x = (self.x / (16 * 0x200)) as usize;
+                    let y = (self.y / (16 * 0x200)) as usize;
+                    if stage.change_tile(x, y, 0) {
+                        let mut npc = NPC::create(4, &state.npc_table);
+                        npc.cond.set_alive(true);
+                        npc.x = self.x;
+                        npc.y = self.y;
+
+                        let _ = npc_list.spawn(0x100, npc);
+                    }
+
+                    self.vel_x = if self.direction == Direction::Left { -0x400 } else { 0x400 };
+                }
+
+                // crawls clockwise along the walls
+                if self.vel_x < 0 && self.flags.hit_left_wall() {
+                    self.vel_x = 0;
+                    self.vel_y = -0x400;
+                } else if self.vel_y < 0 && self.flags.hit_top_wall() {
+                    self.vel_y = 0;
+                    self.vel_x = 0x400;
+                } else if self.vel_x > 0 && self.flags.hit_right_wall() {
+                    self.vel_x = 0;
+                    self.vel_y = 0x400;
+                } else if self.vel_y > 0 && self.flags.hit_bottom_wall() {
+                    self.vel_y = 0;
+                    self.vel_x = -0x400;
+                }
+            }
+            _ => {}
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.animate(1, 0, 2);
+
+        self.anim_rect = state.constants.npc.n330_rolling[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n337_numahachi(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.y -= 8 * 0x200;
+                    self.vel_x = 0;
+                    self.anim_num = 0;
+                }
+
+                self.animate(50, 0, 1);
+            }
+            _ => {}
+        }
+
+        self.vel_y += 0x40;
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.anim_rect = state.constants.npc.n337_numahachi[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n338_green_devil(&mut self, state: &mut SharedGameState, stage: &Stage) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = self.rng.range(0..1) as u16;
+                    self.target_y = self.y;
+                    self.vel_y = self.rng.range(-0x200..0x200) * 2;
+                    self.vel_x = self.direction.vector_x() * 0x200;
+                    self.npc_flags.set_shootable(true);
+                }
+
+                self.vel_y += if self.y < self.target_y { 0x80 } else { -0x80 };
+                self.vel_x += self.direction.vector_x() * 0x20;
+                self.vel_x = clamp(self.vel_x, -0x400, 0x400);
+
+                if self.x < 0 || self.x > stage.map.width as i32 * 16 * 0x200 {
+                    self.vanish(state);
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.animate(2, 0, 1);
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 2 };
+
+        self.anim_rect = state.constants.npc.n338_green_devil[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n339_green_devil_generator(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.action_counter = self.rng.range(0..40) as u16;
+                }
+
+                if self.action_counter > 0 {
+                    self.action_counter -= 1;
+                } else {
+                    self.action_num = 0;
+
+                    let mut npc = NPC::create(338, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y + self.rng.range(-16..16) * 0x200;
+                    npc.direction = self.direction;
+
+                    let _ = npc_list.spawn(0x100, npc);
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n347_hoppy(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                }
+
+                self.anim_num = 0;
+
+                if player.y < self.y + 128 * 0x200 && player.y > self.y - 128 * 0x200 {
+                    self.action_num = 10;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+            }
+            10 => {
+                self.action_counter += 1;
+                if self.action_counter == 4 {
+                    self.anim_num = 2;
+                }
+
+                if self.action_counter > 12 {
+                    self.action_num = 12;
+                    self.anim_num = 3;
+                    self.vel_x = 0x700;
+
+                    state.sound_manager.play_sfx(6);
+                }
+            }
+            12 => {
+                // pushes off the wall and curves towards the player
+                self.vel_y += if player.y < self.y { -0xaa } else { 0xaa };
+                self.vel_x -= 0x2a;
+
+                if self.flags.hit_left_wall() && self.vel_x < 0 {
+                    self.action_num = 13;
+                    self.action_counter = 0;
+                    self.anim_num = 2;
+                    self.vel_x = 0;
+                    self.vel_y = 0;
+                }
+            }
+            13 => {
+                self.action_counter += 1;
+                if self.action_counter > 2 {
+                    self.action_num = 1;
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y = clamp(self.vel_y, -0x300, 0x300);
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.anim_rect = state.constants.npc.n347_hoppy[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n349_statue(&mut self, state: &mut SharedGameState) -> GameResult {
+        if self.action_num == 0 {
+            self.action_num = 1;
+
+            match self.direction {
+                Direction::Left => self.x += 8 * 0x200,
+                Direction::Right => self.y += 16 * 0x200,
+                _ => {}
+            }
+
+            self.anim_rect = state.constants.npc.n349_statue;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n350_flying_bute_archer(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList, stage: &Stage) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.target_x = self.x + self.direction.vector_x() * 128 * 0x200;
+                    self.target_y = self.y;
+                    self.vel_x = self.direction.vector_x() * 0x400;
+                    self.vel_y = self.rng.range(-0x200..0x200);
+                }
+
+                self.animate(1, 0, 1);
+
+                if (self.direction == Direction::Left && self.x <= self.target_x)
+                    || (self.direction == Direction::Right && self.x >= self.target_x) {
+                    self.action_num = 20;
+                }
+            }
+            20 | 21 => {
+                if self.action_num == 20 {
+                    self.action_num = 21;
+                    self.action_counter = self.rng.range(0..150) as u16;
+                    self.anim_num = 2;
+                    self.anim_counter = 0;
+                }
+
+                self.animate(1, 2, 3);
+
+                self.action_counter += 1;
+                if self.action_counter > 300 {
+                    self.action_num = 30;
+                }
+
+                if abs(player.x - self.x) < 112 * 0x200 && abs(player.y - self.y) < 16 * 0x200 {
+                    self.action_num = 30;
+                }
+            }
+            30 | 31 => {
+                if self.action_num == 30 {
+                    self.action_num = 31;
+                    self.action_counter = 0;
+                    self.anim_counter = 0;
+                }
+
+                self.animate(1, 3, 4);
+
+                self.action_counter += 1;
+                if self.action_counter > 30 {
+                    self.action_num = 40;
+                    self.anim_num = 5;
+
+                    let mut npc = NPC::create(312, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y;
+                    npc.vel_x = self.direction.vector_x() * 0x600;
+                    npc.direction = self.direction;
+
+                    let _ = npc_list.spawn(0x100, npc);
+                }
+            }
+            40 | 41 => {
+                if self.action_num == 40 {
+                    self.action_num = 41;
+                    self.action_counter = 0;
+                    self.anim_counter = 0;
+                }
+
+                self.animate(1, 5, 6);
+
+                self.action_counter += 1;
+                if self.action_counter > 40 {
+                    self.action_num = 50;
+                }
+            }
+            50 | 51 => {
+                if self.action_num == 50 {
+                    self.action_num = 51;
+                    self.action_counter = 0;
+                }
+
+                self.animate(1, 0, 1);
+
+                self.vel_x -= self.direction.vector_x() * 0x20;
+
+                if self.x < 0 || self.x > stage.map.width as i32 * 16 * 0x200 {
+                    self.cond.set_alive(false);
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+
+        if self.action_num >= 20 && self.action_num < 50 {
+            self.vel_x = 7 * self.vel_x / 8;
+            self.vel_y += if self.y < self.target_y { 0x10 } else { -0x10 };
+        }
+
+        self.vel_x = clamp(self.vel_x, -0x400, 0x400);
+        self.vel_y = clamp(self.vel_y, -0x200, 0x200);
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        if self.life <= 992 {
+            self.npc_type = 316;
+            self.action_num = 0;
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 7 };
+
+        self.anim_rect = state.constants.npc.n350_flying_bute_archer[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n351_statue_shootable(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = (self.event_num / 10).min(4);
+                    self.x += 8 * 0x200;
+                    self.y += 12 * 0x200;
+                }
+
+                if self.life <= 900 {
+                    self.action_num = 10;
+                    self.anim_num = (self.anim_num + 4).min(8);
+                    self.npc_flags.set_shootable(false);
+
+                    npc_list.create_death_smoke(self.x, self.y, 16 * 0x200, 8, state, &self.rng);
+                    state.sound_manager.play_sfx(71);
+                }
+            }
+            _ => {}
+        }
+
+        self.anim_rect = state.constants.npc.n351_statue_shootable[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n353_bute_sword_flying(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.action_counter = 0;
+                    self.npc_flags.set_ignore_solidity(true);
+
+                    match self.direction {
+                        Direction::Left => self.vel_x = -0x600,
+                        Direction::Up => self.vel_y = -0x600,
+                        Direction::Right => self.vel_x = 0x600,
+                        Direction::Bottom => self.vel_y = 0x600,
+                        Direction::FacingPlayer => {}
+                    }
+                }
+
+                self.x += self.vel_x;
+                self.y += self.vel_y;
+
+                self.action_counter += 1;
+                if self.action_counter == 16 {
+                    self.npc_flags.set_ignore_solidity(false);
+                }
+
+                if self.action_counter > 20 {
+                    self.action_num = 10;
+                    self.vel_x2 = self.vel_x;
+                    self.vel_y2 = self.vel_y;
+                }
+
+                self.animate(1, 0, 1);
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.npc_flags.set_shootable(true);
+                    self.damage = 5;
+                }
+
+                if self.x > player.x {
+                    self.direction = Direction::Left;
+                    self.vel_x2 -= 0x10;
+                } else {
+                    self.direction = Direction::Right;
+                    self.vel_x2 += 0x10;
+                }
+
+                self.vel_y2 += if self.y > player.y { -0x10 } else { 0x10 };
+
+                if (self.vel_x2 < 0 && self.flags.hit_left_wall()) || (self.vel_x2 > 0 && self.flags.hit_right_wall()) {
+                    self.vel_x2 = -self.vel_x2;
+                }
+
+                if (self.vel_y2 < 0 && self.flags.hit_top_wall()) || (self.vel_y2 > 0 && self.flags.hit_bottom_wall()) {
+                    self.vel_y2 = -self.vel_y2;
+                }
+
+                self.vel_x2 = clamp(self.vel_x2, -0x5ff, 0x5ff);
+                self.vel_y2 = clamp(self.vel_y2, -0x5ff, 0x5ff);
+
+                self.x += self.vel_x2;
+                self.y += self.vel_y2;
+
+                self.animate(1, 0, 1);
+            }
+            _ => {}
+        }
+
+        if self.life <= 996 {
+            self.npc_type = 316;
+            self.action_num = 0;
+        }
+
+        // swings the sword once it has closed in
+        let sword_offset = if self.action_num >= 10 { 4 } else { 0 };
+        let dir_offset = match self.direction {
+            Direction::Left | Direction::Up => 0,
+            _ => 2,
+        };
+
+        self.anim_rect = state.constants.npc.n353_bute_sword_flying[self.anim_num as usize + dir_offset + sword_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n354_invisible_deathtrap_wall(&mut self, state: &mut SharedGameState, npc_list: &NPCList, stage: &mut Stage) -> GameResult {
+        match self.action_num {
+            0 => {
+                self.hit_bounds.bottom = 280 * 0x200;
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.action_counter = 0;
+                    self.x += if self.direction == Direction::Left { 16 * 0x200 } else { -16 * 0x200 };
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 100 {
+                    self.action_counter = 0;
+
+                    state.quake_counter = 20;
+                    state.sound_manager.play_sfx(26);
+                    state.sound_manager.play_sfx(12);
+
+                    self.x += if self.direction == Direction::Left { -16 * 0x200 } else { 16 * 0x200 };
+
+                    let x = (self.x / (16 * 0x200)) as usize;
+                    let y = (self.y / (16 * 0x200)) as usize;
+
+                    let mut npc = NPC::create(4, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+
+                    for i in 0..20 {
+                        if stage.change_tile(x, y + i, 109) {
+                            npc.y = (y + i) as i32 * 16 * 0x200;
+
+                            let _ = npc_list.spawn(0x100, npc.clone());
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
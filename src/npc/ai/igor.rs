@@ -1,4 +1,5 @@
 use ggez::GameResult;
+use num_traits::{abs, clamp};
 
 use crate::common::{CDEG_RAD, Direction};
 use crate::npc::list::NPCList;
@@ -379,4 +380,129 @@ impl NPC {
 
         Ok(())
     }
+
+    pub(crate) fn tick_n268_igor_enemy(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        if abs(self.x - player.x) > 320 * 0x200 || abs(self.y - player.y) > 240 * 0x200 {
+            self.action_num = 1;
+        }
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.y += 8 * 0x200;
+                }
+
+                self.animate(20, 0, 1);
+
+                if (abs(self.x - player.x) < 112 * 0x200 && abs(self.y - player.y) < 48 * 0x200) || self.shock > 0 {
+                    self.action_num = 10;
+                }
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.action_counter = 0;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+
+                    self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+                }
+
+                self.vel_x = self.direction.vector_x() * 0x200;
+
+                if abs(self.x - player.x) < 64 * 0x200
+                    || (self.vel_x < 0 && self.flags.hit_left_wall())
+                    || (self.vel_x > 0 && self.flags.hit_right_wall()) {
+                    self.action_num = 20;
+                    self.action_counter = 0;
+                }
+
+                self.animate(4, 2, 5);
+            }
+            20 => {
+                self.vel_x = 0;
+                self.anim_num = 6;
+
+                self.action_counter += 1;
+                if self.action_counter > 10 {
+                    self.action_num = 30;
+                    self.vel_y = -0x5ff;
+                    self.vel_x = self.direction.vector_x() * 0x200;
+
+                    state.sound_manager.play_sfx(108);
+                }
+            }
+            30 => {
+                self.anim_num = 7;
+
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 40;
+                    self.action_counter = 0;
+
+                    state.quake_counter = 20;
+                    state.sound_manager.play_sfx(26);
+                }
+            }
+            40 => {
+                self.vel_x = 0;
+                self.anim_num = 6;
+
+                self.action_counter += 1;
+                if self.action_counter > 30 {
+                    self.action_num = 50;
+                }
+            }
+            50 | 51 => {
+                if self.action_num == 50 {
+                    self.action_num = 51;
+                    self.action_counter = 0;
+
+                    self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 30 && self.action_counter % 4 == 1 {
+                    // breathes a spray of energy shots at the player
+                    let angle = f64::atan2((self.y - player.y) as f64, (self.x - player.x) as f64)
+                        + self.rng.range(-6..6) as f64 * CDEG_RAD;
+
+                    let mut npc = NPC::create(11, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x + self.direction.vector_x() * 16 * 0x200;
+                    npc.y = self.y;
+                    npc.vel_x = (angle.cos() * -1536.0) as i32;
+                    npc.vel_y = (angle.sin() * -1536.0) as i32;
+
+                    let _ = npc_list.spawn(0x100, npc);
+
+                    state.sound_manager.play_sfx(12);
+
+                    self.anim_num = 9;
+                } else {
+                    self.anim_num = 8;
+                }
+
+                if self.action_counter > 50 {
+                    self.action_num = 10;
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y += 0x33;
+
+        self.vel_x = clamp(self.vel_x, -0x5ff, 0x5ff);
+        self.vel_y = clamp(self.vel_y, -0x5ff, 0x5ff);
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 10 };
+        self.anim_rect = state.constants.npc.n268_igor_enemy[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
 }
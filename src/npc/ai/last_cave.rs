@@ -0,0 +1,468 @@
+use ggez::GameResult;
+use num_traits::{abs, clamp};
+
+use crate::caret::CaretType;
+use crate::common::Direction;
+use crate::npc::list::NPCList;
+use crate::npc::NPC;
+use crate::player::Player;
+use crate::rng::RNG;
+use crate::shared_game_state::SharedGameState;
+use crate::stage::Stage;
+
+impl NPC {
+    pub(crate) fn tick_n241_critter_red(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.y += 3 * 0x200;
+                }
+
+                if self.action_counter >= 8
+                    && abs(self.x - player.x) < 72 * 0x200
+                    && self.y - 48 * 0x200 < player.y
+                    && self.y + 24 * 0x200 > player.y {
+                    self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+                    self.anim_num = 1;
+                } else {
+                    if self.action_counter < 8 {
+                        self.action_counter += 1;
+                    }
+
+                    self.anim_num = 0;
+                }
+
+                if self.shock > 0 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 0;
+                }
+
+                if self.action_counter >= 8
+                    && abs(self.x - player.x) < 48 * 0x200
+                    && self.y - 48 * 0x200 < player.y
+                    && self.y + 24 * 0x200 > player.y {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 0;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 8 {
+                    self.action_num = 3;
+                    self.anim_num = 2;
+                    self.vel_y = -0x5ff;
+                    self.vel_x = self.direction.vector_x() * 0x200;
+
+                    state.sound_manager.play_sfx(30);
+                }
+            }
+            3 => {
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 1;
+                    self.action_counter = 0;
+                    self.anim_num = 0;
+                    self.vel_x = 0;
+
+                    state.sound_manager.play_sfx(23);
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y += 0x55;
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 3 };
+        self.anim_rect = state.constants.npc.n241_critter_red[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n242_bat_last_cave(&mut self, state: &mut SharedGameState, stage: &Stage) -> GameResult {
+        if self.x < 0 || self.x > stage.map.width as i32 * 16 * 0x200 {
+            self.vanish(state);
+            return Ok(());
+        }
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.target_x = self.x;
+                    self.target_y = self.y;
+                    self.action_counter = self.rng.range(0..50) as u16;
+                }
+
+                self.action_counter += 1;
+                if self.action_counter >= 50 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.vel_y = 0x300;
+                }
+            }
+            2 => {
+                self.vel_x = self.direction.vector_x() * 0x100;
+
+                self.vel_y += if self.y > self.target_y { -0x10 } else { 0x10 };
+                self.vel_y = clamp(self.vel_y, -0x300, 0x300);
+            }
+            _ => {}
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.animate(1, 0, 2);
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 4 };
+        self.anim_rect = state.constants.npc.n242_bat_last_cave[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n243_bat_generator(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.action_counter = self.rng.range(0..500) as u16;
+                }
+
+                if self.action_counter > 0 {
+                    self.action_counter -= 1;
+                } else {
+                    self.action_num = 0;
+
+                    let mut npc = NPC::create(242, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y + self.rng.range(-32..32) * 0x200;
+                    npc.direction = self.direction;
+
+                    let _ = npc_list.spawn(0x100, npc);
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n244_lava_drop(&mut self, state: &mut SharedGameState, stage: &Stage) -> GameResult {
+        self.vel_y += 0x40;
+
+        self.action_counter += 1;
+        if self.action_counter > 10 && ((self.flags.0 & 0xff) != 0 || self.flags.in_water()) {
+            for _ in 0..3 {
+                state.create_caret(self.x, self.y + 4 * 0x200, CaretType::Bubble, Direction::Right);
+            }
+
+            self.cond.set_alive(false);
+            return Ok(());
+        }
+
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.y += self.vel_y;
+
+        if self.y > stage.map.height as i32 * 16 * 0x200 {
+            self.cond.set_alive(false);
+            return Ok(());
+        }
+
+        self.anim_rect = state.constants.npc.n244_lava_drop;
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n245_lava_drop_generator(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.npc_flags.set_invulnerable(true);
+                    self.target_x = self.x;
+                    self.action_counter = self.event_num;
+                }
+
+                self.anim_num = 0;
+
+                if self.action_counter > 0 {
+                    self.action_counter -= 1;
+                } else {
+                    self.action_num = 10;
+                    self.anim_counter = 0;
+                }
+            }
+            10 => {
+                self.anim_counter += 1;
+                if self.anim_counter > 10 {
+                    self.anim_counter = 0;
+                    self.anim_num += 1;
+                }
+
+                if self.anim_num > 3 {
+                    self.anim_num = 0;
+                    self.action_num = 1;
+                    self.action_counter = self.flag_num;
+
+                    let mut npc = NPC::create(244, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y;
+
+                    let _ = npc_list.spawn(0x100, npc);
+                }
+            }
+            _ => {}
+        }
+
+        self.x = self.target_x + if self.anim_counter / 2 % 2 != 0 { 0x200 } else { 0 };
+
+        self.anim_rect = state.constants.npc.n245_lava_drop_generator[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n246_press_proximity(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.y -= 4 * 0x200;
+                }
+
+                if abs(self.x - player.x) < 8 * 0x200 && player.y > self.y + 8 * 0x200 && player.y < self.y + 128 * 0x200 {
+                    self.action_num = 5;
+                }
+            }
+            5 => {
+                if !self.flags.hit_bottom_wall() {
+                    self.action_num = 10;
+                    self.anim_num = 1;
+                    self.anim_counter = 0;
+                }
+            }
+            10 => {
+                self.anim_counter += 1;
+                if self.anim_counter > 2 {
+                    self.anim_counter = 0;
+                    self.anim_num += 1;
+                    if self.anim_num > 2 {
+                        self.anim_num = 2;
+                    }
+                }
+
+                // only hurts while coming down on top of the player
+                if player.y > self.y {
+                    self.npc_flags.set_solid_hard(false);
+                    self.damage = 127;
+                } else {
+                    self.npc_flags.set_solid_hard(true);
+                    self.damage = 0;
+                }
+
+                if self.flags.hit_bottom_wall() {
+                    if self.anim_num > 1 {
+                        npc_list.create_death_smoke(self.x, self.y, 0, 4, state, &self.rng);
+
+                        state.quake_counter = 10;
+                        state.sound_manager.play_sfx(26);
+                    }
+
+                    self.action_num = 20;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                    self.npc_flags.set_solid_hard(true);
+                    self.damage = 0;
+                }
+            }
+            _ => {}
+        }
+
+        if self.action_num >= 5 {
+            self.vel_y += 0x80;
+            if self.vel_y > 0x5ff {
+                self.vel_y = 0x5ff;
+            }
+
+            self.y += self.vel_y;
+        }
+
+        self.anim_rect = state.constants.npc.n246_press_proximity[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n276_red_demon(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.target_x = self.x;
+                    self.y -= 8 * 0x200;
+                    self.vel_x = 0;
+                }
+
+                self.animate(20, 0, 1);
+
+                if self.shock > 0 {
+                    self.action_num = 10;
+                }
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.action_counter = 0;
+                    self.anim_num = 3;
+                    self.npc_flags.set_shootable(true);
+                }
+
+                self.action_counter += 1;
+                match self.action_counter {
+                    30 | 40 | 50 => {
+                        self.anim_num = 4;
+                        self.tick_n276_red_demon_shoot(state, player, npc_list);
+                    }
+                    34 | 44 | 54 => self.anim_num = 3,
+                    _ => {}
+                }
+
+                if self.action_counter > 60 {
+                    self.action_num = 20;
+                    self.action_counter = 0;
+                    self.anim_num = 2;
+                }
+            }
+            20 => {
+                self.action_counter += 1;
+                if self.action_counter > 20 {
+                    self.action_num = 21;
+                    self.action_counter = 0;
+                    self.anim_num = 5;
+                    self.vel_y = -0x5ff;
+                    self.vel_x = if self.x < player.x { 0x100 } else { -0x100 };
+                }
+            }
+            21 => {
+                self.action_counter += 1;
+                match self.action_counter {
+                    30 | 40 | 50 => {
+                        self.anim_num = 6;
+                        self.tick_n276_red_demon_shoot(state, player, npc_list);
+                    }
+                    34 | 44 => self.anim_num = 5,
+                    54 => self.anim_num = 7,
+                    _ => {}
+                }
+
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 22;
+                    self.action_counter = 0;
+                    self.anim_num = 2;
+
+                    state.quake_counter = 10;
+                    state.sound_manager.play_sfx(26);
+                }
+            }
+            22 => {
+                self.vel_x /= 2;
+
+                self.action_counter += 1;
+                if self.action_counter > 22 {
+                    self.action_num = 10;
+                }
+            }
+            50 | 51 => {
+                if self.action_num == 50 {
+                    self.action_num = 51;
+                    self.anim_num = 8;
+                    self.npc_flags.set_shootable(false);
+                    self.damage = 0;
+                }
+
+                if self.flags.hit_bottom_wall() {
+                    self.vel_x = 0;
+                }
+            }
+            _ => {}
+        }
+
+        if self.action_num < 50 {
+            self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+        }
+
+        self.vel_y += 0x20;
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 9 };
+        self.anim_rect = state.constants.npc.n276_red_demon[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    fn tick_n276_red_demon_shoot(&self, state: &mut SharedGameState, player: &Player, npc_list: &NPCList) {
+        let angle = f64::atan2((self.y - player.y) as f64, (self.x - player.x) as f64);
+
+        let mut npc = NPC::create(277, &state.npc_table);
+        npc.cond.set_alive(true);
+        npc.x = self.x;
+        npc.y = self.y;
+        npc.vel_x = (angle.cos() * -2048.0) as i32;
+        npc.vel_y = (angle.sin() * -2048.0) as i32;
+
+        let _ = npc_list.spawn(0x100, npc);
+
+        state.sound_manager.play_sfx(39);
+    }
+
+    pub(crate) fn tick_n277_red_demon_projectile(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        if self.action_num == 0 {
+            self.action_num = 1;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        if (self.flags.0 & 0xff) != 0 {
+            npc_list.create_death_smoke(self.x, self.y, 0, 3, state, &self.rng);
+            self.cond.set_alive(false);
+            return Ok(());
+        }
+
+        self.action_counter += 1;
+        if self.action_counter % 5 == 0 {
+            state.sound_manager.play_sfx(110);
+        }
+
+        self.anim_num += 1;
+        if self.anim_num > 2 {
+            self.anim_num = 0;
+        }
+
+        self.anim_rect = state.constants.npc.n277_red_demon_projectile[self.anim_num as usize];
+
+        Ok(())
+    }
+}
@@ -1,33 +1,1353 @@
 use ggez::GameResult;
-use num_traits::clamp;
+use num_traits::{abs, clamp};
 
 use crate::caret::CaretType;
-use crate::common::Direction;
+use crate::common::{CDEG_RAD, Direction};
 use crate::npc::list::NPCList;
 use crate::npc::NPC;
 use crate::player::Player;
+use crate::rng::RNG;
 use crate::shared_game_state::SharedGameState;
 
 impl NPC {
+    pub(crate) fn tick_n156_gaudi_projectile(&mut self, state: &mut SharedGameState) -> GameResult {
+        if self.action_counter > 300 || (self.flags.0 & 0xff) != 0 {
+            self.cond.set_alive(false);
+            state.create_caret(self.x, self.y, CaretType::ProjectileDissipation, Direction::Left);
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.anim_num = (self.anim_num + 1) % 3;
+
+        self.anim_rect = state.constants.npc.n156_gaudi_projectile[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n147_critter_purple(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.y += 3 * 0x200;
+                }
+
+                if self.action_counter >= 8 && abs(player.x - self.x) < 96 * 0x200
+                    && player.y > self.y - 96 * 0x200 && player.y < self.y + 32 * 0x200 {
+                    self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+                    self.anim_num = 1;
+                } else {
+                    if self.action_counter < 8 {
+                        self.action_counter += 1;
+                    }
+
+                    self.anim_num = 0;
+                }
+
+                if self.shock > 0 || (self.action_counter >= 8 && abs(player.x - self.x) < 48 * 0x200
+                    && player.y > self.y - 96 * 0x200 && player.y < self.y + 32 * 0x200) {
+                    self.action_num = 2;
+                    self.anim_num = 0;
+                    self.action_counter = 0;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 8 {
+                    self.action_num = 3;
+                    self.anim_num = 2;
+                    self.vel_y = -0x5ff;
+                    self.vel_x = self.direction.vector_x() * 0x100;
+
+                    state.sound_manager.play_sfx(30);
+                }
+            }
+            3 => {
+                if self.vel_y > 0x100 {
+                    self.target_y = self.y;
+                    self.action_num = 4;
+                    self.anim_num = 3;
+                    self.action_counter = 0;
+                }
+            }
+            4 => {
+                self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+
+                self.action_counter += 1;
+                if (self.flags.0 & 0x07) != 0 || self.action_counter > 60 {
+                    self.damage = 3;
+                    self.action_num = 5;
+                    self.anim_num = 2;
+                } else {
+                    // hovers in the air for a while, spitting at the player
+                    if self.action_counter % 30 == 6 {
+                        let angle = f64::atan2((self.y - player.y) as f64, (self.x - player.x) as f64)
+                            + self.rng.range(-6..6) as f64 * CDEG_RAD;
+
+                        let mut npc = NPC::create(148, &state.npc_table);
+                        npc.cond.set_alive(true);
+                        npc.x = self.x;
+                        npc.y = self.y;
+                        npc.vel_x = (angle.cos() * -1536.0) as i32;
+                        npc.vel_y = (angle.sin() * -1536.0) as i32;
+
+                        let _ = npc_list.spawn(0x100, npc);
+
+                        state.sound_manager.play_sfx(39);
+                    }
+
+                    self.anim_num += 1;
+                    if self.anim_num > 4 {
+                        self.anim_num = 3;
+                    }
+                }
+            }
+            5 => {
+                if self.flags.hit_bottom_wall() {
+                    self.damage = 2;
+                    self.vel_x = 0;
+                    self.action_counter = 0;
+                    self.anim_num = 0;
+                    self.action_num = 1;
+
+                    state.sound_manager.play_sfx(23);
+                }
+            }
+            _ => {}
+        }
+
+        if self.action_num == 4 {
+            self.vel_y += if self.y > self.target_y { -0x10 } else { 0x10 };
+
+            self.vel_x = clamp(self.vel_x, -0x200, 0x200);
+            self.vel_y = clamp(self.vel_y, -0x200, 0x200);
+        } else {
+            self.vel_y += 0x20;
+            if self.vel_y > 0x5ff {
+                self.vel_y = 0x5ff;
+            }
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 6 };
+        self.anim_rect = state.constants.npc.n147_critter_purple[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n148_critter_purple_projectile(&mut self, state: &mut SharedGameState) -> GameResult {
+        if (self.flags.0 & 0xff) != 0 {
+            state.create_caret(self.x, self.y, CaretType::ProjectileDissipation, Direction::Left);
+            self.cond.set_alive(false);
+            return Ok(());
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.anim_num += 1;
+        if self.anim_num > 1 {
+            self.anim_num = 0;
+        }
+
+        self.anim_rect = state.constants.npc.n148_critter_purple_projectile[self.anim_num as usize];
+
+        self.action_counter2 += 1;
+        if self.action_counter2 > 300 {
+            state.create_caret(self.x, self.y, CaretType::ProjectileDissipation, Direction::Left);
+            self.cond.set_alive(false);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n152_shutter_stuck(&mut self) -> GameResult {
+        if self.action_num == 0 {
+            self.action_num = 1;
+
+            if self.direction == Direction::Right {
+                self.y += 16 * 0x200;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n153_gaudi(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        if abs(self.x - player.x) > 320 * 0x200 || abs(self.y - player.y) > 240 * 0x200 {
+            return Ok(());
+        }
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.vel_x = 0;
+                    self.anim_num = 0;
+                    self.y += 3 * 0x200;
+                }
+
+                if self.rng.range(0..100) == 1 {
+                    self.action_num = 2;
+                    self.anim_num = 1;
+                    self.action_counter = 0;
+                }
+
+                if self.rng.range(0..100) == 1 {
+                    self.direction = self.direction.opposite();
+                }
+
+                if self.rng.range(0..100) == 1 {
+                    self.action_num = 10;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 20 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.action_counter = self.rng.range(25..100) as u16;
+                    self.anim_num = 2;
+                    self.anim_counter = 0;
+                }
+
+                self.animate(3, 2, 5);
+
+                self.vel_x = self.direction.vector_x() * 0x200;
+
+                if self.action_counter > 0 {
+                    self.action_counter -= 1;
+                } else {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                    self.vel_x = 0;
+                }
+
+                if (self.direction == Direction::Left && self.flags.hit_left_wall())
+                    || (self.direction == Direction::Right && self.flags.hit_right_wall()) {
+                    self.anim_num = 2;
+                    self.vel_y = -0x5ff;
+                    self.action_num = 20;
+
+                    if !player.cond.hidden() {
+                        state.sound_manager.play_sfx(30);
+                    }
+                }
+            }
+            20 => {
+                if (self.direction == Direction::Left && self.flags.hit_left_wall())
+                    || (self.direction == Direction::Right && self.flags.hit_right_wall()) {
+                    self.action_counter2 += 1;
+                } else {
+                    self.action_counter2 = 0;
+                }
+
+                if self.action_counter2 > 10 {
+                    self.direction = self.direction.opposite();
+                }
+
+                self.vel_x = self.direction.vector_x() * 0x100;
+
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 21;
+                    self.anim_num = 2;
+                    self.action_counter = 0;
+                    self.vel_x = 0;
+
+                    if !player.cond.hidden() {
+                        state.sound_manager.play_sfx(23);
+                    }
+                }
+            }
+            21 => {
+                self.action_counter += 1;
+                if self.action_counter > 10 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y += 0x40;
+
+        self.vel_x = clamp(self.vel_x, -0x5ff, 0x5ff);
+        self.vel_y = clamp(self.vel_y, -0x5ff, 0x5ff);
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 7 };
+        self.anim_rect = state.constants.npc.n153_gaudi[self.anim_num as usize + dir_offset];
+
+        if self.life <= 985 {
+            self.npc_type = 154;
+            self.action_num = 0;
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn tick_n154_gaudi_dead(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 => {
+                self.action_num = 1;
+                self.anim_num = 0;
+                self.damage = 0;
+                self.npc_flags.set_shootable(false);
+                self.npc_flags.set_ignore_solidity(false);
+                self.vel_y = -0x200;
+                self.vel_x = if self.direction == Direction::Left { 0x100 } else { -0x100 };
+
+                state.sound_manager.play_sfx(53);
+            }
+            1 => {
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                    self.anim_counter = 0;
+                }
+            }
+            2 => {
+                self.vel_x = 8 * self.vel_x / 9;
+
+                self.animate(3, 1, 2);
+
+                self.action_counter += 1;
+                if self.action_counter > 50 {
+                    self.cond.set_explode_die(true);
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y += 0x20;
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
         let dir_offset = if self.direction == Direction::Left { 0 } else { 3 };
         self.anim_rect = state.constants.npc.n154_gaudi_dead[self.anim_num as usize + dir_offset];
 
         Ok(())
     }
 
-    pub(crate) fn tick_n156_gaudi_projectile(&mut self, state: &mut SharedGameState) -> GameResult {
-        if self.action_counter > 300 || (self.flags.0 & 0xff) != 0 {
-            self.cond.set_alive(false);
+    pub(crate) fn tick_n155_gaudi_flying(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        if abs(self.x - player.x) > 320 * 0x200 || abs(self.y - player.y) > 240 * 0x200 {
+            return Ok(());
+        }
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    let angle = self.rng.range(0..0xff) as f64 * CDEG_RAD;
+                    self.vel_x = (angle.cos() * 512.0) as i32;
+                    self.target_x = self.x + ((angle + 64.0 * CDEG_RAD).cos() * 8.0 * 512.0) as i32;
+
+                    let angle = self.rng.range(0..0xff) as f64 * CDEG_RAD;
+                    self.vel_y = (angle.sin() * 512.0) as i32;
+                    self.target_y = self.y + ((angle + 64.0 * CDEG_RAD).sin() * 8.0 * 512.0) as i32;
+
+                    self.action_num = 1;
+                    self.action_counter = self.rng.range(70..150) as u16;
+                    self.anim_num = 0;
+                }
+
+                self.animate(0, 0, 1);
+
+                if self.action_counter > 0 {
+                    self.action_counter -= 1;
+                } else {
+                    self.action_num = 2;
+                    self.action_counter = 30;
+                    self.anim_num = 2;
+                }
+            }
+            2 => {
+                // flashes for a moment before spitting
+                self.anim_num = if self.action_counter / 2 % 2 == 0 { 2 } else { 3 };
+
+                if self.action_counter > 0 {
+                    self.action_counter -= 1;
+                } else {
+                    self.action_num = 1;
+                    self.action_counter = self.rng.range(70..150) as u16;
+                    self.anim_num = 0;
+
+                    let angle = f64::atan2((self.y - player.y) as f64, (self.x - player.x) as f64)
+                        + self.rng.range(-6..6) as f64 * CDEG_RAD;
+
+                    let mut npc = NPC::create(156, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y;
+                    npc.vel_x = (angle.cos() * -1536.0) as i32;
+                    npc.vel_y = (angle.sin() * -1536.0) as i32;
+
+                    let _ = npc_list.spawn(0x100, npc);
+
+                    if !player.cond.hidden() {
+                        state.sound_manager.play_sfx(39);
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+
+        self.vel_x += if self.x > self.target_x { -0x10 } else { 0x10 };
+        self.vel_y += if self.y > self.target_y { -0x10 } else { 0x10 };
+
+        self.vel_x = clamp(self.vel_x, -0x200, 0x200);
+        self.vel_y = clamp(self.vel_y, -0x200, 0x200);
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 4 };
+        self.anim_rect = state.constants.npc.n155_gaudi_flying[self.anim_num as usize + dir_offset];
+
+        if self.life <= 985 {
+            self.npc_type = 154;
+            self.action_num = 0;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n159_monster_x_defeated(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+
+                    let mut npc = NPC::create(4, &state.npc_table);
+                    npc.cond.set_alive(true);
+
+                    for _ in 0..8 {
+                        npc.x = self.x + self.rng.range(-16..16) * 0x200;
+                        npc.y = self.y + self.rng.range(-16..16) * 0x200;
+                        npc.vel_x = self.rng.range(-341..341);
+                        npc.vel_y = self.rng.range(-341..341);
+
+                        let _ = npc_list.spawn(0x100, npc.clone());
+                    }
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 50 {
+                    self.action_num = 2;
+                    self.vel_x = -0x100;
+                }
+
+                if self.action_counter / 2 % 2 != 0 {
+                    self.x += 0x200;
+                } else {
+                    self.x -= 0x200;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                self.vel_y += 0x40;
+
+                if self.y > 40 * 16 * 0x200 {
+                    self.cond.set_alive(false);
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.anim_rect = state.constants.npc.n159_monster_x_defeated;
+
+        if self.action_counter % 8 == 1 {
+            let mut npc = NPC::create(4, &state.npc_table);
+            npc.cond.set_alive(true);
+            npc.x = self.x + self.rng.range(-16..16) * 0x200;
+            npc.y = self.y + self.rng.range(-16..16) * 0x200;
+            npc.vel_x = self.rng.range(-341..341);
+            npc.vel_y = self.rng.range(-341..341);
+
+            let _ = npc_list.spawn(0x100, npc);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n160_puu_black(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.npc_flags.set_invulnerable(true);
+                }
+
+                self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+                self.vel_y = 0xa00;
+
+                if self.y >= 128 * 0x200 {
+                    self.npc_flags.set_ignore_solidity(false);
+                    self.action_num = 2;
+                }
+            }
+            2 => {
+                self.vel_y = 0xa00;
+
+                if self.flags.hit_bottom_wall() {
+                    for npc in npc_list.iter_alive() {
+                        if npc.npc_type == 161 {
+                            npc.cond.set_explode_die(true);
+                        }
+                    }
+
+                    npc_list.create_death_smoke(self.x, self.y + 12 * 0x200, 12 * 0x200, 4, state, &self.rng);
+
+                    self.action_num = 3;
+                    self.action_counter = 0;
+
+                    state.quake_counter = 30;
+                    state.sound_manager.play_sfx(26);
+                    state.sound_manager.play_sfx(72);
+                }
+
+                // crushing the player is the only way it deals damage
+                self.damage = if self.y < player.y && player.flags.hit_bottom_wall() { 20 } else { 0 };
+            }
+            3 => {
+                self.damage = 0;
+
+                self.action_counter += 1;
+                if self.action_counter > 24 {
+                    self.action_num = 4;
+                    self.action_counter2 = 0;
+                }
+            }
+            4 => {
+                state.npc_super_pos = (self.x, self.y);
+
+                if self.shock % 2 == 1 {
+                    let mut npc = NPC::create(161, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x + self.rng.range(-12..12) * 0x200;
+                    npc.y = self.y + self.rng.range(-12..12) * 0x200;
+                    npc.vel_x = self.rng.range(-0x600..0x600);
+                    npc.vel_y = self.rng.range(-0x600..0x600);
+
+                    let _ = npc_list.spawn(0x100, npc);
+
+                    self.action_counter2 += 1;
+                    if self.action_counter2 > 30 {
+                        self.action_counter2 = 0;
+                        self.action_num = 5;
+                        self.vel_y = -0xc00;
+                        self.npc_flags.set_ignore_solidity(true);
+                    }
+                }
+            }
+            5 => {
+                state.npc_super_pos = (self.x, self.y);
+
+                self.action_counter2 += 1;
+                if self.action_counter2 > 60 {
+                    self.action_counter2 = 0;
+                    self.action_num = 6;
+                }
+            }
+            6 => {
+                // sends the bubbles flying off the screen while it's gone
+                state.npc_super_pos = (player.x, 400 * 16 * 0x200);
+
+                self.action_counter2 += 1;
+                if self.action_counter2 > 110 {
+                    self.action_counter2 = 10;
+                    self.x = player.x;
+                    self.y = 0;
+                    self.vel_y = 0x5ff;
+                    self.action_num = 1;
+                }
+            }
+            _ => {}
+        }
+
+        self.y += self.vel_y;
+
+        self.anim_num = match self.action_num {
+            3 => 2,
+            4 => 0,
+            _ => 3,
+        };
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 4 };
+        self.anim_rect = state.constants.npc.n160_puu_black[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n161_puu_black_projectile(&mut self, state: &mut SharedGameState) -> GameResult {
+        self.exp = 0;
+
+        self.vel_x += if self.x > state.npc_super_pos.0 { -0x40 } else { 0x40 };
+        self.vel_y += if self.y > state.npc_super_pos.1 { -0x40 } else { 0x40 };
+
+        self.vel_x = clamp(self.vel_x, -0x11fd, 0x11fd);
+        self.vel_y = clamp(self.vel_y, -0x11fd, 0x11fd);
+
+        if self.life < 100 {
+            self.npc_flags.set_shootable(false);
+            self.npc_flags.set_invulnerable(false);
+            self.damage = 0;
+            self.anim_num = 2;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        if self.anim_num < 2 {
+            self.anim_num = if self.rng.range(0..10) == 2 { 0 } else { 1 };
+        }
+
+        self.anim_rect = state.constants.npc.n161_puu_black_projectile[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n162_puu_black_dead(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.action_counter = 0;
+
+                    for npc in npc_list.iter_alive() {
+                        if npc.npc_type == 161 {
+                            npc.cond.set_explode_die(true);
+                        }
+                    }
+
+                    state.sound_manager.play_sfx(72);
+
+                    let mut npc = NPC::create(4, &state.npc_table);
+                    npc.cond.set_alive(true);
+
+                    for _ in 0..10 {
+                        npc.x = self.x + self.rng.range(-12..12) * 0x200;
+                        npc.y = self.y + self.rng.range(-12..12) * 0x200;
+                        npc.vel_x = self.rng.range(-0x600..0x600);
+                        npc.vel_y = self.rng.range(-0x600..0x600);
+
+                        let _ = npc_list.spawn(0x100, npc.clone());
+                    }
+
+                    let player = self.get_closest_player_mut(players);
+                    self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+                }
+
+                self.action_counter += 1;
+                if self.action_counter % 4 == 0 {
+                    let mut npc = NPC::create(4, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x + self.rng.range(-12..12) * 0x200;
+                    npc.y = self.y + self.rng.range(-12..12) * 0x200;
+
+                    let _ = npc_list.spawn(0x100, npc);
+                }
+
+                if self.action_counter > 160 {
+                    self.action_counter = 0;
+                    self.action_num = 2;
+                    self.target_y = self.y;
+                }
+            }
+            2 => {
+                state.quake_counter = 2;
+
+                self.action_counter += 1;
+                if self.action_counter <= 240 {
+                    // sinks into the floor
+                    let offset = self.action_counter / 10;
+                    self.y = self.target_y + offset as i32 * 0x200;
+                    self.anim_num = 0;
+
+                    let dir_offset = if self.direction == Direction::Left { 0 } else { 1 };
+                    self.anim_rect = state.constants.npc.n162_puu_black_dead[dir_offset];
+                    self.anim_rect.bottom -= offset;
+                } else {
+                    self.action_counter = 0;
+                    self.action_num = 3;
+                    self.anim_rect = state.constants.npc.n162_puu_black_dead[2];
+                }
+
+                if self.action_counter % 3 == 2 {
+                    let mut npc = NPC::create(4, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x + self.rng.range(-12..12) * 0x200;
+                    npc.y = self.y - 12 * 0x200;
+                    npc.vel_x = self.rng.range(-0x200..0x200);
+                    npc.vel_y = 0x100;
+
+                    let _ = npc_list.spawn(0x100, npc);
+                }
+
+                if self.action_counter % 4 == 2 {
+                    state.sound_manager.play_sfx(21);
+                }
+            }
+            3 => {
+                self.action_counter += 1;
+                if self.action_counter >= 60 {
+                    for npc in npc_list.iter_alive() {
+                        if npc.npc_type == 161 {
+                            npc.cond.set_explode_die(true);
+                        }
+                    }
+
+                    self.cond.set_alive(false);
+                }
+            }
+            _ => {}
+        }
+
+        if self.action_num == 1 {
+            let dir_offset = if self.direction == Direction::Left { 0 } else { 1 };
+            self.anim_rect = state.constants.npc.n162_puu_black_dead[dir_offset];
+        }
+
+        state.npc_super_pos = (self.x, -1000 * 0x200);
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n163_dr_gero(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                }
+
+                if self.rng.range(0..120) == 10 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 8 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            _ => {}
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 2 };
+        self.anim_rect = state.constants.npc.n163_dr_gero[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n164_nurse_hasumi(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                }
+
+                if self.rng.range(0..120) == 10 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 8 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            _ => {}
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 2 };
+        self.anim_rect = state.constants.npc.n164_nurse_hasumi[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n166_chaba(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                }
+
+                if self.rng.range(0..120) == 10 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 8 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            _ => {}
+        }
+
+        self.anim_rect = state.constants.npc.n166_chaba[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n168_boulder(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 => {
+                self.action_num = 1;
+                self.anim_rect = state.constants.npc.n168_boulder;
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.action_counter = 0;
+                    self.target_x = self.x;
+                }
+
+                self.action_counter += 1;
+
+                self.x = self.target_x;
+                if self.action_counter / 3 % 2 != 0 {
+                    self.x += 0x200;
+                }
+            }
+            20 | 21 => {
+                if self.action_num == 20 {
+                    self.action_num = 21;
+                    self.action_counter = 0;
+                    self.vel_y = -0x400;
+                    self.vel_x = 0x100;
+
+                    state.sound_manager.play_sfx(25);
+                }
+
+                self.vel_y += 0x10;
+                self.x += self.vel_x;
+                self.y += self.vel_y;
+
+                if self.action_counter != 0 && self.flags.hit_bottom_wall() {
+                    self.action_num = 0;
+
+                    state.sound_manager.play_sfx(35);
+                    state.quake_counter = 40;
+                }
+
+                if self.action_counter == 0 {
+                    self.action_counter += 1;
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n171_fire_whirrr(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+
+        match self.action_num {
+            0 | 1 | 10 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.action_counter = self.rng.range(0..50) as u16;
+                    self.target_y = self.y;
+                }
+
+                if self.action_num == 1 {
+                    if self.action_counter > 0 {
+                        self.action_counter -= 1;
+                    } else {
+                        self.action_num = 10;
+                        self.vel_y = 0x200;
+                    }
+                }
+
+                if self.action_num == 10 {
+                    self.animate(0, 0, 1);
+
+                    self.vel_y += if self.y < self.target_y { 0x10 } else { -0x10 };
+                    self.vel_y = clamp(self.vel_y, -0x200, 0x200);
+                    self.y += self.vel_y;
+
+                    // vel_x2 holds the aiming counter, reset to negative values for a cooldown
+                    let in_front = if self.direction == Direction::Left {
+                        player.x < self.x && player.x > self.x - 160 * 0x200
+                    } else {
+                        player.x > self.x && player.x < self.x + 160 * 0x200
+                    };
+
+                    if in_front && abs(player.y - self.y) < 80 * 0x200 {
+                        self.vel_x2 += 1;
+                    }
+
+                    if self.vel_x2 > 20 {
+                        self.vel_x2 = -100;
+
+                        let mut npc = NPC::create(172, &state.npc_table);
+                        npc.cond.set_alive(true);
+                        npc.x = self.x;
+                        npc.y = self.y;
+                        npc.direction = self.direction;
+
+                        let _ = npc_list.spawn(0x100, npc);
+
+                        state.npc_curly_counter = self.rng.range(80..100) as u16;
+                        state.npc_curly_target = (self.x, self.y);
+                    }
+
+                    if self.vel_x2 < 0 {
+                        self.vel_x2 += 1;
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 2 };
+        self.anim_rect = state.constants.npc.n171_fire_whirrr[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n172_fire_whirrr_projectile(&mut self, state: &mut SharedGameState) -> GameResult {
+        if self.action_num == 0 {
+            self.action_num = 1;
+        }
+
+        self.animate(1, 0, 2);
+
+        self.x += self.direction.vector_x() * 0x200;
+
+        if self.flags.hit_left_wall() || self.flags.hit_right_wall() {
             state.create_caret(self.x, self.y, CaretType::ProjectileDissipation, Direction::Left);
+            self.vanish(state);
+            return Ok(());
+        }
+
+        self.anim_rect = state.constants.npc.n172_fire_whirrr_projectile[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n173_gaudi_armored(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        if abs(self.x - player.x) > 320 * 0x200 || abs(self.y - player.y) > 240 * 0x200 {
+            return Ok(());
+        }
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.target_x = self.x;
+                }
+
+                self.anim_num = 0;
+                self.vel_x = 0;
+
+                if self.action_counter < 5 {
+                    self.action_counter += 1;
+                } else if abs(player.x - self.x) < 192 * 0x200 && abs(player.y - self.y) < 160 * 0x200 {
+                    self.action_num = 10;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+            }
+            10 => {
+                self.action_counter += 1;
+                if self.action_counter > 3 {
+                    self.action_counter2 += 1;
+
+                    // every third hop is a high jump with a volley of shots
+                    if self.action_counter2 == 3 {
+                        self.action_counter2 = 0;
+                        self.action_num = 25;
+                        self.action_counter = 0;
+                        self.anim_num = 2;
+                        self.vel_y = -0x600;
+                        self.vel_x = if self.x < self.target_x { 0x80 } else { -0x80 };
+                    } else {
+                        self.action_num = 20;
+                        self.anim_num = 2;
+                        self.vel_y = -0x200;
+                        self.vel_x = if self.x < self.target_x { 0x200 } else { -0x200 };
+                    }
+
+                    state.sound_manager.play_sfx(30);
+                }
+            }
+            20 => {
+                self.action_counter += 1;
+                if self.action_counter > 2 && self.flags.hit_bottom_wall() {
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                    self.action_num = 10;
+
+                    state.sound_manager.play_sfx(23);
+                }
+            }
+            25 => {
+                self.action_counter += 1;
+                if self.action_counter == 30 || self.action_counter == 40 {
+                    let angle = f64::atan2((self.y - player.y) as f64, (self.x - player.x) as f64);
+
+                    let mut npc = NPC::create(174, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y;
+                    npc.vel_x = (angle.cos() * -2048.0) as i32;
+                    npc.vel_y = (angle.sin() * -2048.0) as i32;
+
+                    let _ = npc_list.spawn(0x100, npc);
+
+                    self.anim_num = 3;
+
+                    state.sound_manager.play_sfx(39);
+                    state.npc_curly_counter = self.rng.range(80..100) as u16;
+                    state.npc_curly_target = (self.x, self.y);
+                }
+
+                if self.action_counter == 35 || self.action_counter == 45 {
+                    self.anim_num = 2;
+                }
+
+                if self.action_counter > 5 && self.flags.hit_bottom_wall() {
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                    self.action_num = 10;
+
+                    state.sound_manager.play_sfx(23);
+                }
+            }
+            _ => {}
         }
 
+        self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+
+        self.vel_y += 0x33;
+
+        self.vel_x = clamp(self.vel_x, -0x5ff, 0x5ff);
+        self.vel_y = clamp(self.vel_y, -0x5ff, 0x5ff);
+
         self.x += self.vel_x;
         self.y += self.vel_y;
 
-        self.anim_num = (self.anim_num + 1) % 3;
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 4 };
+        self.anim_rect = state.constants.npc.n173_gaudi_armored[self.anim_num as usize + dir_offset];
 
-        self.anim_rect = state.constants.npc.n156_gaudi_projectile[self.anim_num as usize];
+        if self.life <= 985 {
+            npc_list.create_death_smoke(self.x, self.y, 0, 2, state, &self.rng);
+            self.npc_type = 154;
+            self.action_num = 0;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n174_gaudi_armored_projectile(&mut self, state: &mut SharedGameState) -> GameResult {
+        let mut bounced = false;
+
+        if self.flags.hit_left_wall() || self.flags.hit_right_wall() {
+            self.vel_x = -self.vel_x;
+            bounced = true;
+        }
+
+        if self.flags.hit_top_wall() || self.flags.hit_bottom_wall() {
+            self.vel_y = -self.vel_y;
+            bounced = true;
+        }
+
+        if bounced {
+            self.action_counter2 += 1;
+        }
+
+        self.action_counter += 1;
+        if self.action_counter2 > 2 || self.action_counter > 300 {
+            state.create_caret(self.x, self.y, CaretType::ProjectileDissipation, Direction::Left);
+            self.cond.set_alive(false);
+            return Ok(());
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.animate(0, 0, 2);
+
+        self.anim_rect = state.constants.npc.n174_gaudi_armored_projectile[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n175_gaudi_egg(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        if self.action_num < 3 && self.life < 90 {
+            if let Some(table_entry) = state.npc_table.get_entry(self.npc_type) {
+                state.sound_manager.play_sfx(table_entry.death_sound);
+            }
+
+            npc_list.create_death_smoke(self.x, self.y, self.display_bounds.right, 7, state, &self.rng);
+            self.create_xp_drop(state, npc_list);
+
+            self.action_num = 10;
+            self.anim_num = 1;
+            self.npc_flags.set_shootable(false);
+            self.damage = 0;
+        }
+
+        if self.action_num == 0 {
+            self.action_num = 1;
+            self.anim_num = 0;
+        }
+
+        // eggs stick to either the floor or the ceiling
+        self.vel_y += if self.direction == Direction::Left { 0x20 } else { -0x20 };
+        self.vel_y = clamp(self.vel_y, -0x5ff, 0x5ff);
+
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 2 };
+        self.anim_rect = state.constants.npc.n175_gaudi_egg[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n176_buyo_buyo_base(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        if self.action_num < 3 && self.life < 940 {
+            if let Some(table_entry) = state.npc_table.get_entry(self.npc_type) {
+                state.sound_manager.play_sfx(table_entry.death_sound);
+            }
+
+            npc_list.create_death_smoke(self.x, self.y, self.display_bounds.right, 7, state, &self.rng);
+            self.create_xp_drop(state, npc_list);
+
+            self.action_num = 10;
+            self.anim_num = 2;
+            self.npc_flags.set_shootable(false);
+            self.damage = 0;
+        }
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                }
+
+                let player = self.get_closest_player_mut(players);
+
+                let in_range = abs(player.x - self.x) < 160 * 0x200 && if self.direction == Direction::Left {
+                    self.y < player.y + 160 * 0x200 && self.y > player.y - 16 * 0x200
+                } else {
+                    self.y < player.y + 16 * 0x200 && self.y > player.y - 160 * 0x200
+                };
+
+                // vel_x2 holds the shot counter, reset to negative values for a cooldown
+                if in_range {
+                    self.vel_x2 += 1;
+                }
+
+                if self.vel_x2 > 10 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                }
+            }
+            2 => {
+                self.animate(3, 0, 1);
+
+                self.action_counter += 1;
+                if self.action_counter > 10 {
+                    self.action_counter2 += 1;
+                    if self.action_counter2 > 2 {
+                        self.action_counter2 = 0;
+                        self.vel_x2 = -90;
+                    } else {
+                        self.vel_x2 = -10;
+                    }
+
+                    let mut npc = NPC::create(177, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+
+                    if self.direction == Direction::Left {
+                        npc.y = self.y - 8 * 0x200;
+                        npc.direction = Direction::Left;
+                    } else {
+                        npc.y = self.y + 8 * 0x200;
+                        npc.direction = Direction::Right;
+                    }
+
+                    let _ = npc_list.spawn(0x100, npc);
+
+                    state.sound_manager.play_sfx(39);
+
+                    self.action_num = 0;
+                    self.anim_num = 0;
+
+                    state.npc_curly_counter = self.rng.range(80..100) as u16;
+                    state.npc_curly_target = (self.x, self.y);
+                }
+            }
+            _ => {}
+        }
+
+        if self.action_num < 2 && self.vel_x2 < 0 {
+            self.vel_x2 += 1;
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 3 };
+        self.anim_rect = state.constants.npc.n176_buyo_buyo_base[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n177_buyo_buyo(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        if (self.flags.0 & 0xff) != 0 {
+            state.create_caret(self.x, self.y, CaretType::Shoot, Direction::Left);
+            self.cond.set_alive(false);
+            return Ok(());
+        }
+
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.vel_y = if self.direction == Direction::Left { -0x600 } else { 0x600 };
+                    self.vel_x = 0;
+                }
+
+                // launched straight out of the base, then starts homing in
+                self.vel_y += if self.direction == Direction::Left { 0x40 } else { -0x40 };
+
+                self.action_counter += 1;
+                if self.action_counter == 16 {
+                    self.action_num = 10;
+                }
+            }
+            10 => {
+                self.vel_x += if self.x < player.x { 0x20 } else { -0x20 };
+                self.vel_y += if self.y < player.y { 0x20 } else { -0x20 };
+
+                self.vel_x = clamp(self.vel_x, -0x3ff, 0x3ff);
+                self.vel_y = clamp(self.vel_y, -0x3ff, 0x3ff);
+
+                self.action_counter += 1;
+                if self.action_counter > 300 {
+                    state.create_caret(self.x, self.y, CaretType::Shoot, Direction::Left);
+                    self.cond.set_alive(false);
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.animate(6, 0, 1);
+
+        self.anim_rect = state.constants.npc.n177_buyo_buyo[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n361_gaudi_dashing(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        if self.action_num == 0 {
+            self.action_num = 1;
+            self.vel_x = self.direction.vector_x() * 0x400;
+        }
+
+        self.animate(1, 0, 1);
+
+        if (self.direction == Direction::Left && self.flags.hit_left_wall())
+            || (self.direction == Direction::Right && self.flags.hit_right_wall()) {
+            npc_list.create_death_smoke(self.x, self.y, self.display_bounds.right, 4, state, &self.rng);
+            self.cond.set_alive(false);
+            return Ok(());
+        }
+
+        self.x += self.vel_x;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 2 };
+        self.anim_rect = state.constants.npc.n361_gaudi_dashing[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n304_gaudi_hospital(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.y += 10 * 0x200;
+                }
+
+                self.anim_num = 0;
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.anim_num = 1;
+                    self.anim_counter = 0;
+                }
+
+                self.animate(2, 1, 2);
+            }
+            20 | 21 => {
+                if self.action_num == 20 {
+                    self.action_num = 21;
+                    self.anim_num = 3;
+                }
+            }
+            _ => {}
+        }
+
+        self.anim_rect = state.constants.npc.n304_gaudi_hospital[self.anim_num as usize];
 
         Ok(())
     }
@@ -4,10 +4,12 @@ use ggez::GameResult;
 use num_traits::{abs, clamp};
 
 use crate::common::Direction;
+use crate::npc::list::NPCList;
 use crate::npc::NPC;
 use crate::player::Player;
 use crate::rng::RNG;
 use crate::shared_game_state::SharedGameState;
+use crate::stage::Stage;
 
 impl NPC {
     pub(crate) fn tick_n069_pignon(&mut self, state: &mut SharedGameState) -> GameResult {
@@ -476,4 +478,358 @@ impl NPC {
 
         Ok(())
     }
+
+    pub(crate) fn tick_n258_mimiga_sleeping(&mut self, state: &mut SharedGameState) -> GameResult {
+        self.anim_rect = state.constants.npc.n258_mimiga_sleeping;
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n313_ma_pignon(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList, stage: &Stage) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                    self.y += 4 * 0x200;
+                }
+
+                if self.rng.range(0..100) == 1 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+
+                self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 8 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            100 | 110 => {
+                if self.action_num == 100 {
+                    self.action_num = 110;
+                    self.action_counter = 0;
+                    self.npc_flags.set_shootable(true);
+                    self.vel_x = 0;
+                }
+
+                self.anim_num = 0;
+                self.vel_x = 0;
+                self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+
+                self.action_counter += 1;
+                if self.action_counter > 4 {
+                    self.action_counter = 0;
+                    self.action_counter2 += 1;
+
+                    self.action_num = match self.action_counter2 % 8 {
+                        2 | 6 => 200,
+                        4 => 300,
+                        7 => 400,
+                        _ => 120,
+                    };
+                }
+            }
+            120 | 121 | 122 => {
+                if self.action_num == 120 {
+                    self.action_num = 121;
+                    self.action_counter = 0;
+                    self.anim_num = 2;
+                }
+
+                if self.action_num == 121 {
+                    self.action_counter += 1;
+                    if self.action_counter > 16 {
+                        self.action_num = 122;
+                        self.anim_num = 3;
+                        self.vel_x = self.direction.vector_x() * 0x200;
+                        self.vel_y = -0x600;
+
+                        state.sound_manager.play_sfx(30);
+                    }
+                } else {
+                    if self.vel_y > 0 {
+                        self.anim_num = 4;
+                    }
+
+                    if self.flags.hit_bottom_wall() {
+                        self.action_num = 110;
+                        self.action_counter = 0;
+                        self.vel_x = 0;
+
+                        state.sound_manager.play_sfx(23);
+                    }
+                }
+            }
+            200 | 201 | 202 => {
+                if self.action_num == 200 {
+                    self.action_num = 201;
+                    self.action_counter = 0;
+                    self.anim_num = 2;
+                }
+
+                if self.action_num == 201 {
+                    self.action_counter += 1;
+                    if self.action_counter > 16 {
+                        self.action_num = 202;
+                        self.anim_num = 3;
+                        self.vel_x = 0;
+                        self.vel_y = -0xa00;
+
+                        state.sound_manager.play_sfx(30);
+                    }
+                } else {
+                    // headbutts the ceiling, shaking rocks loose
+                    if self.flags.hit_top_wall() && self.vel_y2 == 0 {
+                        self.vel_y2 = 1;
+                        state.quake_counter = 30;
+                        state.sound_manager.play_sfx(26);
+
+                        for _ in 0..4 {
+                            let mut npc = NPC::create(314, &state.npc_table);
+                            npc.cond.set_alive(true);
+                            npc.x = player.x + self.rng.range(-6..6) * 16 * 0x200;
+                            npc.y = 16 * 0x200;
+                            npc.vel_y = self.rng.range(0..0x100);
+
+                            let _ = npc_list.spawn(0x100, npc);
+                        }
+                    }
+
+                    if self.vel_y > 0 {
+                        self.anim_num = 4;
+                    }
+
+                    if self.flags.hit_bottom_wall() {
+                        self.action_num = 110;
+                        self.action_counter = 0;
+                        self.vel_y2 = 0;
+
+                        state.quake_counter = 10;
+                        state.sound_manager.play_sfx(26);
+                    }
+                }
+            }
+            300 | 301 => {
+                if self.action_num == 300 {
+                    self.action_num = 301;
+                    self.action_counter = 0;
+                }
+
+                self.anim_num = if self.action_counter / 2 % 2 != 0 { 11 } else { 12 };
+
+                self.action_counter += 1;
+                if self.action_counter % 10 == 0 {
+                    let mut npc = NPC::create(315, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y;
+                    npc.direction = if self.action_counter % 20 == 0 { Direction::Left } else { Direction::Right };
+
+                    let _ = npc_list.spawn(0x100, npc);
+
+                    state.sound_manager.play_sfx(39);
+                }
+
+                if self.action_counter > 40 {
+                    self.action_num = 110;
+                    self.action_counter = 0;
+                }
+            }
+            400 | 401 => {
+                if self.action_num == 400 {
+                    self.action_num = 401;
+                    self.action_counter = 0;
+                    self.anim_num = 7;
+                    self.damage = 10;
+                }
+
+                self.animate(0, 7, 10);
+
+                self.vel_x += self.direction.vector_x() * 0x20;
+
+                if (self.flags.hit_left_wall() && self.direction == Direction::Left)
+                    || (self.flags.hit_right_wall() && self.direction == Direction::Right) {
+                    self.direction = self.direction.opposite();
+                    self.vel_x = self.direction.vector_x() * 0x200;
+
+                    state.quake_counter = 10;
+                    state.sound_manager.play_sfx(26);
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 100 {
+                    self.action_num = 110;
+                    self.action_counter = 0;
+                    self.damage = 1;
+                    self.vel_x = 0;
+                }
+            }
+            500 | 501 => {
+                if self.action_num == 500 {
+                    self.action_num = 501;
+                    self.action_counter = 0;
+                    self.anim_num = 13;
+                    self.npc_flags.set_shootable(false);
+                    self.damage = 0;
+                    self.vel_x = 0;
+
+                    for npc in npc_list.iter_alive() {
+                        if npc.npc_type == 314 || npc.npc_type == 315 {
+                            npc.cond.set_explode_die(true);
+                        }
+                    }
+                }
+
+                self.action_counter += 1;
+                if self.action_counter % 8 == 1 {
+                    npc_list.create_death_smoke(self.x, self.y, self.display_bounds.right, 1, state, &self.rng);
+                }
+            }
+            _ => {}
+        }
+
+        if self.action_num > 100 && self.action_num < 500 && self.shock > 0 {
+            self.anim_num = 13;
+        }
+
+        self.vel_y += 0x40;
+        self.vel_x = clamp(self.vel_x, -0x400, 0x400);
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        if self.y > stage.map.height as i32 * 16 * 0x200 {
+            self.y = stage.map.height as i32 * 16 * 0x200;
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 14 };
+        self.anim_rect = state.constants.npc.n313_ma_pignon[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n314_ma_pignon_rock(&mut self, state: &mut SharedGameState, stage: &Stage) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.action_counter = 0;
+                    self.npc_flags.set_ignore_solidity(true);
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 8 {
+                    self.action_num = 10;
+                    self.npc_flags.set_ignore_solidity(false);
+                }
+            }
+            10 => {
+                if self.flags.hit_bottom_wall() {
+                    self.vel_y = -0x200;
+                    self.action_counter2 += 1;
+
+                    state.sound_manager.play_sfx(12);
+
+                    if self.action_counter2 > 2 {
+                        self.vanish(state);
+                        return Ok(());
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y += 0x40;
+        if self.vel_y > 0x700 {
+            self.vel_y = 0x700;
+        }
+
+        self.y += self.vel_y;
+
+        if self.y > stage.map.height as i32 * 16 * 0x200 {
+            self.cond.set_alive(false);
+            return Ok(());
+        }
+
+        self.animate(3, 0, 2);
+
+        self.anim_rect = state.constants.npc.n314_ma_pignon_rock[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n315_ma_pignon_clone(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.action_counter = 0;
+                    self.anim_num = 0;
+                    self.vel_x = self.direction.vector_x() * 0x200;
+                    self.vel_y = -0x400;
+                }
+
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 10;
+                    self.action_counter = 0;
+                    self.anim_num = 0;
+                    self.vel_x = 0;
+                }
+            }
+            10 => {
+                self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+
+                self.action_counter += 1;
+                if self.action_counter > 16 {
+                    self.action_num = 11;
+                    self.anim_num = 1;
+                    self.vel_x = self.direction.vector_x() * 0x200;
+                    self.vel_y = -0x400;
+                }
+            }
+            11 => {
+                self.anim_num = if self.vel_y < 0 { 2 } else { 3 };
+
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 10;
+                    self.action_counter = 0;
+                    self.anim_num = 0;
+                    self.vel_x = 0;
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y += 0x40;
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.action_counter2 += 1;
+        if self.action_counter2 > 300 {
+            self.vanish(state);
+            return Ok(());
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 4 };
+        self.anim_rect = state.constants.npc.n315_ma_pignon_clone[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
 }
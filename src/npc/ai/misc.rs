@@ -2,11 +2,12 @@ use ggez::GameResult;
 use num_traits::{abs, clamp};
 
 use crate::caret::CaretType;
-use crate::common::Direction;
+use crate::common::{Direction, Rect};
 use crate::npc::list::NPCList;
 use crate::npc::NPC;
 use crate::player::Player;
 use crate::rng::RNG;
+use crate::screen_effect::ScreenEffectType;
 use crate::shared_game_state::SharedGameState;
 use crate::stage::Stage;
 
@@ -1314,4 +1315,994 @@ impl NPC {
 
         Ok(())
     }
+
+    pub(crate) fn tick_n119_table_chair(&mut self, state: &mut SharedGameState) -> GameResult {
+        if self.action_num == 0 {
+            self.action_num = 1;
+            self.anim_rect = state.constants.npc.n119_table_chair;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n146_lighting(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+
+                    if self.direction == Direction::Right {
+                        state.create_screen_effect(0, 0, ScreenEffectType::Flash);
+                    }
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 10 {
+                    self.action_num = 2;
+                    state.sound_manager.play_sfx(101);
+                }
+            }
+            2 => {
+                self.anim_counter += 1;
+                if self.anim_counter > 2 {
+                    self.anim_counter = 0;
+                    self.anim_num += 1;
+                }
+
+                if self.anim_num == 2 {
+                    self.damage = 10;
+                }
+
+                if self.anim_num > 4 {
+                    npc_list.create_death_smoke(self.x, self.y, 8 * 0x200, 8, state, &self.rng);
+                    self.cond.set_alive(false);
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+
+        self.anim_rect = state.constants.npc.n146_lighting[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n189_homing_flame(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.vel_x = -0x40;
+                }
+
+                self.y += self.vel_y;
+
+                self.action_counter += 1;
+                if self.action_counter > 256 {
+                    self.action_num = 10;
+                }
+            }
+            10 => {
+                let player = self.get_closest_player_mut(players);
+
+                self.vel_x += if self.x < player.x { 8 } else { -8 };
+                self.vel_y += if self.y < player.y { 8 } else { -8 };
+
+                self.vel_x = clamp(self.vel_x, -0x400, 0x400);
+                self.vel_y = clamp(self.vel_y, -0x400, 0x400);
+
+                self.x += self.vel_x;
+                self.y += self.vel_y;
+            }
+            _ => {}
+        }
+
+        self.animate(2, 0, 2);
+
+        self.anim_rect = state.constants.npc.n189_homing_flame[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n195_grate(&mut self, state: &mut SharedGameState) -> GameResult {
+        if self.action_num == 0 {
+            self.action_num = 1;
+            self.anim_rect = state.constants.npc.n195_grate;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n219_smoke_generator(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        if self.direction == Direction::Left {
+            if self.rng.range(0..40) == 1 {
+                let mut npc = NPC::create(4, &state.npc_table);
+                npc.cond.set_alive(true);
+                npc.x = self.x + self.rng.range(-20..20) * 0x200;
+                npc.y = self.y;
+                npc.vel_y = -0x200;
+
+                let _ = npc_list.spawn(0x100, npc);
+            }
+        } else {
+            let mut npc = NPC::create(199, &state.npc_table);
+            npc.cond.set_alive(true);
+            npc.x = self.x + self.rng.range(-160..160) * 0x200;
+            npc.y = self.y + self.rng.range(-128..128) * 0x200;
+            npc.direction = Direction::Right;
+
+            let _ = npc_list.spawn(0x100, npc);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n238_press_sideways(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.target_x = self.x;
+                    self.target_y = self.y;
+                    self.display_bounds.left = 16 * 0x200;
+                    self.display_bounds.right = 8 * 0x200;
+                }
+
+                let in_front = if self.direction == Direction::Left {
+                    player.x < self.x && player.x > self.x - 192 * 0x200
+                } else {
+                    player.x > self.x && player.x < self.x + 192 * 0x200
+                };
+
+                if in_front && player.y > self.y - 4 * 0x200 && player.y < self.y + 4 * 0x200 {
+                    self.action_num = 10;
+                    self.action_counter = 0;
+                    self.anim_num = 2;
+                }
+            }
+            10 => {
+                self.damage = 127;
+                self.x += self.direction.vector_x() * 0xc00;
+
+                self.action_counter += 1;
+                if self.action_counter == 8 {
+                    self.action_num = 20;
+                    self.action_counter = 0;
+
+                    let mut npc = NPC::create(4, &state.npc_table);
+                    npc.cond.set_alive(true);
+
+                    for _ in 0..4 {
+                        npc.x = self.x + self.direction.vector_x() * 16 * 0x200;
+                        npc.y = self.y + self.rng.range(-4..4) * 0x200;
+                        npc.vel_x = self.rng.range(-341..341);
+                        npc.vel_y = self.rng.range(-0x600..0);
+
+                        let _ = npc_list.spawn(0x100, npc.clone());
+                    }
+
+                    state.sound_manager.play_sfx(44);
+                }
+            }
+            20 => {
+                self.damage = 0;
+
+                self.action_counter += 1;
+                if self.action_counter > 50 {
+                    self.action_counter = 0;
+                    self.action_num = 30;
+                }
+            }
+            30 => {
+                self.damage = 0;
+                self.anim_num = 1;
+
+                self.action_counter += 1;
+                if self.action_counter == 12 {
+                    self.action_num = 1;
+                    self.action_counter = 0;
+                    self.anim_num = 0;
+                }
+
+                self.x -= self.direction.vector_x() * 0x800;
+            }
+            _ => {}
+        }
+
+        // only solid from behind, so the player can be pushed around by it
+        let behind = if self.direction == Direction::Left { player.x < self.x } else { player.x > self.x };
+        self.npc_flags.set_solid_hard(!behind);
+        self.npc_flags.set_solid_soft(behind);
+
+        self.anim_rect = state.constants.npc.n238_press_sideways[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n254_helicopter(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 => {
+                self.action_num = 1;
+
+                let mut npc = NPC::create(255, &state.npc_table);
+                npc.cond.set_alive(true);
+                npc.x = self.x + 18 * 0x200;
+                npc.y = self.y - 57 * 0x200;
+                npc.parent_id = self.id;
+
+                let _ = npc_list.spawn(0x100, npc.clone());
+
+                npc.x = self.x - 32 * 0x200;
+                npc.y = self.y - 52 * 0x200;
+                npc.direction = Direction::Right;
+
+                let _ = npc_list.spawn(0x100, npc);
+            }
+            20 => {
+                self.action_counter = 0;
+                self.action_counter2 = 60;
+                self.action_num = 21;
+            }
+            30 => {
+                self.action_num = 21;
+
+                let mut npc = NPC::create(223, &state.npc_table);
+                npc.cond.set_alive(true);
+                npc.x = self.x - 11 * 0x200;
+                npc.y = self.y - 14 * 0x200;
+
+                let _ = npc_list.spawn(0x100, npc);
+            }
+            40 => {
+                self.action_num = 21;
+
+                for (npc_type, off_x) in [(223, 9), (40, 22), (93, 35)].iter() {
+                    let mut npc = NPC::create(*npc_type, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x - off_x * 0x200;
+                    npc.y = self.y - 14 * 0x200;
+
+                    let _ = npc_list.spawn(0x100, npc);
+                }
+            }
+            _ => {}
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 1 };
+        self.anim_rect = state.constants.npc.n254_helicopter[dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n255_helicopter_blades(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+
+                    if self.direction == Direction::Left {
+                        self.display_bounds.left = 56 * 0x200;
+                        self.display_bounds.right = 56 * 0x200;
+                    } else {
+                        self.display_bounds.left = 40 * 0x200;
+                        self.display_bounds.right = 40 * 0x200;
+                    }
+                }
+
+                if let Some(parent) = self.get_parent_ref_mut(npc_list) {
+                    if parent.action_num >= 20 {
+                        self.action_num = 10;
+                    }
+                }
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                }
+
+                self.anim_num += 1;
+                if self.anim_num > 3 {
+                    self.anim_num = 0;
+                }
+            }
+            _ => {}
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 4 };
+        self.anim_rect = state.constants.npc.n255_helicopter_blades[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n279_large_falling_block(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList, stage: &Stage) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.npc_flags.set_invulnerable(true);
+
+                    if self.direction == Direction::Right {
+                        self.anim_num = 1;
+                        self.display_bounds = Rect { left: 8 * 0x200, top: 8 * 0x200, right: 8 * 0x200, bottom: 8 * 0x200 };
+                        self.hit_bounds = Rect { left: 8 * 0x200, top: 8 * 0x200, right: 8 * 0x200, bottom: 8 * 0x200 };
+                    } else {
+                        self.anim_num = 0;
+                    }
+                }
+
+                self.vel_y += 0x40;
+                if self.vel_y > 0x700 {
+                    self.vel_y = 0x700;
+                }
+
+                // spawned above the ceiling, falls through it before becoming solid
+                if self.y > 128 * 0x200 {
+                    self.npc_flags.set_ignore_solidity(false);
+                }
+
+                if self.flags.hit_bottom_wall() {
+                    self.vel_y = -0x200;
+                    self.action_num = 2;
+                    self.npc_flags.set_ignore_solidity(true);
+
+                    state.sound_manager.play_sfx(12);
+                    state.quake_counter = 10;
+
+                    let mut npc = NPC::create(4, &state.npc_table);
+                    npc.cond.set_alive(true);
+
+                    for _ in 0..4 {
+                        npc.x = self.x + self.rng.range(-12..12) * 0x200;
+                        npc.y = self.y + 16 * 0x200;
+                        npc.vel_x = self.rng.range(-341..341);
+                        npc.vel_y = self.rng.range(-0x600..0);
+
+                        let _ = npc_list.spawn(0x100, npc.clone());
+                    }
+                }
+            }
+            2 => {
+                self.vel_y += 0x40;
+
+                if self.y > (stage.map.height as i32 + 2) * 16 * 0x200 {
+                    self.cond.set_alive(false);
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+
+        let player = self.get_closest_player_mut(players);
+        self.damage = if player.y > self.y { 10 } else { 0 };
+
+        self.y += self.vel_y;
+
+        self.anim_rect = state.constants.npc.n279_large_falling_block[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n291_mini_undead_core_inactive(&mut self, state: &mut SharedGameState) -> GameResult {
+        if self.action_num == 0 {
+            self.action_num = 20;
+
+            if self.direction == Direction::Right {
+                self.npc_flags.set_solid_hard(false);
+                self.anim_num = 1;
+            }
+        }
+
+        self.anim_rect = state.constants.npc.n291_mini_undead_core_inactive[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n292_quake(&mut self, state: &mut SharedGameState) -> GameResult {
+        state.quake_counter = 10;
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n294_quake_falling_block_generator(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList, stage: &Stage) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+        let map_width = stage.map.width as i32 * 16 * 0x200;
+
+        match self.action_num {
+            0 => {
+                if player.x < map_width - 6 * 16 * 0x200 {
+                    self.action_num = 1;
+                    self.action_counter = 0;
+                }
+            }
+            1 => {
+                self.action_counter += 1;
+
+                // blocks keep raining just ahead of the player
+                let ahead = if player.equip.has_booster_2_0() { 4 } else { 6 };
+                self.x = player.x + ahead * 16 * 0x200;
+
+                if self.x < 26 * 16 * 0x200 {
+                    self.x = 26 * 16 * 0x200;
+                }
+
+                if self.action_counter > 24 {
+                    let spread = if player.equip.has_booster_2_0() { 14 } else { 11 };
+                    let mut x = player.x + self.rng.range(-spread..spread) * 16 * 0x200 + ahead * 16 * 0x200;
+
+                    if x < 26 * 16 * 0x200 {
+                        x = 26 * 16 * 0x200;
+                    }
+
+                    if x > map_width - 10 * 16 * 0x200 {
+                        x = map_width - 10 * 16 * 0x200;
+                    }
+
+                    let mut npc = NPC::create(279, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = x;
+                    npc.y = player.y - 7 * 16 * 0x200;
+                    npc.npc_flags.set_ignore_solidity(true);
+
+                    let _ = npc_list.spawn(0x100, npc);
+
+                    state.sound_manager.play_sfx(34);
+
+                    self.action_counter = self.rng.range(0..3) as u16;
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n295_cloud(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+
+                    // the cloud layer is picked by the generator through the raw direction
+                    let layer = self.tsc_direction % 8;
+                    self.anim_num = layer % 4;
+
+                    let (speed, half_width) = match self.anim_num {
+                        0 => (0x400, 104),
+                        1 => (0x200, 56),
+                        2 => (0x100, 32),
+                        _ => (0x80, 20),
+                    };
+
+                    if layer < 4 {
+                        self.vel_y = -speed * 2;
+                    } else {
+                        self.vel_x = -speed;
+                    }
+
+                    self.display_bounds.left = half_width * 0x200;
+                    self.display_bounds.right = half_width * 0x200;
+                }
+
+                self.x += self.vel_x;
+                self.y += self.vel_y;
+
+                if self.x < -64 * 0x200 || self.y < -32 * 0x200 {
+                    self.cond.set_alive(false);
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+
+        self.anim_rect = state.constants.npc.n295_cloud[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n296_cloud_generator(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        self.action_counter += 1;
+        if self.action_counter > 16 {
+            self.action_counter = self.rng.range(0..16) as u16;
+
+            let layer = (self.rng.range(0..100) % 4) as u16;
+
+            let mut npc = NPC::create(295, &state.npc_table);
+            npc.cond.set_alive(true);
+
+            if self.direction == Direction::Left {
+                npc.x = self.x;
+                npc.y = self.y + self.rng.range(-7..7) * 16 * 0x200;
+                npc.tsc_direction = layer + 4;
+            } else {
+                npc.x = self.x + self.rng.range(-10..10) * 16 * 0x200;
+                npc.y = self.y;
+                npc.tsc_direction = layer;
+            }
+
+            let _ = npc_list.spawn(if layer == 0 { 0x180 } else { 0x80 }, npc);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n302_camera_focus_marker(&mut self, players: [&mut Player; 2]) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            10 => {
+                self.x = player.x;
+                self.y = player.y - 32 * 0x200;
+            }
+            20 => {
+                match self.direction {
+                    Direction::Left => self.x -= 0x400,
+                    Direction::Up => self.y -= 0x400,
+                    Direction::Right => self.x += 0x400,
+                    Direction::Bottom => self.y += 0x400,
+                    _ => {}
+                }
+
+                player.x = self.x;
+                player.y = self.y;
+            }
+            30 => {
+                self.x = player.x;
+                self.y = player.y + 80 * 0x200;
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n326_sue_itoh_human_transition(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.y -= 8 * 0x200;
+                    self.x += 16 * 0x200;
+                    self.anim_num = 0;
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 80 {
+                    self.action_num = 10;
+                    self.action_counter = 0;
+                } else if self.direction == Direction::Left {
+                    if self.action_counter == 30 {
+                        self.anim_num = 1;
+                    }
+
+                    if self.action_counter == 40 {
+                        self.anim_num = 0;
+                    }
+                } else {
+                    if self.action_counter == 50 {
+                        self.anim_num = 1;
+                    }
+
+                    if self.action_counter == 60 {
+                        self.anim_num = 0;
+                    }
+                }
+            }
+            10 => {
+                self.anim_num = 2;
+
+                self.action_counter += 1;
+                if self.action_counter > 50 {
+                    self.action_num = 20;
+                    self.action_counter = 0;
+                    self.anim_num = 3;
+
+                    let mut npc = NPC::create(327, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y - 16 * 0x200;
+                    npc.parent_id = self.id;
+
+                    let _ = npc_list.spawn(0x100, npc);
+                } else if self.action_counter / 2 % 2 != 0 {
+                    self.x += 0x200;
+                } else {
+                    self.x -= 0x200;
+                }
+            }
+            20 => {
+                self.anim_counter += 1;
+                if self.anim_counter > 4 {
+                    self.anim_counter = 0;
+                    self.anim_num += 1;
+
+                    if self.anim_num > 6 {
+                        self.anim_num = 3;
+                    }
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 100 {
+                    self.action_num = 30;
+                    self.action_counter = 0;
+                    self.anim_num = 7;
+                }
+            }
+            _ => {}
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 8 };
+        self.anim_rect = state.constants.npc.n326_sue_itoh_human_transition[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n327_sneeze(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        self.action_counter += 1;
+
+        match self.action_num {
+            0 => {
+                if self.action_counter < 4 {
+                    self.y -= 2 * 0x200;
+                }
+
+                if let Some(parent) = self.get_parent_ref_mut(npc_list) {
+                    if parent.anim_num == 7 {
+                        self.anim_num = 1;
+                        self.action_num = 1;
+                        self.target_x = self.x;
+                        self.target_y = self.y;
+                    }
+                }
+            }
+            1 => {
+                if self.action_counter < 48 {
+                    self.x = self.target_x + self.rng.range(-1..1) * 0x200;
+                    self.y = self.target_y + self.rng.range(-1..1) * 0x200;
+                } else {
+                    self.x = self.target_x;
+                    self.y = self.target_y;
+                }
+            }
+            _ => {}
+        }
+
+        if self.action_counter > 70 {
+            self.cond.set_alive(false);
+            return Ok(());
+        }
+
+        self.anim_rect = state.constants.npc.n327_sneeze[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n328_human_transform_machine(&mut self, state: &mut SharedGameState) -> GameResult {
+        if self.action_num == 0 {
+            self.action_num = 1;
+            self.anim_rect = state.constants.npc.n328_human_transform_machine;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n329_laboratory_fan(&mut self, state: &mut SharedGameState) -> GameResult {
+        self.anim_counter = self.anim_counter.wrapping_add(1);
+
+        self.anim_num = (self.anim_counter / 2) % 2;
+        self.anim_rect = state.constants.npc.n329_laboratory_fan[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n334_sweat(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        match self.action_num {
+            0 | 10 => {
+                if self.action_num == 0 {
+                    self.action_num = 10;
+
+                    if self.direction == Direction::Left {
+                        self.x += 10 * 0x200;
+                        self.y -= 18 * 0x200;
+                    } else {
+                        let player = self.get_closest_player_mut(players);
+
+                        self.x = player.x - 10 * 0x200;
+                        self.y = player.y - 2 * 0x200;
+                    }
+                }
+
+                self.action_counter += 1;
+                self.anim_num = if self.action_counter / 8 % 2 != 0 { 0 } else { 1 };
+
+                if self.action_counter >= 64 {
+                    self.cond.set_alive(false);
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 2 };
+        self.anim_rect = state.constants.npc.n334_sweat[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n335_ikachan(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.action_counter = self.rng.range(3..20) as u16;
+                }
+
+                if self.action_counter > 0 {
+                    self.action_counter -= 1;
+                } else {
+                    self.action_num = 2;
+                    self.action_counter = self.rng.range(10..50) as u16;
+                    self.anim_num = 1;
+                    self.vel_x = 0x600;
+                }
+            }
+            2 => {
+                if self.action_counter > 0 {
+                    self.action_counter -= 1;
+                } else {
+                    self.action_num = 3;
+                    self.action_counter = self.rng.range(40..50) as u16;
+                    self.anim_num = 2;
+                    self.vel_y = self.rng.range(-0x100..0x100);
+                }
+            }
+            3 => {
+                if self.action_counter > 0 {
+                    self.action_counter -= 1;
+                } else {
+                    self.action_num = 1;
+                    self.action_counter = 0;
+                    self.anim_num = 0;
+                }
+
+                self.vel_x -= 0x10;
+            }
+            _ => {}
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.anim_rect = state.constants.npc.n335_ikachan[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n336_ikachan_generator(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 => {
+                let player = self.get_closest_player_mut(players);
+
+                if player.shock_counter > 0 {
+                    self.cond.set_alive(false);
+                }
+            }
+            10 => {
+                self.action_counter += 1;
+                if self.action_counter % 4 == 1 {
+                    let mut npc = NPC::create(335, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y + self.rng.range(0..13) * 16 * 0x200;
+
+                    let _ = npc_list.spawn(0x100, npc);
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n352_ending_characters(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+
+                    // which character to show is picked through the raw direction
+                    self.action_counter2 = self.tsc_direction % 14;
+                    self.vel_y = -0x200;
+                }
+
+                self.vel_y += 0x40;
+                if self.vel_y > 0x5ff {
+                    self.vel_y = 0x5ff;
+                }
+
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 10;
+                    self.anim_num = 1;
+                    self.vel_y = 0;
+                }
+
+                self.y += self.vel_y;
+            }
+            _ => {}
+        }
+
+        self.anim_rect = state.constants.npc.n352_ending_characters[self.action_counter2 as usize * 2 + self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n355_quote_and_curly_on_balrog(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        if self.action_num == 0 {
+            self.action_num = 1;
+            self.anim_num = self.direction as u16 % 4;
+        }
+
+        let (off_x, off_y) = match self.anim_num {
+            0 => (-7, 14),
+            1 => (-7, 6),
+            2 => (7, 14),
+            _ => (7, 6),
+        };
+
+        if let Some(parent) = self.get_parent_ref_mut(npc_list) {
+            self.x = parent.x + off_x * 0x200;
+            self.y = parent.y + off_y * 0x200;
+        }
+
+        self.anim_rect = state.constants.npc.n355_quote_and_curly_on_balrog[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n356_balrog_rescuing(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 | 11 => {
+                if self.action_num == 0 {
+                    self.action_num = 11;
+                    self.anim_counter = 0;
+                    self.target_y = self.y - 16 * 0x200;
+                    self.target_x = self.x - 6 * 0x200;
+                    self.vel_y = 0;
+
+                    let mut npc = NPC::create(355, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y;
+                    npc.parent_id = self.id;
+                    npc.direction = Direction::Bottom;
+
+                    let _ = npc_list.spawn(0xaa, npc.clone());
+
+                    npc.direction = Direction::Right;
+
+                    let _ = npc_list.spawn(0xaa, npc);
+                }
+
+                self.anim_counter += 1;
+
+                self.vel_x += if self.x < self.target_x { 8 } else { -8 };
+                self.vel_y += if self.y < self.target_y { 8 } else { -8 };
+
+                self.x += self.vel_x;
+                self.y += self.vel_y;
+            }
+            20 | 21 => {
+                if self.action_num == 20 {
+                    self.action_num = 21;
+                    self.vel_x = -0x400;
+                    self.vel_y = 0x200;
+                }
+
+                self.anim_counter += 1;
+
+                self.vel_x += 0x10;
+                self.vel_y -= 8;
+
+                if self.x > 60 * 16 * 0x200 {
+                    self.action_num = 22;
+                }
+
+                self.x += self.vel_x;
+                self.y += self.vel_y;
+            }
+            22 => {
+                self.vel_x = 0;
+                self.vel_y = 0;
+            }
+            _ => {}
+        }
+
+        self.anim_num = if self.anim_counter / 2 % 2 != 0 { 1 } else { 0 };
+        self.anim_rect = state.constants.npc.n356_balrog_rescuing[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n357_puppy_ghost(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 => {
+                self.anim_rect = state.constants.npc.n357_puppy_ghost;
+                self.action_counter += 1;
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.action_counter = 0;
+
+                    state.sound_manager.play_sfx(29);
+                }
+
+                self.action_counter += 1;
+
+                self.anim_rect = state.constants.npc.n357_puppy_ghost;
+                if self.action_counter / 2 % 2 != 0 {
+                    self.anim_rect.right = self.anim_rect.left;
+                }
+
+                if self.action_counter > 50 {
+                    self.cond.set_alive(false);
+                }
+            }
+            _ => {}
+        }
+
+        if self.action_counter % 8 == 1 {
+            state.create_caret(self.x + self.rng.range(-8..8) * 0x200, self.y + 8 * 0x200, CaretType::LittleParticles, Direction::Up);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n358_misery_credits(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 => {
+                self.animate(6, 0, 1);
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.anim_num = 2;
+                    self.anim_counter = 0;
+                }
+
+                self.animate(6, 3, 4);
+            }
+            _ => {}
+        }
+
+        self.anim_rect = state.constants.npc.n358_misery_credits[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n359_water_droplet_generator(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        if abs(player.x - self.x) < 320 * 0x200 && abs(player.y - self.y) < 240 * 0x200 && self.rng.range(0..100) == 2 {
+            let mut npc = NPC::create(73, &state.npc_table);
+            npc.cond.set_alive(true);
+            npc.x = self.x + self.rng.range(-6..6) * 0x200;
+            npc.y = self.y - 7 * 0x200;
+
+            let _ = npc_list.spawn(0, npc);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n360_credits_thank_you(&mut self, state: &mut SharedGameState) -> GameResult {
+        if self.action_num == 0 {
+            self.action_num = 1;
+            self.anim_rect = state.constants.npc.n360_credits_thank_you;
+        }
+
+        Ok(())
+    }
 }
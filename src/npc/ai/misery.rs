@@ -1,11 +1,15 @@
 use ggez::GameResult;
-use num_traits::clamp;
+use num_traits::{abs, clamp};
 
-use crate::common::Direction;
+use crate::caret::CaretType;
+use crate::common::{CDEG_RAD, Direction};
 use crate::npc::list::NPCList;
 use crate::npc::NPC;
+use crate::player::Player;
 use crate::rng::RNG;
+use crate::screen_effect::ScreenEffectType;
 use crate::shared_game_state::SharedGameState;
+use crate::stage::Stage;
 
 impl NPC {
     pub(crate) fn tick_n066_misery_bubble(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
@@ -172,7 +176,7 @@ impl NPC {
                 self.action_counter += 1;
                 if self.action_counter == 30 {
                     state.sound_manager.play_sfx(101);
-                    // todo flash
+                    state.create_screen_effect(0, 0, ScreenEffectType::Flash);
                     self.action_num = 27;
                     self.anim_num = 7;
                 }
@@ -293,7 +297,7 @@ impl NPC {
                 self.action_counter += 1;
                 if self.action_counter == 30 {
                     state.sound_manager.play_sfx(101);
-                    // todo flash
+                    state.create_screen_effect(0, 0, ScreenEffectType::Flash);
                     self.action_num = 27;
                     self.anim_num = 7;
                 }
@@ -391,5 +395,749 @@ impl NPC {
 
         Ok(())
     }
-}
 
+    pub(crate) fn tick_n247_misery_boss(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.y += 6 * 0x200;
+                    self.target_y = 64 * 0x200;
+                }
+
+                if self.rng.range(0..120) == 10 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 8 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            20 => {
+                self.vel_x = 0;
+                self.vel_y += 0x40;
+
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 21;
+                    self.anim_num = 2;
+                }
+            }
+            21 => {
+                if self.rng.range(0..120) == 10 {
+                    self.action_num = 22;
+                    self.action_counter = 0;
+                    self.anim_num = 3;
+                }
+            }
+            22 => {
+                self.action_counter += 1;
+                if self.action_counter > 8 {
+                    self.action_num = 21;
+                    self.anim_num = 2;
+                }
+            }
+            100 | 101 => {
+                if self.action_num == 100 {
+                    self.action_num = 101;
+                    self.action_counter = 0;
+                    self.anim_num = 0;
+                    self.vel_x = 0;
+                    self.npc_flags.set_shootable(true);
+                    self.vel_x2 = self.life as i32;
+                }
+
+                self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+
+                self.vel_y += if self.y < self.target_y { 0x20 } else { -0x20 };
+                self.vel_y = clamp(self.vel_y, -0x200, 0x200);
+
+                self.action_counter += 1;
+                if self.action_counter % 48 == 24 {
+                    self.anim_num = 4;
+
+                    let angle = f64::atan2((self.y - player.y) as f64, (self.x - player.x) as f64);
+
+                    let mut npc = NPC::create(249, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y;
+                    npc.vel_x = (angle.cos() * -1024.0) as i32;
+                    npc.vel_y = (angle.sin() * -1024.0) as i32;
+
+                    let _ = npc_list.spawn(0x100, npc);
+
+                    state.sound_manager.play_sfx(34);
+                } else if self.action_counter % 48 == 32 {
+                    self.anim_num = 0;
+                }
+
+                // teleports away after a while, or once she has taken enough damage
+                if self.action_counter > 200 || (self.life as i32) <= self.vel_x2 - 80 {
+                    self.action_num = 110;
+                }
+            }
+            110 | 111 => {
+                if self.action_num == 110 {
+                    self.action_num = 111;
+                    self.action_counter = 0;
+                    self.vel_x = 0;
+                    self.vel_y = 0;
+                    self.npc_flags.set_shootable(false);
+
+                    let mut npc = NPC::create(248, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y;
+
+                    let _ = npc_list.spawn(0x100, npc);
+
+                    state.sound_manager.play_sfx(29);
+                }
+
+                self.anim_num = if self.action_counter / 2 % 2 != 0 { 7 } else { 4 };
+
+                self.action_counter += 1;
+                if self.action_counter > 30 {
+                    self.action_num = 112;
+                    self.action_counter = 0;
+                    self.anim_num = 7;
+                }
+            }
+            112 => {
+                self.action_counter += 1;
+                if self.action_counter > 40 {
+                    self.action_num = 113;
+                    self.action_counter = 0;
+
+                    self.x = player.x + self.rng.range(-6..6) * 16 * 0x200;
+                    self.target_y = self.rng.range(3..5) * 16 * 0x200;
+                    self.y = self.target_y;
+
+                    state.sound_manager.play_sfx(29);
+                }
+            }
+            113 => {
+                self.anim_num = if self.action_counter / 2 % 2 != 0 { 7 } else { 4 };
+
+                self.action_counter += 1;
+                if self.action_counter > 16 {
+                    self.action_counter = 0;
+                    self.action_counter2 += 1;
+                    self.npc_flags.set_shootable(true);
+
+                    self.action_num = match self.action_counter2 % 3 {
+                        0 => 120,
+                        1 => 130,
+                        _ => 100,
+                    };
+                }
+            }
+            120 | 121 => {
+                if self.action_num == 120 {
+                    self.action_num = 121;
+                    self.action_counter = 0;
+                    self.anim_num = 4;
+                }
+
+                self.action_counter += 1;
+                if self.action_counter % 20 == 10 && self.action_counter < 70 {
+                    let mut npc = NPC::create(250, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y - 16 * 0x200;
+
+                    let _ = npc_list.spawn(0x100, npc);
+
+                    state.sound_manager.play_sfx(34);
+                }
+
+                if self.action_counter > 90 {
+                    self.action_num = 100;
+                }
+            }
+            130 | 131 => {
+                if self.action_num == 130 {
+                    self.action_num = 131;
+                    self.action_counter = 0;
+                    self.anim_num = 4;
+
+                    // a ring of bats that circles her before flying off
+                    for i in 0..4 {
+                        let mut npc = NPC::create(252, &state.npc_table);
+                        npc.cond.set_alive(true);
+                        npc.x = self.x;
+                        npc.y = self.y;
+                        npc.vel_x2 = i * 0x40;
+                        npc.parent_id = self.id;
+
+                        let _ = npc_list.spawn(0x100, npc);
+                    }
+
+                    state.sound_manager.play_sfx(34);
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 50 {
+                    self.action_num = 100;
+                }
+            }
+            1000 | 1001 => {
+                if self.action_num == 1000 {
+                    self.action_num = 1001;
+                    self.action_counter = 0;
+                    self.anim_num = 8;
+                    self.npc_flags.set_shootable(false);
+                    self.damage = 0;
+                    self.target_x = self.x;
+                    self.vel_x = 0;
+                    self.vel_y = 0;
+
+                    for npc in npc_list.iter_alive() {
+                        if npc.npc_type == 249 || npc.npc_type == 250 || npc.npc_type == 251 || npc.npc_type == 252 {
+                            npc.cond.set_explode_die(true);
+                        }
+                    }
+                }
+
+                self.action_counter += 1;
+                self.x = self.target_x + if self.action_counter / 2 % 2 != 0 { 0x200 } else { 0 };
+
+                if self.action_counter % 6 == 1 {
+                    npc_list.create_death_smoke(self.x, self.y, 8 * 0x200, 1, state, &self.rng);
+                }
+            }
+            _ => {}
+        }
+
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 9 };
+        self.anim_rect = state.constants.npc.n247_misery_boss[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n248_misery_boss_vanishing(&mut self, state: &mut SharedGameState) -> GameResult {
+        self.anim_counter += 1;
+        if self.anim_counter > 3 {
+            self.anim_counter = 0;
+            self.anim_num += 1;
+        }
+
+        if self.anim_num > 2 {
+            self.cond.set_alive(false);
+            return Ok(());
+        }
+
+        self.anim_rect = state.constants.npc.n248_misery_boss_vanishing[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n249_misery_boss_energy_shot(&mut self, state: &mut SharedGameState) -> GameResult {
+        if (self.flags.0 & 0xff) != 0 {
+            state.create_caret(self.x, self.y, CaretType::ProjectileDissipation, Direction::Left);
+            self.cond.set_alive(false);
+            return Ok(());
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.anim_num += 1;
+        if self.anim_num > 1 {
+            self.anim_num = 0;
+        }
+
+        self.anim_rect = state.constants.npc.n249_misery_boss_energy_shot[self.anim_num as usize];
+
+        self.action_counter += 1;
+        if self.action_counter > 300 {
+            state.create_caret(self.x, self.y, CaretType::ProjectileDissipation, Direction::Left);
+            self.cond.set_alive(false);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n250_misery_boss_lighting_ball(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.target_y = self.y;
+                    self.vel_x = 0;
+                    self.vel_y = -0x200;
+                }
+
+                self.vel_x += if self.x < player.x { 0x10 } else { -0x10 };
+                self.vel_y += if self.y < self.target_y { 0x20 } else { -0x20 };
+
+                self.vel_x = clamp(self.vel_x, -0x200, 0x200);
+                self.vel_y = clamp(self.vel_y, -0x200, 0x200);
+
+                self.animate(2, 0, 1);
+
+                // strikes once the player passes underneath
+                if abs(player.x - self.x) < 8 * 0x200 && player.y > self.y {
+                    self.action_num = 10;
+                    self.action_counter = 0;
+                }
+            }
+            10 => {
+                self.vel_x = 0;
+                self.vel_y = 0;
+                self.anim_num = 2;
+
+                self.action_counter += 1;
+                if self.action_counter > 10 {
+                    let mut npc = NPC::create(251, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y;
+
+                    let _ = npc_list.spawn(0x100, npc);
+
+                    state.sound_manager.play_sfx(101);
+
+                    self.vanish(state);
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.anim_rect = state.constants.npc.n250_misery_boss_lighting_ball[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n251_misery_boss_lighting(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        if self.action_num == 0 {
+            self.action_num = 1;
+        }
+
+        self.anim_num += 1;
+        if self.anim_num > 1 {
+            self.anim_num = 0;
+        }
+
+        self.y += 0x1000;
+
+        if (self.flags.0 & 0xff) != 0 {
+            npc_list.create_death_smoke(self.x, self.y, self.display_bounds.right, 3, state, &self.rng);
+            self.cond.set_alive(false);
+            return Ok(());
+        }
+
+        self.anim_rect = state.constants.npc.n251_misery_boss_lighting[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n252_misery_boss_bats(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.action_counter = 0;
+                }
+
+                let (parent_x, parent_y) = match self.get_parent_ref_mut(npc_list) {
+                    Some(parent) => (parent.x, parent.y),
+                    None => {
+                        self.cond.set_alive(false);
+                        return Ok(());
+                    }
+                };
+
+                // vel_x2 holds the angle around misery
+                self.vel_x2 = (self.vel_x2 + 2) & 0xff;
+
+                let radius = self.action_counter.min(24) as f64 * 512.0;
+                let angle = self.vel_x2 as f64 * CDEG_RAD;
+                self.x = parent_x + (angle.cos() * radius) as i32;
+                self.y = parent_y + (angle.sin() * radius) as i32;
+
+                self.action_counter += 1;
+                if self.action_counter > 80 {
+                    self.action_num = 10;
+                }
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.action_counter = 0;
+
+                    let player = self.get_closest_player_mut(players);
+                    let angle = f64::atan2((self.y - player.y) as f64, (self.x - player.x) as f64);
+
+                    self.vel_x = (angle.cos() * -1536.0) as i32;
+                    self.vel_y = (angle.sin() * -1536.0) as i32;
+                    self.npc_flags.set_ignore_solidity(false);
+                }
+
+                self.x += self.vel_x;
+                self.y += self.vel_y;
+
+                self.action_counter += 1;
+                if (self.flags.0 & 0xff) != 0 || self.action_counter > 200 {
+                    self.vanish(state);
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+
+        self.direction = if self.vel_x < 0 { Direction::Left } else { Direction::Right };
+
+        self.animate(1, 1, 3);
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 4 };
+        self.anim_rect = state.constants.npc.n252_misery_boss_bats[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n283_misery_possessed(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.y -= 8 * 0x200;
+                    self.anim_num = 0;
+                }
+
+                self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.action_counter = 0;
+                    self.anim_num = 2;
+                    self.npc_flags.set_shootable(true);
+                    self.damage = 1;
+
+                    // keeps hovering above and to the side of the player
+                    self.target_x = player.x + self.rng.range(-1..1) * 96 * 0x200;
+                    self.target_y = player.y - 48 * 0x200;
+                }
+
+                self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+
+                self.vel_x += if self.x < self.target_x { 0x20 } else { -0x20 };
+                self.vel_y += if self.y < self.target_y { 0x20 } else { -0x20 };
+
+                self.anim_counter += 1;
+                if self.anim_counter > 3 {
+                    self.anim_counter = 0;
+                    self.anim_num = if self.anim_num == 2 { 3 } else { 2 };
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 60 {
+                    self.action_counter2 += 1;
+
+                    self.action_num = match self.action_counter2 % 3 {
+                        0 => 20,
+                        1 => 30,
+                        _ => 40,
+                    };
+                }
+            }
+            20 | 21 => {
+                if self.action_num == 20 {
+                    self.action_num = 21;
+                    self.action_counter = 0;
+                    self.anim_num = 4;
+                    self.damage = 5;
+
+                    let angle = f64::atan2((self.y - player.y) as f64, (self.x - player.x) as f64);
+                    self.vel_x = (angle.cos() * -1536.0) as i32;
+                    self.vel_y = (angle.sin() * -1536.0) as i32;
+
+                    state.sound_manager.play_sfx(25);
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 30 || (self.flags.0 & 0x0f) != 0 {
+                    self.action_num = 10;
+                    self.damage = 1;
+                    self.vel_x /= 4;
+                    self.vel_y /= 4;
+                }
+            }
+            30 | 31 => {
+                if self.action_num == 30 {
+                    self.action_num = 31;
+                    self.action_counter = 0;
+                    self.anim_num = 5;
+                    self.vel_x = 0;
+                    self.vel_y = 0;
+                }
+
+                self.action_counter += 1;
+                if self.action_counter == 20 {
+                    let mut npc = NPC::create(290, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y;
+
+                    for direction in [Direction::Left, Direction::Right].iter() {
+                        npc.direction = *direction;
+                        let _ = npc_list.spawn(0x100, npc.clone());
+                    }
+
+                    let mut npc = NPC::create(289, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = player.x;
+                    npc.y = player.y - 128 * 0x200;
+
+                    let _ = npc_list.spawn(0x100, npc);
+
+                    state.sound_manager.play_sfx(39);
+                }
+
+                if self.action_counter > 50 {
+                    self.action_num = 10;
+                }
+            }
+            40 | 41 => {
+                if self.action_num == 40 {
+                    self.action_num = 41;
+                    self.action_counter = 0;
+                    self.anim_num = 6;
+                    self.vel_x = 0;
+                    self.vel_y = 0;
+                }
+
+                self.action_counter += 1;
+                if self.action_counter == 20 {
+                    for i in 0..4 {
+                        let mut npc = NPC::create(301, &state.npc_table);
+                        npc.cond.set_alive(true);
+                        npc.x = self.x;
+                        npc.y = self.y;
+                        npc.vel_x2 = 0x20 + i * 0x40;
+
+                        let _ = npc_list.spawn(0x100, npc);
+                    }
+
+                    state.sound_manager.play_sfx(39);
+                }
+
+                if self.action_counter > 50 {
+                    self.action_num = 10;
+                }
+            }
+            100 | 101 => {
+                if self.action_num == 100 {
+                    self.action_num = 101;
+                    self.action_counter = 0;
+                    self.anim_num = 9;
+                    self.npc_flags.set_shootable(false);
+                    self.damage = 0;
+                    self.vel_x = 0;
+                    self.vel_y = 0;
+
+                    for npc in npc_list.iter_alive() {
+                        if npc.npc_type == 289 || npc.npc_type == 290 || npc.npc_type == 301 {
+                            npc.cond.set_explode_die(true);
+                        }
+                    }
+                }
+
+                self.vel_y += 0x20;
+
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 102;
+                    self.anim_num = 10;
+                    self.vel_y = 0;
+                }
+            }
+            _ => {}
+        }
+
+        if self.action_num != 1 && self.action_num < 100 {
+            self.vel_x = clamp(self.vel_x, -0x5ff, 0x5ff);
+            self.vel_y = clamp(self.vel_y, -0x5ff, 0x5ff);
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 11 };
+        self.anim_rect = state.constants.npc.n283_misery_possessed[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n289_critter_orange(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], stage: &Stage) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = 2;
+                    self.npc_flags.set_ignore_solidity(true);
+                }
+
+                // dropped in from above, becomes solid once it's in the room
+                self.action_counter += 1;
+                if self.action_counter > 16 {
+                    self.npc_flags.set_ignore_solidity(false);
+                }
+
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 10;
+                    self.action_counter = 0;
+                    self.anim_num = 0;
+                    self.vel_x = 0;
+                }
+            }
+            10 => {
+                self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+
+                self.action_counter += 1;
+                if self.action_counter > 10 {
+                    self.action_num = 11;
+                    self.anim_num = 2;
+                    self.vel_y = -0x5ff;
+                    self.vel_x = self.direction.vector_x() * 0x200;
+
+                    state.sound_manager.play_sfx(30);
+                }
+            }
+            11 => {
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 10;
+                    self.action_counter = 0;
+                    self.anim_num = 0;
+                    self.vel_x = 0;
+
+                    state.sound_manager.play_sfx(23);
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y += 0x40;
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        if self.y > stage.map.height as i32 * 16 * 0x200 {
+            self.cond.set_alive(false);
+            return Ok(());
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 3 };
+        self.anim_rect = state.constants.npc.n289_critter_orange[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n290_bat_misery(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.target_y = self.y;
+                    self.vel_x = self.direction.vector_x() * 0x300;
+                    self.vel_y = -0x200;
+                }
+
+                self.vel_x += if self.x < player.x { 0x10 } else { -0x10 };
+                self.vel_y += if self.y < player.y { 0x10 } else { -0x10 };
+
+                self.vel_x = clamp(self.vel_x, -0x300, 0x300);
+                self.vel_y = clamp(self.vel_y, -0x300, 0x300);
+
+                self.action_counter += 1;
+                if self.action_counter > 300 {
+                    self.vanish(state);
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+
+        self.direction = if self.vel_x < 0 { Direction::Left } else { Direction::Right };
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.animate(1, 0, 2);
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 3 };
+        self.anim_rect = state.constants.npc.n290_bat_misery[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n301_misery_fish_missile(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        if self.action_num == 0 {
+            self.action_num = 1;
+        }
+
+        // vel_x2 holds the heading, slowly turning towards the player
+        let target = (f64::atan2((player.y - self.y) as f64, (player.x - self.x) as f64) / CDEG_RAD) as i32 & 0xff;
+        let diff = (target - self.vel_x2) & 0xff;
+
+        if diff != 0 {
+            self.vel_x2 += if diff < 0x80 { 1 } else { -1 };
+            self.vel_x2 &= 0xff;
+        }
+
+        let angle = self.vel_x2 as f64 * CDEG_RAD;
+        self.vel_x = (angle.cos() * 1024.0) as i32;
+        self.vel_y = (angle.sin() * 1024.0) as i32;
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.anim_counter += 1;
+        if self.anim_counter > 2 {
+            self.anim_counter = 0;
+            state.create_caret(self.x, self.y, CaretType::Exhaust, Direction::FacingPlayer);
+        }
+
+        self.action_counter += 1;
+        if (self.flags.0 & 0xff) != 0 || self.action_counter > 300 {
+            self.vanish(state);
+            return Ok(());
+        }
+
+        self.anim_num = (((self.vel_x2 + 0x10) & 0xff) / 0x20) as u16;
+        self.anim_rect = state.constants.npc.n301_misery_fish_missile[self.anim_num as usize];
+
+        Ok(())
+    }
+}
@@ -1,17 +1,24 @@
+pub mod almond;
 pub mod balrog;
 pub mod booster;
 pub mod chaco;
 pub mod characters;
+pub mod curly;
+pub mod doctor;
 pub mod egg_corridor;
 pub mod first_cave;
 pub mod grasstown;
+pub mod hell;
 pub mod igor;
 pub mod intro;
+pub mod last_cave;
 pub mod maze;
 pub mod mimiga_village;
 pub mod misc;
 pub mod misery;
+pub mod outer_wall;
 pub mod pickups;
+pub mod plantation;
 pub mod quote;
 pub mod sand_zone;
 pub mod santa;
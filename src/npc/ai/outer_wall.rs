@@ -0,0 +1,453 @@
+use ggez::GameResult;
+use num_traits::{abs, clamp};
+
+use crate::common::Direction;
+use crate::npc::list::NPCList;
+use crate::npc::NPC;
+use crate::player::Player;
+use crate::rng::RNG;
+use crate::shared_game_state::SharedGameState;
+
+impl NPC {
+    pub(crate) fn tick_n212_sky_dragon(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.y -= 4 * 0x200;
+                }
+
+                self.anim_counter += 1;
+                if self.anim_counter > 30 {
+                    self.anim_counter = 0;
+                    self.anim_num += 1;
+                    if self.anim_num > 1 {
+                        self.anim_num = 0;
+                    }
+                }
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.anim_num = 2;
+                    self.anim_counter = 0;
+                    self.target_x = self.x - 6 * 0x200;
+                    self.target_y = self.y - 16 * 0x200;
+                    self.vel_y = 0;
+                    self.npc_flags.set_ignore_solidity(true);
+                }
+
+                self.vel_x += if self.x < self.target_x { 8 } else { -8 };
+                self.vel_y += if self.y < self.target_y { 8 } else { -8 };
+
+                self.x += self.vel_x;
+                self.y += self.vel_y;
+
+                self.animate(5, 2, 3);
+            }
+            20 | 21 => {
+                if self.action_num == 20 {
+                    self.action_num = 21;
+                    self.npc_flags.set_ignore_solidity(true);
+                }
+
+                self.vel_y += if self.y < self.target_y { 0x10 } else { -0x10 };
+                self.vel_x += 0x20;
+                if self.vel_x > 0x600 {
+                    self.vel_x = 0x600;
+                }
+
+                self.x += self.vel_x;
+                self.y += self.vel_y;
+
+                self.animate(2, 2, 3);
+            }
+            30 => {
+                self.action_num = 31;
+
+                let mut npc = NPC::create(297, &state.npc_table);
+                npc.cond.set_alive(true);
+                npc.x = self.x;
+                npc.y = self.y;
+                npc.parent_id = self.id;
+
+                let _ = npc_list.spawn(0x100, npc);
+            }
+            _ => {}
+        }
+
+        self.anim_rect = state.constants.npc.n212_sky_dragon[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n213_night_spirit(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                    self.target_x = self.x;
+                    self.target_y = self.y;
+                }
+
+                // appears a screen away from where the player passes by
+                if player.y > self.y - 16 * 0x200 && player.y < self.y + 16 * 0x200 {
+                    self.y += if self.direction == Direction::Left { -240 * 0x200 } else { 240 * 0x200 };
+
+                    self.action_num = 10;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                    self.vel_y = 0;
+                    self.npc_flags.set_shootable(true);
+                }
+            }
+            10 => {
+                self.animate(2, 1, 3);
+
+                self.action_counter += 1;
+                if self.action_counter > 200 {
+                    self.action_num = 20;
+                    self.action_counter = 0;
+                    self.anim_num = 4;
+                }
+            }
+            20 => {
+                self.animate(2, 4, 6);
+
+                self.action_counter += 1;
+                if self.action_counter > 50 {
+                    self.action_num = 30;
+                    self.action_counter = 0;
+                    self.anim_num = 7;
+                }
+            }
+            30 => {
+                self.animate(2, 7, 9);
+
+                self.action_counter += 1;
+                if self.action_counter % 5 == 1 {
+                    let mut npc = NPC::create(214, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y;
+                    npc.vel_x = self.rng.range(2..12) * 0x200;
+                    npc.vel_y = self.rng.range(-0x200..0x200);
+
+                    let _ = npc_list.spawn(0x100, npc);
+
+                    state.sound_manager.play_sfx(33);
+                }
+
+                if self.action_counter > 50 {
+                    self.action_num = 10;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+            }
+            40 => {
+                self.vel_y += if self.y < self.target_y { 0x40 } else { -0x40 };
+                self.vel_y = clamp(self.vel_y, -0x400, 0x400);
+
+                self.y += if self.shock > 0 { self.vel_y / 2 } else { self.vel_y };
+
+                self.animate(2, 1, 3);
+
+                if player.y < self.target_y + 240 * 0x200 && player.y > self.target_y - 240 * 0x200 {
+                    self.action_num = 20;
+                    self.action_counter = 0;
+                    self.anim_num = 4;
+                }
+            }
+            _ => {}
+        }
+
+        if self.action_num >= 10 && self.action_num <= 30 {
+            self.vel_y += if self.y < player.y { 0x19 } else { -0x19 };
+            self.vel_y = clamp(self.vel_y, -0x400, 0x400);
+
+            if self.flags.hit_top_wall() {
+                self.vel_y = 0x200;
+            }
+
+            if self.flags.hit_bottom_wall() {
+                self.vel_y = -0x200;
+            }
+
+            self.y += if self.shock > 0 { self.vel_y / 2 } else { self.vel_y };
+
+            // gives up and returns home once the player is out of reach
+            if player.y > self.target_y + 240 * 0x200 || player.y < self.target_y - 240 * 0x200 {
+                self.action_num = 40;
+            }
+        }
+
+        self.anim_rect = state.constants.npc.n213_night_spirit[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n214_night_spirit_projectile(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        if self.action_num == 0 {
+            self.action_num = 1;
+            self.npc_flags.set_ignore_solidity(true);
+        }
+
+        self.animate(2, 0, 2);
+
+        self.vel_x -= 0x19;
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        if self.vel_x < 0 {
+            self.npc_flags.set_ignore_solidity(false);
+        }
+
+        if (self.flags.0 & 0xff) != 0 {
+            npc_list.create_death_smoke(self.x, self.y, self.display_bounds.right, 3, state, &self.rng);
+            state.sound_manager.play_sfx(28);
+
+            self.cond.set_alive(false);
+            return Ok(());
+        }
+
+        self.anim_rect = state.constants.npc.n214_night_spirit_projectile[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n215_sandcroc_outer_wall(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.action_counter = 0;
+                    self.anim_num = 0;
+                    self.target_y = self.y;
+                    self.npc_flags.set_shootable(false);
+                    self.npc_flags.set_ignore_solidity(false);
+                    self.npc_flags.set_invulnerable(false);
+                    self.npc_flags.set_solid_soft(false);
+                }
+
+                let player = self.get_closest_player_mut(players);
+                if abs(self.x - player.x) < 12 * 0x200 && player.y > self.y && player.y < self.y + 8 * 0x200 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    state.sound_manager.play_sfx(102);
+                }
+            }
+            2 => {
+                self.anim_counter += 1;
+                if self.anim_counter > 3 {
+                    self.anim_num += 1;
+                    self.anim_counter = 0;
+                }
+
+                match self.anim_num {
+                    3 => self.damage = 15,
+                    4 => {
+                        self.action_num = 3;
+                        self.action_counter = 0;
+                        self.npc_flags.set_shootable(true);
+                    }
+                    _ => {}
+                }
+            }
+            3 => {
+                self.damage = 0;
+                self.npc_flags.set_solid_soft(true);
+
+                self.action_counter += 1;
+                if self.shock > 0 {
+                    self.action_num = 4;
+                    self.action_counter = 0;
+                }
+            }
+            4 => {
+                self.npc_flags.set_ignore_solidity(true);
+                self.y += 0x200;
+                self.action_counter += 1;
+                if self.action_counter == 32 {
+                    self.action_num = 5;
+                    self.action_counter = 0;
+                    self.npc_flags.set_solid_soft(false);
+                    self.npc_flags.set_shootable(false);
+                }
+            }
+            5 => {
+                if self.action_counter > 99 {
+                    self.y = self.target_y;
+                    self.action_num = 0;
+                    self.anim_num = 0;
+                } else {
+                    self.action_counter += 1;
+                }
+            }
+            _ => {}
+        }
+
+        self.anim_rect = state.constants.npc.n215_sandcroc_outer_wall[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n216_debug_cat(&mut self, state: &mut SharedGameState) -> GameResult {
+        self.anim_rect = state.constants.npc.n216_debug_cat;
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n217_itoh(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                    self.vel_x = 0;
+                }
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                    self.target_x = self.x;
+                }
+
+                // startled
+                self.action_counter += 1;
+                self.x = self.target_x + if self.action_counter / 2 % 2 != 0 { 0x200 } else { 0 };
+
+                if self.action_counter > 32 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                    self.x = self.target_x;
+                }
+            }
+            20 | 21 => {
+                if self.action_num == 20 {
+                    self.action_num = 21;
+                    self.anim_num = 2;
+                    self.anim_counter = 0;
+                }
+
+                self.animate(8, 2, 3);
+            }
+            30 | 31 => {
+                if self.action_num == 30 {
+                    self.action_num = 31;
+                    self.anim_num = 3;
+                    self.vel_y = -0x400;
+                }
+
+                if self.flags.hit_bottom_wall() && self.vel_y >= 0 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            40 | 41 => {
+                if self.action_num == 40 {
+                    self.action_num = 41;
+                    self.anim_num = 4;
+                    self.anim_counter = 0;
+                }
+
+                self.animate(3, 4, 7);
+
+                self.x += 0x200;
+            }
+            _ => {}
+        }
+
+        self.vel_y += 0x40;
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.y += self.vel_y;
+
+        self.anim_rect = state.constants.npc.n217_itoh[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n278_little_family(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                    self.vel_x = 0;
+                }
+
+                if self.rng.range(0..60) == 1 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+
+                if self.rng.range(0..60) == 1 {
+                    self.action_num = 10;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 8 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.action_counter = self.rng.range(0..16) as u16;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                    self.direction = if self.rng.range(0..9) % 2 != 0 { Direction::Left } else { Direction::Right };
+                }
+
+                if self.direction == Direction::Left && self.flags.hit_left_wall() {
+                    self.direction = Direction::Right;
+                } else if self.direction == Direction::Right && self.flags.hit_right_wall() {
+                    self.direction = Direction::Left;
+                }
+
+                self.vel_x = self.direction.vector_x() * 0x100;
+
+                self.animate(4, 0, 1);
+
+                self.action_counter += 1;
+                if self.action_counter > 32 {
+                    self.action_num = 0;
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y += 0x20;
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        // the same npc type is used for all three members, told apart by their event number
+        let member_offset = match self.event_num {
+            200 => 0,
+            210 => 2,
+            _ => 4,
+        };
+
+        self.anim_rect = state.constants.npc.n278_little_family[self.anim_num as usize + member_offset];
+
+        Ok(())
+    }
+}
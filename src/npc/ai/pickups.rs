@@ -2,6 +2,7 @@ use ggez::GameResult;
 use num_traits::clamp;
 
 use crate::common::Direction;
+use crate::npc::list::NPCList;
 use crate::npc::NPC;
 use crate::rng::RNG;
 use crate::shared_game_state::SharedGameState;
@@ -280,4 +281,35 @@ impl NPC {
 
         Ok(())
     }
+
+    pub(crate) fn tick_n253_experience_capsule(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        if self.action_num == 0 {
+            self.action_num = 1;
+        }
+
+        self.anim_counter += 1;
+        if self.anim_counter > 4 {
+            self.anim_counter = 0;
+            self.anim_num += 1;
+            if self.anim_num > 1 {
+                self.anim_num = 0;
+            }
+        }
+
+        // the capsule is given a huge pool of health so it only breaks after a few hits
+        if self.life <= 990 {
+            npc_list.create_death_smoke(self.x, self.y, self.display_bounds.right, 8, state, &self.rng);
+            state.sound_manager.play_sfx(25);
+
+            self.exp = self.event_num;
+            self.create_xp_drop(state, npc_list);
+
+            self.cond.set_alive(false);
+            return Ok(());
+        }
+
+        self.anim_rect = state.constants.npc.n253_experience_capsule[self.anim_num as usize];
+
+        Ok(())
+    }
 }
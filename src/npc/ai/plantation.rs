@@ -0,0 +1,1083 @@
+use ggez::GameResult;
+use num_traits::{abs, clamp};
+
+use crate::caret::CaretType;
+use crate::common::{CDEG_RAD, Direction};
+use crate::npc::list::NPCList;
+use crate::npc::NPC;
+use crate::player::Player;
+use crate::rng::RNG;
+use crate::shared_game_state::SharedGameState;
+
+impl NPC {
+    pub(crate) fn tick_n220_shovel_brigade(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                }
+
+                if self.rng.range(0..120) == 10 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 8 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            _ => {}
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 2 };
+        self.anim_rect = state.constants.npc.n220_shovel_brigade[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n221_shovel_brigade_walking(&mut self, state: &mut SharedGameState) -> GameResult {
+        self.tick_plantation_walker(0x200);
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 6 };
+        self.anim_rect = state.constants.npc.n221_shovel_brigade_walking[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    /// Idle behavior shared by the mimigas wandering around the plantation.
+    fn tick_plantation_walker(&mut self, speed: i32) {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                    self.vel_x = 0;
+                }
+
+                if self.rng.range(0..60) == 1 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+
+                if self.rng.range(0..60) == 1 {
+                    self.action_num = 10;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 8 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.action_counter = self.rng.range(0..16) as u16;
+                    self.anim_num = 2;
+                    self.anim_counter = 0;
+                    self.direction = if self.rng.range(0..9) % 2 != 0 { Direction::Left } else { Direction::Right };
+                }
+
+                if self.direction == Direction::Left && self.flags.hit_left_wall() {
+                    self.direction = Direction::Right;
+                } else if self.direction == Direction::Right && self.flags.hit_right_wall() {
+                    self.direction = Direction::Left;
+                }
+
+                self.vel_x = self.direction.vector_x() * speed;
+
+                self.animate(4, 2, 5);
+
+                self.action_counter += 1;
+                if self.action_counter > 32 {
+                    self.action_num = 0;
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y += 0x20;
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+    }
+
+    pub(crate) fn tick_n222_prison_bars(&mut self, state: &mut SharedGameState) -> GameResult {
+        if self.action_num == 0 {
+            self.action_num = 1;
+            self.y -= 8 * 0x200;
+            self.anim_rect = state.constants.npc.n222_prison_bars;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n223_momorin(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                }
+
+                if self.rng.range(0..160) == 1 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+
+                let player = self.get_closest_player_mut(players);
+                if abs(self.x - player.x) < 32 * 0x200
+                    && self.y - 32 * 0x200 < player.y
+                    && self.y + 16 * 0x200 > player.y {
+                    self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 12 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            3 => {
+                self.anim_num = 2;
+            }
+            _ => {}
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 3 };
+        self.anim_rect = state.constants.npc.n223_momorin[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n224_chie(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                    self.y -= 4 * 0x200;
+                }
+
+                if self.rng.range(0..160) == 1 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+
+                let player = self.get_closest_player_mut(players);
+                if abs(self.x - player.x) < 32 * 0x200
+                    && self.y - 32 * 0x200 < player.y
+                    && self.y + 16 * 0x200 > player.y {
+                    self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 12 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            _ => {}
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 2 };
+        self.anim_rect = state.constants.npc.n224_chie[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n225_megane(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                }
+
+                if self.rng.range(0..160) == 1 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 12 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            _ => {}
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 2 };
+        self.anim_rect = state.constants.npc.n225_megane[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n226_kanpachi_plantation(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.x += 8 * 0x200;
+                    self.y -= 2 * 0x200;
+                    self.anim_num = 0;
+                }
+
+                if self.rng.range(0..60) == 1 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 6;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 8 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.anim_num = 1;
+                    self.anim_counter = 0;
+                }
+
+                self.animate(3, 1, 4);
+
+                self.x += 0x200;
+            }
+            20 => {
+                self.vel_x = 0;
+                self.anim_num = 6;
+            }
+            _ => {}
+        }
+
+        self.vel_y += 0x20;
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.y += self.vel_y;
+
+        self.anim_rect = state.constants.npc.n226_kanpachi_plantation[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n227_bucket(&mut self, state: &mut SharedGameState) -> GameResult {
+        if self.action_num == 0 {
+            self.action_num = 1;
+            self.anim_rect = state.constants.npc.n227_bucket;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n228_droll(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.y -= 8 * 0x200;
+                    self.anim_num = 0;
+                    self.vel_x = 0;
+                }
+
+                let player = self.get_closest_player_mut(players);
+                self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+
+                if self.rng.range(0..160) == 1 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 12 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.action_counter = 0;
+                    self.anim_num = 2;
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 8 {
+                    self.action_num = 12;
+                    self.anim_num = 3;
+                    self.vel_y = -0x600;
+                    self.vel_x = self.direction.vector_x() * 0x200;
+                }
+            }
+            12 => {
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                    self.vel_x = 0;
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y += 0x40;
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 4 };
+        self.anim_rect = state.constants.npc.n228_droll[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n229_red_flowers_sprouts(&mut self, state: &mut SharedGameState) -> GameResult {
+        if self.action_num == 0 {
+            self.action_num = 1;
+            self.y -= 16 * 0x200;
+
+            match self.direction {
+                Direction::Left => self.anim_rect = state.constants.npc.n229_red_flowers_sprouts[0],
+                _ => self.anim_rect = state.constants.npc.n229_red_flowers_sprouts[1],
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n230_red_flowers_blooming(&mut self, state: &mut SharedGameState) -> GameResult {
+        if self.action_num == 0 {
+            self.action_num = 1;
+            self.x += 16 * 0x200;
+            self.y += 16 * 0x200;
+
+            match self.direction {
+                Direction::Left => self.anim_rect = state.constants.npc.n230_red_flowers_blooming[0],
+                _ => self.anim_rect = state.constants.npc.n230_red_flowers_blooming[1],
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n231_rocket(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                }
+
+                self.anim_num = 0;
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.action_counter = 0;
+                }
+
+                self.action_counter += 1;
+                self.vel_y += 8;
+
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = if self.action_counter < 10 { 12 } else { 1 };
+                }
+            }
+            12 | 13 => {
+                if self.action_num == 12 {
+                    self.action_num = 13;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                    self.npc_flags.set_interactable(false);
+
+                    for _ in 0..10 {
+                        let mut npc = NPC::create(4, &state.npc_table);
+                        npc.cond.set_alive(true);
+                        npc.x = self.x + self.rng.range(-16..16) * 0x200;
+                        npc.y = self.y + 8 * 0x200;
+
+                        let _ = npc_list.spawn(0x100, npc);
+                    }
+
+                    state.sound_manager.play_sfx(44);
+                }
+
+                self.vel_y -= 8;
+
+                self.action_counter += 1;
+                self.tick_n231_rocket_exhaust(state);
+
+                let player_hit_ceiling = players.iter().any(|p| p.cond.alive() && p.flags.hit_top_wall());
+
+                if self.flags.hit_top_wall() || player_hit_ceiling || self.action_counter > 450 {
+                    if self.flags.hit_top_wall() || player_hit_ceiling {
+                        self.vel_y = 0;
+                    }
+
+                    self.action_num = 15;
+
+                    for _ in 0..6 {
+                        let mut npc = NPC::create(4, &state.npc_table);
+                        npc.cond.set_alive(true);
+                        npc.x = self.x + self.rng.range(-16..16) * 0x200;
+                        npc.y = self.y + 8 * 0x200;
+
+                        let _ = npc_list.spawn(0x100, npc);
+                    }
+                }
+            }
+            15 => {
+                self.vel_y += 8;
+
+                self.action_counter += 1;
+                if self.vel_y < 0 {
+                    self.tick_n231_rocket_exhaust(state);
+                }
+
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                    self.npc_flags.set_interactable(true);
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y = clamp(self.vel_y, -0x5ff, 0x5ff);
+        self.y += self.vel_y;
+
+        self.anim_rect = state.constants.npc.n231_rocket[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    fn tick_n231_rocket_exhaust(&self, state: &mut SharedGameState) {
+        if self.action_counter % 2 == 0 {
+            state.create_caret(self.x - 10 * 0x200, self.y + 8 * 0x200, CaretType::Exhaust, Direction::Bottom);
+        } else {
+            state.create_caret(self.x + 10 * 0x200, self.y + 8 * 0x200, CaretType::Exhaust, Direction::Bottom);
+        }
+
+        if self.action_counter % 4 == 1 {
+            state.sound_manager.play_sfx(34);
+        }
+    }
+
+    pub(crate) fn tick_n232_orangebell(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.target_x = self.x;
+                    self.target_y = self.y;
+                    self.vel_y = 0x200;
+
+                    let mut npc = NPC::create(233, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y;
+                    npc.parent_id = self.id;
+
+                    for _ in 0..8 {
+                        let _ = npc_list.spawn(0x100, npc.clone());
+                    }
+                }
+
+                if self.direction == Direction::Left && self.x < self.target_x - 16 * 0x200 {
+                    self.direction = Direction::Right;
+                }
+
+                if self.direction == Direction::Right && self.x > self.target_x + 16 * 0x200 {
+                    self.direction = Direction::Left;
+                }
+
+                self.vel_x = self.direction.vector_x() * 0x100;
+
+                self.vel_y += if self.y < self.target_y { 8 } else { -8 };
+                self.vel_y = clamp(self.vel_y, -0x200, 0x200);
+            }
+            _ => {}
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.animate(5, 0, 2);
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 3 };
+        self.anim_rect = state.constants.npc.n232_orangebell[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n233_orangebell_bat(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+
+                    let angle = self.rng.range(0..0xff) as f64 * CDEG_RAD;
+                    self.vel_x = (angle.cos() * 512.0) as i32;
+
+                    let angle = self.rng.range(0..0xff) as f64 * CDEG_RAD;
+                    self.vel_y = (angle.sin() * 512.0) as i32;
+
+                    self.action_counter = 120;
+                    self.vel_y2 = self.rng.range(-32..32) * 0x200;
+                }
+
+                if let Some(parent) = self.get_parent_ref_mut(npc_list) {
+                    if parent.npc_type == 232 {
+                        self.target_x = parent.x;
+                        self.target_y = parent.y;
+                        self.direction = parent.direction;
+                    }
+                }
+
+                self.vel_x += if self.x > self.target_x { -8 } else { 8 };
+                self.vel_y += if self.y > self.target_y + self.vel_y2 { -0x20 } else { 0x20 };
+
+                self.vel_x = clamp(self.vel_x, -0x400, 0x400);
+                self.vel_y = clamp(self.vel_y, -0x400, 0x400);
+
+                // dive bombs the player when passing overhead
+                if self.action_counter < 120 {
+                    self.action_counter += 1;
+                } else if abs(player.x - self.x) < 8 * 0x200 && player.y > self.y && player.y < self.y + 96 * 0x200 {
+                    self.vel_x /= 4;
+                    self.vel_y = 0;
+                    self.action_num = 3;
+                    self.anim_num = 3;
+                }
+            }
+            3 => {
+                self.vel_y += 0x40;
+                if self.vel_y > 0x5ff {
+                    self.vel_y = 0x5ff;
+                }
+
+                if self.flags.hit_bottom_wall() {
+                    self.vel_y = 0;
+                    self.vel_x *= 2;
+                    self.action_counter = 0;
+                    self.action_num = 1;
+                }
+            }
+            _ => {}
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        if self.action_num != 3 {
+            self.animate(1, 0, 2);
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 4 };
+        self.anim_rect = state.constants.npc.n233_orangebell_hat[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n235_midorin(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                    self.vel_x = 0;
+                }
+
+                if self.rng.range(0..30) == 1 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+
+                if self.rng.range(0..30) == 1 {
+                    self.action_num = 10;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 8 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.action_counter = self.rng.range(0..16) as u16;
+                    self.anim_num = 2;
+                    self.anim_counter = 0;
+                    self.direction = if self.rng.range(0..9) % 2 != 0 { Direction::Left } else { Direction::Right };
+                }
+
+                if self.direction == Direction::Left && self.flags.hit_left_wall() {
+                    self.direction = Direction::Right;
+                } else if self.direction == Direction::Right && self.flags.hit_right_wall() {
+                    self.direction = Direction::Left;
+                }
+
+                self.vel_x = self.direction.vector_x() * 0x400;
+
+                self.animate(1, 2, 3);
+
+                self.action_counter += 1;
+                if self.action_counter > 64 {
+                    self.action_num = 0;
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y += 0x20;
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 4 };
+        self.anim_rect = state.constants.npc.n235_midorin[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n236_gunfish(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.action_counter = self.rng.range(0..50) as u16;
+                    self.target_y = self.y;
+                    self.vel_y = 0;
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 50 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.vel_y = -0x200;
+                }
+            }
+            2 => {
+                if abs(player.x - self.x) < 128 * 0x200 && player.y < self.y + 32 * 0x200 && player.y > self.y - 160 * 0x200 {
+                    self.action_counter2 += 1;
+                }
+
+                self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+
+                self.animate(1, 0, 1);
+
+                if self.action_counter2 > 80 {
+                    self.action_num = 10;
+                    self.action_counter = 0;
+                }
+            }
+            10 => {
+                self.animate(1, 2, 3);
+
+                self.action_counter += 1;
+                if self.action_counter > 20 {
+                    self.action_num = 20;
+                    self.action_counter = 0;
+                }
+            }
+            20 => {
+                self.animate(1, 4, 5);
+
+                self.action_counter += 1;
+                if self.action_counter % 10 == 3 {
+                    let mut npc = NPC::create(237, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x + self.direction.vector_x() * 8 * 0x200;
+                    npc.y = self.y - 8 * 0x200;
+                    npc.vel_x = self.direction.vector_x() * 0x400;
+                    npc.vel_y = -0x400;
+
+                    let _ = npc_list.spawn(0x100, npc);
+
+                    state.sound_manager.play_sfx(39);
+                }
+
+                if self.action_counter > 60 {
+                    self.action_num = 2;
+                    self.action_counter2 = 0;
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y += if self.y < self.target_y { 0x10 } else { -0x10 };
+        self.vel_y = clamp(self.vel_y, -0x100, 0x100);
+
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 6 };
+        self.anim_rect = state.constants.npc.n236_gunfish[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n237_gunfish_projectile(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        if self.action_num == 0 {
+            self.action_num = 1;
+            self.anim_rect = state.constants.npc.n237_gunfish_projectile;
+        }
+
+        self.vel_y += 0x20;
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        if (self.flags.0 & 0xff) != 0 || self.flags.in_water() {
+            let mut npc = NPC::create(73, &state.npc_table);
+            npc.cond.set_alive(true);
+            npc.x = self.x;
+            npc.y = self.y;
+
+            for _ in 0..5 {
+                npc.vel_x = self.vel_x + self.rng.range(-0x200..0x200);
+                npc.vel_y = self.vel_y + self.rng.range(-0x200..0x80);
+
+                let _ = npc_list.spawn(0x100, npc.clone());
+            }
+
+            state.sound_manager.play_sfx(21);
+
+            self.cond.set_alive(false);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n239_cage_bars(&mut self, state: &mut SharedGameState) -> GameResult {
+        if self.action_num == 0 {
+            self.action_num = 1;
+
+            if self.direction == Direction::Left {
+                self.x += 8 * 0x200;
+                self.y += 16 * 0x200;
+                self.anim_rect = state.constants.npc.n239_cage_bars[0];
+            } else {
+                self.y -= 8 * 0x200;
+                self.anim_rect = state.constants.npc.n239_cage_bars[1];
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n240_mimiga_jailed(&mut self, state: &mut SharedGameState) -> GameResult {
+        self.tick_plantation_walker(0x200);
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 6 };
+        self.anim_rect = state.constants.npc.n240_mimiga_jailed[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n260_shovel_brigade_caged(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.x += 0x200;
+                    self.y -= 2 * 0x200;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                }
+
+                if self.rng.range(0..160) == 1 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 12 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.anim_num = 2;
+                }
+            }
+            _ => {}
+        }
+
+        if self.action_num < 10 {
+            let player = self.get_closest_player_mut(players);
+            self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 3 };
+        self.anim_rect = state.constants.npc.n260_shovel_brigade_caged[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n261_chie_caged(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        self.tick_caged_idle(players);
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 2 };
+        self.anim_rect = state.constants.npc.n261_chie_caged[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n262_chaco_caged(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        self.tick_caged_idle(players);
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 2 };
+        self.anim_rect = state.constants.npc.n262_chaco_caged[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    /// Blinks and keeps facing the player from inside the cage.
+    fn tick_caged_idle(&mut self, players: [&mut Player; 2]) {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.x -= 0x200;
+                    self.y -= 2 * 0x200;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                }
+
+                if self.rng.range(0..160) == 1 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 12 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            _ => {}
+        }
+
+        let player = self.get_closest_player_mut(players);
+        self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+    }
+
+    pub(crate) fn tick_n273_droll_projectile(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        if self.action_num == 0 {
+            self.action_num = 1;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        if (self.flags.0 & 0xff) != 0 {
+            npc_list.create_death_smoke(self.x, self.y, 0, 3, state, &self.rng);
+            self.cond.set_alive(false);
+            return Ok(());
+        }
+
+        self.action_counter += 1;
+        if self.action_counter % 5 == 0 {
+            state.sound_manager.play_sfx(110);
+        }
+
+        self.anim_num += 1;
+        if self.anim_num > 2 {
+            self.anim_num = 0;
+        }
+
+        self.anim_rect = state.constants.npc.n273_droll_projectile[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n274_droll(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.target_x = self.x;
+                }
+
+                self.action_num = 2;
+                self.action_counter = 0;
+                self.vel_x = 0;
+                self.anim_num = 0;
+            }
+            2 => {
+                self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+
+                self.action_counter += 1;
+                if self.action_counter > 40 || self.shock > 0 {
+                    self.action_num = 10;
+                    self.action_counter = 0;
+                    self.anim_num = 2;
+                }
+            }
+            10 => {
+                self.action_counter += 1;
+                if self.action_counter > 10 {
+                    self.action_num = 11;
+                    self.action_counter = 0;
+                    self.anim_num = 3;
+                    self.vel_y = -0x600;
+                    self.vel_x = if self.target_x < self.x { -0x200 } else { 0x200 };
+                }
+            }
+            11 => {
+                if self.vel_y > 0 {
+                    self.action_num = 12;
+                    self.action_counter = 0;
+                    self.anim_num = 4;
+                }
+            }
+            12 => {
+                self.action_counter += 1;
+                if self.action_counter == 2 {
+                    self.anim_num = 5;
+
+                    let angle = f64::atan2((self.y - player.y) as f64, (self.x - player.x) as f64);
+
+                    let mut npc = NPC::create(273, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y;
+                    npc.vel_x = (angle.cos() * -1536.0) as i32;
+                    npc.vel_y = (angle.sin() * -1536.0) as i32;
+
+                    let _ = npc_list.spawn(0x100, npc);
+
+                    state.sound_manager.play_sfx(39);
+                }
+
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 13;
+                    self.action_counter = 0;
+                    self.anim_num = 2;
+                }
+            }
+            13 => {
+                self.vel_x /= 2;
+
+                self.action_counter += 1;
+                if self.action_counter > 10 {
+                    self.action_num = 1;
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y += 0x55;
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 6 };
+        self.anim_rect = state.constants.npc.n274_droll[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n275_puppy_plantation(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                }
+
+                if self.rng.range(0..120) == 10 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+
+                let player = self.get_closest_player_mut(players);
+                if abs(self.x - player.x) < 64 * 0x200
+                    && self.y - 32 * 0x200 < player.y
+                    && self.y + 16 * 0x200 > player.y {
+                    self.animate(3, 2, 3);
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 8 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y += 0x40;
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.y += self.vel_y;
+
+        self.anim_rect = state.constants.npc.n275_puppy_plantation[self.anim_num as usize];
+
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
 use ggez::GameResult;
 use num_traits::{abs, clamp};
 
-use crate::common::Direction;
+use crate::bullet::BulletManager;
+use crate::caret::CaretType;
+use crate::common::{CDEG_RAD, Direction};
 use crate::npc::list::NPCList;
 use crate::npc::NPC;
 use crate::player::Player;
@@ -333,4 +335,979 @@ impl NPC {
 
         Ok(())
     }
+
+    pub(crate) fn tick_n050_skeleton_projectile(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 && self.direction == Direction::Right {
+                    self.action_num = 2;
+                }
+
+                self.x += self.vel_x;
+                self.y += self.vel_y;
+
+                if self.flags.hit_left_wall() {
+                    self.action_num = 2;
+                    self.vel_x = 0x200;
+                    self.action_counter2 += 1;
+                }
+
+                if self.flags.hit_right_wall() {
+                    self.action_num = 2;
+                    self.vel_x = -0x200;
+                    self.action_counter2 += 1;
+                }
+
+                if self.flags.hit_top_wall() {
+                    self.action_num = 2;
+                    self.vel_y = 0x200;
+                    self.action_counter2 += 1;
+                }
+
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 2;
+                    self.vel_y = -0x200;
+                    self.action_counter2 += 1;
+                }
+            }
+            2 => {
+                self.vel_y += 0x40;
+                self.x += self.vel_x;
+                self.y += self.vel_y;
+
+                if self.flags.hit_bottom_wall() {
+                    self.action_counter2 += 1;
+                    if self.action_counter2 > 1 {
+                        state.create_caret(self.x, self.y, CaretType::ProjectileDissipation, Direction::Left);
+                        self.cond.set_alive(false);
+                        return Ok(());
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y = clamp(self.vel_y, -0x5ff, 0x5ff);
+
+        self.anim_counter += 1;
+        if self.anim_counter > 1 {
+            self.anim_counter = 0;
+
+            if self.direction == Direction::Left {
+                self.anim_num = (self.anim_num + 1) % 4;
+            } else {
+                self.anim_num = (self.anim_num + 3) % 4;
+            }
+        }
+
+        self.anim_rect = state.constants.npc.n050_skeleton_projectile[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n115_ravil(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.vel_x = 0;
+                    self.action_counter = 0;
+                    self.action_counter2 = 0;
+                }
+
+                if (player.x < self.x + 96 * 0x200 && player.x > self.x - 96 * 0x200
+                    && player.y < self.y + 32 * 0x200 && player.y > self.y - 96 * 0x200)
+                    || self.shock > 0 {
+                    self.action_num = 10;
+                }
+            }
+            10 => {
+                self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+                self.anim_num = 1;
+
+                self.action_counter += 1;
+                if self.action_counter > 20 {
+                    self.action_counter = 0;
+                    self.action_num = 20;
+                }
+            }
+            20 => {
+                self.damage = 0;
+                self.vel_x = 0;
+
+                self.anim_counter += 1;
+                if self.anim_counter > 2 {
+                    self.anim_counter = 0;
+                    self.anim_num += 1;
+                }
+
+                if self.anim_num > 2 {
+                    self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+                    self.vel_x = self.direction.vector_x() * 0x200;
+                    self.vel_y = -0x400;
+                    self.action_num = 21;
+
+                    self.action_counter2 += 1;
+                    if self.action_counter2 > 2 {
+                        // every third hop is a lunge
+                        self.action_counter2 = 0;
+                        self.anim_num = 4;
+                        self.vel_x *= 2;
+                        self.damage = 5;
+
+                        state.sound_manager.play_sfx(102);
+                    } else {
+                        self.anim_num = 3;
+
+                        state.sound_manager.play_sfx(30);
+                    }
+                }
+            }
+            21 => {
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 20;
+                    self.anim_num = 1;
+                    self.anim_counter = 0;
+                    self.damage = 0;
+
+                    state.sound_manager.play_sfx(23);
+
+                    if player.x > self.x + 144 * 0x200 || player.x < self.x - 144 * 0x200
+                        || player.y > self.y + 48 * 0x200 || player.y < self.y - 144 * 0x200 {
+                        self.action_num = 0;
+                    }
+                }
+            }
+            30 => {
+                npc_list.create_death_smoke(self.x, self.y, 12 * 0x200, 8, state, &self.rng);
+
+                self.anim_num = 0;
+                self.action_num = 0;
+            }
+            50 | 51 => {
+                if self.action_num == 50 {
+                    self.action_num = 51;
+                    self.anim_num = 4;
+                    self.damage = 0;
+                    self.vel_y = -0x200;
+                    self.npc_flags.set_shootable(false);
+                    self.npc_flags.set_solid_soft(false);
+
+                    state.sound_manager.play_sfx(51);
+                }
+
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 52;
+                    self.anim_num = 5;
+                    self.vel_x = 0;
+
+                    state.sound_manager.play_sfx(23);
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y += if self.action_num > 50 { 0x20 } else { 0x40 };
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 6 };
+        self.anim_rect = state.constants.npc.n115_ravil[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n116_red_petals(&mut self, state: &mut SharedGameState) -> GameResult {
+        if self.action_num == 0 {
+            self.action_num = 1;
+            self.anim_rect = state.constants.npc.n116_red_petals;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n120_colon_a(&mut self, state: &mut SharedGameState) -> GameResult {
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 1 };
+        self.anim_rect = state.constants.npc.n120_colon_a[dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n121_colon_b(&mut self, state: &mut SharedGameState) -> GameResult {
+        if self.direction == Direction::Left {
+            match self.action_num {
+                0 | 1 => {
+                    if self.action_num == 0 {
+                        self.action_num = 1;
+                        self.anim_num = 0;
+                        self.anim_counter = 0;
+                    }
+
+                    if self.rng.range(0..120) == 10 {
+                        self.action_num = 2;
+                        self.action_counter = 0;
+                        self.anim_num = 1;
+                    }
+                }
+                2 => {
+                    self.action_counter += 1;
+                    if self.action_counter > 8 {
+                        self.action_num = 1;
+                        self.anim_num = 0;
+                    }
+                }
+                _ => {}
+            }
+
+            self.anim_rect = state.constants.npc.n121_colon_b[self.anim_num as usize];
+        } else {
+            self.anim_rect = state.constants.npc.n121_colon_b[2];
+
+            self.action_counter += 1;
+            if self.action_counter > 100 {
+                self.action_counter = 0;
+                state.create_caret(self.x, self.y, CaretType::Zzz, Direction::Left);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n122_colon_enraged(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                }
+
+                if self.rng.range(0..120) == 10 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 8 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.life = 1000;
+                    self.action_counter = self.rng.range(0..50) as u16;
+                    self.anim_num = 0;
+                    self.damage = 0;
+                }
+
+                if self.action_counter > 0 {
+                    self.action_counter -= 1;
+                } else {
+                    self.action_num = 13;
+                }
+            }
+            13 | 14 => {
+                if self.action_num == 13 {
+                    self.action_num = 14;
+                    self.action_counter = self.rng.range(0..50) as u16;
+
+                    let player = self.get_closest_player_mut(players);
+                    self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+                }
+
+                self.animate(2, 2, 5);
+
+                self.vel_x += self.direction.vector_x() * 0x40;
+
+                if self.action_counter > 0 {
+                    self.action_counter -= 1;
+                } else {
+                    self.npc_flags.set_shootable(true);
+                    self.action_num = 15;
+                    self.anim_num = 2;
+                    self.vel_y = -0x200;
+                    self.damage = 2;
+                }
+            }
+            15 => {
+                if self.flags.hit_bottom_wall() {
+                    self.npc_flags.set_shootable(true);
+                    self.vel_x = 0;
+                    self.action_num = 10;
+                    self.damage = 0;
+                }
+            }
+            20 => {
+                if self.flags.hit_bottom_wall() {
+                    self.vel_x = 0;
+                    self.action_num = 21;
+                    self.damage = 0;
+                    self.anim_num = if self.anim_num == 6 { 8 } else { 9 };
+                    self.action_counter = self.rng.range(300..400) as u16;
+                }
+            }
+            21 => {
+                if self.action_counter > 0 {
+                    self.action_counter -= 1;
+                } else {
+                    self.npc_flags.set_shootable(true);
+                    self.life = 1000;
+                    self.action_num = 11;
+                    self.action_counter = self.rng.range(0..50) as u16;
+                    self.anim_num = 0;
+                }
+            }
+            _ => {}
+        }
+
+        // colons can't be killed, getting hit just knocks them out for a while
+        if self.action_num > 10 && self.action_num < 20 && self.life != 1000 {
+            self.action_num = 20;
+            self.vel_y = -0x200;
+            self.anim_num = self.rng.range(6..7) as u16;
+            self.npc_flags.set_shootable(false);
+        }
+
+        self.vel_y += 0x20;
+
+        self.vel_x = clamp(self.vel_x, -0x1ff, 0x1ff);
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 10 };
+        self.anim_rect = state.constants.npc.n122_colon_enraged[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n126_puppy_running(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                }
+
+                if self.rng.range(0..120) == 10 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+
+                if abs(player.x - self.x) < 96 * 0x200 && abs(player.y - self.y) < 32 * 0x200 {
+                    // run away from the player
+                    self.direction = if self.x > player.x { Direction::Right } else { Direction::Left };
+                    self.action_num = 10;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 8 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.anim_num = 4;
+                    self.anim_counter = 0;
+                }
+
+                if self.flags.hit_bottom_wall() {
+                    self.animate(2, 2, 4);
+                } else {
+                    self.anim_num = 5;
+                    self.anim_counter = 0;
+                }
+
+                if self.vel_x < 0 && self.flags.hit_left_wall() {
+                    self.vel_x /= -2;
+                    self.direction = Direction::Right;
+                }
+
+                if self.vel_x > 0 && self.flags.hit_right_wall() {
+                    self.vel_x /= -2;
+                    self.direction = Direction::Left;
+                }
+
+                self.vel_x += self.direction.vector_x() * 0x40;
+
+                if self.vel_x > 0x5ff {
+                    self.vel_x = 0x400;
+                }
+
+                if self.vel_x < -0x5ff {
+                    self.vel_x = -0x400;
+                }
+            }
+            _ => {}
+        }
+
+        // the puppy can only be picked up while the player is pressing down
+        self.npc_flags.set_interactable(player.controller.trigger_down());
+
+        self.vel_y += 0x40;
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 6 };
+        self.anim_rect = state.constants.npc.n126_puppy_running[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n130_puppy_sitting(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                    self.npc_flags.set_interactable(true);
+                }
+
+                if self.rng.range(0..120) == 10 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+
+                let player = self.get_closest_player_mut(players);
+                if abs(player.x - self.x) < 64 * 0x200 && player.y > self.y - 32 * 0x200 && player.y < self.y + 16 * 0x200 {
+                    self.animate(3, 2, 3);
+                } else if self.anim_num > 1 {
+                    self.anim_num = 0;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 8 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y += 0x40;
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 4 };
+        self.anim_rect = state.constants.npc.n130_puppy_sitting[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n131_puppy_sleeping(&mut self, state: &mut SharedGameState) -> GameResult {
+        self.action_counter += 1;
+        if self.action_counter > 100 {
+            self.action_counter = 0;
+            state.create_caret(self.x, self.y, CaretType::Zzz, Direction::Left);
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 1 };
+        self.anim_rect = state.constants.npc.n131_puppy_sleeping[dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n132_puppy_barking(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        if self.action_num < 100 {
+            self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+        }
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                }
+
+                if self.rng.range(0..120) == 10 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+
+                if abs(player.x - self.x) < 64 * 0x200 && player.y > self.y - 16 * 0x200 && player.y < self.y + 16 * 0x200 {
+                    self.animate(4, 2, 4);
+
+                    if self.anim_num == 4 && self.anim_counter == 0 {
+                        state.sound_manager.play_sfx(105);
+                    }
+                } else if self.anim_num == 4 {
+                    self.anim_num = 2;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 8 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                }
+
+                if self.rng.range(0..120) == 10 {
+                    self.action_num = 12;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+            }
+            12 => {
+                self.action_counter += 1;
+                if self.action_counter > 8 {
+                    self.action_num = 11;
+                    self.anim_num = 0;
+                }
+            }
+            100 | 101 => {
+                if self.action_num == 100 {
+                    self.action_num = 101;
+                    self.action_counter2 = 0;
+                }
+
+                self.anim_counter += 1;
+                if self.anim_counter > 4 {
+                    self.anim_counter = 0;
+                    self.anim_num += 1;
+                }
+
+                if self.anim_num > 4 {
+                    if self.action_counter2 < 3 {
+                        self.anim_num = 2;
+                        self.action_counter2 += 1;
+                    } else {
+                        self.anim_num = 0;
+                        self.action_counter2 = 0;
+                    }
+                }
+
+                if self.anim_num == 4 && self.anim_counter == 0 {
+                    state.sound_manager.play_sfx(105);
+                }
+            }
+            120 => {
+                self.anim_num = 0;
+            }
+            _ => {}
+        }
+
+        self.vel_y += 0x40;
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 5 };
+        self.anim_rect = state.constants.npc.n132_puppy_barking[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n133_jenka(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                }
+
+                if self.rng.range(0..120) == 10 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 8 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            _ => {}
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 2 };
+        self.anim_rect = state.constants.npc.n133_jenka[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n134_armadillo(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], bullet_manager: &BulletManager) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = 2;
+                    self.npc_flags.set_shootable(false);
+                    self.npc_flags.set_invulnerable(true);
+                }
+
+                let player = self.get_closest_player_mut(players);
+                if abs(player.x - self.x) < 320 * 0x200 && abs(player.y - self.y) < 160 * 0x200 {
+                    self.action_num = 10;
+                    self.npc_flags.set_shootable(true);
+                    self.npc_flags.set_invulnerable(false);
+                }
+            }
+            10 => {
+                self.animate(4, 0, 1);
+
+                if self.direction == Direction::Left && self.flags.hit_left_wall() {
+                    self.direction = Direction::Right;
+                }
+
+                if self.direction == Direction::Right && self.flags.hit_right_wall() {
+                    self.direction = Direction::Left;
+                }
+
+                self.x += self.direction.vector_x() * 0x100;
+
+                // curl up into the shell as soon as a missile goes off
+                if bullet_manager.count_bullets_type_idx_all(6) > 0 {
+                    self.action_num = 20;
+                    self.action_counter = 0;
+                    self.anim_num = 2;
+                    self.npc_flags.set_shootable(false);
+                    self.npc_flags.set_invulnerable(true);
+                }
+            }
+            20 => {
+                self.action_counter += 1;
+                if self.action_counter > 100 {
+                    self.action_num = 10;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                    self.npc_flags.set_shootable(true);
+                    self.npc_flags.set_invulnerable(false);
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y += 0x40;
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 3 };
+        self.anim_rect = state.constants.npc.n134_armadillo[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n135_skeleton(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 => {
+                if abs(player.x - self.x) < 320 * 0x200 && abs(player.y - self.y) < 160 * 0x200 {
+                    self.action_num = 10;
+                }
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.action_counter = 0;
+                    self.anim_num = 0;
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 5 && self.flags.hit_bottom_wall() {
+                    self.action_num = 20;
+                    self.anim_num = 1;
+                    self.action_counter2 = 0;
+                    self.action_counter = self.rng.range(25..75) as u16;
+                    self.vel_x = 0;
+                }
+            }
+            20 => {
+                self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+
+                if self.action_counter > 0 {
+                    self.action_counter -= 1;
+                } else {
+                    self.action_num = 21;
+                    self.anim_num = 0;
+                    self.vel_y = -0x200 * self.rng.range(1..3);
+                    self.vel_x = self.direction.vector_x() * 0x100;
+
+                    state.sound_manager.play_sfx(30);
+                }
+            }
+            21 => {
+                // throw a bone at the top of the jump
+                if self.vel_y >= 0 && self.action_counter2 == 0 {
+                    self.action_counter2 = 1;
+
+                    let angle = f64::atan2((self.y - player.y) as f64, (self.x - player.x) as f64)
+                        + self.rng.range(-6..6) as f64 * CDEG_RAD;
+
+                    let mut npc = NPC::create(50, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y - 8 * 0x200;
+                    npc.vel_x = (angle.cos() * -1024.0) as i32;
+                    npc.vel_y = (angle.sin() * -1024.0) as i32;
+
+                    let _ = npc_list.spawn(0x100, npc);
+
+                    state.sound_manager.play_sfx(39);
+                }
+
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 10;
+                    self.vel_x = 0;
+
+                    state.sound_manager.play_sfx(23);
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y += 0x33;
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 2 };
+        self.anim_rect = state.constants.npc.n135_skeleton[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n136_puppy_carried(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                    self.npc_flags.set_interactable(false);
+                }
+
+                if self.rng.range(0..120) == 10 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 8 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            _ => {}
+        }
+
+        let player = self.get_closest_player_mut(players);
+
+        self.direction = player.direction;
+        self.x = player.x - self.direction.vector_x() * 4 * 0x200;
+        self.y = player.y - 10 * 0x200;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 2 };
+        self.anim_rect = state.constants.npc.n136_puppy_carried[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n137_large_door_frame(&mut self, state: &mut SharedGameState) -> GameResult {
+        if self.action_num == 0 {
+            self.action_num = 1;
+            self.anim_rect = state.constants.npc.n137_large_door_frame;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n138_large_door(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 => {
+                self.action_num = 1;
+
+                if self.direction == Direction::Left {
+                    self.anim_rect = state.constants.npc.n138_large_door[0];
+                    self.x += 8 * 0x200;
+                } else {
+                    self.anim_rect = state.constants.npc.n138_large_door[1];
+                    self.x -= 8 * 0x200;
+                }
+
+                self.target_x = self.x;
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.anim_num = 1;
+                    self.action_counter = 0;
+                    self.npc_flags.set_ignore_solidity(true);
+                }
+
+                self.action_counter += 1;
+                if self.action_counter % 8 == 0 {
+                    state.sound_manager.play_sfx(26);
+                }
+
+                // the door slides into the wall, so the part that's already inside gets cut off
+                let offset = self.action_counter / 8;
+                if self.direction == Direction::Left {
+                    self.x = self.target_x - offset as i32 * 0x200;
+                    self.anim_rect.left = state.constants.npc.n138_large_door[0].left + offset;
+                    self.display_bounds.left = (8 - offset as usize) * 0x200;
+                } else {
+                    self.x = self.target_x + offset as i32 * 0x200;
+                    self.anim_rect.right = state.constants.npc.n138_large_door[1].right - offset;
+                }
+
+                if self.action_counter == 104 {
+                    self.cond.set_alive(false);
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n142_flower_cub(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        match self.action_num {
+            0 | 10 | 11 => {
+                if self.action_num != 11 {
+                    self.action_num = 11;
+                    self.anim_num = 0;
+                    self.action_counter = 0;
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 30 {
+                    self.action_num = 12;
+                    self.anim_num = 1;
+                    self.anim_counter = 0;
+                }
+            }
+            12 => {
+                self.anim_counter += 1;
+                if self.anim_counter > 8 {
+                    self.anim_counter = 0;
+                    self.anim_num += 1;
+                }
+
+                if self.anim_num == 3 {
+                    let player = self.get_closest_player_mut(players);
+
+                    self.action_num = 20;
+                    self.vel_y = -0x200;
+                    self.vel_x = if self.x > player.x { -0x200 } else { 0x200 };
+                }
+            }
+            20 => {
+                self.anim_num = if self.vel_y > -0x80 { 4 } else { 3 };
+
+                if self.flags.hit_bottom_wall() {
+                    self.anim_num = 2;
+                    self.action_num = 21;
+                    self.action_counter = 0;
+                    self.vel_x = 0;
+
+                    state.sound_manager.play_sfx(23);
+                }
+            }
+            21 => {
+                self.action_counter += 1;
+                if self.action_counter > 10 {
+                    self.action_num = 10;
+                    self.anim_num = 0;
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y += 0x40;
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.anim_rect = state.constants.npc.n142_flower_cub[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n143_jenka_collapsed(&mut self, state: &mut SharedGameState) -> GameResult {
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 1 };
+        self.anim_rect = state.constants.npc.n143_jenka_collapsed[dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n305_small_puppy(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.y -= 16 * 0x200;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                }
+
+                self.animate(6, 0, 1);
+            }
+            _ => {}
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 2 };
+        self.anim_rect = state.constants.npc.n305_small_puppy[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
 }
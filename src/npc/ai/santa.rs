@@ -71,4 +71,40 @@ impl NPC {
 
         Ok(())
     }
+
+    pub(crate) fn tick_n307_santa_caged(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.x += 0x200;
+                    self.y -= 2 * 0x200;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                }
+
+                if self.rng.range(0..160) == 1 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 12 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            _ => {}
+        }
+
+        let player = self.get_closest_player_mut(players);
+        self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 2 };
+        self.anim_rect = state.constants.npc.n307_santa_caged[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
 }
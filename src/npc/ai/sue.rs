@@ -283,4 +283,224 @@ impl NPC {
 
         Ok(())
     }
+
+    pub(crate) fn tick_n280_sue_teleported(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.action_counter = 0;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                    self.x += 6 * 0x200;
+                    self.target_x = self.x;
+
+                    state.sound_manager.play_sfx(29);
+                }
+
+                self.action_counter += 1;
+                if self.action_counter >= 64 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 20 {
+                    self.action_num = 3;
+                    self.anim_num = 1;
+                    self.hit_bounds.bottom = 8 * 0x200;
+                }
+            }
+            3 => {
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 4;
+                    self.action_counter = 0;
+                    self.anim_num = 0;
+                }
+            }
+            _ => {}
+        }
+
+        if self.action_num > 1 {
+            self.vel_y += 0x20;
+            if self.vel_y > 0x5ff {
+                self.vel_y = 0x5ff;
+            }
+
+            self.y += self.vel_y;
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 2 };
+        self.anim_rect = state.constants.npc.n280_sue_teleported[self.anim_num as usize + dir_offset];
+
+        if self.action_num == 1 {
+            self.anim_rect.bottom = self.anim_rect.top + self.action_counter / 4;
+
+            if self.action_counter / 2 % 2 != 0 {
+                self.x = self.target_x;
+            } else {
+                self.x = self.target_x + 0x200;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n284_sue_possessed(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.y -= 4 * 0x200;
+                    self.anim_num = 0;
+
+                    state.sound_manager.play_sfx(29);
+                }
+
+                self.action_counter += 1;
+                if self.action_counter / 2 % 2 != 0 {
+                    self.anim_num = 9;
+                } else {
+                    self.anim_num = 0;
+                }
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.action_counter = 0;
+                    self.anim_num = 0;
+                    self.npc_flags.set_shootable(true);
+                    self.npc_flags.set_ignore_solidity(false);
+                    self.damage = 0;
+                }
+
+                self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+
+                // drifts towards a point above the player before dashing
+                self.vel_x += if self.x < player.x { 0x20 } else { -0x20 };
+                self.vel_y += if self.y < player.y - 32 * 0x200 { 0x20 } else { -0x20 };
+
+                self.animate(1, 0, 1);
+
+                self.action_counter += 1;
+                if self.action_counter > 50 {
+                    self.action_num = 20;
+                }
+            }
+            20 | 21 => {
+                if self.action_num == 20 {
+                    self.action_num = 21;
+                    self.action_counter = 0;
+                    self.anim_num = 2;
+                    self.vel_x = 0;
+                    self.vel_y = 0;
+                }
+
+                self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+
+                self.action_counter += 1;
+                if self.action_counter > 16 {
+                    self.action_num = 30;
+                }
+            }
+            30 | 31 => {
+                if self.action_num == 30 {
+                    self.action_num = 31;
+                    self.action_counter = 0;
+                    self.anim_num = 3;
+                    self.damage = 4;
+
+                    let angle = f64::atan2((self.y - player.y) as f64, (self.x - player.x) as f64);
+                    self.vel_x = (angle.cos() * -2048.0) as i32;
+                    self.vel_y = (angle.sin() * -2048.0) as i32;
+
+                    state.sound_manager.play_sfx(25);
+                }
+
+                self.animate(1, 3, 5);
+
+                self.action_counter += 1;
+                if self.action_counter > 20 || (self.flags.0 & 0x0f) != 0 {
+                    self.action_counter2 += 1;
+                    self.damage = 0;
+
+                    if self.action_counter2 % 3 == 0 {
+                        self.action_num = 40;
+                    } else {
+                        self.action_num = 20;
+                    }
+                }
+            }
+            40 | 41 => {
+                if self.action_num == 40 {
+                    self.action_num = 41;
+                    self.action_counter = 0;
+                    self.anim_num = 6;
+                }
+
+                self.vel_x = 4 * self.vel_x / 5;
+                self.vel_y = 4 * self.vel_y / 5;
+
+                self.action_counter += 1;
+                if self.action_counter > 30 {
+                    self.action_num = 10;
+                }
+            }
+            99 | 100 => {
+                if self.action_num == 99 {
+                    self.action_num = 100;
+                    self.action_counter = 0;
+                    self.anim_num = 11;
+                    self.npc_flags.set_shootable(false);
+                    self.npc_flags.set_ignore_solidity(false);
+                    self.damage = 0;
+                    self.vel_x = 0;
+                    self.vel_y = -0x200;
+                }
+
+                self.vel_y += 0x20;
+
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 101;
+                    self.anim_num = 12;
+                    self.vel_y = 0;
+
+                    state.sound_manager.play_sfx(23);
+                }
+            }
+            _ => {}
+        }
+
+        if self.action_num > 10 && self.action_num < 99 {
+            let max = if self.action_num == 31 { 0x800 } else { 0x400 };
+
+            self.vel_x = clamp(self.vel_x, -max, max);
+            self.vel_y = clamp(self.vel_y, -max, max);
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 13 };
+        self.anim_rect = state.constants.npc.n284_sue_possessed[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n297_sue_dragon_mouth(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        if let Some(parent) = self.get_parent_ref_mut(npc_list) {
+            self.x = parent.x + 16 * 0x200;
+            self.y = parent.y + 8 * 0x200;
+        } else {
+            self.cond.set_alive(false);
+            return Ok(());
+        }
+
+        self.anim_rect = state.constants.npc.n297_sue_dragon_mouth;
+
+        Ok(())
+    }
 }
@@ -1,7 +1,10 @@
 use ggez::GameResult;
 use num_traits::clamp;
 
+use crate::bullet::BulletManager;
+use crate::caret::CaretType;
 use crate::common::Direction;
+use crate::npc::list::NPCList;
 use crate::npc::NPC;
 use crate::player::Player;
 use crate::rng::RNG;
@@ -271,4 +274,417 @@ impl NPC {
 
         Ok(())
     }
+
+    pub(crate) fn tick_n140_toroko_frenzied(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList, bullet_manager: &BulletManager) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.action_counter = 0;
+                    self.anim_num = 9;
+                    self.npc_flags.set_shootable(false);
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 50 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 8;
+                }
+            }
+            2 => {
+                self.anim_num += 1;
+                if self.anim_num > 10 {
+                    self.anim_num = 9;
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 50 {
+                    self.action_num = 3;
+                    self.action_counter = 0;
+                    self.anim_num = 0;
+                }
+            }
+            3 => {
+                self.action_counter += 1;
+                if self.action_counter > 50 {
+                    self.action_num = 10;
+                    self.npc_flags.set_shootable(true);
+                }
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                    self.action_counter = self.rng.range(20..130) as u16;
+                    self.vel_x = 0;
+                }
+
+                self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+
+                self.animate(4, 0, 1);
+
+                // jumps over missiles and fireballs
+                if bullet_manager.count_bullets_type_idx_all(6) > 0 || bullet_manager.count_bullets_type_idx_all(3) > 0 {
+                    self.action_num = 20;
+                }
+
+                if self.action_counter > 0 {
+                    self.action_counter -= 1;
+                } else {
+                    self.action_num = if self.rng.range(0..99) % 2 != 0 { 20 } else { 50 };
+                }
+            }
+            20 | 21 => {
+                if self.action_num == 20 {
+                    self.action_num = 21;
+                    self.anim_num = 2;
+                    self.action_counter = 0;
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 10 {
+                    self.action_num = 22;
+                    self.action_counter = 0;
+                    self.anim_num = 3;
+                    self.vel_y = -0x5ff;
+                    self.vel_x = self.direction.vector_x() * 0x200;
+                }
+            }
+            22 => {
+                self.action_counter += 1;
+                if self.action_counter > 10 {
+                    self.action_num = 23;
+                    self.action_counter = 0;
+                    self.anim_num = 6;
+
+                    self.tick_n140_toroko_frenzied_spawn_block(state, npc_list);
+                }
+            }
+            23 => {
+                self.action_counter += 1;
+                if self.action_counter > 30 {
+                    self.action_num = 24;
+                    self.action_counter = 0;
+                    self.anim_num = 7;
+                }
+
+                self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+            }
+            24 => {
+                self.action_counter += 1;
+                if self.action_counter > 3 {
+                    self.action_num = 25;
+                    self.anim_num = 3;
+                }
+            }
+            25 => {
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 26;
+                    self.action_counter = 0;
+                    self.anim_num = 2;
+
+                    state.sound_manager.play_sfx(26);
+                    state.quake_counter = 20;
+                }
+            }
+            26 => {
+                self.vel_x = 8 * self.vel_x / 9;
+
+                self.action_counter += 1;
+                if self.action_counter > 20 {
+                    self.action_num = 10;
+                    self.anim_num = 0;
+                }
+            }
+            50 | 51 => {
+                if self.action_num == 50 {
+                    self.action_num = 51;
+                    self.action_counter = 0;
+                    self.anim_num = 4;
+
+                    self.tick_n140_toroko_frenzied_spawn_block(state, npc_list);
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 30 {
+                    self.action_num = 52;
+                    self.action_counter = 0;
+                    self.anim_num = 5;
+                }
+
+                self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+            }
+            52 => {
+                self.action_counter += 1;
+                if self.action_counter > 3 {
+                    self.action_num = 10;
+                    self.anim_num = 0;
+                }
+            }
+            100 => {
+                self.anim_num = 3;
+                self.action_num = 101;
+                self.npc_flags.set_shootable(false);
+                self.damage = 0;
+
+                npc_list.create_death_smoke(self.x, self.y, 12 * 0x200, 8, state, &self.rng);
+            }
+            101 => {
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 102;
+                    self.action_counter = 0;
+                    self.anim_num = 10;
+                    self.target_x = self.x;
+
+                    state.sound_manager.play_sfx(23);
+                }
+            }
+            102 => {
+                self.x = self.target_x;
+
+                self.action_counter += 1;
+                if self.action_counter / 2 % 2 != 0 {
+                    self.x += 0x200;
+                }
+
+                if self.action_counter > 50 {
+                    self.action_num = 103;
+                    self.action_counter = 0;
+                    self.x = self.target_x;
+                }
+            }
+            103 => {
+                self.anim_num = 10;
+            }
+            140 | 141 => {
+                if self.action_num == 140 {
+                    self.action_num = 141;
+                    self.action_counter = 0;
+                    self.anim_num = 12;
+
+                    state.sound_manager.play_sfx(29);
+                }
+
+                self.anim_num += 1;
+                if self.anim_num > 13 {
+                    self.anim_num = 12;
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 100 {
+                    npc_list.create_death_smoke(self.x, self.y, 12 * 0x200, 4, state, &self.rng);
+
+                    self.cond.set_alive(false);
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+
+        if self.action_num > 100 && self.action_num < 104 && self.action_counter % 9 == 0 {
+            let mut npc = NPC::create(4, &state.npc_table);
+            npc.cond.set_alive(true);
+            npc.x = self.x + self.rng.range(-12..12) * 0x200;
+            npc.y = self.y + self.rng.range(-12..12) * 0x200;
+            npc.vel_x = self.rng.range(-341..341);
+            npc.vel_y = self.rng.range(-0x600..0);
+
+            let _ = npc_list.spawn(0x100, npc);
+        }
+
+        self.vel_y += 0x20;
+        self.vel_y = clamp(self.vel_y, -0x5ff, 0x5ff);
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 14 };
+        self.anim_rect = state.constants.npc.n140_toroko_frenzied[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    fn tick_n140_toroko_frenzied_spawn_block(&mut self, state: &mut SharedGameState, npc_list: &NPCList) {
+        let mut npc = NPC::create(141, &state.npc_table);
+        npc.cond.set_alive(true);
+        npc.x = self.x;
+        npc.y = self.y;
+        npc.parent_id = self.id;
+
+        let _ = npc_list.spawn(0x100, npc);
+    }
+
+    pub(crate) fn tick_n141_toroko_block_projectile(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.action_counter = 0;
+                }
+
+                let parent = match self.get_parent_ref_mut(npc_list) {
+                    Some(parent) => parent,
+                    None => {
+                        self.cond.set_alive(false);
+                        return Ok(());
+                    }
+                };
+
+                // held above toroko's head until she throws it
+                self.x = parent.x - parent.direction.vector_x() * 10 * 0x200;
+                self.y = parent.y - 8 * 0x200;
+
+                if parent.action_num == 24 || parent.action_num == 52 {
+                    self.action_num = 10;
+                    self.x = parent.x + parent.direction.vector_x() * 16 * 0x200;
+                    self.y = parent.y;
+
+                    let player = self.get_closest_player_mut(players);
+                    let angle = f64::atan2((self.y - player.y) as f64, (self.x - player.x) as f64);
+
+                    self.vel_x = (angle.cos() * -2048.0) as i32;
+                    self.vel_y = (angle.sin() * -2048.0) as i32;
+
+                    state.sound_manager.play_sfx(39);
+                }
+            }
+            10 => {
+                if (self.flags.0 & 0x0f) != 0 {
+                    self.action_num = 20;
+                    self.action_counter = 0;
+
+                    state.create_caret(self.x, self.y, CaretType::ProjectileDissipation, Direction::Left);
+                    state.sound_manager.play_sfx(12);
+
+                    self.tick_n141_toroko_block_projectile_spawn_smoke(state, npc_list);
+                } else {
+                    self.x += self.vel_x;
+                    self.y += self.vel_y;
+                }
+            }
+            20 => {
+                self.x += self.vel_x;
+                self.y += self.vel_y;
+
+                self.action_counter += 1;
+                if self.action_counter > 4 {
+                    self.tick_n141_toroko_block_projectile_spawn_smoke(state, npc_list);
+
+                    // the block breaks open, revealing a flower cub
+                    self.npc_type = 142;
+                    self.anim_num = 0;
+                    self.action_num = 10;
+                    self.vel_x = 0;
+                    self.npc_flags.set_invulnerable(false);
+                    self.npc_flags.set_shootable(true);
+                    self.damage = 1;
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+
+        self.anim_num += 1;
+        if self.anim_num > 1 {
+            self.anim_num = 0;
+        }
+
+        self.anim_rect = state.constants.npc.n141_toroko_block_projectile[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    fn tick_n141_toroko_block_projectile_spawn_smoke(&mut self, state: &mut SharedGameState, npc_list: &NPCList) {
+        let mut npc = NPC::create(4, &state.npc_table);
+        npc.cond.set_alive(true);
+        npc.x = self.x;
+        npc.y = self.y;
+
+        for _ in 0..4 {
+            npc.vel_x = self.rng.range(-0x200..0x200);
+            npc.vel_y = self.rng.range(-0x200..0x200);
+
+            let _ = npc_list.spawn(0x100, npc.clone());
+        }
+    }
+
+    pub(crate) fn tick_n144_toroko_teleporting_in(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                    self.target_x = self.x;
+
+                    state.sound_manager.play_sfx(29);
+                }
+
+                self.action_counter += 1;
+                if self.action_counter == 64 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                }
+            }
+            2 => {
+                self.animate(2, 2, 3);
+
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 4;
+                    self.action_counter = 0;
+                    self.anim_num = 4;
+
+                    state.sound_manager.play_sfx(23);
+                }
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.anim_num = 0;
+                    self.anim_counter = 0;
+                }
+
+                if self.rng.range(0..120) == 10 {
+                    self.action_num = 12;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+            }
+            12 => {
+                self.action_counter += 1;
+                if self.action_counter > 8 {
+                    self.action_num = 11;
+                    self.anim_num = 0;
+                }
+            }
+            _ => {}
+        }
+
+        if self.action_num > 1 {
+            self.vel_y += 0x20;
+            if self.vel_y > 0x5ff {
+                self.vel_y = 0x5ff;
+            }
+
+            self.y += self.vel_y;
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 5 };
+        self.anim_rect = state.constants.npc.n144_toroko_teleporting_in[self.anim_num as usize + dir_offset];
+
+        if self.action_num == 1 {
+            self.anim_rect.bottom = self.anim_rect.top + self.action_counter / 4;
+
+            self.x = self.target_x;
+            if self.action_counter / 2 % 2 != 0 {
+                self.x += 0x200;
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -1,9 +1,53 @@
 use ggez::GameResult;
 
+use crate::common::Direction;
 use crate::npc::NPC;
 use crate::shared_game_state::SharedGameState;
 
 impl NPC {
+    pub(crate) fn tick_n127_machine_gun_trail_l2(&mut self, state: &mut SharedGameState) -> GameResult {
+        self.anim_counter += 1;
+        if self.anim_counter > 0 {
+            self.anim_counter = 0;
+
+            self.anim_num += 1;
+            if self.anim_num > 2 {
+                self.cond.set_alive(false);
+                return Ok(());
+            }
+        }
+
+        let dir_offset = if self.direction == Direction::Up { 0 } else { 3 };
+
+        self.anim_rect = state.constants.npc.n127_machine_gun_trail_l2[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n128_machine_gun_trail_l3(&mut self, state: &mut SharedGameState) -> GameResult {
+        if self.action_num == 0 {
+            self.action_num = 1;
+
+            if self.direction == Direction::Left || self.direction == Direction::Right {
+                self.display_bounds.left = 4 * 0x200;
+                self.display_bounds.top = 8 * 0x200;
+            } else {
+                self.display_bounds.left = 8 * 0x200;
+                self.display_bounds.top = 4 * 0x200;
+            }
+        }
+
+        self.anim_num += 1;
+        if self.anim_num > 4 {
+            self.cond.set_alive(false);
+            return Ok(());
+        }
+
+        self.anim_rect = state.constants.npc.n128_machine_gun_trail_l3[self.anim_num as usize + self.direction as usize * 5];
+
+        Ok(())
+    }
+
     pub(crate) fn tick_n129_fireball_snake_trail(&mut self, state: &mut SharedGameState) -> GameResult {
         self.anim_counter += 1;
 
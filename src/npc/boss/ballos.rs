@@ -1,7 +1,1200 @@
+use ggez::GameResult;
+
+use crate::caret::CaretType;
+use crate::common::{CDEG_RAD, Direction, Rect};
 use crate::npc::boss::BossNPC;
+use crate::npc::list::NPCList;
+use crate::npc::NPC;
+use crate::player::Player;
+use crate::rng::RNG;
+use crate::screen_effect::ScreenEffectType;
+use crate::shared_game_state::SharedGameState;
+use crate::stage::Stage;
+
+impl NPC {
+    pub(crate) fn tick_n332_ballos_shockwave(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.vel_x = if self.direction == Direction::Left { -0x400 } else { 0x400 };
+
+                    state.sound_manager.play_sfx(44);
+                }
+
+                self.animate(0, 0, 2);
+
+                self.action_counter += 1;
+                if self.action_counter % 6 == 1 {
+                    let off_x = if self.direction == Direction::Left {
+                        self.rng.range(-16..4)
+                    } else {
+                        self.rng.range(-4..16)
+                    };
+
+                    let mut npc = NPC::create(4, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x + off_x * 0x200;
+                    npc.y = self.y + self.rng.range(-32..8) * 0x200;
+
+                    let _ = npc_list.spawn(0x100, npc);
+                }
+
+                if self.flags.hit_left_wall() || self.flags.hit_right_wall() {
+                    state.create_caret(self.x, self.y, CaretType::ProjectileDissipation, Direction::Left);
+                    self.vanish(state);
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+
+        self.x += self.vel_x;
+
+        self.anim_rect = state.constants.npc.n332_ballos_shockwave[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n333_ballos_lighting(&mut self, state: &mut SharedGameState) -> GameResult {
+        if self.action_num == 0 {
+            self.action_num = 1;
+            state.sound_manager.play_sfx(103);
+        }
+
+        self.action_counter += 1;
+        self.anim_num = if self.action_counter / 2 % 2 != 0 { 0 } else { 1 };
+
+        if self.action_counter > 40 {
+            self.cond.set_alive(false);
+        }
+
+        self.anim_rect = state.constants.npc.n333_ballos_lighting[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n342_ballos_1_eye(&mut self, state: &mut SharedGameState) -> GameResult {
+        // centre of the orbit is kept up to date by the boss
+        match self.action_num {
+            0 | 10 => {
+                if self.action_num == 0 {
+                    self.action_num = 10;
+                    self.vel_y2 = 0;
+                }
+
+                self.anim_num = 2;
+
+                self.vel_y2 += 0x200;
+                if self.vel_y2 >= 112 * 0x200 {
+                    self.vel_y2 = 112 * 0x200;
+                    self.action_num = 20;
+                }
+            }
+            20 => {
+                self.anim_num = if self.shock > 0 { 1 } else { 0 };
+            }
+            _ => {}
+        }
+
+        self.action_counter2 = (self.action_counter2 as i32 + self.vel_x2).rem_euclid(0x400) as u16;
+
+        let angle = (self.action_counter2 / 4) as f64 * CDEG_RAD;
+        self.x = self.target_x + (angle.cos() * self.vel_y2 as f64) as i32;
+        self.y = self.target_y + (angle.sin() * self.vel_y2 as f64) as i32;
+
+        self.anim_rect = state.constants.npc.n342_ballos_1_eye[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n346_ballos_orbiting_platform(&mut self, state: &mut SharedGameState) -> GameResult {
+        // rotation speed and centre are driven by the boss, velocity is kept so players riding it move along
+        if self.action_num == 0 {
+            self.action_num = 1;
+        }
+
+        self.action_counter2 = (self.action_counter2 as i32 + self.vel_x2).rem_euclid(0x400) as u16;
+
+        let angle = (self.action_counter2 / 4) as f64 * CDEG_RAD;
+        let x = self.target_x + (angle.cos() * (112 * 0x200) as f64) as i32;
+        let y = self.target_y + (angle.sin() * (112 * 0x200) as f64) as i32;
+
+        self.vel_x = x - self.x;
+        self.vel_y = y - self.y;
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.anim_rect = state.constants.npc.n346_ballos_orbiting_platform;
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n348_ballos_4_spikes(&mut self, state: &mut SharedGameState) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.damage = 0;
+                }
+
+                self.action_counter += 1;
+                if self.action_counter < 128 {
+                    self.y -= 0x80;
+                } else {
+                    self.action_num = 10;
+                    self.anim_num = 1;
+                    self.damage = 2;
+                }
+            }
+            _ => {}
+        }
+
+        self.anim_rect = state.constants.npc.n348_ballos_4_spikes[self.anim_num as usize];
+
+        Ok(())
+    }
+    pub(crate) fn tick_n331_ballos_bone_projectile(&mut self, state: &mut SharedGameState) -> GameResult {
+        if self.action_num == 0 {
+            self.action_num = 1;
+        }
+
+        if (self.flags.0 & 0xff) != 0 {
+            state.create_caret(self.x, self.y, CaretType::ProjectileDissipation, Direction::Left);
+            self.cond.set_alive(false);
+            return Ok(());
+        }
+
+        self.vel_y += 0x40;
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        // spins in the direction it was thrown
+        self.anim_counter += 1;
+        if self.anim_counter > 3 {
+            self.anim_counter = 0;
+            self.anim_num = if self.vel_x > 0 { (self.anim_num + 1) % 4 } else { (self.anim_num + 3) % 4 };
+        }
+
+        self.anim_rect = state.constants.npc.n331_ballos_bone_projectile[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n340_ballos(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) -> GameResult {
+        let player = self.get_closest_player_mut(players);
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                    self.y -= 6 * 0x200;
+                }
+
+                if self.rng.range(0..100) == 1 {
+                    self.action_num = 2;
+                    self.action_counter = 0;
+                    self.anim_num = 1;
+                }
+            }
+            2 => {
+                self.action_counter += 1;
+                if self.action_counter > 8 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.action_counter = 0;
+                    self.anim_num = 2;
+                    self.anim_counter = 0;
+                    self.npc_flags.set_shootable(true);
+                    self.npc_flags.set_invulnerable(false);
+                }
+
+                self.direction = if self.x > player.x { Direction::Left } else { Direction::Right };
+                self.vel_x = self.direction.vector_x() * 0x200;
+
+                self.animate(5, 2, 3);
+
+                self.action_counter += 1;
+                if self.action_counter > 50 {
+                    self.action_counter2 += 1;
+
+                    self.action_num = match self.action_counter2 % 4 {
+                        0 => 100,
+                        1 => 200,
+                        2 => 300,
+                        _ => 400,
+                    };
+                }
+            }
+            100 | 101 => {
+                if self.action_num == 100 {
+                    self.action_num = 101;
+                    self.action_counter = 0;
+                    self.anim_num = 4;
+                    self.vel_x = 0;
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 20 {
+                    self.action_num = 102;
+                    self.action_counter = 0;
+                    self.anim_num = 6;
+                    self.damage = 10;
+                    self.vel_x = self.direction.vector_x() * 0x800;
+
+                    state.sound_manager.play_sfx(25);
+                }
+            }
+            102 => {
+                self.animate(1, 6, 7);
+
+                // rams into the wall, sending a shockwave back across the room
+                if (self.direction == Direction::Left && self.flags.hit_left_wall())
+                    || (self.direction == Direction::Right && self.flags.hit_right_wall()) {
+                    self.action_num = 110;
+                    self.action_counter = 0;
+                    self.anim_num = 4;
+                    self.damage = 3;
+                    self.vel_x = 0;
+
+                    let mut npc = NPC::create(332, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y + 12 * 0x200;
+                    npc.direction = self.direction.opposite();
+
+                    let _ = npc_list.spawn(0x100, npc);
+
+                    state.quake_counter = 10;
+                    state.sound_manager.play_sfx(26);
+                }
+            }
+            200 | 201 => {
+                if self.action_num == 200 {
+                    self.action_num = 201;
+                    self.action_counter = 0;
+                    self.anim_num = 4;
+                    self.vel_x = 0;
+                }
+
+                self.action_counter += 1;
+                if self.action_counter > 20 {
+                    self.action_num = 202;
+                    self.anim_num = 5;
+                    self.vel_y = -0x800;
+                    self.vel_x = if self.x > player.x { -0x200 } else { 0x200 };
+
+                    state.sound_manager.play_sfx(30);
+                }
+            }
+            202 => {
+                if self.vel_y > 0 {
+                    self.anim_num = 8;
+                    self.damage = 10;
+                }
+
+                if self.flags.hit_bottom_wall() && self.vel_y >= 0 {
+                    self.action_num = 110;
+                    self.action_counter = 0;
+                    self.anim_num = 4;
+                    self.damage = 3;
+                    self.vel_x = 0;
+
+                    let mut npc = NPC::create(332, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y + 12 * 0x200;
+
+                    npc.direction = Direction::Left;
+                    let _ = npc_list.spawn(0x100, npc.clone());
+
+                    npc.direction = Direction::Right;
+                    let _ = npc_list.spawn(0x100, npc);
+
+                    state.quake_counter = 30;
+                    state.sound_manager.play_sfx(26);
+                }
+            }
+            300 | 301 => {
+                if self.action_num == 300 {
+                    self.action_num = 301;
+                    self.action_counter = 0;
+                    self.anim_num = 9;
+                    self.vel_x = 0;
+                }
+
+                self.action_counter += 1;
+                if self.action_counter % 8 == 0 && self.action_counter <= 40 {
+                    let mut npc = NPC::create(331, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y - 16 * 0x200;
+                    npc.vel_x = self.rng.range(-0x400..0x400);
+                    npc.vel_y = -0x400;
+
+                    let _ = npc_list.spawn(0x100, npc);
+
+                    state.sound_manager.play_sfx(39);
+                }
+
+                if self.action_counter > 60 {
+                    self.action_num = 110;
+                    self.action_counter = 0;
+                }
+            }
+            400 | 401 => {
+                if self.action_num == 400 {
+                    self.action_num = 401;
+                    self.action_counter = 0;
+                    self.anim_num = 9;
+                    self.vel_x = 0;
+                }
+
+                self.action_counter += 1;
+                if self.action_counter == 20 {
+                    for _ in 0..5 {
+                        let mut npc = NPC::create(345, &state.npc_table);
+                        npc.cond.set_alive(true);
+                        npc.x = player.x + self.rng.range(-120..120) * 0x200;
+                        npc.y = self.y - 160 * 0x200;
+
+                        let _ = npc_list.spawn(0x100, npc);
+                    }
+
+                    state.sound_manager.play_sfx(39);
+                }
+
+                if self.action_counter > 50 {
+                    self.action_num = 110;
+                    self.action_counter = 0;
+                }
+            }
+            110 => {
+                self.vel_x = 0;
+
+                self.action_counter += 1;
+                if self.action_counter > 20 {
+                    self.action_num = 10;
+                    self.damage = 3;
+                }
+            }
+            1000 | 1001 => {
+                if self.action_num == 1000 {
+                    self.action_num = 1001;
+                    self.action_counter = 0;
+                    self.anim_num = 10;
+                    self.target_x = self.x;
+                    self.vel_x = 0;
+                    self.damage = 0;
+                    self.npc_flags.set_shootable(false);
+
+                    for npc in npc_list.iter_alive() {
+                        if npc.npc_type == 331 || npc.npc_type == 332 || npc.npc_type == 345 {
+                            npc.cond.set_explode_die(true);
+                        }
+                    }
+                }
+
+                self.action_counter += 1;
+                self.x = self.target_x + if self.action_counter / 2 % 2 != 0 { 0x200 } else { 0 };
+
+                if self.action_counter % 8 == 1 {
+                    npc_list.create_death_smoke(self.x, self.y, 24 * 0x200, 1, state, &self.rng);
+                }
+
+                if self.action_counter > 150 {
+                    self.action_num = 1002;
+                    self.x = self.target_x;
+
+                    let mut npc = NPC::create(343, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y;
+                    npc.parent_id = self.id;
+
+                    let _ = npc_list.spawn(0x100, npc);
+
+                    let mut npc = NPC::create(344, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y;
+                    npc.parent_id = self.id;
+
+                    npc.direction = Direction::Left;
+                    let _ = npc_list.spawn(0x100, npc.clone());
+
+                    npc.direction = Direction::Right;
+                    let _ = npc_list.spawn(0x100, npc);
+                }
+            }
+            _ => {}
+        }
+
+        self.vel_y += 0x40;
+        if self.vel_y > 0x5ff {
+            self.vel_y = 0x5ff;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 11 };
+        self.anim_rect = state.constants.npc.n340_ballos[self.anim_num as usize + dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n341_ballos_1_head(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        if let Some(parent) = self.get_parent_ref_mut(npc_list) {
+            self.x = parent.x;
+            self.y = parent.y - 18 * 0x200;
+        }
+
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.anim_num = 0;
+                }
+            }
+            10 | 11 => {
+                if self.action_num == 10 {
+                    self.action_num = 11;
+                    self.anim_counter = 0;
+                }
+
+                self.anim_counter += 1;
+                if self.anim_counter > 30 && self.anim_num < 2 {
+                    self.anim_counter = 0;
+                    self.anim_num += 1;
+                }
+            }
+            _ => {}
+        }
+
+        self.anim_rect = state.constants.npc.n341_ballos_1_head[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n343_ballos_2_cutscene(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        self.action_counter += 1;
+        if self.action_counter > 100 {
+            self.cond.set_alive(false);
+            return Ok(());
+        }
+
+        if let Some(parent) = self.get_parent_ref_mut(npc_list) {
+            self.x = parent.x;
+            self.y = parent.y;
+        }
+
+        self.anim_rect = state.constants.npc.n343_ballos_2_cutscene;
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n344_ballos_2_eyes(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        self.action_counter += 1;
+        if self.action_counter > 100 {
+            self.cond.set_alive(false);
+            return Ok(());
+        }
+
+        if let Some(parent) = self.get_parent_ref_mut(npc_list) {
+            self.x = parent.x + if self.direction == Direction::Left { -24 * 0x200 } else { 24 * 0x200 };
+            self.y = parent.y - 36 * 0x200;
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 1 };
+        self.anim_rect = state.constants.npc.n344_ballos_2_eyes[dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n345_ballos_skull_projectile(&mut self, state: &mut SharedGameState, npc_list: &NPCList, stage: &Stage) -> GameResult {
+        match self.action_num {
+            0 | 100 => {
+                if self.action_num == 0 {
+                    self.action_num = 100;
+                    self.anim_num = (self.rng.range(0..16) % 4) as u16;
+                }
+
+                self.vel_y += 0x40;
+                if self.vel_y > 0x700 {
+                    self.vel_y = 0x700;
+                }
+
+                if self.y > 128 * 0x200 {
+                    self.npc_flags.set_ignore_solidity(false);
+                }
+
+                self.action_counter += 1;
+                if self.action_counter % 4 == 1 {
+                    let mut npc = NPC::create(4, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y;
+
+                    let _ = npc_list.spawn(0x100, npc);
+                }
+
+                // bounces once off the floor, then falls through it
+                if self.flags.hit_bottom_wall() {
+                    self.action_num = 110;
+                    self.vel_y = -0x200;
+                    self.npc_flags.set_ignore_solidity(true);
+
+                    state.quake_counter = 10;
+                    state.sound_manager.play_sfx(12);
+
+                    npc_list.create_death_smoke(self.x, self.y, 0, 4, state, &self.rng);
+                }
+            }
+            110 => {
+                self.vel_y += 0x40;
+
+                if self.y > (stage.map.height as i32 + 2) * 16 * 0x200 {
+                    self.cond.set_alive(false);
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+
+        self.anim_counter += 1;
+        if self.anim_counter > 8 {
+            self.anim_counter = 0;
+            self.anim_num = (self.anim_num + 1) % 4;
+        }
+
+        self.y += self.vel_y;
+
+        self.anim_rect = state.constants.npc.n345_ballos_skull_projectile[self.anim_num as usize];
+
+        Ok(())
+    }
+
+}
 
 impl BossNPC {
-    pub(crate) fn tick_b09_ballos(&mut self) {
+    pub(crate) fn tick_b09_ballos(&mut self, state: &mut SharedGameState, mut players: [&mut Player; 2], npc_list: &NPCList, stage: &mut Stage) {
+        let floor_y = 304 * 0x200 - self.parts[0].hit_bounds.bottom as i32;
+
+        match self.parts[0].action_num {
+            0 => {
+                self.parts[0].action_num = 1;
+                self.parts[0].cond.set_alive(true);
+                self.parts[0].exp = 1;
+                self.parts[0].direction = Direction::Left;
+                self.parts[0].x = 320 * 0x200;
+                self.parts[0].y = -64 * 0x200;
+                self.parts[0].npc_flags.0 = 0;
+                self.parts[0].npc_flags.set_ignore_solidity(true);
+                self.parts[0].npc_flags.set_event_when_killed(true);
+                self.parts[0].npc_flags.set_show_damage(true);
+                self.parts[0].size = 3;
+                self.parts[0].damage = 0;
+                self.parts[0].event_num = 1000;
+                self.parts[0].life = 800;
+                self.parts[0].display_bounds = Rect {
+                    left: 60 * 0x200,
+                    top: 60 * 0x200,
+                    right: 60 * 0x200,
+                    bottom: 60 * 0x200,
+                };
+                self.parts[0].hit_bounds = Rect {
+                    left: 32 * 0x200,
+                    top: 48 * 0x200,
+                    right: 32 * 0x200,
+                    bottom: 48 * 0x200,
+                };
+                self.hurt_sound[0] = 54;
+
+                self.parts[1].cond.set_alive(true);
+                self.parts[1].cond.set_damage_boss(true);
+                self.parts[1].action_num = 0;
+                self.parts[1].anim_num = 0;
+                self.parts[1].direction = Direction::Left;
+                self.parts[1].npc_flags.0 = 0;
+                self.parts[1].npc_flags.set_ignore_solidity(true);
+                self.parts[1].life = 10000;
+                self.parts[1].display_bounds = Rect {
+                    left: 12 * 0x200,
+                    top: 8 * 0x200,
+                    right: 12 * 0x200,
+                    bottom: 8 * 0x200,
+                };
+                self.parts[1].hit_bounds = Rect {
+                    left: 12 * 0x200,
+                    top: 8 * 0x200,
+                    right: 12 * 0x200,
+                    bottom: 8 * 0x200,
+                };
+                self.hurt_sound[1] = 54;
+
+                self.parts[2] = self.parts[1].clone();
+                self.parts[2].direction = Direction::Right;
+                self.hurt_sound[2] = 54;
+
+                self.parts[3].cond.set_alive(true);
+                self.parts[3].npc_flags.0 = 0;
+                self.parts[3].npc_flags.set_ignore_solidity(true);
+                self.parts[3].npc_flags.set_solid_soft(true);
+                self.parts[3].npc_flags.set_invulnerable(true);
+                self.parts[3].display_bounds = Rect {
+                    left: 60 * 0x200,
+                    top: 60 * 0x200,
+                    right: 60 * 0x200,
+                    bottom: 60 * 0x200,
+                };
+                self.parts[3].hit_bounds = Rect {
+                    left: 48 * 0x200,
+                    top: 24 * 0x200,
+                    right: 48 * 0x200,
+                    bottom: 32 * 0x200,
+                };
+                self.hurt_sound[3] = 54;
+
+                self.parts[4].cond.set_alive(true);
+                self.parts[4].npc_flags.0 = 0;
+                self.parts[4].npc_flags.set_ignore_solidity(true);
+                self.parts[4].npc_flags.set_solid_hard(true);
+                self.parts[4].npc_flags.set_invulnerable(true);
+                self.parts[4].hit_bounds = Rect {
+                    left: 32 * 0x200,
+                    top: 8 * 0x200,
+                    right: 32 * 0x200,
+                    bottom: 8 * 0x200,
+                };
+                self.hurt_sound[4] = 54;
+
+                self.parts[5].cond.set_alive(true);
+                self.parts[5].npc_flags.0 = 0;
+                self.parts[5].npc_flags.set_ignore_solidity(true);
+                self.parts[5].npc_flags.set_solid_hard(true);
+                self.parts[5].npc_flags.set_invulnerable(true);
+                self.parts[5].hit_bounds = Rect {
+                    left: 32 * 0x200,
+                    top: 0,
+                    right: 32 * 0x200,
+                    bottom: 48 * 0x200,
+                };
+                self.hurt_sound[5] = 54;
+
+                for npc in self.parts.iter_mut() {
+                    npc.init_rng();
+                }
+            }
+            100 | 101 => {
+                if self.parts[0].action_num == 100 {
+                    self.parts[0].action_num = 101;
+                    self.parts[0].action_counter = 0;
+                    self.parts[0].anim_num = 0;
+
+                    let idx = self.parts[0].get_closest_player_idx_mut(&players);
+                    self.parts[0].x = players[idx].x;
+
+                    let mut npc = NPC::create(333, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.parts[0].x;
+                    npc.y = 304 * 0x200;
+                    npc.direction = Direction::Right;
+
+                    let _ = npc_list.spawn(0x100, npc);
+                }
+
+                self.parts[0].action_counter += 1;
+                if self.parts[0].action_counter > 30 {
+                    self.parts[0].action_num = 102;
+                }
+            }
+            102 => {
+                self.parts[0].vel_y += 0x40;
+                if self.parts[0].vel_y > 0xc00 {
+                    self.parts[0].vel_y = 0xc00;
+                }
+
+                self.parts[0].y += self.parts[0].vel_y;
+
+                if self.parts[0].y > floor_y {
+                    self.parts[0].y = floor_y;
+                    self.parts[0].vel_y = 0;
+                    self.parts[0].action_num = 103;
+                    self.parts[0].action_counter = 0;
+
+                    self.tick_b09_ballos_land(state, &mut players, npc_list);
+                }
+            }
+            103 => {
+                self.parts[0].action_counter += 1;
+                if self.parts[0].action_counter > 50 {
+                    self.parts[0].action_num = 104;
+                    self.parts[1].action_num = 100;
+                    self.parts[2].action_num = 100;
+                }
+            }
+            200 => {
+                self.parts[0].action_num = 202;
+                self.parts[0].action_counter = 0;
+                self.parts[0].action_counter2 = 0;
+                self.parts[0].vel_x = 0;
+            }
+            202 => {
+                self.parts[0].action_counter += 1;
+
+                let wait = if self.parts[0].action_counter2 % 3 == 2 { 150 } else { 50 };
+                if self.parts[0].action_counter > wait {
+                    self.parts[0].action_num = 203;
+                    self.parts[0].action_counter2 += 1;
+                    self.parts[0].vel_y = -0xc00;
+
+                    let idx = self.parts[0].get_closest_player_idx_mut(&players);
+                    self.parts[0].vel_x = if self.parts[0].x > players[idx].x { -0x200 } else { 0x200 };
+                }
+            }
+            203 => {
+                self.parts[0].vel_y += 0x55;
+                if self.parts[0].vel_y > 0xc00 {
+                    self.parts[0].vel_y = 0xc00;
+                }
+
+                if (self.parts[0].x < 80 * 0x200 && self.parts[0].vel_x < 0)
+                    || (self.parts[0].x > 544 * 0x200 && self.parts[0].vel_x > 0) {
+                    self.parts[0].vel_x = 0;
+                }
+
+                self.parts[0].x += self.parts[0].vel_x;
+                self.parts[0].y += self.parts[0].vel_y;
+
+                if self.parts[0].vel_y > 0 && self.parts[0].y > floor_y {
+                    self.parts[0].y = floor_y;
+                    self.parts[0].vel_x = 0;
+                    self.parts[0].vel_y = 0;
+                    self.parts[0].action_num = 202;
+                    self.parts[0].action_counter = 0;
+
+                    self.tick_b09_ballos_land(state, &mut players, npc_list);
+
+                    for direction in [Direction::Left, Direction::Right].iter() {
+                        let mut npc = NPC::create(332, &state.npc_table);
+                        npc.cond.set_alive(true);
+                        npc.x = self.parts[0].x + if *direction == Direction::Left { -12 * 0x200 } else { 12 * 0x200 };
+                        npc.y = self.parts[0].y + 52 * 0x200;
+                        npc.direction = *direction;
+
+                        let _ = npc_list.spawn(0x100, npc);
+                    }
+                }
+            }
+            220 | 221 => {
+                if self.parts[0].action_num == 220 {
+                    self.parts[0].action_num = 221;
+                    self.parts[0].life = 1200;
+                    self.parts[0].vel_x = 0;
+                    self.parts[1].action_num = 200;
+                    self.parts[2].action_num = 200;
+                }
+
+                self.parts[0].vel_y += 0x40;
+                if self.parts[0].vel_y > 0xc00 {
+                    self.parts[0].vel_y = 0xc00;
+                }
+
+                self.parts[0].y += self.parts[0].vel_y;
+
+                if self.parts[0].y > floor_y {
+                    self.parts[0].y = floor_y;
+                    self.parts[0].vel_y = 0;
+                    self.parts[0].action_num = 222;
+                }
+            }
+            300 | 301 => {
+                if self.parts[0].action_num == 300 {
+                    self.parts[0].action_num = 301;
+                    self.parts[0].action_counter = 0;
+
+                    for i in 0..8 {
+                        let mut npc = NPC::create(342, &state.npc_table);
+                        npc.cond.set_alive(true);
+                        npc.x = self.parts[0].x;
+                        npc.y = self.parts[0].y;
+                        npc.target_x = self.parts[0].x;
+                        npc.target_y = self.parts[0].y;
+                        npc.life = 1000;
+                        npc.action_counter2 = i * 0x80;
+                        npc.vel_x2 = 2;
+
+                        let _ = npc_list.spawn(0x100, npc);
+                    }
+
+                    state.quake_counter = 30;
+                    state.sound_manager.play_sfx(26);
+                }
+
+                self.parts[0].y += (225 * 0x200 - self.parts[0].y) / 8;
+
+                self.parts[0].action_counter += 1;
+                if self.parts[0].action_counter > 50 {
+                    self.parts[0].action_num = 311;
+                    self.parts[0].damage = 10;
+                }
+            }
+            311 => {
+                self.parts[0].x -= 0x3aa;
+                if self.parts[0].x < 111 * 0x200 {
+                    self.parts[0].x = 111 * 0x200;
+                    self.parts[0].action_num = 312;
+                }
+            }
+            312 => {
+                self.parts[0].y -= 0x3aa;
+                if self.parts[0].y < 111 * 0x200 {
+                    self.parts[0].y = 111 * 0x200;
+                    self.parts[0].action_num = 313;
+                }
+            }
+            313 => {
+                self.parts[0].x += 0x3aa;
+                if self.parts[0].x > 529 * 0x200 {
+                    self.parts[0].x = 529 * 0x200;
+                    self.parts[0].action_num = 314;
+                }
+
+                if self.parts[0].life < 900 && self.parts[0].x > 304 * 0x200 && self.parts[0].x < 336 * 0x200 {
+                    self.parts[0].action_num = 400;
+                }
+            }
+            314 => {
+                self.parts[0].y += 0x3aa;
+                if self.parts[0].y > 225 * 0x200 {
+                    self.parts[0].y = 225 * 0x200;
+                    self.parts[0].action_num = 311;
+                }
+            }
+            400 | 401 => {
+                if self.parts[0].action_num == 400 {
+                    self.parts[0].action_num = 401;
+                    self.parts[0].action_counter = 0;
+                    self.parts[0].damage = 0;
+
+                    for npc in npc_list.iter_alive() {
+                        if npc.npc_type == 342 {
+                            npc_list.create_death_smoke(npc.x, npc.y, npc.display_bounds.right, 4, state, &npc.rng);
+                            npc.cond.set_alive(false);
+                        }
+                    }
+
+                    state.quake_counter = 30;
+                    state.sound_manager.play_sfx(26);
+                }
+
+                self.parts[0].x += (320 * 0x200 - self.parts[0].x) / 8;
+                self.parts[0].y += (159 * 0x200 - self.parts[0].y) / 8;
+
+                self.parts[0].action_counter += 1;
+                if self.parts[0].action_counter > 50 {
+                    self.parts[0].action_num = 410;
+                    self.parts[0].action_counter = 0;
+                    self.parts[0].vel_x2 = 0;
+
+                    for i in 0..8 {
+                        let angle = (i * 0x20) as f64 * CDEG_RAD;
+
+                        let mut npc = NPC::create(346, &state.npc_table);
+                        npc.cond.set_alive(true);
+                        npc.target_x = self.parts[0].x;
+                        npc.target_y = self.parts[0].y;
+                        npc.x = self.parts[0].x + (angle.cos() * (112 * 0x200) as f64) as i32;
+                        npc.y = self.parts[0].y + (angle.sin() * (112 * 0x200) as f64) as i32;
+                        npc.action_counter2 = i * 0x80;
+
+                        let _ = npc_list.spawn(0x50, npc);
+                    }
+                }
+            }
+            410 => {
+                self.parts[0].action_counter += 1;
+                if self.parts[0].action_counter > 50 {
+                    self.parts[0].action_num = 411;
+                    self.parts[0].action_counter = 0;
+                }
+            }
+            411 => {
+                self.parts[0].action_counter += 1;
+                if self.parts[0].action_counter % 30 == 1 {
+                    let column = (self.parts[0].action_counter / 30) as usize * 2 + 1;
+
+                    let mut npc = NPC::create(348, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = (column as i32 + 1) * 16 * 0x200;
+                    npc.y = 336 * 0x200;
+
+                    let _ = npc_list.spawn(0x180, npc);
+
+                    // the floor crumbles away under the spikes
+                    for x in column..column + 2 {
+                        if x < stage.map.width as usize && stage.change_tile(x, 19, 0) {
+                            let mut npc = NPC::create(4, &state.npc_table);
+                            npc.cond.set_alive(true);
+                            npc.x = x as i32 * 16 * 0x200;
+                            npc.y = 19 * 16 * 0x200;
+
+                            let _ = npc_list.spawn(0x100, npc.clone());
+                            let _ = npc_list.spawn(0x100, npc);
+                        }
+                    }
+
+                    state.quake_counter = 10;
+                    state.sound_manager.play_sfx(26);
+                }
+
+                if self.parts[0].action_counter > 540 {
+                    self.parts[0].action_num = 420;
+                }
+            }
+            420 => {
+                self.parts[0].action_num = 421;
+                self.parts[0].vel_x2 = 0;
+                self.parts[1].action_num = 100;
+                self.parts[2].action_num = 100;
+
+                state.quake_counter = 30;
+                state.sound_manager.play_sfx(35);
+
+                npc_list.create_death_smoke(self.parts[0].x, self.parts[0].y, 60 * 0x200, 32, state, &self.parts[0].rng);
+            }
+            421..=428 => {
+                let durations = [500, 200, 20, 200, 500, 200, 20, 200];
+
+                self.parts[0].vel_x2 += 1;
+                if self.parts[0].vel_x2 > durations[(self.parts[0].action_num - 421) as usize] {
+                    self.parts[0].vel_x2 = 0;
+                    self.parts[0].action_num = if self.parts[0].action_num == 428 { 421 } else { self.parts[0].action_num + 1 };
+                }
+            }
+            1000 | 1001 => {
+                if self.parts[0].action_num == 1000 {
+                    self.parts[0].action_num = 1001;
+                    self.parts[0].action_counter = 0;
+                    self.parts[0].damage = 0;
+                    self.parts[1].action_num = 300;
+                    self.parts[2].action_num = 300;
+
+                    for part in self.parts.iter_mut().take(6) {
+                        part.npc_flags.set_shootable(false);
+                        part.npc_flags.set_solid_soft(false);
+                        part.npc_flags.set_solid_hard(false);
+                        part.npc_flags.set_invulnerable(false);
+                    }
+                }
+
+                state.quake_counter = 100;
+
+                self.parts[0].action_counter += 1;
+                if self.parts[0].action_counter % 12 == 0 {
+                    state.sound_manager.play_sfx(44);
+                }
+
+                npc_list.create_death_smoke(self.parts[0].x + self.parts[0].rng.range(-60..60) * 0x200,
+                                            self.parts[0].y + self.parts[0].rng.range(-60..60) * 0x200,
+                                            0x200, 1, state, &self.parts[0].rng);
+
+                if self.parts[0].action_counter > 150 {
+                    self.parts[0].action_num = 1002;
+                    self.parts[0].action_counter = 0;
+
+                    state.create_screen_effect(self.parts[0].x, self.parts[0].y, ScreenEffectType::Explosion);
+                    state.sound_manager.play_sfx(35);
+                }
+            }
+            1002 => {
+                state.quake_counter = 40;
+
+                self.parts[0].action_counter += 1;
+                if self.parts[0].action_counter > 50 {
+                    for part in self.parts.iter_mut() {
+                        part.cond.set_alive(false);
+                    }
+
+                    for npc in npc_list.iter_alive() {
+                        if npc.npc_type == 332 || npc.npc_type == 348 || npc.npc_type == 350 {
+                            npc_list.create_death_smoke(npc.x, npc.y, npc.display_bounds.right, 4, state, &npc.rng);
+                            npc.cond.set_alive(false);
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        let weak_point = self.parts[0].action_num > 420 && self.parts[0].action_num < 500;
+        let dying = self.parts[0].action_num >= 1000;
+        for part in self.parts[3..6].iter_mut() {
+            part.cond.set_damage_boss(weak_point);
+            part.npc_flags.set_shootable(weak_point);
+            part.npc_flags.set_invulnerable(!weak_point && !dying);
+        }
+
+        let platform_speed = match self.parts[0].action_num {
+            421 => 1,
+            422 => 2,
+            424 => -2,
+            425 => -1,
+            426 => -2,
+            428 => 2,
+            _ => 0,
+        };
+
+        // orbiting eyeballs and platforms follow the boss, damage dealt to the eyeballs goes to the boss
+        for npc in npc_list.iter_alive() {
+            match npc.npc_type {
+                342 => {
+                    npc.target_x = self.parts[0].x;
+                    npc.target_y = self.parts[0].y;
+
+                    if npc.life < 1000 {
+                        let damage = 1000 - npc.life;
+                        self.parts[0].life = self.parts[0].life.saturating_sub(damage).max(1);
+                        self.parts[0].shock = 8;
+                        npc.life = 1000;
+                    }
+                }
+                346 => {
+                    npc.target_x = self.parts[0].x;
+                    npc.target_y = self.parts[0].y;
+                    npc.vel_x2 = platform_speed;
+                }
+                _ => {}
+            }
+        }
+
+        self.tick_b09_ballos_eye(1, state, npc_list);
+        self.tick_b09_ballos_eye(2, state, npc_list);
+        self.tick_b09_ballos_body(3, state);
+        self.tick_b09_ballos_hitbox(4, -44 * 0x200);
+        self.tick_b09_ballos_hitbox(5, 0);
+    }
+
+    fn tick_b09_ballos_land(&mut self, state: &mut SharedGameState, players: &mut [&mut Player; 2], npc_list: &NPCList) {
+        state.quake_counter = 30;
+        state.sound_manager.play_sfx(26);
+        state.sound_manager.play_sfx(44);
+
+        for player in players.iter_mut() {
+            if !player.cond.alive() {
+                continue;
+            }
+
+            if player.flags.hit_bottom_wall() {
+                // crushed underneath the boss
+                if player.y > self.parts[0].y
+                    && player.x > self.parts[0].x - self.parts[0].hit_bounds.left as i32
+                    && player.x < self.parts[0].x + self.parts[0].hit_bounds.right as i32 {
+                    player.damage(16, state, npc_list);
+                }
+
+                player.vel_y = -0x200;
+            }
+        }
+
+        for _ in 0..16 {
+            let mut npc = NPC::create(4, &state.npc_table);
+            npc.cond.set_alive(true);
+            npc.x = self.parts[0].x + self.parts[0].rng.range(-40..40) * 0x200;
+            npc.y = self.parts[0].y + 40 * 0x200;
+
+            let _ = npc_list.spawn(0x100, npc);
+        }
+    }
+
+    fn tick_b09_ballos_eye(&mut self, i: usize, state: &mut SharedGameState, npc_list: &NPCList) {
+        match self.parts[i].action_num {
+            100 | 101 => {
+                if self.parts[i].action_num == 100 {
+                    self.parts[i].action_num = 101;
+                    self.parts[i].anim_num = 0;
+                    self.parts[i].anim_counter = 0;
+                }
+
+                self.parts[i].anim_counter += 1;
+                if self.parts[i].anim_counter > 2 {
+                    self.parts[i].anim_counter = 0;
+                    self.parts[i].anim_num += 1;
+                }
+
+                if self.parts[i].anim_num >= 3 {
+                    self.parts[i].anim_num = 3;
+                    self.parts[i].action_num = 102;
+                }
+            }
+            102 => {
+                self.parts[i].anim_num = 3;
+            }
+            200 | 201 => {
+                if self.parts[i].action_num == 200 {
+                    self.parts[i].action_num = 201;
+                    self.parts[i].anim_counter = 0;
+                }
+
+                self.parts[i].anim_counter += 1;
+                if self.parts[i].anim_counter > 2 {
+                    self.parts[i].anim_counter = 0;
+
+                    if self.parts[i].anim_num > 0 {
+                        self.parts[i].anim_num -= 1;
+                    } else {
+                        self.parts[i].action_num = 202;
+                    }
+                }
+            }
+            300 => {
+                self.parts[i].action_num = 301;
+                self.parts[i].anim_num = 4;
+
+                npc_list.create_death_smoke(self.parts[i].x, self.parts[i].y, self.parts[i].display_bounds.right, 4, state, &self.parts[i].rng);
+            }
+            _ => {}
+        }
+
+        self.parts[i].x = self.parts[0].x + if self.parts[i].direction == Direction::Left { -24 * 0x200 } else { 24 * 0x200 };
+        self.parts[i].y = self.parts[0].y - 36 * 0x200;
+
+        let open = self.parts[i].anim_num == 3 && self.parts[i].action_num < 300;
+        self.parts[i].npc_flags.set_shootable(open);
+
+        let offset = if self.parts[i].direction == Direction::Left { 0 } else { 5 };
+        self.parts[i].anim_rect = state.constants.npc.b09_ballos[offset + self.parts[i].anim_num as usize];
+    }
+
+    fn tick_b09_ballos_body(&mut self, i: usize, state: &mut SharedGameState) {
+        self.parts[0].anim_counter = self.parts[0].anim_counter.wrapping_add(1);
+
+        let mut anim_num = if self.parts[0].shock > 0 && self.parts[0].anim_counter / 2 % 2 != 0 { 1 } else { 0 };
+        if self.parts[0].action_num > 420 {
+            anim_num += 2;
+        }
+
+        self.parts[i].anim_num = anim_num;
+        self.parts[i].x = self.parts[0].x;
+        self.parts[i].y = self.parts[0].y;
+
+        self.parts[i].anim_rect = state.constants.npc.b09_ballos[10 + self.parts[i].anim_num as usize];
+    }
 
+    fn tick_b09_ballos_hitbox(&mut self, i: usize, off_y: i32) {
+        self.parts[i].x = self.parts[0].x;
+        self.parts[i].y = self.parts[0].y + off_y;
     }
 }
@@ -1,7 +1,683 @@
+use ggez::GameResult;
+use num_traits::clamp;
+
+use crate::caret::CaretType;
+use crate::common::{CDEG_RAD, Direction, Rect};
 use crate::npc::boss::BossNPC;
+use crate::npc::list::NPCList;
+use crate::npc::NPC;
+use crate::player::Player;
+use crate::rng::RNG;
+use crate::shared_game_state::SharedGameState;
+use crate::stage::Stage;
+
+impl NPC {
+    pub(crate) fn tick_n178_core_blade_projectile(&mut self, state: &mut SharedGameState) -> GameResult {
+        if (self.flags.0 & 0xff) != 0 {
+            state.create_caret(self.x, self.y, CaretType::ProjectileDissipation, Direction::Left);
+            self.cond.set_alive(false);
+        }
+
+        if self.flags.in_water() {
+            self.x += self.vel_x / 2;
+            self.y += self.vel_y / 2;
+        } else {
+            self.x += self.vel_x;
+            self.y += self.vel_y;
+        }
+
+        self.animate(1, 0, 2);
+
+        self.action_counter2 += 1;
+        if self.action_counter2 > 150 {
+            self.vanish(state);
+            state.create_caret(self.x, self.y, CaretType::ProjectileDissipation, Direction::Left);
+        }
+
+        self.anim_rect = state.constants.npc.n178_core_blade_projectile[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n179_core_wisp_projectile(&mut self, state: &mut SharedGameState) -> GameResult {
+        if (self.flags.0 & 0xff) != 0 {
+            self.cond.set_alive(false);
+            state.create_caret(self.x, self.y, CaretType::ProjectileDissipation, Direction::Left);
+        }
+
+        self.vel_x -= 0x20;
+        self.vel_y = 0;
+
+        if self.vel_x < -0x400 {
+            self.vel_x = -0x400;
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.animate(1, 0, 2);
+
+        self.action_counter2 += 1;
+        if self.action_counter2 > 300 {
+            self.vanish(state);
+            state.create_caret(self.x, self.y, CaretType::ProjectileDissipation, Direction::Left);
+        }
+
+        self.anim_rect = state.constants.npc.n179_core_wisp_projectile[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n218_core_giant_ball(&mut self, state: &mut SharedGameState) -> GameResult {
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.action_counter += 1;
+        if self.action_counter > 200 {
+            self.cond.set_alive(false);
+        }
+
+        self.animate(2, 0, 1);
+
+        self.anim_rect = state.constants.npc.n218_core_giant_ball[self.anim_num as usize];
+
+        Ok(())
+    }
+}
 
 impl BossNPC {
-    pub(crate) fn tick_b04_core(&mut self) {
+    pub(crate) fn tick_b04_core(&mut self, state: &mut SharedGameState, mut players: [&mut Player; 2], npc_list: &NPCList, stage: &Stage) {
+        match self.parts[0].action_num {
+            0 => {
+                self.parts[0].action_num = 10;
+                self.parts[0].exp = 1;
+                self.parts[0].cond.set_alive(true);
+                self.parts[0].npc_flags.0 = 0;
+                self.parts[0].npc_flags.set_invulnerable(true);
+                self.parts[0].npc_flags.set_ignore_solidity(true);
+                self.parts[0].npc_flags.set_show_damage(true);
+                self.parts[0].npc_flags.set_event_when_killed(true);
+                self.parts[0].life = 650;
+                self.parts[0].x = 77 * 16 * 0x200;
+                self.parts[0].y = 14 * 16 * 0x200;
+                self.parts[0].vel_x = 0;
+                self.parts[0].vel_y = 0;
+                self.parts[0].event_num = 1000;
+                self.hurt_sound[0] = 114;
+
+                self.parts[4].cond.set_alive(true);
+                self.parts[4].action_num = 10;
+
+                self.parts[5].cond.set_alive(true);
+                self.parts[5].action_num = 10;
+
+                self.parts[8].cond.set_alive(true);
+                self.parts[8].npc_flags.0 = 0;
+                self.parts[8].npc_flags.set_ignore_solidity(true);
+                self.parts[8].npc_flags.set_invulnerable(true);
+                self.parts[8].display_bounds = Rect { left: 0, top: 0, right: 0, bottom: 0 };
+                self.parts[8].hit_bounds = Rect {
+                    left: 80 * 0x200,
+                    top: 16 * 0x200,
+                    right: 80 * 0x200,
+                    bottom: 16 * 0x200,
+                };
+                self.parts[8].target_x = 0;
+
+                self.parts[9] = self.parts[8].clone();
+                self.parts[9].hit_bounds = Rect {
+                    left: 36 * 0x200,
+                    top: 48 * 0x200,
+                    right: 36 * 0x200,
+                    bottom: 48 * 0x200,
+                };
+                self.parts[9].target_x = 1;
+
+                self.parts[10] = self.parts[8].clone();
+                self.parts[10].hit_bounds = Rect {
+                    left: 44 * 0x200,
+                    top: 8 * 0x200,
+                    right: 44 * 0x200,
+                    bottom: 8 * 0x200,
+                };
+                self.parts[10].target_x = 2;
+
+                self.parts[11] = self.parts[8].clone();
+                self.parts[11].cond.set_damage_boss(true);
+                self.parts[11].hit_bounds = Rect {
+                    left: 20 * 0x200,
+                    top: 20 * 0x200,
+                    right: 20 * 0x200,
+                    bottom: 20 * 0x200,
+                };
+                self.parts[11].target_x = 3;
+
+                self.parts[1].cond.set_alive(true);
+                self.parts[1].action_num = 10;
+                self.parts[1].npc_flags.0 = 0;
+                self.parts[1].npc_flags.set_shootable(true);
+                self.parts[1].npc_flags.set_ignore_solidity(true);
+                self.parts[1].npc_flags.set_invulnerable(true);
+                self.parts[1].life = 1000;
+                self.parts[1].display_bounds = Rect {
+                    left: 32 * 0x200,
+                    top: 20 * 0x200,
+                    right: 32 * 0x200,
+                    bottom: 20 * 0x200,
+                };
+                self.parts[1].hit_bounds = Rect {
+                    left: 24 * 0x200,
+                    top: 16 * 0x200,
+                    right: 24 * 0x200,
+                    bottom: 16 * 0x200,
+                };
+                self.parts[1].x = self.parts[0].x - 8 * 0x200;
+                self.parts[1].y = self.parts[0].y - 64 * 0x200;
+                self.hurt_sound[1] = 54;
+
+                self.parts[2] = self.parts[1].clone();
+                self.parts[2].x = self.parts[0].x + 16 * 0x200;
+                self.parts[2].y = self.parts[0].y;
+                self.hurt_sound[2] = 54;
+
+                self.parts[3] = self.parts[1].clone();
+                self.parts[3].x = self.parts[0].x - 8 * 0x200;
+                self.parts[3].y = self.parts[0].y + 64 * 0x200;
+                self.hurt_sound[3] = 54;
+
+                self.parts[6] = self.parts[1].clone();
+                self.parts[6].x = self.parts[0].x - 48 * 0x200;
+                self.parts[6].y = self.parts[0].y - 32 * 0x200;
+                self.hurt_sound[6] = 54;
+
+                self.parts[7] = self.parts[1].clone();
+                self.parts[7].x = self.parts[0].x - 48 * 0x200;
+                self.parts[7].y = self.parts[0].y + 32 * 0x200;
+                self.hurt_sound[7] = 54;
+
+                for npc in self.parts.iter_mut() {
+                    npc.init_rng();
+                }
+            }
+            200 | 201 => {
+                if self.parts[0].action_num == 200 {
+                    self.parts[0].action_num = 201;
+                    self.parts[0].action_counter = 0;
+                    self.parts[11].npc_flags.set_shootable(false);
+                    state.sound_manager.play_stream_sfx(400);
+                }
+
+                let player_idx = self.parts[0].get_closest_player_idx_mut(&players);
+                self.parts[0].target_x = players[player_idx].x;
+                self.parts[0].target_y = players[player_idx].y;
+
+                self.parts[0].action_counter += 1;
+                if self.parts[0].action_counter > 400 {
+                    self.parts[0].action_counter2 += 1;
+                    state.sound_manager.play_sfx(115);
+
+                    if self.parts[0].action_counter2 > 3 {
+                        self.parts[0].action_counter2 = 0;
+                        self.parts[0].action_num = 220;
+                    } else {
+                        self.parts[0].action_num = 210;
+                    }
+
+                    self.parts[4].action_num = 50;
+                    self.parts[5].action_num = 50;
+                    self.parts[8].npc_flags.set_invulnerable(false);
+                    self.parts[9].npc_flags.set_invulnerable(false);
+                    self.parts[10].npc_flags.set_invulnerable(false);
+                    self.parts[11].npc_flags.set_invulnerable(false);
+                }
+            }
+            210 | 211 => {
+                if self.parts[0].action_num == 210 {
+                    self.parts[0].action_num = 211;
+                    self.parts[0].action_counter = 0;
+                    self.parts[0].vel_y2 = self.parts[0].life as i32;
+                    self.parts[11].npc_flags.set_shootable(true);
+                }
+
+                let player_idx = self.parts[0].get_closest_player_idx_mut(&players);
+                self.parts[0].target_x = players[player_idx].x;
+                self.parts[0].target_y = players[player_idx].y;
+
+                self.tick_b04_core_flash();
+
+                self.parts[0].action_counter += 1;
+                if self.parts[0].action_counter % 100 == 1 {
+                    state.npc_curly_counter = self.parts[0].rng.range(80..100) as u16;
+                    state.npc_curly_target = (self.parts[11].x, self.parts[11].y);
+                }
+
+                if self.parts[0].action_counter < 200 && self.parts[0].action_counter % 20 == 1 {
+                    let mut npc = NPC::create(179, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.parts[0].x + self.parts[0].rng.range(-48..-16) * 0x200;
+                    npc.y = self.parts[0].y + self.parts[0].rng.range(-64..64) * 0x200;
+
+                    let _ = npc_list.spawn(0x100, npc);
+                }
+
+                if self.parts[0].action_counter > 400
+                    || (self.parts[0].life as i32) < self.parts[0].vel_y2.saturating_sub(200) {
+                    self.parts[0].action_num = 200;
+                    self.tick_b04_core_close();
+                }
+            }
+            220 | 221 => {
+                if self.parts[0].action_num == 220 {
+                    self.parts[0].action_num = 221;
+                    self.parts[0].action_counter = 0;
+                    self.parts[0].vel_y2 = self.parts[0].life as i32;
+                    state.quake_counter = 100;
+                    state.sound_manager.play_stream_sfx(1000);
+                }
+
+                self.parts[0].action_counter += 1;
+
+                let player_idx = self.parts[0].get_closest_player_idx_mut(&players);
+                let mut npc = NPC::create(199, &state.npc_table);
+                npc.cond.set_alive(true);
+                npc.direction = Direction::Left;
+                npc.x = players[player_idx].x + self.parts[0].rng.range(-50..150) * 0x200 * 2;
+                npc.y = players[player_idx].y + self.parts[0].rng.range(-160..160) * 0x200;
+
+                let _ = npc_list.spawn(0x100, npc);
+
+                for player in players.iter_mut() {
+                    player.vel_x -= 0x20;
+                    player.cond.set_increase_acceleration(true);
+                }
+
+                self.tick_b04_core_flash();
+
+                if [300, 350, 400].contains(&self.parts[0].action_counter) {
+                    let px = self.parts[0].x - players[player_idx].x;
+                    let py = self.parts[0].y - players[player_idx].y;
+
+                    let deg = f64::atan2(py as f64, px as f64);
+
+                    let mut npc = NPC::create(218, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.parts[0].x - 40 * 0x200;
+                    npc.y = self.parts[0].y;
+                    npc.vel_x = (deg.cos() * -1536.0) as i32;
+                    npc.vel_y = (deg.sin() * -1536.0) as i32;
+
+                    let _ = npc_list.spawn(0x100, npc);
+
+                    state.sound_manager.play_sfx(101);
+                }
+
+                if self.parts[0].action_counter > 400 {
+                    self.parts[0].action_num = 200;
+                    self.tick_b04_core_close();
+                }
+            }
+            500 | 501 => {
+                if self.parts[0].action_num == 500 {
+                    state.sound_manager.stop_stream_sfx();
+                    self.parts[0].action_num = 501;
+                    self.parts[0].action_counter = 0;
+                    self.parts[0].vel_x = 0;
+                    self.parts[0].vel_y = 0;
+                    self.parts[4].anim_num = 2;
+                    self.parts[5].anim_num = 0;
+                    self.parts[1].action_num = 200;
+                    self.parts[2].action_num = 200;
+                    self.parts[3].action_num = 200;
+                    self.parts[6].action_num = 200;
+                    self.parts[7].action_num = 200;
+
+                    state.quake_counter = 20;
+
+                    for _ in 0..32 {
+                        let mut npc = NPC::create(4, &state.npc_table);
+                        npc.cond.set_alive(true);
+                        npc.x = self.parts[0].x + self.parts[0].rng.range(-128..128) * 0x200;
+                        npc.y = self.parts[0].y + self.parts[0].rng.range(-64..64) * 0x200;
+
+                        let _ = npc_list.spawn(0x100, npc);
+                    }
+
+                    for part in self.parts.iter_mut().take(12) {
+                        part.npc_flags.set_invulnerable(false);
+                        part.npc_flags.set_shootable(false);
+                    }
+                }
+
+                self.parts[0].action_counter += 1;
+                if self.parts[0].action_counter % 16 != 0 {
+                    let mut npc = NPC::create(4, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.parts[0].x + self.parts[0].rng.range(-64..64) * 0x200;
+                    npc.y = self.parts[0].y + self.parts[0].rng.range(-32..32) * 0x200;
+
+                    let _ = npc_list.spawn(0x100, npc);
+                }
+
+                if self.parts[0].action_counter / 2 % 2 != 0 {
+                    self.parts[0].x -= 0x200;
+                } else {
+                    self.parts[0].x += 0x200;
+                }
+
+                if self.parts[0].x < 63 * 16 * 0x200 {
+                    self.parts[0].x += 0x80;
+                } else {
+                    self.parts[0].x -= 0x80;
+                }
+
+                if self.parts[0].y < 11 * 16 * 0x200 {
+                    self.parts[0].y += 0x80;
+                } else {
+                    self.parts[0].y -= 0x80;
+                }
+            }
+            600 | 601 => {
+                if self.parts[0].action_num == 600 {
+                    self.parts[0].action_num = 601;
+                    self.parts[4].action_num = 50;
+                    self.parts[5].action_num = 50;
+                    self.parts[8].npc_flags.set_invulnerable(false);
+                    self.parts[9].npc_flags.set_invulnerable(false);
+                    self.parts[10].npc_flags.set_invulnerable(false);
+                    self.parts[11].npc_flags.set_invulnerable(false);
+                }
+
+                self.parts[0].action_counter += 1;
+
+                if self.parts[0].action_counter / 2 % 2 != 0 {
+                    self.parts[0].x -= 4 * 0x200;
+                } else {
+                    self.parts[0].x += 4 * 0x200;
+                }
+            }
+            _ => {}
+        }
+
+        if self.parts[0].action_num >= 200 && self.parts[0].action_num < 300 {
+            match self.parts[0].action_counter {
+                80 => self.parts[1].action_num = 120,
+                110 => self.parts[2].action_num = 120,
+                140 => self.parts[3].action_num = 120,
+                170 => self.parts[6].action_num = 120,
+                200 => self.parts[7].action_num = 120,
+                _ => {}
+            }
+
+            if self.parts[0].x < self.parts[0].target_x + 160 * 0x200 {
+                self.parts[0].vel_x += 4;
+            }
+            if self.parts[0].x > self.parts[0].target_x + 160 * 0x200 {
+                self.parts[0].vel_x -= 4;
+            }
+
+            if self.parts[0].y < self.parts[0].target_y {
+                self.parts[0].vel_y += 4;
+            }
+            if self.parts[0].y > self.parts[0].target_y {
+                self.parts[0].vel_y -= 4;
+            }
+        }
+
+        self.parts[0].vel_x = clamp(self.parts[0].vel_x, -0x80, 0x80);
+        self.parts[0].vel_y = clamp(self.parts[0].vel_y, -0x80, 0x80);
+
+        self.parts[0].x += self.parts[0].vel_x;
+        self.parts[0].y += self.parts[0].vel_y;
+
+        self.tick_b04_core_face(4, state);
+        self.tick_b04_core_tail(5, state);
+        self.tick_b04_core_small_head(1, state, &players, npc_list, stage);
+        self.tick_b04_core_small_head(2, state, &players, npc_list, stage);
+        self.tick_b04_core_small_head(3, state, &players, npc_list, stage);
+        self.tick_b04_core_small_head(6, state, &players, npc_list, stage);
+        self.tick_b04_core_small_head(7, state, &players, npc_list, stage);
+        self.tick_b04_core_hitbox(8);
+        self.tick_b04_core_hitbox(9);
+        self.tick_b04_core_hitbox(10);
+        self.tick_b04_core_hitbox(11);
+    }
+
+    fn tick_b04_core_flash(&mut self) {
+        if self.parts[0].shock > 0 {
+            self.parts[0].anim_counter = self.parts[0].anim_counter.wrapping_add(1);
+
+            let anim_num = if self.parts[0].anim_counter / 2 % 2 != 0 { 0 } else { 1 };
+            self.parts[4].anim_num = anim_num;
+            self.parts[5].anim_num = anim_num;
+        } else {
+            self.parts[4].anim_num = 0;
+            self.parts[5].anim_num = 0;
+        }
+    }
+
+    fn tick_b04_core_close(&mut self) {
+        self.parts[4].action_num = 100;
+        self.parts[5].action_num = 100;
+        self.parts[8].npc_flags.set_invulnerable(true);
+        self.parts[9].npc_flags.set_invulnerable(true);
+        self.parts[10].npc_flags.set_invulnerable(true);
+        self.parts[11].npc_flags.set_invulnerable(true);
+        self.parts[11].npc_flags.set_shootable(false);
+    }
+
+    fn tick_b04_core_face(&mut self, i: usize, state: &mut SharedGameState) {
+        match self.parts[i].action_num {
+            10 | 11 => {
+                if self.parts[i].action_num == 10 {
+                    self.parts[i].action_num = 11;
+                    self.parts[i].anim_num = 2;
+                    self.parts[i].npc_flags.0 = 0;
+                    self.parts[i].npc_flags.set_ignore_solidity(true);
+                    self.parts[i].display_bounds = Rect {
+                        left: 36 * 0x200,
+                        top: 56 * 0x200,
+                        right: 36 * 0x200,
+                        bottom: 56 * 0x200,
+                    };
+                }
+
+                self.parts[i].x = self.parts[0].x - 36 * 0x200;
+                self.parts[i].y = self.parts[0].y;
+            }
+            50 | 51 => {
+                if self.parts[i].action_num == 50 {
+                    self.parts[i].action_num = 51;
+                    self.parts[i].action_counter = 112;
+                }
+
+                self.parts[i].action_counter -= 1;
+                if self.parts[i].action_counter == 0 {
+                    self.parts[i].action_num = 100;
+                    self.parts[i].anim_num = 3;
+                }
+            }
+            100 => {
+                self.parts[i].anim_num = 3;
+            }
+            _ => {}
+        }
+
+        self.parts[i].anim_rect = state.constants.npc.b04_core[self.parts[i].anim_num as usize];
+
+        if self.parts[i].action_num == 51 {
+            self.parts[i].anim_rect.bottom = self.parts[i].anim_rect.top + self.parts[i].action_counter;
+        }
+    }
+
+    fn tick_b04_core_tail(&mut self, i: usize, state: &mut SharedGameState) {
+        match self.parts[i].action_num {
+            10 | 11 => {
+                if self.parts[i].action_num == 10 {
+                    self.parts[i].action_num = 11;
+                    self.parts[i].anim_num = 0;
+                    self.parts[i].npc_flags.0 = 0;
+                    self.parts[i].npc_flags.set_ignore_solidity(true);
+                    self.parts[i].display_bounds = Rect {
+                        left: 44 * 0x200,
+                        top: 56 * 0x200,
+                        right: 44 * 0x200,
+                        bottom: 56 * 0x200,
+                    };
+                }
+
+                self.parts[i].x = self.parts[0].x + 44 * 0x200;
+                self.parts[i].y = self.parts[0].y;
+            }
+            50 | 51 => {
+                if self.parts[i].action_num == 50 {
+                    self.parts[i].action_num = 51;
+                    self.parts[i].action_counter = 112;
+                }
+
+                self.parts[i].action_counter -= 1;
+                if self.parts[i].action_counter == 0 {
+                    self.parts[i].action_num = 100;
+                    self.parts[i].anim_num = 2;
+                }
+            }
+            100 => {
+                self.parts[i].anim_num = 2;
+            }
+            _ => {}
+        }
+
+        self.parts[i].anim_rect = state.constants.npc.b04_core[4 + self.parts[i].anim_num as usize];
+
+        if self.parts[i].action_num == 51 {
+            self.parts[i].anim_rect.bottom = self.parts[i].anim_rect.top + self.parts[i].action_counter;
+        }
+    }
+
+    fn tick_b04_core_small_head(&mut self, i: usize, state: &mut SharedGameState, players: &[&mut Player; 2], npc_list: &NPCList, stage: &Stage) {
+        self.parts[i].life = 1000;
+
+        match self.parts[i].action_num {
+            10 => {
+                self.parts[i].anim_num = 2;
+                self.parts[i].npc_flags.set_shootable(false);
+            }
+            100 | 101 => {
+                if self.parts[i].action_num == 100 {
+                    self.parts[i].action_num = 101;
+                    self.parts[i].anim_num = 2;
+                    self.parts[i].action_counter = 0;
+                    self.parts[i].target_x = self.parts[0].x + self.parts[i].rng.range(-128..32) * 0x200;
+                    self.parts[i].target_y = self.parts[0].y + self.parts[i].rng.range(-64..64) * 0x200;
+                    self.parts[i].npc_flags.set_shootable(true);
+                }
+
+                self.parts[i].x += (self.parts[i].target_x - self.parts[i].x) / 16;
+                self.parts[i].y += (self.parts[i].target_y - self.parts[i].y) / 16;
+
+                self.parts[i].action_counter += 1;
+                if self.parts[i].action_counter > 50 {
+                    self.parts[i].anim_num = 0;
+                }
+            }
+            120 | 121 => {
+                if self.parts[i].action_num == 120 {
+                    self.parts[i].action_num = 121;
+                    self.parts[i].action_counter = 0;
+                }
+
+                self.parts[i].action_counter += 1;
+                self.parts[i].anim_num = if self.parts[i].action_counter / 2 % 2 != 0 { 0 } else { 1 };
+
+                if self.parts[i].action_counter > 20 {
+                    self.parts[i].action_num = 130;
+                }
+            }
+            130 | 131 => {
+                if self.parts[i].action_num == 130 {
+                    self.parts[i].action_num = 131;
+                    self.parts[i].anim_num = 2;
+                    self.parts[i].action_counter = 0;
+                    self.parts[i].target_x = self.parts[i].x + self.parts[i].rng.range(24..48) * 0x200;
+                    self.parts[i].target_y = self.parts[i].y + self.parts[i].rng.range(-4..4) * 0x200;
+                }
+
+                self.parts[i].x += (self.parts[i].target_x - self.parts[i].x) / 16;
+                self.parts[i].y += (self.parts[i].target_y - self.parts[i].y) / 16;
+
+                self.parts[i].action_counter += 1;
+                if self.parts[i].action_counter > 50 {
+                    self.parts[i].action_num = 140;
+                    self.parts[i].anim_num = 0;
+                }
+
+                if self.parts[i].action_counter == 1 || self.parts[i].action_counter == 3 {
+                    let player_idx = self.parts[i].get_closest_player_idx_mut(players);
+                    let px = self.parts[i].x - players[player_idx].x;
+                    let py = self.parts[i].y - players[player_idx].y;
+
+                    let deg = f64::atan2(py as f64, px as f64)
+                        + self.parts[i].rng.range(-2..2) as f64 * CDEG_RAD;
+
+                    let mut npc = NPC::create(178, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.parts[i].x;
+                    npc.y = self.parts[i].y;
+                    npc.vel_x = (deg.cos() * -1024.0) as i32;
+                    npc.vel_y = (deg.sin() * -1024.0) as i32;
+
+                    let _ = npc_list.spawn(0x100, npc);
+
+                    state.sound_manager.play_sfx(39);
+                }
+            }
+            140 => {
+                self.parts[i].x += (self.parts[i].target_x - self.parts[i].x) / 16;
+                self.parts[i].y += (self.parts[i].target_y - self.parts[i].y) / 16;
+            }
+            200 | 201 => {
+                if self.parts[i].action_num == 200 {
+                    self.parts[i].action_num = 201;
+                    self.parts[i].anim_num = 2;
+                    self.parts[i].vel_x = 0;
+                    self.parts[i].vel_y = 0;
+                }
+
+                self.parts[i].vel_x += 0x20;
+                self.parts[i].x += self.parts[i].vel_x;
+
+                if self.parts[i].x > (stage.map.width as i32 + 2) * 16 * 0x200 {
+                    self.parts[i].cond.set_alive(false);
+                }
+            }
+            _ => {}
+        }
+
+        if self.parts[i].shock > 0 {
+            self.parts[i].target_x += 2 * 0x200;
+        }
+
+        self.parts[i].anim_rect = state.constants.npc.b04_core[7 + self.parts[i].anim_num as usize];
+    }
 
+    fn tick_b04_core_hitbox(&mut self, i: usize) {
+        match self.parts[i].target_x {
+            0 => {
+                self.parts[i].x = self.parts[0].x;
+                self.parts[i].y = self.parts[0].y - 32 * 0x200;
+            }
+            1 => {
+                self.parts[i].x = self.parts[0].x + 28 * 0x200;
+                self.parts[i].y = self.parts[0].y;
+            }
+            2 => {
+                self.parts[i].x = self.parts[0].x + 4 * 0x200;
+                self.parts[i].y = self.parts[0].y + 32 * 0x200;
+            }
+            3 => {
+                self.parts[i].x = self.parts[0].x - 28 * 0x200;
+                self.parts[i].y = self.parts[0].y + 4 * 0x200;
+            }
+            _ => {}
+        }
     }
 }
@@ -1,7 +1,377 @@
+use ggez::GameResult;
+use num_traits::clamp;
+
+use crate::caret::CaretType;
+use crate::common::{Direction, Rect};
 use crate::npc::boss::BossNPC;
+use crate::npc::list::NPCList;
+use crate::npc::NPC;
+use crate::player::Player;
+use crate::rng::RNG;
+use crate::shared_game_state::SharedGameState;
+use crate::stage::Stage;
+
+impl NPC {
+    pub(crate) fn tick_n196_ironhead_motion_wall(&mut self, state: &mut SharedGameState) -> GameResult {
+        self.x -= 6 * 0x200;
+        if self.x <= 19 * 16 * 0x200 {
+            self.x += 22 * 16 * 0x200;
+        }
+
+        let dir_offset = if self.direction == Direction::Left { 0 } else { 1 };
+
+        self.anim_rect = state.constants.npc.n196_ironhead_motion_wall[dir_offset];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n197_porcupine_fish(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 | 10 => {
+                if self.action_num == 0 {
+                    self.action_num = 10;
+                    self.anim_counter = 0;
+                    self.vel_x = 0x800;
+                    self.vel_y = self.rng.range(-0x200..0x200);
+                }
+
+                self.animate(2, 0, 1);
+
+                if self.vel_x < 0 {
+                    self.damage = 3;
+                    self.action_num = 20;
+                }
+            }
+            20 => {
+                self.damage = 3;
+                self.animate(0, 2, 3);
+
+                if self.x < 48 * 0x200 {
+                    npc_list.create_death_smoke(self.x, self.y, self.display_bounds.right, 4, state, &self.rng);
+                    self.cond.set_alive(false);
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+
+        if self.flags.hit_top_wall() {
+            self.vel_y = 0x200;
+        }
+
+        if self.flags.hit_bottom_wall() {
+            self.vel_y = -0x200;
+        }
+
+        self.vel_x -= 12;
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.anim_rect = state.constants.npc.n197_porcupine_fish[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n198_ironhead_projectile(&mut self, state: &mut SharedGameState) -> GameResult {
+        if self.action_num == 0 {
+            self.action_counter += 1;
+            if self.action_counter > 20 {
+                self.action_num = 1;
+                self.vel_x = 0;
+                self.vel_y = 0;
+                self.action_counter2 = 0;
+            }
+        } else if self.action_num == 1 {
+            self.vel_x += 0x20;
+        }
+
+        self.animate(0, 0, 2);
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.anim_rect = state.constants.npc.n198_ironhead_projectile[self.anim_num as usize];
+
+        self.action_counter2 += 1;
+        if self.action_counter2 > 100 {
+            self.cond.set_alive(false);
+        }
+
+        if self.action_counter2 % 4 == 1 {
+            state.sound_manager.play_sfx(46);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n271_ironhead_block(&mut self, state: &mut SharedGameState, npc_list: &NPCList, stage: &Stage) -> GameResult {
+        if self.vel_x < 0 && self.flags.hit_left_wall() {
+            npc_list.create_death_smoke(self.x, self.y, self.display_bounds.right, 4, state, &self.rng);
+            self.vanish(state);
+            return Ok(());
+        }
+
+        if self.action_num == 0 {
+            self.action_num = 1;
+            self.npc_flags.set_ignore_solidity(true);
+
+            let block = self.rng.range(0..9) as u16;
+            if block == 9 {
+                self.anim_rect = Rect::new(0, 64, 32, 96);
+                self.display_bounds = Rect {
+                    left: 16 * 0x200,
+                    top: 16 * 0x200,
+                    right: 16 * 0x200,
+                    bottom: 16 * 0x200,
+                };
+                self.hit_bounds = Rect {
+                    left: 12 * 0x200,
+                    top: 12 * 0x200,
+                    right: 12 * 0x200,
+                    bottom: 12 * 0x200,
+                };
+            } else {
+                self.anim_rect = Rect::new_size(16 * (block % 3 + 7), 16 * (block / 3), 16, 16);
+            }
+
+            self.vel_x = self.direction.vector_x() * 2 * self.rng.range(0x100..0x200);
+            self.vel_y = self.rng.range(-0x200..0x200);
+        }
+
+        if self.vel_y < 0 && self.y - (self.hit_bounds.top as i32) < 8 * 0x200 {
+            self.vel_y = -self.vel_y;
+            state.create_caret(self.x, self.y - 8 * 0x200, CaretType::LittleParticles, Direction::Left);
+            state.create_caret(self.x, self.y - 8 * 0x200, CaretType::LittleParticles, Direction::Left);
+        }
+
+        if self.vel_y > 0 && self.y + (self.hit_bounds.bottom as i32) > 232 * 0x200 {
+            self.vel_y = -self.vel_y;
+            state.create_caret(self.x, self.y + 8 * 0x200, CaretType::LittleParticles, Direction::Left);
+            state.create_caret(self.x, self.y + 8 * 0x200, CaretType::LittleParticles, Direction::Left);
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        if self.x < -32 * 0x200 || self.x > (stage.map.width as i32 + 2) * 16 * 0x200 {
+            self.cond.set_alive(false);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n272_ironhead_block_generator(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.action_counter = self.rng.range(0..200) as u16;
+                }
+
+                if self.action_counter > 0 {
+                    self.action_counter -= 1;
+                } else {
+                    self.action_num = 0;
+
+                    let mut npc = NPC::create(271, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y + self.rng.range(-32..32) * 0x200;
+                    npc.direction = self.direction;
+
+                    let _ = npc_list.spawn(0x100, npc);
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
 
 impl BossNPC {
-    pub(crate) fn tick_b05_ironhead(&mut self) {
+    pub(crate) fn tick_b05_ironhead(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) {
+        match self.parts[0].action_num {
+            0 => {
+                self.parts[0].cond.set_alive(true);
+                self.parts[0].exp = 1;
+                self.parts[0].direction = Direction::Right;
+                self.parts[0].action_num = 100;
+                self.parts[0].x = 160 * 0x200;
+                self.parts[0].y = 128 * 0x200;
+                self.parts[0].display_bounds = Rect {
+                    left: 40 * 0x200,
+                    top: 12 * 0x200,
+                    right: 24 * 0x200,
+                    bottom: 12 * 0x200,
+                };
+                self.parts[0].hit_bounds = Rect {
+                    left: 16 * 0x200,
+                    top: 10 * 0x200,
+                    right: 16 * 0x200,
+                    bottom: 10 * 0x200,
+                };
+                self.parts[0].npc_flags.0 = 0;
+                self.parts[0].npc_flags.set_ignore_solidity(true);
+                self.parts[0].npc_flags.set_shootable(true);
+                self.parts[0].npc_flags.set_event_when_killed(true);
+                self.parts[0].npc_flags.set_show_damage(true);
+                self.parts[0].size = 3;
+                self.parts[0].damage = 10;
+                self.parts[0].event_num = 1000;
+                self.parts[0].life = 400;
+                self.hurt_sound[0] = 54;
+
+                self.parts[0].init_rng();
+            }
+            100 | 101 => {
+                if self.parts[0].action_num == 100 {
+                    self.parts[0].action_num = 101;
+                    self.parts[0].action_counter = 0;
+                    self.parts[0].npc_flags.set_shootable(false);
+                }
+
+                self.parts[0].action_counter += 1;
+                if self.parts[0].action_counter > 50 {
+                    self.parts[0].action_num = 250;
+                    self.parts[0].action_counter = 0;
+                }
+
+                if self.parts[0].action_counter % 4 == 0 {
+                    let mut npc = NPC::create(197, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.parts[0].rng.range(15..18) * 16 * 0x200;
+                    npc.y = self.parts[0].rng.range(2..13) * 16 * 0x200;
+
+                    let _ = npc_list.spawn(0x100, npc);
+                }
+            }
+            250 | 251 => {
+                let player_idx = self.parts[0].get_closest_player_idx_mut(&players);
+
+                if self.parts[0].action_num == 250 {
+                    self.parts[0].action_num = 251;
+
+                    if self.parts[0].direction == Direction::Right {
+                        self.parts[0].x = 240 * 0x200;
+                        self.parts[0].y = players[player_idx].y;
+                    } else {
+                        self.parts[0].x = 720 * 0x200;
+                        self.parts[0].y = self.parts[0].rng.range(2..13) * 16 * 0x200;
+                    }
+
+                    self.parts[0].target_x = self.parts[0].x;
+                    self.parts[0].target_y = self.parts[0].y;
+
+                    self.parts[0].vel_x = self.parts[0].rng.range(-0x200..0x200);
+                    self.parts[0].vel_y = self.parts[0].rng.range(-0x200..0x200);
+
+                    self.parts[0].npc_flags.set_shootable(true);
+                }
+
+                if self.parts[0].direction == Direction::Right {
+                    self.parts[0].target_x += 2 * 0x200;
+                } else {
+                    self.parts[0].target_x -= 0x200;
+
+                    if self.parts[0].target_y < players[player_idx].y {
+                        self.parts[0].target_y += 0x200;
+                    } else {
+                        self.parts[0].target_y -= 0x200;
+                    }
+                }
+
+                self.parts[0].vel_x += if self.parts[0].x < self.parts[0].target_x { 8 } else { -8 };
+                self.parts[0].vel_y += if self.parts[0].y < self.parts[0].target_y { 8 } else { -8 };
+
+                self.parts[0].vel_y = clamp(self.parts[0].vel_y, -0x200, 0x200);
+
+                self.parts[0].x += self.parts[0].vel_x;
+                self.parts[0].y += self.parts[0].vel_y;
+
+                if self.parts[0].direction == Direction::Right {
+                    if self.parts[0].x > 720 * 0x200 {
+                        self.parts[0].direction = Direction::Left;
+                        self.parts[0].action_num = 100;
+                    }
+                } else if self.parts[0].x < 272 * 0x200 {
+                    self.parts[0].direction = Direction::Right;
+                    self.parts[0].action_num = 100;
+                }
+
+                if self.parts[0].direction == Direction::Left {
+                    self.parts[0].action_counter += 1;
+
+                    if [300, 310, 320].contains(&self.parts[0].action_counter) {
+                        state.sound_manager.play_sfx(39);
+
+                        let mut npc = NPC::create(198, &state.npc_table);
+                        npc.cond.set_alive(true);
+                        npc.x = self.parts[0].x + 10 * 0x200;
+                        npc.y = self.parts[0].y + 0x200;
+                        npc.vel_x = self.parts[0].rng.range(-3..0) * 0x200;
+                        npc.vel_y = self.parts[0].rng.range(-3..3) * 0x200;
+                        npc.direction = Direction::Right;
+
+                        let _ = npc_list.spawn(0x100, npc);
+                    }
+                }
+
+                self.parts[0].animate(2, 0, 7);
+            }
+            1000 | 1001 => {
+                if self.parts[0].action_num == 1000 {
+                    self.parts[0].action_num = 1001;
+                    self.parts[0].npc_flags.set_shootable(false);
+                    self.parts[0].anim_num = 8;
+                    self.parts[0].damage = 0;
+                    self.parts[0].target_x = self.parts[0].x;
+                    self.parts[0].target_y = self.parts[0].y;
+
+                    state.quake_counter = 20;
+
+                    for _ in 0..32 {
+                        let mut npc = NPC::create(4, &state.npc_table);
+                        npc.cond.set_alive(true);
+                        npc.x = self.parts[0].x + self.parts[0].rng.range(-128..128) * 0x200;
+                        npc.y = self.parts[0].y + self.parts[0].rng.range(-64..64) * 0x200;
+
+                        let _ = npc_list.spawn(0x100, npc);
+                    }
+
+                    for npc in npc_list.iter_alive() {
+                        if npc.npc_type == 197 || npc.npc_type == 271 || npc.npc_type == 272 {
+                            npc_list.create_death_smoke(npc.x, npc.y, npc.display_bounds.right, 4, state, &npc.rng);
+                            npc.cond.set_alive(false);
+                        }
+                    }
+                }
+
+                self.parts[0].target_x -= 0x200;
+                self.parts[0].x = self.parts[0].target_x + self.parts[0].rng.range(-1..1) * 0x200;
+                self.parts[0].y = self.parts[0].target_y + self.parts[0].rng.range(-1..1) * 0x200;
+
+                self.parts[0].action_counter += 1;
+                if self.parts[0].action_counter % 4 == 0 {
+                    let mut npc = NPC::create(4, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.parts[0].x + self.parts[0].rng.range(-128..128) * 0x200;
+                    npc.y = self.parts[0].y + self.parts[0].rng.range(-64..64) * 0x200;
+
+                    let _ = npc_list.spawn(0x100, npc);
+                }
+            }
+            _ => {}
+        }
+
+        let hurt_offset = if self.parts[0].shock > 0 {
+            self.parts[0].action_counter2 = self.parts[0].action_counter2.wrapping_add(1);
+            if self.parts[0].action_counter2 / 2 % 2 != 0 { 0 } else { 9 }
+        } else {
+            0
+        };
 
+        self.parts[0].anim_rect = state.constants.npc.b05_ironhead[self.parts[0].anim_num as usize + hurt_offset];
     }
 }
@@ -63,7 +63,7 @@ impl BossNPC {
 }
 
 impl GameEntity<([&mut Player; 2], &NPCList, &mut Stage, &BulletManager)> for BossNPC {
-    fn tick(&mut self, state: &mut SharedGameState, (players, npc_list, _stage, bullet_manager): ([&mut Player; 2], &NPCList, &mut Stage, &BulletManager)) -> GameResult {
+    fn tick(&mut self, state: &mut SharedGameState, (players, npc_list, stage, bullet_manager): ([&mut Player; 2], &NPCList, &mut Stage, &BulletManager)) -> GameResult {
         if !self.parts[0].cond.alive() {
             return Ok(());
         }
@@ -72,11 +72,12 @@ impl GameEntity<([&mut Player; 2], &NPCList, &mut Stage, &BulletManager)> for Bo
             1 => self.tick_b01_omega(state, players, npc_list, bullet_manager),
             2 => self.tick_b02_balfrog(state, players, npc_list),
             3 => self.tick_b03_monster_x(state, players, npc_list),
-            4 => self.tick_b04_core(),
-            5 => self.tick_b05_ironhead(),
-            6 => self.tick_b06_twins(),
-            7 => self.tick_b07_undead_core(),
-            8 => self.tick_b09_ballos(),
+            4 => self.tick_b04_core(state, players, npc_list, stage),
+            5 => self.tick_b05_ironhead(state, players, npc_list),
+            6 => self.tick_b06_twins(state, players, npc_list),
+            7 => self.tick_b07_undead_core(state, players, npc_list, stage),
+            8 => self.tick_b08_press(state, npc_list, stage),
+            9 => self.tick_b09_ballos(state, players, npc_list, stage),
             _ => {}
         }
 
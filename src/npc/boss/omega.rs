@@ -8,6 +8,7 @@ use crate::npc::list::NPCList;
 use crate::npc::NPC;
 use crate::player::Player;
 use crate::rng::RNG;
+use crate::screen_effect::ScreenEffectType;
 use crate::shared_game_state::SharedGameState;
 
 impl NPC {
@@ -363,7 +364,7 @@ impl BossNPC {
                 if self.parts[0].action_counter > 100 {
                     self.parts[0].action_num = 160;
                     self.parts[0].action_counter = 0;
-                    // todo flash
+                    state.create_screen_effect(self.parts[0].x, self.parts[0].y, ScreenEffectType::Explosion);
                     state.sound_manager.play_sfx(35);
                 }
             }
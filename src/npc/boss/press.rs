@@ -1,7 +1,258 @@
+use crate::common::{Direction, Rect};
 use crate::npc::boss::BossNPC;
+use crate::npc::list::NPCList;
+use crate::npc::NPC;
+use crate::rng::RNG;
+use crate::shared_game_state::SharedGameState;
+use crate::stage::Stage;
 
 impl BossNPC {
-    pub(crate) fn tick_b08_press(&mut self) {
+    pub(crate) fn tick_b08_press(&mut self, state: &mut SharedGameState, npc_list: &NPCList, stage: &mut Stage) {
+        match self.parts[0].action_num {
+            0 => {
+                self.parts[0].action_num = 10;
+                self.parts[0].cond.set_alive(true);
+                self.parts[0].exp = 1;
+                self.parts[0].direction = Direction::Right;
+                self.parts[0].x = 0;
+                self.parts[0].y = 0;
+                self.parts[0].display_bounds = Rect {
+                    left: 40 * 0x200,
+                    top: 60 * 0x200,
+                    right: 40 * 0x200,
+                    bottom: 60 * 0x200,
+                };
+                self.parts[0].hit_bounds = Rect {
+                    left: 49 * 0x200,
+                    top: 60 * 0x200,
+                    right: 40 * 0x200,
+                    bottom: 48 * 0x200,
+                };
+                self.parts[0].npc_flags.0 = 0;
+                self.parts[0].npc_flags.set_ignore_solidity(true);
+                self.parts[0].npc_flags.set_solid_hard(true);
+                self.parts[0].npc_flags.set_event_when_killed(true);
+                self.parts[0].npc_flags.set_show_damage(true);
+                self.parts[0].size = 3;
+                self.parts[0].damage = 10;
+                self.parts[0].event_num = 1000;
+                self.parts[0].life = 700;
+                self.hurt_sound[0] = 54;
+            }
+            5 => {
+                self.parts[0].action_num = 6;
+                self.parts[0].x = 0;
+                self.parts[0].y = 0;
+                self.parts[1].cond.set_alive(false);
+                self.parts[2].cond.set_alive(false);
+            }
+            10 => {
+                self.parts[0].action_num = 11;
+                self.parts[0].x = 160 * 0x200;
+                self.parts[0].y = 74 * 0x200;
+            }
+            20 | 21 => {
+                if self.parts[0].action_num == 20 {
+                    self.parts[0].action_num = 21;
+                    self.parts[0].damage = 0;
+                    self.parts[0].x = 160 * 0x200;
+                    self.parts[0].y = 413 * 0x200;
+                    self.parts[0].npc_flags.set_solid_hard(false);
+                    self.parts[1].cond.set_alive(false);
+                    self.parts[2].cond.set_alive(false);
+                }
 
+                self.parts[0].action_counter += 1;
+                if self.parts[0].action_counter % 16 == 0 {
+                    npc_list.create_death_smoke(self.parts[0].x + self.parts[0].rng.range(-40..40) * 0x200,
+                                                self.parts[0].y + self.parts[0].rng.range(-60..60) * 0x200,
+                                                0x200, 1, state, &self.parts[0].rng);
+                }
+            }
+            30 | 31 => {
+                if self.parts[0].action_num == 30 {
+                    self.parts[0].action_num = 31;
+                    self.parts[0].anim_num = 2;
+                    self.parts[0].x = 160 * 0x200;
+                    self.parts[0].y = 64 * 0x200;
+                }
+
+                self.parts[0].y += 4 * 0x200;
+                if self.parts[0].y >= 413 * 0x200 {
+                    self.parts[0].y = 413 * 0x200;
+                    self.parts[0].anim_num = 0;
+                    self.parts[0].action_num = 20;
+
+                    state.sound_manager.play_sfx(44);
+
+                    for _ in 0..5 {
+                        let mut npc = NPC::create(4, &state.npc_table);
+                        npc.cond.set_alive(true);
+                        npc.x = self.parts[0].x + self.parts[0].rng.range(-40..40) * 0x200;
+                        npc.y = self.parts[0].y + 60 * 0x200;
+
+                        let _ = npc_list.spawn(0x100, npc);
+                    }
+                }
+            }
+            100 | 101 => {
+                if self.parts[0].action_num == 100 {
+                    self.parts[0].action_num = 101;
+                    self.parts[0].action_counter2 = 9;
+                    self.parts[0].vel_x2 = -100;
+
+                    self.parts[1].cond.set_alive(true);
+                    self.parts[1].npc_flags.0 = 0;
+                    self.parts[1].npc_flags.set_invulnerable(true);
+                    self.parts[1].npc_flags.set_ignore_solidity(true);
+                    self.parts[1].hit_bounds = Rect {
+                        left: 14 * 0x200,
+                        top: 8 * 0x200,
+                        right: 14 * 0x200,
+                        bottom: 8 * 0x200,
+                    };
+
+                    self.parts[2] = self.parts[1].clone();
+
+                    self.parts[3].cond.set_alive(true);
+                    self.parts[3].cond.set_damage_boss(true);
+                    self.parts[3].npc_flags.set_shootable(true);
+                    self.parts[3].hit_bounds = Rect {
+                        left: 6 * 0x200,
+                        top: 8 * 0x200,
+                        right: 6 * 0x200,
+                        bottom: 8 * 0x200,
+                    };
+                    self.hurt_sound[3] = 54;
+
+                    for npc in self.parts.iter_mut() {
+                        npc.init_rng();
+                    }
+
+                    self.tick_b08_press_spawn_lighting(state, npc_list);
+                }
+
+                // the press is held up by rows of blocks, which crumble as it takes damage
+                if self.parts[0].action_counter2 > 1 && self.parts[0].life < self.parts[0].action_counter2 * 70 {
+                    self.parts[0].action_counter2 -= 1;
+
+                    let y = self.parts[0].action_counter2 as usize;
+                    for x in 8..13 {
+                        if stage.change_tile(x, y, 0) {
+                            npc_list.create_death_smoke(x as i32 * 16 * 0x200, y as i32 * 16 * 0x200,
+                                                        0, 4, state, &self.parts[0].rng);
+                            state.sound_manager.play_sfx(12);
+                        }
+                    }
+                }
+
+                self.parts[0].vel_x2 += 1;
+                if self.parts[0].vel_x2 == 81 || self.parts[0].vel_x2 == 241 {
+                    self.tick_b08_press_spawn_bute(48 * 0x200, state, npc_list);
+                }
+
+                if self.parts[0].vel_x2 == 1 || self.parts[0].vel_x2 == 161 {
+                    self.tick_b08_press_spawn_bute(272 * 0x200, state, npc_list);
+                }
+
+                if self.parts[0].vel_x2 >= 300 {
+                    self.parts[0].vel_x2 = 0;
+                    self.tick_b08_press_spawn_lighting(state, npc_list);
+                }
+            }
+            500 | 501 => {
+                if self.parts[0].action_num == 500 {
+                    self.parts[0].action_num = 501;
+                    self.parts[0].action_counter = 0;
+                    self.parts[0].vel_y2 = 0;
+                    self.parts[3].npc_flags.set_shootable(false);
+
+                    for npc in npc_list.iter_alive() {
+                        if npc.npc_type == 325 || npc.npc_type == 330 {
+                            npc_list.create_death_smoke(npc.x, npc.y, npc.display_bounds.right, 4, state, &npc.rng);
+                            npc.cond.set_alive(false);
+                        }
+                    }
+                }
+
+                self.parts[0].action_counter += 1;
+                if self.parts[0].action_counter % 16 == 0 {
+                    state.sound_manager.play_sfx(12);
+                    npc_list.create_death_smoke(self.parts[0].x + self.parts[0].rng.range(-40..40) * 0x200,
+                                                self.parts[0].y + self.parts[0].rng.range(-60..60) * 0x200,
+                                                0x200, 1, state, &self.parts[0].rng);
+                }
+
+                if self.parts[0].action_counter == 95 {
+                    self.parts[0].anim_num = 1;
+                }
+
+                if self.parts[0].action_counter == 98 {
+                    self.parts[0].anim_num = 2;
+                }
+
+                if self.parts[0].action_counter > 100 {
+                    self.parts[0].action_num = 510;
+                }
+            }
+            510 => {
+                self.parts[0].vel_y += 0x40;
+                self.parts[0].damage = 127;
+                self.parts[0].y += self.parts[0].vel_y;
+
+                // smash through the floor on the way down
+                if self.parts[0].vel_y2 == 0 && self.parts[0].y > 160 * 0x200 {
+                    self.parts[0].vel_y2 = 1;
+                    self.parts[0].vel_y = -0x200;
+                    self.parts[0].npc_flags.set_ignore_solidity(true);
+
+                    for x in 7..14 {
+                        if stage.change_tile(x, 14, 0) {
+                            npc_list.create_death_smoke(x as i32 * 16 * 0x200, 224 * 0x200,
+                                                        0, 4, state, &self.parts[0].rng);
+                            state.sound_manager.play_sfx(12);
+                        }
+                    }
+                }
+
+                if self.parts[0].y > 480 * 0x200 {
+                    self.parts[0].action_num = 520;
+                }
+            }
+            _ => {}
+        }
+
+        self.parts[1].x = self.parts[0].x - 24 * 0x200;
+        self.parts[1].y = self.parts[0].y + 52 * 0x200;
+
+        self.parts[2].x = self.parts[0].x + 24 * 0x200;
+        self.parts[2].y = self.parts[0].y + 52 * 0x200;
+
+        self.parts[3].x = self.parts[0].x;
+        self.parts[3].y = self.parts[0].y + 40 * 0x200;
+
+        self.parts[0].anim_counter = self.parts[0].anim_counter.wrapping_add(1);
+
+        let offset = if self.parts[0].shock > 0 && self.parts[0].anim_counter / 2 % 2 == 0 { 3 } else { 0 };
+        self.parts[0].anim_rect = state.constants.npc.b08_press[offset + self.parts[0].anim_num as usize];
+    }
+
+    fn tick_b08_press_spawn_bute(&mut self, x: i32, state: &mut SharedGameState, npc_list: &NPCList) {
+        let mut npc = NPC::create(323, &state.npc_table);
+        npc.cond.set_alive(true);
+        npc.x = x;
+        npc.y = 240 * 0x200;
+        npc.direction = Direction::Up;
+
+        let _ = npc_list.spawn(0x100, npc);
+    }
+
+    fn tick_b08_press_spawn_lighting(&mut self, state: &mut SharedGameState, npc_list: &NPCList) {
+        let mut npc = NPC::create(325, &state.npc_table);
+        npc.cond.set_alive(true);
+        npc.x = self.parts[0].x;
+        npc.y = self.parts[0].y + 60 * 0x200;
+
+        let _ = npc_list.spawn(0x100, npc);
     }
 }
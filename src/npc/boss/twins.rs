@@ -1,7 +1,485 @@
+use crate::common::{CDEG_RAD, Direction, Rect};
 use crate::npc::boss::BossNPC;
+use crate::npc::list::NPCList;
+use crate::npc::NPC;
+use crate::player::Player;
+use crate::rng::RNG;
+use crate::screen_effect::ScreenEffectType;
+use crate::shared_game_state::SharedGameState;
 
 impl BossNPC {
-    pub(crate) fn tick_b06_twins(&mut self) {
+    pub(crate) fn tick_b06_twins(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList) {
+        match self.parts[0].action_num {
+            0 => {
+                self.parts[0].cond.set_alive(true);
+                self.parts[0].direction = Direction::Left;
+                self.parts[0].action_num = 10;
+                self.parts[0].exp = 0;
+                self.parts[0].x = 160 * 0x200;
+                self.parts[0].y = 128 * 0x200;
+                self.parts[0].display_bounds = Rect {
+                    left: 8 * 0x200,
+                    top: 8 * 0x200,
+                    right: 128 * 0x200,
+                    bottom: 8 * 0x200,
+                };
+                self.parts[0].hit_bounds = Rect {
+                    left: 8 * 0x200,
+                    top: 8 * 0x200,
+                    right: 8 * 0x200,
+                    bottom: 8 * 0x200,
+                };
+                self.parts[0].npc_flags.0 = 0;
+                self.parts[0].npc_flags.set_ignore_solidity(true);
+                self.parts[0].npc_flags.set_event_when_killed(true);
+                self.parts[0].size = 3;
+                self.parts[0].damage = 0;
+                self.parts[0].event_num = 1000;
+                self.parts[0].life = 500;
+                self.parts[0].action_counter2 = 0;
+                self.parts[0].vel_y2 = self.parts[0].rng.range(700..1200);
+                self.parts[0].target_x = 180;
+                self.parts[0].target_y = 61;
+                self.hurt_sound[0] = 54;
 
+                self.parts[2].cond.set_alive(true);
+                self.parts[2].cond.set_damage_boss(true);
+                self.parts[2].display_bounds = Rect {
+                    left: 20 * 0x200,
+                    top: 16 * 0x200,
+                    right: 20 * 0x200,
+                    bottom: 16 * 0x200,
+                };
+                self.parts[2].hit_bounds = Rect {
+                    left: 12 * 0x200,
+                    top: 10 * 0x200,
+                    right: 12 * 0x200,
+                    bottom: 10 * 0x200,
+                };
+                self.parts[2].npc_flags.0 = 0;
+                self.parts[2].npc_flags.set_ignore_solidity(true);
+                self.parts[2].parent_id = 3;
+                self.parts[2].damage = 10;
+
+                self.parts[3].cond.set_alive(true);
+                self.parts[3].display_bounds = Rect {
+                    left: 20 * 0x200,
+                    top: 20 * 0x200,
+                    right: 20 * 0x200,
+                    bottom: 20 * 0x200,
+                };
+                self.parts[3].hit_bounds = Rect {
+                    left: 12 * 0x200,
+                    top: 2 * 0x200,
+                    right: 12 * 0x200,
+                    bottom: 16 * 0x200,
+                };
+                self.parts[3].npc_flags.0 = 0;
+                self.parts[3].npc_flags.set_ignore_solidity(true);
+                self.parts[3].parent_id = 0;
+                self.parts[3].damage = 10;
+                self.parts[3].action_counter2 = 0;
+
+                self.parts[4] = self.parts[2].clone();
+                self.parts[4].parent_id = 5;
+
+                self.parts[5] = self.parts[3].clone();
+                self.parts[5].action_counter2 = 128;
+
+                for npc in self.parts.iter_mut() {
+                    npc.init_rng();
+                }
+            }
+            20 => {
+                self.parts[0].target_x -= 1;
+                if self.parts[0].target_x <= 112 {
+                    self.parts[0].action_num = 100;
+                    self.parts[0].action_counter = 0;
+                    self.parts[2].action_num = 100;
+                    self.parts[3].action_num = 100;
+                    self.parts[4].action_num = 100;
+                    self.parts[5].action_num = 100;
+                }
+            }
+            100 => {
+                self.parts[0].action_counter += 1;
+
+                let counter = self.parts[0].action_counter as i32;
+                let duration = self.parts[0].vel_y2;
+                let speed = if counter < 100 {
+                    1
+                } else if counter < 120 {
+                    2
+                } else if counter < duration {
+                    4
+                } else if counter < duration + 40 {
+                    2
+                } else if counter < duration + 60 {
+                    1
+                } else {
+                    0
+                };
+
+                if speed == 0 {
+                    self.parts[0].action_num = 110;
+                    self.parts[0].action_counter = 0;
+                    self.parts[0].vel_y2 = self.parts[0].rng.range(400..700);
+                } else {
+                    self.parts[0].action_counter2 = (self.parts[0].action_counter2 + speed) % 0x400;
+                }
+            }
+            110 => {
+                self.parts[0].action_counter += 1;
+
+                let counter = self.parts[0].action_counter as i32;
+                let duration = self.parts[0].vel_y2;
+                let speed = if counter < 20 {
+                    1
+                } else if counter < 60 {
+                    2
+                } else if counter < duration {
+                    4
+                } else if counter < duration + 40 {
+                    2
+                } else if counter < duration + 60 {
+                    1
+                } else {
+                    0
+                };
+
+                if speed == 0 {
+                    self.parts[0].action_counter = 0;
+
+                    if self.parts[0].life < 300 {
+                        self.parts[0].action_num = 400;
+                        self.parts[2].action_num = 400;
+                        self.parts[4].action_num = 400;
+                    } else {
+                        self.parts[0].action_num = 100;
+                        self.parts[0].vel_y2 = self.parts[0].rng.range(400..700);
+                    }
+                } else {
+                    self.parts[0].action_counter2 = (self.parts[0].action_counter2 + 0x400 - speed) % 0x400;
+                }
+            }
+            400 => {
+                self.parts[0].action_counter += 1;
+                if self.parts[0].action_counter > 100 {
+                    self.parts[0].action_num = 401;
+                    self.parts[0].action_counter = 0;
+                }
+            }
+            401 => {
+                self.parts[0].action_counter += 1;
+
+                let counter = self.parts[0].action_counter;
+                let speed = if counter < 100 {
+                    1
+                } else if counter < 120 {
+                    2
+                } else if counter < 500 {
+                    4
+                } else if counter < 540 {
+                    2
+                } else if counter < 560 {
+                    1
+                } else {
+                    0
+                };
+
+                if speed == 0 {
+                    self.parts[0].action_num = 100;
+                    self.parts[0].action_counter = 0;
+                    self.parts[2].action_num = 100;
+                    self.parts[4].action_num = 100;
+                } else {
+                    self.parts[0].action_counter2 = (self.parts[0].action_counter2 + speed) % 0x400;
+                }
+            }
+            1000 => {
+                self.parts[0].action_num = 1001;
+                self.parts[0].action_counter = 0;
+                self.parts[2].action_num = 1000;
+                self.parts[3].action_num = 1000;
+                self.parts[4].action_num = 1000;
+                self.parts[5].action_num = 1000;
+
+                npc_list.create_death_smoke(self.parts[0].x, self.parts[0].y, self.parts[0].display_bounds.right, 40, state, &self.parts[0].rng);
+            }
+            1001 => {
+                self.parts[0].action_counter += 1;
+                if self.parts[0].action_counter > 100 {
+                    self.parts[0].action_num = 1010;
+                }
+
+                let mut npc = NPC::create(4, &state.npc_table);
+                npc.cond.set_alive(true);
+                npc.x = self.parts[0].x + self.parts[0].rng.range(-128..128) * 0x200;
+                npc.y = self.parts[0].y + self.parts[0].rng.range(-70..70) * 0x200;
+
+                let _ = npc_list.spawn(0x100, npc);
+            }
+            1010 => {
+                self.parts[0].action_counter2 = (self.parts[0].action_counter2 + 4) % 0x400;
+
+                if self.parts[0].target_x > 8 {
+                    self.parts[0].target_x -= 1;
+                }
+
+                if self.parts[0].target_y > 0 {
+                    self.parts[0].target_y -= 1;
+                }
+
+                if self.parts[0].target_x < -8 {
+                    self.parts[0].target_x += 1;
+                }
+
+                if self.parts[0].target_y < 0 {
+                    self.parts[0].target_y += 1;
+                }
+
+                if self.parts[0].target_y == 0 {
+                    self.parts[0].action_num = 1020;
+                    self.parts[0].action_counter = 0;
+
+                    state.create_screen_effect(self.parts[0].x, self.parts[0].y, ScreenEffectType::Explosion);
+                    state.sound_manager.play_sfx(35);
+                }
+            }
+            1020 => {
+                self.parts[0].action_counter += 1;
+                if self.parts[0].action_counter > 50 {
+                    for npc in npc_list.iter_alive() {
+                        if npc.npc_type == 211 {
+                            npc_list.create_death_smoke(npc.x, npc.y, npc.display_bounds.right, 4, state, &npc.rng);
+                            npc.cond.set_alive(false);
+                        }
+                    }
+
+                    for part in self.parts.iter_mut().take(6) {
+                        part.cond.set_alive(false);
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        self.tick_b06_twins_head(2, state, &players, npc_list);
+        self.tick_b06_twins_body(3, state, &players);
+        self.tick_b06_twins_head(4, state, &players, npc_list);
+        self.tick_b06_twins_body(5, state, &players);
+
+        self.parts[0].anim_rect = Rect::new(0, 0, 0, 0);
+    }
+
+    fn tick_b06_twins_head(&mut self, i: usize, state: &mut SharedGameState, players: &[&mut Player; 2], npc_list: &NPCList) {
+        match self.parts[i].action_num {
+            0 => {
+                self.parts[i].action_num = 1;
+            }
+            100 | 200 | 201 => {
+                if self.parts[i].action_num != 201 {
+                    self.parts[i].action_num = 201;
+                    self.parts[i].anim_num = 0;
+                    self.parts[i].hit_bounds.left = 16 * 0x200;
+                    self.parts[i].npc_flags.set_shootable(false);
+                    self.parts[i].action_counter = self.parts[i].rng.range(100..200) as u16;
+                }
+
+                if self.parts[i].action_counter > 0 {
+                    self.parts[i].action_counter -= 1;
+                } else {
+                    self.parts[i].action_num = 210;
+                    self.parts[i].action_counter = 0;
+                    self.parts[i].action_counter2 = 0;
+                }
+            }
+            210 => {
+                self.parts[i].action_counter += 1;
+
+                if self.parts[i].action_counter == 3 {
+                    self.parts[i].anim_num = 1;
+                }
+
+                if self.parts[i].action_counter == 6 {
+                    self.parts[i].anim_num = 2;
+                    self.parts[i].hit_bounds.left = 8 * 0x200;
+                    self.parts[i].npc_flags.set_shootable(true);
+                    self.parts[i].action_counter2 = 0;
+                }
+
+                if self.parts[i].action_counter > 150 {
+                    self.parts[i].action_num = 220;
+                    self.parts[i].action_counter = 0;
+                }
+
+                if self.parts[i].shock > 0 {
+                    self.parts[i].action_counter2 += 1;
+                }
+
+                if self.parts[i].action_counter2 > 10 {
+                    state.sound_manager.play_sfx(51);
+                    npc_list.create_death_smoke(self.parts[i].x, self.parts[i].y, self.parts[i].display_bounds.right, 4, state, &self.parts[i].rng);
+
+                    self.parts[i].action_num = 300;
+                    self.parts[i].action_counter = 0;
+                    self.parts[i].anim_num = 3;
+                    self.parts[i].hit_bounds.left = 16 * 0x200;
+                }
+            }
+            220 => {
+                self.parts[i].action_counter += 1;
+
+                if self.parts[i].action_counter % 8 == 1 {
+                    self.tick_b06_twins_head_shoot(i, state, players, npc_list);
+                }
+
+                if self.parts[i].action_counter > 50 {
+                    self.parts[i].action_num = 200;
+                }
+            }
+            300 => {
+                self.parts[i].action_counter += 1;
+                if self.parts[i].action_counter > 100 {
+                    self.parts[i].action_num = 200;
+                }
+            }
+            400 | 401 => {
+                if self.parts[i].action_num == 400 {
+                    self.parts[i].action_num = 401;
+                    self.parts[i].action_counter = 0;
+                    self.parts[i].anim_num = 0;
+                    self.parts[i].hit_bounds.left = 16 * 0x200;
+                    self.parts[i].npc_flags.set_shootable(false);
+                }
+
+                self.parts[i].action_counter += 1;
+
+                if self.parts[i].action_counter == 3 {
+                    self.parts[i].anim_num = 1;
+                }
+
+                if self.parts[i].action_counter == 6 {
+                    self.parts[i].anim_num = 2;
+                    self.parts[i].hit_bounds.left = 8 * 0x200;
+                    self.parts[i].npc_flags.set_shootable(true);
+                    self.parts[i].action_counter2 = 0;
+                }
+
+                if self.parts[i].action_counter > 20 && self.parts[i].action_counter % 32 == 1 {
+                    self.tick_b06_twins_head_shoot(i, state, players, npc_list);
+                }
+            }
+            1000 => {
+                self.parts[i].npc_flags.set_shootable(false);
+                self.parts[i].anim_num = 3;
+            }
+            _ => {}
+        }
+
+        let parent = self.parts[i].parent_id as usize;
+
+        self.parts[i].direction = self.parts[parent].direction;
+        self.parts[i].x = if self.parts[i].direction == Direction::Left {
+            self.parts[parent].x - 4 * 0x200
+        } else {
+            self.parts[parent].x + 4 * 0x200
+        };
+        self.parts[i].y = self.parts[parent].y - 8 * 0x200;
+
+        let dir_offset = if self.parts[i].direction == Direction::Left { 6 } else { 10 };
+        self.parts[i].anim_rect = state.constants.npc.b06_twins[self.parts[i].anim_num as usize + dir_offset];
+    }
+
+    fn tick_b06_twins_head_shoot(&mut self, i: usize, state: &mut SharedGameState, players: &[&mut Player; 2], npc_list: &NPCList) {
+        let player_idx = self.parts[i].get_closest_player_idx_mut(players);
+        let px = self.parts[i].x - players[player_idx].x;
+        let py = self.parts[i].y - players[player_idx].y;
+
+        let deg = f64::atan2(py as f64, px as f64)
+            + self.parts[i].rng.range(-6..6) as f64 * CDEG_RAD;
+
+        let mut npc = NPC::create(202, &state.npc_table);
+        npc.cond.set_alive(true);
+        npc.x = if self.parts[i].direction == Direction::Left {
+            self.parts[i].x - 8 * 0x200
+        } else {
+            self.parts[i].x + 8 * 0x200
+        };
+        npc.y = self.parts[i].y;
+        npc.vel_x = (deg.cos() * -512.0) as i32;
+        npc.vel_y = (deg.sin() * -512.0) as i32;
+
+        let _ = npc_list.spawn(0x100, npc);
+
+        state.sound_manager.play_sfx(33);
+    }
+
+    fn tick_b06_twins_body(&mut self, i: usize, state: &mut SharedGameState, players: &[&mut Player; 2]) {
+        let parent = self.parts[i].parent_id as usize;
+        let deg = ((self.parts[parent].action_counter2 / 4 + self.parts[i].action_counter2) % 0x100) as f64 * CDEG_RAD;
+        let target_x = self.parts[parent].x + (deg.cos() * (self.parts[parent].target_x * 0x200) as f64) as i32;
+        let target_y = self.parts[parent].y + (deg.sin() * (self.parts[parent].target_y * 0x200) as f64) as i32;
+
+        match self.parts[i].action_num {
+            0 | 10 => {
+                if self.parts[i].action_num == 0 {
+                    self.parts[i].action_num = 10;
+                    self.parts[i].x = target_x;
+                    self.parts[i].y = target_y;
+                }
+
+                let player_idx = self.parts[i].get_closest_player_idx_mut(players);
+                self.parts[i].direction = if self.parts[i].x > players[player_idx].x {
+                    Direction::Left
+                } else {
+                    Direction::Right
+                };
+            }
+            100 => {
+                self.parts[i].target_x = target_x;
+                self.parts[i].target_y = target_y;
+                self.parts[i].x += (self.parts[i].target_x - self.parts[i].x) / 8;
+                self.parts[i].y += (self.parts[i].target_y - self.parts[i].y) / 8;
+
+                let player_idx = self.parts[i].get_closest_player_idx_mut(players);
+                self.parts[i].direction = if self.parts[i].x > players[player_idx].x {
+                    Direction::Left
+                } else {
+                    Direction::Right
+                };
+            }
+            1000 | 1001 => {
+                if self.parts[i].action_num == 1000 {
+                    self.parts[i].action_num = 1001;
+                    self.parts[i].npc_flags.set_shootable(false);
+                }
+
+                self.parts[i].target_x = target_x;
+                self.parts[i].target_y = target_y;
+                self.parts[i].x += (self.parts[i].target_x - self.parts[i].x) / 8;
+                self.parts[i].y += (self.parts[i].target_y - self.parts[i].y) / 8;
+
+                self.parts[i].direction = if self.parts[i].x > self.parts[parent].x {
+                    Direction::Left
+                } else {
+                    Direction::Right
+                };
+            }
+            _ => {}
+        }
+
+        self.parts[i].anim_counter += 1;
+        if self.parts[i].anim_counter > 2 {
+            self.parts[i].anim_counter = 0;
+            self.parts[i].anim_num += 1;
+        }
+
+        if self.parts[i].anim_num > 2 {
+            self.parts[i].anim_num = 0;
+        }
+
+        let dir_offset = if self.parts[i].direction == Direction::Left { 0 } else { 3 };
+        self.parts[i].anim_rect = state.constants.npc.b06_twins[self.parts[i].anim_num as usize + dir_offset];
     }
 }
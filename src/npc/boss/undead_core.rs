@@ -1,7 +1,934 @@
+use ggez::GameResult;
+use num_traits::clamp;
+
+use crate::common::{CDEG_RAD, Direction, Rect};
 use crate::npc::boss::BossNPC;
+use crate::npc::list::NPCList;
+use crate::npc::NPC;
+use crate::player::Player;
+use crate::rng::RNG;
+use crate::screen_effect::ScreenEffectType;
+use crate::shared_game_state::SharedGameState;
+use crate::stage::Stage;
+
+impl NPC {
+    pub(crate) fn tick_n282_mini_undead_core_active(&mut self, state: &mut SharedGameState, players: [&mut Player; 2]) -> GameResult {
+        match self.action_num {
+            0 | 20 => {
+                if self.action_num == 0 {
+                    self.action_num = 20;
+                    self.target_y = self.y;
+                    self.vel_y = if self.rng.range(0..100) % 2 == 0 { 0x100 } else { -0x100 };
+                }
+
+                self.vel_x = -0x200;
+
+                if self.x < -64 * 0x200 {
+                    self.cond.set_alive(false);
+                }
+
+                if self.target_y < self.y {
+                    self.vel_y -= 0x10;
+                }
+                if self.target_y > self.y {
+                    self.vel_y += 0x10;
+                }
+
+                self.vel_y = clamp(self.vel_y, -0x100, 0x100);
+
+                let left = self.x - self.hit_bounds.left as i32;
+                let right = self.x + self.hit_bounds.right as i32;
+                let top = self.y - self.hit_bounds.top as i32;
+                let bottom = self.y + self.hit_bounds.bottom as i32;
+
+                let standing = players.iter().any(|player| {
+                    player.cond.alive()
+                        && player.flags.hit_bottom_wall()
+                        && player.y < self.y - 4 * 0x200
+                        && player.x > self.x - 24 * 0x200
+                        && player.x < self.x + 24 * 0x200
+                });
+
+                if standing {
+                    self.target_y = 144 * 0x200;
+                    self.anim_num = 2;
+                } else if self.anim_num != 1 {
+                    self.anim_num = 0;
+                }
+
+                // stop being solid instead of crushing the player against a wall
+                let crushing = players.iter().any(|player| {
+                    if !player.cond.alive() {
+                        return false;
+                    }
+
+                    let overlaps_x = player.x + (player.hit_bounds.right as i32) > left
+                        && player.x - (player.hit_bounds.left as i32) < right;
+                    let overlaps_y = player.y + (player.hit_bounds.bottom as i32) > top
+                        && player.y - (player.hit_bounds.top as i32) < bottom;
+
+                    (player.flags.hit_left_wall() && overlaps_y
+                        && player.x < left && player.x > left - 8 * 0x200)
+                        || (player.flags.hit_right_wall() && overlaps_y
+                        && player.x > right && player.x < right + 8 * 0x200)
+                        || (player.flags.hit_top_wall() && overlaps_x
+                        && player.y < top && player.y > top - 8 * 0x200)
+                        || (player.flags.hit_bottom_wall() && overlaps_x
+                        && player.y > bottom - 4 * 0x200 && player.y < bottom + 12 * 0x200)
+                });
+
+                if crushing {
+                    self.npc_flags.set_solid_hard(false);
+                    self.anim_num = 1;
+                }
+            }
+            _ => {}
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.anim_rect = state.constants.npc.n282_mini_undead_core_active[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n285_undead_core_spiral_projectile(&mut self, state: &mut SharedGameState, npc_list: &NPCList, stage: &Stage) -> GameResult {
+        if self.x < 0 || self.x > stage.map.width as i32 * 16 * 0x200 {
+            self.vanish(state);
+            return Ok(());
+        }
+
+        if self.action_num == 0 {
+            self.action_num = 1;
+            self.target_x = self.x;
+            self.target_y = self.y;
+        }
+
+        self.action_counter2 = (self.action_counter2 + 24) % 0x100;
+
+        if self.direction == Direction::Left {
+            self.vel_x -= 21;
+        } else {
+            self.vel_x += 21;
+        }
+
+        let angle = self.action_counter2 as f64 * CDEG_RAD;
+
+        self.target_x += self.vel_x;
+        self.x = self.target_x + (angle.cos() * 4.0 * 512.0) as i32;
+        self.y = self.target_y + (angle.sin() * 6.0 * 512.0) as i32;
+
+        let mut npc = NPC::create(286, &state.npc_table);
+        npc.cond.set_alive(true);
+        npc.x = self.x;
+        npc.y = self.y;
+
+        let _ = npc_list.spawn(0x100, npc);
+
+        self.anim_rect = state.constants.npc.n285_undead_core_spiral_projectile;
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n286_undead_core_spiral_projectile_trail(&mut self, state: &mut SharedGameState) -> GameResult {
+        self.anim_num += 1;
+        if self.anim_num > 2 {
+            self.cond.set_alive(false);
+            return Ok(());
+        }
+
+        self.anim_rect = state.constants.npc.n286_undead_core_spiral_projectile_trail[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n287_orange_smoke(&mut self, state: &mut SharedGameState) -> GameResult {
+        if self.action_num == 0 {
+            self.action_num = 1;
+            self.vel_x = self.rng.range(-4..4) * 0x200;
+        } else {
+            self.vel_x = (self.vel_x * 20) / 21;
+            self.vel_y = (self.vel_y * 20) / 21;
+
+            self.x += self.vel_x;
+            self.y += self.vel_y;
+        }
+
+        self.anim_counter += 1;
+        if self.anim_counter > 1 {
+            self.anim_counter = 0;
+            self.anim_num += 1;
+        }
+
+        if self.anim_num > 6 {
+            self.cond.set_alive(false);
+            return Ok(());
+        }
+
+        self.anim_rect = state.constants.npc.n287_orange_smoke[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n288_undead_core_exploding_rock(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList, stage: &Stage) -> GameResult {
+        match self.action_num {
+            0 | 1 => {
+                if self.action_num == 0 {
+                    self.action_num = 1;
+                    self.vel_x = -0x200;
+                }
+
+                if self.direction == Direction::Up {
+                    self.vel_y -= 0x20;
+                    if self.vel_y < -0x5ff {
+                        self.vel_y = -0x5ff;
+                    }
+
+                    if self.flags.hit_top_wall() {
+                        self.action_num = 2;
+                    }
+                } else if self.direction == Direction::Bottom {
+                    self.vel_y += 0x20;
+                    if self.vel_y > 0x5ff {
+                        self.vel_y = 0x5ff;
+                    }
+
+                    if self.flags.hit_bottom_wall() {
+                        self.action_num = 2;
+                    }
+                }
+
+                self.animate(3, 0, 1);
+            }
+            2 | 3 => {
+                if self.action_num == 2 {
+                    self.action_num = 3;
+                    self.action_counter = 0;
+                    self.npc_flags.set_ignore_solidity(true);
+                    self.vel_y = 0;
+
+                    let player = self.get_closest_player_mut(players);
+                    self.vel_x = if self.x > player.x { -0x400 } else { 0x400 };
+
+                    self.display_bounds = Rect {
+                        left: 12 * 0x200,
+                        top: 12 * 0x200,
+                        right: 12 * 0x200,
+                        bottom: 12 * 0x200,
+                    };
+
+                    state.sound_manager.play_sfx(44);
+                }
+
+                self.anim_num += 1;
+                if self.anim_num > 4 {
+                    self.anim_num = 2;
+                }
+
+                self.action_counter += 1;
+                if self.action_counter % 4 == 1 {
+                    let mut npc = NPC::create(287, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.x;
+                    npc.y = self.y;
+                    npc.vel_y = if self.direction == Direction::Up { 0x400 } else { -0x400 };
+
+                    let _ = npc_list.spawn(0x100, npc);
+                }
+
+                if self.x < 16 * 0x200 || self.x > (stage.map.width as i32 - 1) * 16 * 0x200 {
+                    self.cond.set_alive(false);
+                }
+            }
+            _ => {}
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
+        self.anim_rect = state.constants.npc.n288_undead_core_exploding_rock[self.anim_num as usize];
+
+        Ok(())
+    }
+
+    pub(crate) fn tick_n293_undead_core_energy_shot(&mut self, state: &mut SharedGameState, npc_list: &NPCList) -> GameResult {
+        if self.action_num == 0 {
+            self.action_num = 1;
+        }
+
+        self.anim_num += 1;
+        if self.anim_num > 1 {
+            self.anim_num = 0;
+        }
+
+        let mut npc = NPC::create(4, &state.npc_table);
+        npc.cond.set_alive(true);
+        npc.x = self.x + self.rng.range(0..16) * 0x200;
+        npc.y = self.y + self.rng.range(-16..16) * 0x200;
+
+        let _ = npc_list.spawn(0x100, npc);
+
+        self.x -= 8 * 0x200;
+        if self.x < -32 * 0x200 {
+            self.cond.set_alive(false);
+        }
+
+        self.anim_rect = state.constants.npc.n293_undead_core_energy_shot[self.anim_num as usize];
+
+        Ok(())
+    }
+}
 
 impl BossNPC {
-    pub(crate) fn tick_b07_undead_core(&mut self) {
+    pub(crate) fn tick_b07_undead_core(&mut self, state: &mut SharedGameState, players: [&mut Player; 2], npc_list: &NPCList, stage: &Stage) {
+        let mut shock = false;
+
+        match self.parts[0].action_num {
+            0 => {
+                self.parts[0].action_num = 10;
+                self.parts[0].exp = 1;
+                self.parts[0].cond.set_alive(true);
+                self.parts[0].npc_flags.0 = 0;
+                self.parts[0].npc_flags.set_ignore_solidity(true);
+                self.parts[0].npc_flags.set_show_damage(true);
+                self.parts[0].npc_flags.set_event_when_killed(true);
+                self.parts[0].life = 700;
+                self.parts[0].x = 592 * 0x200;
+                self.parts[0].y = 120 * 0x200;
+                self.parts[0].vel_x = 0;
+                self.parts[0].vel_y = 0;
+                self.parts[0].event_num = 1000;
+                self.parts[0].vel_x2 = 0;
+                self.hurt_sound[0] = 114;
+
+                self.parts[3].cond.set_alive(true);
+                self.parts[3].action_num = 0;
+                self.parts[3].npc_flags.0 = 0;
+                self.parts[3].npc_flags.set_ignore_solidity(true);
+                self.parts[3].display_bounds = Rect {
+                    left: 36 * 0x200,
+                    top: 20 * 0x200,
+                    right: 36 * 0x200,
+                    bottom: 20 * 0x200,
+                };
+
+                self.parts[4].cond.set_alive(true);
+                self.parts[4].npc_flags.0 = 0;
+                self.parts[4].npc_flags.set_ignore_solidity(true);
+                self.parts[4].display_bounds = Rect {
+                    left: 36 * 0x200,
+                    top: 56 * 0x200,
+                    right: 36 * 0x200,
+                    bottom: 56 * 0x200,
+                };
+
+                self.parts[5].cond.set_alive(true);
+                self.parts[5].npc_flags.0 = 0;
+                self.parts[5].npc_flags.set_ignore_solidity(true);
+                self.parts[5].display_bounds = Rect {
+                    left: 44 * 0x200,
+                    top: 56 * 0x200,
+                    right: 44 * 0x200,
+                    bottom: 56 * 0x200,
+                };
+
+                self.parts[8].cond.set_alive(true);
+                self.parts[8].npc_flags.0 = 0;
+                self.parts[8].npc_flags.set_ignore_solidity(true);
+                self.parts[8].npc_flags.set_invulnerable(true);
+                self.parts[8].display_bounds = Rect { left: 0, top: 0, right: 0, bottom: 0 };
+                self.parts[8].hit_bounds = Rect {
+                    left: 40 * 0x200,
+                    top: 16 * 0x200,
+                    right: 40 * 0x200,
+                    bottom: 16 * 0x200,
+                };
+                self.parts[8].target_x = 0;
+
+                self.parts[9] = self.parts[8].clone();
+                self.parts[9].hit_bounds = Rect {
+                    left: 36 * 0x200,
+                    top: 24 * 0x200,
+                    right: 36 * 0x200,
+                    bottom: 24 * 0x200,
+                };
+                self.parts[9].target_x = 1;
+
+                self.parts[10] = self.parts[8].clone();
+                self.parts[10].hit_bounds = Rect {
+                    left: 44 * 0x200,
+                    top: 8 * 0x200,
+                    right: 44 * 0x200,
+                    bottom: 8 * 0x200,
+                };
+                self.parts[10].target_x = 2;
+
+                self.parts[11] = self.parts[8].clone();
+                self.parts[11].cond.set_damage_boss(true);
+                self.parts[11].hit_bounds = Rect {
+                    left: 16 * 0x200,
+                    top: 16 * 0x200,
+                    right: 16 * 0x200,
+                    bottom: 16 * 0x200,
+                };
+                self.parts[11].target_x = 3;
+
+                self.parts[1].cond.set_alive(true);
+                self.parts[1].action_num = 10;
+                self.parts[1].npc_flags.0 = 0;
+                self.parts[1].npc_flags.set_shootable(true);
+                self.parts[1].npc_flags.set_ignore_solidity(true);
+                self.parts[1].npc_flags.set_invulnerable(true);
+                self.parts[1].life = 1000;
+                self.parts[1].display_bounds = Rect {
+                    left: 32 * 0x200,
+                    top: 20 * 0x200,
+                    right: 32 * 0x200,
+                    bottom: 20 * 0x200,
+                };
+                self.parts[1].hit_bounds = Rect {
+                    left: 24 * 0x200,
+                    top: 16 * 0x200,
+                    right: 24 * 0x200,
+                    bottom: 16 * 0x200,
+                };
+                self.parts[1].action_counter2 = 0;
+                self.parts[1].vel_x2 = 0x80;
+                self.hurt_sound[1] = 54;
+
+                self.parts[2] = self.parts[1].clone();
+                self.parts[2].action_counter2 = 0x80;
+                self.hurt_sound[2] = 54;
+
+                self.parts[6] = self.parts[1].clone();
+                self.parts[6].vel_x2 = 0x180;
+                self.hurt_sound[6] = 54;
+
+                self.parts[7] = self.parts[1].clone();
+                self.parts[7].action_counter2 = 0x80;
+                self.parts[7].vel_x2 = 0x180;
+                self.hurt_sound[7] = 54;
+
+                for npc in self.parts.iter_mut() {
+                    npc.init_rng();
+                }
+            }
+            15 | 16 => {
+                if self.parts[0].action_num == 15 {
+                    self.parts[0].action_num = 16;
+                    self.parts[0].direction = Direction::Left;
+                    self.parts[3].action_num = 10;
+                    self.parts[4].anim_num = 0;
+
+                    shock = true;
+                }
+            }
+            20 => {
+                self.parts[0].action_num = 210;
+                self.parts[0].direction = Direction::Left;
+                self.parts[1].action_num = 5;
+                self.parts[2].action_num = 5;
+                self.parts[6].action_num = 5;
+                self.parts[7].action_num = 5;
+
+                shock = true;
+            }
+            200 | 201 => {
+                if self.parts[0].action_num == 200 {
+                    self.parts[0].action_num = 201;
+                    self.parts[0].action_counter = 0;
+                    self.parts[3].action_num = 0;
+                    self.parts[4].anim_num = 2;
+                    self.parts[5].anim_num = 0;
+                    self.parts[8].npc_flags.set_invulnerable(false);
+                    self.parts[9].npc_flags.set_invulnerable(false);
+                    self.parts[10].npc_flags.set_invulnerable(false);
+                    self.parts[11].npc_flags.set_shootable(false);
+
+                    shock = true;
+                }
+
+                self.parts[0].action_counter += 1;
+                if self.parts[0].action_counter > 200 {
+                    self.parts[0].action_counter2 += 1;
+                    state.sound_manager.play_sfx(115);
+
+                    if self.parts[0].life < 200 {
+                        self.parts[0].action_num = 230;
+                    } else if self.parts[0].action_counter2 > 2 {
+                        self.parts[0].action_num = 220;
+                    } else {
+                        self.parts[0].action_num = 210;
+                    }
+                }
+            }
+            210 | 211 => {
+                if self.parts[0].action_num == 210 {
+                    self.parts[0].action_num = 211;
+                    self.parts[0].action_counter = 0;
+                    self.parts[0].vel_y2 = self.parts[0].life as i32;
+                    self.parts[3].action_num = 10;
+                    self.tick_b07_undead_core_open();
+
+                    shock = true;
+                }
+
+                self.tick_b07_undead_core_flash();
+
+                self.parts[0].action_counter += 1;
+                if self.parts[0].action_counter % 100 == 1 {
+                    state.npc_curly_counter = self.parts[0].rng.range(80..100) as u16;
+                    state.npc_curly_target = (self.parts[11].x, self.parts[11].y);
+                }
+
+                if self.parts[0].action_counter < 300 {
+                    if self.parts[0].action_counter % 120 == 1 {
+                        self.tick_b07_undead_core_spawn_rock(Direction::Up, state, npc_list);
+                    }
+
+                    if self.parts[0].action_counter % 120 == 61 {
+                        self.tick_b07_undead_core_spawn_rock(Direction::Bottom, state, npc_list);
+                    }
+                }
+
+                if (self.parts[0].life as i32) < self.parts[0].vel_y2 - 50 || self.parts[0].action_counter > 400 {
+                    self.parts[0].action_num = 200;
+                }
+            }
+            220 | 221 => {
+                if self.parts[0].action_num == 220 {
+                    self.parts[0].action_num = 221;
+                    self.parts[0].action_counter = 0;
+                    self.parts[0].action_counter2 = 0;
+                    self.parts[0].vel_y2 = self.parts[0].life as i32;
+                    self.parts[3].action_num = 20;
+                    self.tick_b07_undead_core_open();
+
+                    state.quake_counter = 100;
+
+                    shock = true;
+                }
+
+                self.parts[0].action_counter += 1;
+                if self.parts[0].action_counter % 40 == 1 {
+                    let part = [1, 2, 6, 7][self.parts[0].rng.range(0..3) as usize];
+                    let x = self.parts[part].x - 16 * 0x200;
+                    let y = self.parts[part].y;
+
+                    self.tick_b07_undead_core_spawn_spiral(x, y, state, npc_list);
+
+                    state.sound_manager.play_sfx(25);
+                }
+
+                self.tick_b07_undead_core_flash();
+
+                if (self.parts[0].life as i32) < self.parts[0].vel_y2 - 150
+                    || self.parts[0].action_counter > 400
+                    || self.parts[0].life < 200 {
+                    self.parts[0].action_num = 200;
+                }
+            }
+            230 | 231 => {
+                if self.parts[0].action_num == 230 {
+                    self.parts[0].action_num = 231;
+                    self.parts[0].action_counter = 0;
+                    self.parts[3].action_num = 30;
+                    self.tick_b07_undead_core_open();
+
+                    let (x, y) = (self.parts[3].x, self.parts[3].y);
+                    self.tick_b07_undead_core_spawn_spiral(x - 16 * 0x200, y, state, npc_list);
+                    self.tick_b07_undead_core_spawn_spiral(x, y - 16 * 0x200, state, npc_list);
+                    self.tick_b07_undead_core_spawn_spiral(x, y + 16 * 0x200, state, npc_list);
+
+                    state.sound_manager.play_sfx(25);
+
+                    shock = true;
+                }
+
+                self.tick_b07_undead_core_flash();
+
+                self.parts[0].action_counter += 1;
+                if self.parts[0].action_counter % 100 == 1 {
+                    state.npc_curly_counter = self.parts[0].rng.range(80..100) as u16;
+                    state.npc_curly_target = (self.parts[11].x, self.parts[11].y);
+                }
+
+                if self.parts[0].action_counter % 120 == 1 {
+                    self.tick_b07_undead_core_spawn_rock(Direction::Up, state, npc_list);
+                }
+
+                if self.parts[0].action_counter % 120 == 61 {
+                    self.tick_b07_undead_core_spawn_rock(Direction::Bottom, state, npc_list);
+                }
+            }
+            500 | 501 => {
+                if self.parts[0].action_num == 500 {
+                    state.sound_manager.stop_stream_sfx();
+                    self.parts[0].action_num = 501;
+                    self.parts[0].action_counter = 0;
+                    self.parts[0].vel_x = 0;
+                    self.parts[0].vel_y = 0;
+                    self.parts[3].action_num = 0;
+                    self.parts[4].anim_num = 2;
+                    self.parts[5].anim_num = 0;
+                    self.parts[1].action_num = 5;
+                    self.parts[2].action_num = 5;
+                    self.parts[6].action_num = 5;
+                    self.parts[7].action_num = 5;
+
+                    state.quake_counter = 20;
+
+                    for _ in 0..100 {
+                        let mut npc = NPC::create(4, &state.npc_table);
+                        npc.cond.set_alive(true);
+                        npc.x = self.parts[0].x + self.parts[0].rng.range(-128..128) * 0x200;
+                        npc.y = self.parts[0].y + self.parts[0].rng.range(-64..64) * 0x200;
+
+                        let _ = npc_list.spawn(0x100, npc);
+                    }
+
+                    for npc in npc_list.iter_alive() {
+                        if npc.npc_type == 282 {
+                            npc_list.create_death_smoke(npc.x, npc.y, npc.display_bounds.right, 4, state, &npc.rng);
+                            npc.cond.set_alive(false);
+                        }
+                    }
+
+                    self.parts[11].npc_flags.set_shootable(false);
+
+                    for part in self.parts.iter_mut().take(12) {
+                        part.npc_flags.set_invulnerable(false);
+                    }
+                }
+
+                self.parts[0].action_counter += 1;
+                if self.parts[0].action_counter % 16 != 0 {
+                    let mut npc = NPC::create(4, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.parts[0].x + self.parts[0].rng.range(-64..64) * 0x200;
+                    npc.y = self.parts[0].y + self.parts[0].rng.range(-32..32) * 0x200;
+
+                    let _ = npc_list.spawn(0x100, npc);
+                }
+
+                self.parts[0].x += 0x40;
+                self.parts[0].y += 0x80;
+
+                if self.parts[0].action_counter > 200 {
+                    self.parts[0].action_num = 1000;
+                    self.parts[0].action_counter = 0;
+                }
+            }
+            1000 => {
+                state.quake_counter = 100;
+
+                self.parts[0].action_counter += 1;
+                if self.parts[0].action_counter % 8 == 0 {
+                    state.sound_manager.play_sfx(44);
+                }
+
+                npc_list.create_death_smoke(self.parts[0].x + self.parts[0].rng.range(-72..72) * 0x200,
+                                            self.parts[0].y + self.parts[0].rng.range(-64..64) * 0x200,
+                                            0x200, 1, state, &self.parts[0].rng);
+
+                if self.parts[0].action_counter > 100 {
+                    self.parts[0].action_num = 1001;
+                    self.parts[0].action_counter = 0;
+
+                    state.create_screen_effect(self.parts[0].x, self.parts[0].y, ScreenEffectType::Explosion);
+                    state.sound_manager.play_sfx(35);
+                }
+            }
+            1001 => {
+                state.quake_counter = 40;
+
+                self.parts[0].action_counter += 1;
+                if self.parts[0].action_counter > 50 {
+                    for part in self.parts.iter_mut() {
+                        part.cond.set_alive(false);
+                    }
+
+                    for npc in npc_list.iter_alive() {
+                        if npc.npc_type == 158 || npc.npc_type == 301 {
+                            npc_list.create_death_smoke(npc.x, npc.y, npc.display_bounds.right, 4, state, &npc.rng);
+                            npc.cond.set_alive(false);
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        if shock {
+            state.quake_counter = 20;
+
+            let mini_action = match self.parts[0].action_num {
+                201 | 211 => Some(10),
+                221 => Some(20),
+                231 => Some(30),
+                _ => None,
+            };
+
+            if let Some(action_num) = mini_action {
+                self.parts[1].action_num = action_num;
+                self.parts[2].action_num = action_num;
+                self.parts[6].action_num = action_num;
+                self.parts[7].action_num = action_num;
+            }
+
+            state.sound_manager.play_sfx(26);
+
+            for _ in 0..8 {
+                let mut npc = NPC::create(4, &state.npc_table);
+                npc.cond.set_alive(true);
+                npc.x = self.parts[4].x + self.parts[0].rng.range(-32..16) * 0x200;
+                npc.y = self.parts[4].y;
+
+                let _ = npc_list.spawn(0x100, npc);
+            }
+        }
+
+        if self.parts[0].action_num >= 200 && self.parts[0].action_num < 300 {
+            if self.parts[0].x < 400 * 0x200 {
+                self.parts[0].direction = Direction::Right;
+            }
+            if self.parts[0].x > 560 * 0x200 {
+                self.parts[0].direction = Direction::Left;
+            }
+
+            if self.parts[0].direction == Direction::Left {
+                self.parts[0].vel_x -= 4;
+            } else {
+                self.parts[0].vel_x += 4;
+            }
+
+            // the island is falling, keep feeding platforms in from the right edge
+            self.parts[0].vel_x2 += 1;
+            if self.parts[0].vel_x2 == 150 {
+                self.parts[0].vel_x2 = 0;
+                self.tick_b07_undead_core_spawn_platform(self.parts[0].rng.range(2..6), state, npc_list, stage);
+            } else if self.parts[0].vel_x2 == 75 {
+                self.tick_b07_undead_core_spawn_platform(self.parts[0].rng.range(0..3), state, npc_list, stage);
+            }
+        }
+
+        self.parts[0].vel_x = clamp(self.parts[0].vel_x, -0x80, 0x80);
+        self.parts[0].vel_y = clamp(self.parts[0].vel_y, -0x80, 0x80);
+
+        self.parts[0].x += self.parts[0].vel_x;
+        self.parts[0].y += self.parts[0].vel_y;
+
+        self.tick_b07_undead_core_face(3, state, npc_list);
+        self.tick_b07_undead_core_head(4, state);
+        self.tick_b07_undead_core_tail(5, state);
+        self.tick_b07_undead_core_small_head(1, state, stage);
+        self.tick_b07_undead_core_small_head(2, state, stage);
+        self.tick_b07_undead_core_small_head(6, state, stage);
+        self.tick_b07_undead_core_small_head(7, state, stage);
+        self.tick_b07_undead_core_hitbox(8);
+        self.tick_b07_undead_core_hitbox(9);
+        self.tick_b07_undead_core_hitbox(10);
+        self.tick_b07_undead_core_hitbox(11);
+    }
+
+    fn tick_b07_undead_core_open(&mut self) {
+        self.parts[8].npc_flags.set_invulnerable(true);
+        self.parts[9].npc_flags.set_invulnerable(true);
+        self.parts[10].npc_flags.set_invulnerable(true);
+        self.parts[11].npc_flags.set_shootable(true);
+    }
+
+    fn tick_b07_undead_core_flash(&mut self) {
+        self.parts[0].anim_counter = self.parts[0].anim_counter.wrapping_add(1);
+
+        if self.parts[0].shock > 0 && self.parts[0].anim_counter / 2 % 2 != 0 {
+            self.parts[4].anim_num = 1;
+            self.parts[5].anim_num = 1;
+        } else {
+            self.parts[4].anim_num = 0;
+            self.parts[5].anim_num = 0;
+        }
+    }
+
+    fn tick_b07_undead_core_spawn_rock(&mut self, direction: Direction, state: &mut SharedGameState, npc_list: &NPCList) {
+        let mut npc = NPC::create(288, &state.npc_table);
+        npc.cond.set_alive(true);
+        npc.x = self.parts[0].x - 32 * 0x200;
+        npc.y = if direction == Direction::Up {
+            self.parts[0].y - 16 * 0x200
+        } else {
+            self.parts[0].y + 16 * 0x200
+        };
+        npc.direction = direction;
+
+        let _ = npc_list.spawn(0x20, npc);
+    }
+
+    fn tick_b07_undead_core_spawn_spiral(&mut self, x: i32, y: i32, state: &mut SharedGameState, npc_list: &NPCList) {
+        for phase in [0, 0x80].iter() {
+            let mut npc = NPC::create(285, &state.npc_table);
+            npc.cond.set_alive(true);
+            npc.x = x;
+            npc.y = y;
+            npc.direction = Direction::Left;
+            npc.action_counter2 = *phase;
+
+            let _ = npc_list.spawn(0x100, npc);
+        }
+    }
+
+    fn tick_b07_undead_core_spawn_platform(&mut self, tile_y: i32, state: &mut SharedGameState, npc_list: &NPCList, stage: &Stage) {
+        let mut npc = NPC::create(282, &state.npc_table);
+        npc.cond.set_alive(true);
+        npc.x = stage.map.width as i32 * 16 * 0x200 + 0x40;
+        npc.y = tile_y * 32 * 0x200 + 32 * 0x200;
+
+        let _ = npc_list.spawn(0x30, npc);
+    }
+
+    fn tick_b07_undead_core_face(&mut self, i: usize, state: &mut SharedGameState, npc_list: &NPCList) {
+        match self.parts[i].action_num {
+            0 => {
+                self.parts[i].anim_num = 0;
+            }
+            10 => {
+                self.parts[i].anim_num = 1;
+            }
+            20 => {
+                self.parts[i].anim_num = 2;
+            }
+            30 | 31 => {
+                if self.parts[i].action_num == 30 {
+                    self.parts[i].action_num = 31;
+                    self.parts[i].anim_num = 3;
+                    self.parts[i].action_counter = 100;
+                }
+
+                self.parts[i].action_counter += 1;
+                if self.parts[i].action_counter > 300 {
+                    self.parts[i].action_counter = 0;
+                }
+
+                if self.parts[i].action_counter > 250 && self.parts[i].action_counter % 16 == 1 {
+                    state.sound_manager.play_sfx(26);
+                }
+
+                if self.parts[i].action_counter > 250 && self.parts[i].action_counter % 16 == 7 {
+                    let mut npc = NPC::create(293, &state.npc_table);
+                    npc.cond.set_alive(true);
+                    npc.x = self.parts[i].x;
+                    npc.y = self.parts[i].y;
+
+                    let _ = npc_list.spawn(0x80, npc);
+
+                    state.sound_manager.play_sfx(101);
+                }
+
+                if self.parts[i].action_counter == 200 {
+                    state.sound_manager.play_sfx(116);
+                }
+
+                self.parts[i].anim_num = if self.parts[i].action_counter > 200 && self.parts[i].action_counter % 2 != 0 { 4 } else { 3 };
+            }
+            _ => {}
+        }
+
+        self.parts[i].x = self.parts[0].x - 36 * 0x200;
+        self.parts[i].y = self.parts[0].y + 4 * 0x200;
+
+        self.parts[i].anim_rect = state.constants.npc.b07_undead_core[self.parts[i].anim_num as usize];
+    }
+
+    fn tick_b07_undead_core_head(&mut self, i: usize, state: &mut SharedGameState) {
+        self.parts[i].x = self.parts[0].x - 36 * 0x200;
+        self.parts[i].y = self.parts[0].y;
+
+        self.parts[i].anim_rect = state.constants.npc.b07_undead_core[5 + self.parts[i].anim_num as usize];
+    }
+
+    fn tick_b07_undead_core_tail(&mut self, i: usize, state: &mut SharedGameState) {
+        self.parts[i].x = self.parts[0].x + 44 * 0x200;
+        self.parts[i].y = self.parts[0].y;
+
+        self.parts[i].anim_rect = state.constants.npc.b07_undead_core[8 + self.parts[i].anim_num as usize];
+    }
+
+    fn tick_b07_undead_core_small_head(&mut self, i: usize, state: &mut SharedGameState, stage: &Stage) {
+        if !self.parts[i].cond.alive() {
+            return;
+        }
+
+        self.parts[i].life = 1000;
+
+        match self.parts[i].action_num {
+            5 => {
+                self.parts[i].anim_num = 0;
+                self.parts[i].npc_flags.set_shootable(false);
+                self.parts[i].action_counter2 = (self.parts[i].action_counter2 + 1) % 0x100;
+            }
+            10 => {
+                self.parts[i].anim_num = 0;
+                self.parts[i].npc_flags.set_shootable(false);
+                self.parts[i].action_counter2 = (self.parts[i].action_counter2 + 2) % 0x100;
+            }
+            20 => {
+                self.parts[i].anim_num = 1;
+                self.parts[i].npc_flags.set_shootable(false);
+                self.parts[i].action_counter2 = (self.parts[i].action_counter2 + 2) % 0x100;
+            }
+            30 => {
+                self.parts[i].anim_num = 0;
+                self.parts[i].npc_flags.set_shootable(false);
+                self.parts[i].action_counter2 = (self.parts[i].action_counter2 + 4) % 0x100;
+            }
+            200 | 201 => {
+                if self.parts[i].action_num == 200 {
+                    self.parts[i].action_num = 201;
+                    self.parts[i].anim_num = 2;
+                    self.parts[i].vel_x = 0;
+                    self.parts[i].vel_y = 0;
+                }
+
+                self.parts[i].vel_x += 0x20;
+                self.parts[i].x += self.parts[i].vel_x;
+
+                if self.parts[i].x > (stage.map.width as i32 + 2) * 16 * 0x200 {
+                    self.parts[i].cond.set_alive(false);
+                }
+            }
+            _ => {}
+        }
+
+        if self.parts[i].action_num < 50 {
+            let deg = ((self.parts[i].action_counter2 as i32 + self.parts[i].vel_x2) / 2 % 0x100) as f64 * CDEG_RAD;
+
+            self.parts[i].x = self.parts[0].x - 8 * 0x200 + (deg.cos() * 48.0 * 512.0) as i32;
+            self.parts[i].y = self.parts[0].y + (deg.sin() * 80.0 * 512.0) as i32;
+        }
+
+        self.parts[i].anim_rect = state.constants.npc.b07_undead_core[11 + self.parts[i].anim_num as usize];
+    }
 
+    fn tick_b07_undead_core_hitbox(&mut self, i: usize) {
+        match self.parts[i].target_x {
+            0 => {
+                self.parts[i].x = self.parts[0].x;
+                self.parts[i].y = self.parts[0].y - 32 * 0x200;
+            }
+            1 => {
+                self.parts[i].x = self.parts[0].x + 28 * 0x200;
+                self.parts[i].y = self.parts[0].y;
+            }
+            2 => {
+                self.parts[i].x = self.parts[0].x + 4 * 0x200;
+                self.parts[i].y = self.parts[0].y + 32 * 0x200;
+            }
+            3 => {
+                self.parts[i].x = self.parts[0].x - 28 * 0x200;
+                self.parts[i].y = self.parts[0].y + 4 * 0x200;
+            }
+            _ => {}
+        }
     }
 }
@@ -146,8 +146,8 @@ impl NPC {
     }
 }
 
-impl GameEntity<([&mut Player; 2], &NPCList, &mut Stage, &BulletManager)> for NPC {
-    fn tick(&mut self, state: &mut SharedGameState, (players, npc_list, stage, _bullet_manager): ([&mut Player; 2], &NPCList, &mut Stage, &BulletManager)) -> GameResult {
+impl GameEntity<([&mut Player; 2], &NPCList, &mut Stage, &mut BulletManager)> for NPC {
+    fn tick(&mut self, state: &mut SharedGameState, (players, npc_list, stage, bullet_manager): ([&mut Player; 2], &NPCList, &mut Stage, &mut BulletManager)) -> GameResult {
         match self.npc_type {
             0 => self.tick_n000_null(),
             1 => self.tick_n001_experience(state),
@@ -199,12 +199,13 @@ impl GameEntity<([&mut Player; 2], &NPCList, &mut Stage, &BulletManager)> for NP
             47 => self.tick_n047_sandcroc(state, players),
             48 => self.tick_n048_omega_projectiles(state),
             49 => self.tick_n049_skullhead(state, npc_list),
+            50 => self.tick_n050_skeleton_projectile(state),
             52 => self.tick_n052_sitting_blue_robot(state),
             55 => self.tick_n055_kazuma(state),
             58 => self.tick_n058_basu(state, players, npc_list),
             59 => self.tick_n059_eye_door(state, players),
             60 => self.tick_n060_toroko(state, players),
-            61 => self.tick_n061_king(state),
+            61 => self.tick_n061_king(state, npc_list),
             62 => self.tick_n062_kazuma_computer(state),
             63 => self.tick_n063_toroko_stick(state),
             64 => self.tick_n064_first_cave_critter(state, players),
@@ -257,25 +258,253 @@ impl GameEntity<([&mut Player; 2], &NPCList, &mut Stage, &BulletManager)> for NP
             112 => self.tick_n112_quote_teleport_in(state, players),
             113 => self.tick_n113_professor_booster(state),
             114 => self.tick_n114_press(state, players, npc_list),
+            115 => self.tick_n115_ravil(state, players, npc_list),
+            116 => self.tick_n116_red_petals(state),
+            117 => self.tick_n117_curly(state, players, npc_list),
+            118 => self.tick_n118_curly_boss(state, players, npc_list, bullet_manager),
+            119 => self.tick_n119_table_chair(state),
+            120 => self.tick_n120_colon_a(state),
+            121 => self.tick_n121_colon_b(state),
+            122 => self.tick_n122_colon_enraged(state, players),
+            123 => self.tick_n123_curly_boss_bullet(state),
             124 => self.tick_n124_sunstone(state),
             125 => self.tick_n125_hidden_item(state, npc_list),
+            126 => self.tick_n126_puppy_running(state, players),
+            127 => self.tick_n127_machine_gun_trail_l2(state),
+            128 => self.tick_n128_machine_gun_trail_l3(state),
             129 => self.tick_n129_fireball_snake_trail(state),
+            130 => self.tick_n130_puppy_sitting(state, players),
+            131 => self.tick_n131_puppy_sleeping(state),
+            132 => self.tick_n132_puppy_barking(state, players),
+            133 => self.tick_n133_jenka(state),
+            134 => self.tick_n134_armadillo(state, players, bullet_manager),
+            135 => self.tick_n135_skeleton(state, players, npc_list),
+            136 => self.tick_n136_puppy_carried(state, players),
+            137 => self.tick_n137_large_door_frame(state),
+            138 => self.tick_n138_large_door(state),
+            139 => self.tick_n139_doctor(state),
+            140 => self.tick_n140_toroko_frenzied(state, players, npc_list, bullet_manager),
+            141 => self.tick_n141_toroko_block_projectile(state, players, npc_list),
+            142 => self.tick_n142_flower_cub(state, players),
+            143 => self.tick_n143_jenka_collapsed(state),
+            144 => self.tick_n144_toroko_teleporting_in(state),
+            145 => self.tick_n145_king_sword(state, npc_list),
+            146 => self.tick_n146_lighting(state, npc_list),
+            147 => self.tick_n147_critter_purple(state, players, npc_list),
+            148 => self.tick_n148_critter_purple_projectile(state),
             149 => self.tick_n149_horizontal_moving_block(state, players, npc_list),
             150 => self.tick_n150_quote(state, players, npc_list),
             151 => self.tick_n151_blue_robot_standing(state),
+            152 => self.tick_n152_shutter_stuck(),
+            153 => self.tick_n153_gaudi(state, players),
             154 => self.tick_n154_gaudi_dead(state),
+            155 => self.tick_n155_gaudi_flying(state, players, npc_list),
             156 => self.tick_n156_gaudi_projectile(state),
             157 => self.tick_n157_vertical_moving_block(state, players, npc_list),
             158 => self.tick_n158_fish_missile(state, players),
+            159 => self.tick_n159_monster_x_defeated(state, npc_list),
+            160 => self.tick_n160_puu_black(state, players, npc_list),
+            161 => self.tick_n161_puu_black_projectile(state),
+            162 => self.tick_n162_puu_black_dead(state, players, npc_list),
+            163 => self.tick_n163_dr_gero(state),
+            164 => self.tick_n164_nurse_hasumi(state),
+            165 => self.tick_n165_curly_collapsed(state, players),
+            166 => self.tick_n166_chaba(state),
+            167 => self.tick_n167_booster_falling(state, npc_list),
+            168 => self.tick_n168_boulder(state),
+            169 => self.tick_n169_balrog_shooting_missiles(state, players, npc_list),
+            170 => self.tick_n170_balrog_missile(state, players, npc_list),
+            171 => self.tick_n171_fire_whirrr(state, players, npc_list),
+            172 => self.tick_n172_fire_whirrr_projectile(state),
+            173 => self.tick_n173_gaudi_armored(state, players, npc_list),
+            174 => self.tick_n174_gaudi_armored_projectile(state),
+            175 => self.tick_n175_gaudi_egg(state, npc_list),
+            176 => self.tick_n176_buyo_buyo_base(state, players, npc_list),
+            177 => self.tick_n177_buyo_buyo(state, players),
+            178 => self.tick_n178_core_blade_projectile(state),
+            179 => self.tick_n179_core_wisp_projectile(state),
+            180 => self.tick_n180_curly_ai(state, players, npc_list),
+            181 => self.tick_n181_curly_ai_machine_gun(state, npc_list, bullet_manager),
+            182 => self.tick_n182_curly_ai_polar_star(state, npc_list, bullet_manager),
+            183 => self.tick_n183_curly_air_tank_bubble(state, npc_list),
+            184 => self.tick_n184_shutter(state, npc_list),
+            185 => self.tick_n185_small_shutter(state),
+            186 => self.tick_n186_lift_block(state),
+            187 => self.tick_n187_fuzz_core(state, players, npc_list),
+            188 => self.tick_n188_fuzz(state, players, npc_list),
+            189 => self.tick_n189_homing_flame(state, players),
+            190 => self.tick_n190_broken_robot(state, npc_list),
+            191 => self.tick_n191_water_level(state),
             192 => self.tick_n192_scooter(state),
             193 => self.tick_n193_broken_scooter(state),
             194 => self.tick_n194_broken_blue_robot(state),
+            195 => self.tick_n195_grate(state),
+            196 => self.tick_n196_ironhead_motion_wall(state),
+            197 => self.tick_n197_porcupine_fish(state, npc_list),
+            198 => self.tick_n198_ironhead_projectile(state),
             199 => self.tick_n199_wind_particles(state),
+            200 => self.tick_n200_zombie_dragon(state, players, npc_list),
+            201 => self.tick_n201_zombie_dragon_dead(state),
+            202 => self.tick_n202_zombie_dragon_projectile(state),
+            203 => self.tick_n203_critter_destroyed_egg_corridor(state, players),
+            204 => self.tick_n204_small_falling_spike(state, players, npc_list),
+            205 => self.tick_n205_large_falling_spike(state, players, npc_list),
+            206 => self.tick_n206_counter_bomb(state, players, npc_list),
+            207 => self.tick_n207_counter_bomb_countdown(state),
+            208 => self.tick_n208_basu_destroyed_egg_corridor(state, players, npc_list),
+            209 => self.tick_n209_basu_projectile_destroyed_egg_corridor(state),
+            210 => self.tick_n210_destroyed_egg_corridor(state, players),
             211 => self.tick_n211_small_spikes(state),
+            212 => self.tick_n212_sky_dragon(state, npc_list),
+            213 => self.tick_n213_night_spirit(state, players, npc_list),
+            214 => self.tick_n214_night_spirit_projectile(state, npc_list),
+            215 => self.tick_n215_sandcroc_outer_wall(state, players),
+            216 => self.tick_n216_debug_cat(state),
+            217 => self.tick_n217_itoh(state),
+            218 => self.tick_n218_core_giant_ball(state),
+            219 => self.tick_n219_smoke_generator(state, npc_list),
+            220 => self.tick_n220_shovel_brigade(state),
+            221 => self.tick_n221_shovel_brigade_walking(state),
+            222 => self.tick_n222_prison_bars(state),
+            223 => self.tick_n223_momorin(state, players),
+            224 => self.tick_n224_chie(state, players),
+            225 => self.tick_n225_megane(state),
+            226 => self.tick_n226_kanpachi_plantation(state),
+            227 => self.tick_n227_bucket(state),
+            228 => self.tick_n228_droll(state, players),
+            229 => self.tick_n229_red_flowers_sprouts(state),
+            230 => self.tick_n230_red_flowers_blooming(state),
+            231 => self.tick_n231_rocket(state, players, npc_list),
+            232 => self.tick_n232_orangebell(state, npc_list),
+            233 => self.tick_n233_orangebell_bat(state, players, npc_list),
             234 => self.tick_n234_red_flowers_picked(state),
+            235 => self.tick_n235_midorin(state),
+            236 => self.tick_n236_gunfish(state, players, npc_list),
+            237 => self.tick_n237_gunfish_projectile(state, npc_list),
+            238 => self.tick_n238_press_sideways(state, players, npc_list),
+            239 => self.tick_n239_cage_bars(state),
+            240 => self.tick_n240_mimiga_jailed(state),
+            241 => self.tick_n241_critter_red(state, players),
+            242 => self.tick_n242_bat_last_cave(state, stage),
+            243 => self.tick_n243_bat_generator(state, npc_list),
+            244 => self.tick_n244_lava_drop(state, stage),
+            245 => self.tick_n245_lava_drop_generator(state, npc_list),
+            246 => self.tick_n246_press_proximity(state, players, npc_list),
+            247 => self.tick_n247_misery_boss(state, players, npc_list),
+            248 => self.tick_n248_misery_boss_vanishing(state),
+            249 => self.tick_n249_misery_boss_energy_shot(state),
+            250 => self.tick_n250_misery_boss_lighting_ball(state, players, npc_list),
+            251 => self.tick_n251_misery_boss_lighting(state, npc_list),
+            252 => self.tick_n252_misery_boss_bats(state, players, npc_list),
+            253 => self.tick_n253_experience_capsule(state, npc_list),
+            254 => self.tick_n254_helicopter(state, npc_list),
+            255 => self.tick_n255_helicopter_blades(state, npc_list),
+            256 => self.tick_n256_doctor_facing_away(state, npc_list),
+            257 => self.tick_n257_red_crystal(state),
+            258 => self.tick_n258_mimiga_sleeping(state),
+            259 => self.tick_n259_curly_unconcious(state, players, npc_list),
+            260 => self.tick_n260_shovel_brigade_caged(state, players),
+            261 => self.tick_n261_chie_caged(state, players),
+            262 => self.tick_n262_chaco_caged(state, players),
+            263 => self.tick_n263_doctor_boss(state, players, npc_list, stage),
+            264 => self.tick_n264_doctor_boss_red_wave_projectile(state, npc_list, stage),
+            265 => self.tick_n265_doctor_boss_red_ball_projectile(state),
+            266 => self.tick_n266_doctor_boss_red_ball_projectile_bouncing(state, npc_list),
+            267 => self.tick_n267_muscle_doctor(state, players, npc_list),
+            268 => self.tick_n268_igor_enemy(state, players, npc_list),
+            269 => self.tick_n269_red_bat_bouncing(state),
+            270 => self.tick_n270_doctor_red_energy(state, npc_list),
+            271 => self.tick_n271_ironhead_block(state, npc_list, stage),
+            272 => self.tick_n272_ironhead_block_generator(state, npc_list),
+            273 => self.tick_n273_droll_projectile(state, npc_list),
+            274 => self.tick_n274_droll(state, players, npc_list),
+            275 => self.tick_n275_puppy_plantation(state, players),
+            276 => self.tick_n276_red_demon(state, players, npc_list),
+            277 => self.tick_n277_red_demon_projectile(state, npc_list),
+            278 => self.tick_n278_little_family(state),
+            279 => self.tick_n279_large_falling_block(state, players, npc_list, stage),
+            280 => self.tick_n280_sue_teleported(state),
+            281 => self.tick_n281_doctor_energy_form(state, npc_list),
+            282 => self.tick_n282_mini_undead_core_active(state, players),
+            283 => self.tick_n283_misery_possessed(state, players, npc_list),
+            284 => self.tick_n284_sue_possessed(state, players),
+            285 => self.tick_n285_undead_core_spiral_projectile(state, npc_list, stage),
+            286 => self.tick_n286_undead_core_spiral_projectile_trail(state),
+            287 => self.tick_n287_orange_smoke(state),
+            288 => self.tick_n288_undead_core_exploding_rock(state, players, npc_list, stage),
+            289 => self.tick_n289_critter_orange(state, players, stage),
+            290 => self.tick_n290_bat_misery(state, players),
+            291 => self.tick_n291_mini_undead_core_inactive(state),
+            292 => self.tick_n292_quake(state),
+            293 => self.tick_n293_undead_core_energy_shot(state, npc_list),
+            294 => self.tick_n294_quake_falling_block_generator(state, players, npc_list, stage),
+            295 => self.tick_n295_cloud(state),
+            296 => self.tick_n296_cloud_generator(state, npc_list),
+            297 => self.tick_n297_sue_dragon_mouth(state, npc_list),
             298 => self.tick_n298_intro_doctor(state),
             299 => self.tick_n299_intro_balrog_misery(state),
             300 => self.tick_n300_intro_demon_crown(state),
+            301 => self.tick_n301_misery_fish_missile(state, players),
+            302 => self.tick_n302_camera_focus_marker(players),
+            303 => self.tick_n303_curly_machine_gun(state, npc_list),
+            304 => self.tick_n304_gaudi_hospital(state),
+            305 => self.tick_n305_small_puppy(state),
+            306 => self.tick_n306_balrog_nurse(state),
+            307 => self.tick_n307_santa_caged(state, players),
+            308 => self.tick_n308_stumpy(state, players),
+            309 => self.tick_n309_bute(state, players),
+            310 => self.tick_n310_bute_sword(state, players),
+            311 => self.tick_n311_bute_archer(state, players, npc_list),
+            312 => self.tick_n312_bute_arrow_projectile(state),
+            313 => self.tick_n313_ma_pignon(state, players, npc_list, stage),
+            314 => self.tick_n314_ma_pignon_rock(state, stage),
+            315 => self.tick_n315_ma_pignon_clone(state, players),
+            316 => self.tick_n316_bute_dead(state),
+            317 => self.tick_n317_mesa(state, players, npc_list),
+            318 => self.tick_n318_mesa_dead(state),
+            319 => self.tick_n319_mesa_block(state, players, npc_list),
+            320 => self.tick_n320_curly_carried(state, players, npc_list),
+            321 => self.tick_n321_curly_nemesis(state, players, npc_list, bullet_manager),
+            322 => self.tick_n322_deleet(state, npc_list, stage),
+            323 => self.tick_n323_bute_spinning(state, players, npc_list),
+            324 => self.tick_n324_bute_generator(state, npc_list),
+            325 => self.tick_n325_heavy_press_lighting(state, npc_list),
+            326 => self.tick_n326_sue_itoh_human_transition(state, npc_list),
+            327 => self.tick_n327_sneeze(state, npc_list),
+            328 => self.tick_n328_human_transform_machine(state),
+            329 => self.tick_n329_laboratory_fan(state),
+            330 => self.tick_n330_rolling(state, npc_list, stage),
+            331 => self.tick_n331_ballos_bone_projectile(state),
+            332 => self.tick_n332_ballos_shockwave(state, npc_list),
+            333 => self.tick_n333_ballos_lighting(state),
+            334 => self.tick_n334_sweat(state, players),
+            335 => self.tick_n335_ikachan(state),
+            336 => self.tick_n336_ikachan_generator(state, players, npc_list),
+            337 => self.tick_n337_numahachi(state),
+            338 => self.tick_n338_green_devil(state, stage),
+            339 => self.tick_n339_green_devil_generator(state, npc_list),
+            340 => self.tick_n340_ballos(state, players, npc_list),
+            341 => self.tick_n341_ballos_1_head(state, npc_list),
+            342 => self.tick_n342_ballos_1_eye(state),
+            343 => self.tick_n343_ballos_2_cutscene(state, npc_list),
+            344 => self.tick_n344_ballos_2_eyes(state, npc_list),
+            345 => self.tick_n345_ballos_skull_projectile(state, npc_list, stage),
+            346 => self.tick_n346_ballos_orbiting_platform(state),
+            347 => self.tick_n347_hoppy(state, players),
+            348 => self.tick_n348_ballos_4_spikes(state),
+            349 => self.tick_n349_statue(state),
+            350 => self.tick_n350_flying_bute_archer(state, players, npc_list, stage),
+            351 => self.tick_n351_statue_shootable(state, npc_list),
+            352 => self.tick_n352_ending_characters(state),
+            353 => self.tick_n353_bute_sword_flying(state, players),
+            354 => self.tick_n354_invisible_deathtrap_wall(state, npc_list, stage),
+            355 => self.tick_n355_quote_and_curly_on_balrog(state, npc_list),
+            356 => self.tick_n356_balrog_rescuing(state, npc_list),
+            357 => self.tick_n357_puppy_ghost(state),
+            358 => self.tick_n358_misery_credits(state),
+            359 => self.tick_n359_water_droplet_generator(state, players, npc_list),
+            360 => self.tick_n360_credits_thank_you(state),
+            361 => self.tick_n361_gaudi_dashing(state, npc_list),
             _ => Ok(()),
         }?;
 
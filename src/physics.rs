@@ -467,5 +467,9 @@ pub trait PhysicalEntity {
                 _ => {}
             }
         }
+
+        if self.y() > state.water_level + 4 * 0x200 {
+            self.flags().set_in_water(true);
+        }
     }
 }
@@ -6,12 +6,14 @@ use num_traits::clamp;
 
 use crate::caret::CaretType;
 use crate::common::{Condition, Direction, Equipment, Flag, interpolate_fix9_scale, Rect};
+use crate::engine_constants::EngineConstants;
 use crate::entity::GameEntity;
 use crate::frame::Frame;
 use crate::input::dummy_player_controller::DummyPlayerController;
 use crate::input::player_controller::PlayerController;
 use crate::npc::NPC;
 use crate::rng::RNG;
+use crate::screen_effect::ScreenEffectType;
 use crate::shared_game_state::SharedGameState;
 use crate::npc::list::NPCList;
 
@@ -76,6 +78,8 @@ pub struct Player {
     pub current_weapon: u8,
     pub stars: u8,
     pub damage: u16,
+    /// Set whenever the player takes damage, cleared by `<UNJ`.
+    pub was_damaged: bool,
     pub air_counter: u16,
     pub air: u16,
     pub appearance: PlayerAppearance,
@@ -95,8 +99,7 @@ pub struct Player {
 }
 
 impl Player {
-    pub fn new(state: &mut SharedGameState) -> Player {
-        let constants = &state.constants;
+    pub fn new(constants: &EngineConstants) -> Player {
 
         Player {
             x: 0,
@@ -129,6 +132,7 @@ impl Player {
             booster_switch: 0,
             stars: 0,
             damage: 0,
+            was_damaged: false,
             air_counter: 0,
             air: 0,
             appearance: PlayerAppearance::Quote,
@@ -511,8 +515,97 @@ impl Player {
         Ok(())
     }
 
-    fn tick_ironhead(&mut self, _state: &mut SharedGameState) -> GameResult {
-        // todo ironhead boss controls
+    fn tick_ironhead(&mut self, state: &mut SharedGameState) -> GameResult {
+        self.up = false;
+        self.down = false;
+
+        if state.control_flags.control_enabled() {
+            if self.controller.move_left() || self.controller.move_right() {
+                if self.controller.move_left() {
+                    self.vel_x -= 0x100;
+                }
+
+                if self.controller.move_right() {
+                    self.vel_x += 0x100;
+                }
+            } else if self.vel_x < 0x80 && self.vel_x > -0x80 {
+                self.vel_x = 0;
+            } else if self.vel_x > 0 {
+                self.vel_x -= 0x80;
+            } else if self.vel_x < 0 {
+                self.vel_x += 0x80;
+            }
+
+            if self.controller.move_up() || self.controller.move_down() {
+                if self.controller.move_up() {
+                    self.vel_y -= 0x100;
+                }
+
+                if self.controller.move_down() {
+                    self.vel_y += 0x100;
+                }
+            } else if self.vel_y < 0x80 && self.vel_y > -0x80 {
+                self.vel_y = 0;
+            } else if self.vel_y > 0 {
+                self.vel_y -= 0x80;
+            } else if self.vel_y < 0 {
+                self.vel_y += 0x80;
+            }
+        } else {
+            if self.vel_x < 0x80 && self.vel_x > -0x40 {
+                self.vel_x = 0;
+            } else if self.vel_x > 0 {
+                self.vel_x -= 0x80;
+            } else if self.vel_x < 0 {
+                self.vel_x += 0x80;
+            }
+
+            if self.vel_y < 0x80 && self.vel_y > -0x80 {
+                self.vel_y = 0;
+            } else if self.vel_y > 0 {
+                self.vel_y -= 0x80;
+            } else if self.vel_y < 0 {
+                self.vel_y += 0x80;
+            }
+        }
+
+        if self.vel_y < -0x200 && self.flags.hit_top_wall() {
+            state.create_caret(self.x, self.y - self.hit_bounds.top as i32, CaretType::LittleParticles, Direction::FacingPlayer);
+        }
+
+        if self.vel_y > 0x200 && self.flags.hit_bottom_wall() {
+            state.create_caret(self.x, self.y + self.hit_bounds.bottom as i32, CaretType::LittleParticles, Direction::FacingPlayer);
+        }
+
+        self.vel_x = clamp(self.vel_x, -0x400, 0x400);
+        self.vel_y = clamp(self.vel_y, -0x400, 0x400);
+
+        // diagonal movement is slowed down to match the speed of the straight one
+        if state.control_flags.control_enabled() {
+            if self.controller.move_left() && self.controller.move_up() {
+                self.vel_x = self.vel_x.max(-780);
+                self.vel_y = self.vel_y.max(-780);
+            }
+
+            if self.controller.move_right() && self.controller.move_up() {
+                self.vel_x = self.vel_x.min(780);
+                self.vel_y = self.vel_y.max(-780);
+            }
+
+            if self.controller.move_left() && self.controller.move_down() {
+                self.vel_x = self.vel_x.max(-780);
+                self.vel_y = self.vel_y.min(780);
+            }
+
+            if self.controller.move_right() && self.controller.move_down() {
+                self.vel_x = self.vel_x.min(780);
+                self.vel_y = self.vel_y.min(780);
+            }
+        }
+
+        self.x += self.vel_x;
+        self.y += self.vel_y;
+
         Ok(())
     }
 
@@ -623,6 +716,7 @@ impl Player {
 
         state.sound_manager.play_sfx(16);
         self.shock_counter = 128;
+        self.was_damaged = true;
         self.cond.set_interacted(false);
 
         if self.control_mode == ControlMode::Normal {
@@ -645,6 +739,7 @@ impl Player {
             state.textscript_vm.start_script(40);
 
             state.create_caret(self.x, self.y, CaretType::Explosion, Direction::Left);
+            state.create_screen_effect(0, 0, ScreenEffectType::WhiteOut);
             let mut npc = NPC::create(4, &state.npc_table);
             npc.cond.set_alive(true);
             for _ in 0..0x40 {
@@ -11,6 +11,7 @@ use crate::npc::NPC;
 use crate::physics::PhysicalEntity;
 use crate::player::{ControlMode, Player, TargetPlayer};
 use crate::shared_game_state::SharedGameState;
+use crate::weapon::WeaponType;
 
 impl PhysicalEntity for Player {
     #[inline(always)]
@@ -272,7 +273,12 @@ impl Player {
                 }
                 // missile pickup
                 86 => {
-                    // todo add bullets
+                    if let Some(weapon) = inventory.get_weapon_by_type_mut(WeaponType::MissileLauncher) {
+                        weapon.refill_ammo(npc.exp);
+                    } else if let Some(weapon) = inventory.get_weapon_by_type_mut(WeaponType::SuperMissileLauncher) {
+                        weapon.refill_ammo(npc.exp);
+                    }
+
                     npc.cond.set_alive(false);
 
                     state.sound_manager.play_sfx(42);
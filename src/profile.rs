@@ -1,17 +1,23 @@
 use std::io;
+use std::io::Read;
 
+use bitvec::vec::BitVec;
 use byteorder::{BE, LE, ReadBytesExt, WriteBytesExt};
 use num_traits::{clamp, FromPrimitive};
 
 use crate::common::{Direction, FadeState};
+#[cfg(test)]
+use crate::engine_constants::EngineConstants;
 use ggez::{Context, GameResult};
 use ggez::GameError::ResourceLoadError;
-use crate::player::ControlMode;
+use crate::inventory::Inventory;
+use crate::player::{ControlMode, Player, PlayerAppearance};
 use crate::scene::game_scene::GameScene;
 use crate::shared_game_state::SharedGameState;
 use crate::str;
 use crate::weapon::{WeaponLevel, WeaponType};
 
+#[derive(Clone, Copy, Default)]
 pub struct WeaponData {
     pub weapon_id: u32,
     pub level: u32,
@@ -20,11 +26,67 @@ pub struct WeaponData {
     pub ammo: u32,
 }
 
+#[derive(Clone, Copy, Default)]
 pub struct TeleporterSlotData {
     pub index: u32,
     pub event_num: u32,
 }
 
+/// Extra data stored after the flags by Cave Story+.
+#[derive(Clone, Copy, Default)]
+pub struct CSPlusProfileData {
+    pub unknown: u32,
+    /// Unix timestamp of when the profile was saved.
+    pub timestamp: u64,
+    pub difficulty: u8,
+    pub reserved: [u8; 15],
+}
+
+/// State of the second player, stored in `ExtendedProfileData`.
+pub struct Player2Data {
+    pub pos_x: i32,
+    pub pos_y: i32,
+    pub direction: Direction,
+    pub max_life: u16,
+    pub stars: u16,
+    pub life: u16,
+    pub equipment: u32,
+    pub current_weapon: u32,
+    pub current_item: u32,
+    pub weapon_data: [WeaponData; 8],
+    pub items: [u32; 32],
+    pub item_amounts: [u16; 32],
+}
+
+/// State that doesn't fit in the original profile layout, stored after the flags of single-slot profiles.
+/// The original game ignores it, Cave Story+ slots have a fixed size and don't include it.
+pub struct ExtendedProfileData {
+    pub item_amounts: [u16; 32],
+    pub skip_flags: [u8; 8],
+    pub player2: Option<Player2Data>,
+}
+
+/// Parts of `SharedGameState` and `GameScene` stored in a profile.
+pub struct ProfileState<'a> {
+    pub player1: &'a mut Player,
+    pub player2: &'a mut Player,
+    pub inventory_player1: &'a mut Inventory,
+    pub inventory_player2: &'a mut Inventory,
+    pub teleporter_slots: &'a mut Vec<(u16, u16)>,
+    pub map_flags: &'a mut BitVec,
+    pub game_flags: &'a mut BitVec,
+    pub skip_flags: &'a mut BitVec,
+    pub play_time: &'a mut u32,
+}
+
+/// Size of a freeware profile.
+pub const PROFILE_SIZE: usize = 0x604;
+/// Size of a single slot in Cave Story+ multi-slot profile.
+pub const CS_PLUS_PROFILE_SIZE: usize = 0x620;
+/// "DRSX", marks the start of `ExtendedProfileData`.
+const EXT_SIGNATURE: u32 = 0x44525358;
+const EXT_VERSION: u32 = 1;
+
 pub struct GameProfile {
     pub current_map: u32,
     pub current_song: u32,
@@ -42,7 +104,12 @@ pub struct GameProfile {
     pub weapon_data: [WeaponData; 8],
     pub items: [u32; 32],
     pub teleporter_slots: [TeleporterSlotData; 8],
+    pub map_flags: [u8; 0x80],
     pub flags: [u8; 1000],
+    /// Present if the profile was loaded from or is going to be written to a CS+ save.
+    pub cs_plus_data: Option<CSPlusProfileData>,
+    /// Written only if the profile isn't a Cave Story+ one.
+    pub ext_data: Option<ExtendedProfileData>,
 }
 
 impl GameProfile {
@@ -53,153 +120,166 @@ impl GameProfile {
 
         let _ = state.sound_manager.play_song(self.current_song as usize, &state.constants, ctx);
 
-        game_scene.inventory_player1.current_weapon = self.current_weapon as u16;
-        game_scene.inventory_player1.current_item = self.current_item as u16;
-        for weapon in self.weapon_data.iter() {
-            if weapon.weapon_id == 0 { continue; }
-            let weapon_type: Option<WeaponType> = FromPrimitive::from_u8(weapon.weapon_id as u8);
-
-            if let Some(wtype) = weapon_type {
-                let w = game_scene.inventory_player1.add_weapon(wtype, weapon.max_ammo as u16);
-                w.ammo = weapon.ammo as u16;
-                w.level = match weapon.level {
-                    2 => { WeaponLevel::Level2 }
-                    3 => { WeaponLevel::Level3 }
-                    _ => { WeaponLevel::Level1 }
-                };
-                w.experience = weapon.exp as u16;
-            }
-        }
-
-        for item in self.items.iter().copied() {
-            if item == 0 { break; }
+        self.apply_state(&mut ProfileState {
+            player1: &mut game_scene.player1,
+            player2: &mut game_scene.player2,
+            inventory_player1: &mut game_scene.inventory_player1,
+            inventory_player2: &mut game_scene.inventory_player2,
+            teleporter_slots: &mut state.teleporter_slots,
+            map_flags: &mut state.map_flags,
+            game_flags: &mut state.game_flags,
+            skip_flags: &mut state.skip_flags,
+            play_time: &mut state.play_time,
+        });
+    }
 
-            game_scene.inventory_player1.add_item(item as u16);
-        }
+    pub fn apply_state(&self, target: &mut ProfileState) {
+        let item_amounts = self.ext_data.as_ref().map(|ext| &ext.item_amounts);
+        restore_inventory(target.inventory_player1, &self.weapon_data, &self.items, item_amounts);
+        target.inventory_player1.current_weapon = self.current_weapon as u16;
+        target.inventory_player1.current_item = self.current_item as u16;
 
+        target.teleporter_slots.clear();
         for slot in self.teleporter_slots.iter() {
             if slot.event_num == 0 { break; }
 
-            state.teleporter_slots.push((slot.index as u16, slot.event_num as u16));
+            target.teleporter_slots.push((slot.index as u16, slot.event_num as u16));
         }
 
-        for (idx, &flags) in self.flags.iter().enumerate() {
-            if flags & 0b00000001 != 0 { state.game_flags.set(idx * 8, true); }
-            if flags & 0b00000010 != 0 { state.game_flags.set(idx * 8 + 1, true); }
-            if flags & 0b00000100 != 0 { state.game_flags.set(idx * 8 + 2, true); }
-            if flags & 0b00001000 != 0 { state.game_flags.set(idx * 8 + 3, true); }
-            if flags & 0b00010000 != 0 { state.game_flags.set(idx * 8 + 4, true); }
-            if flags & 0b00100000 != 0 { state.game_flags.set(idx * 8 + 5, true); }
-            if flags & 0b01000000 != 0 { state.game_flags.set(idx * 8 + 6, true); }
-            if flags & 0b10000000 != 0 { state.game_flags.set(idx * 8 + 7, true); }
+        for (idx, &flag) in self.map_flags.iter().enumerate() {
+            if idx < target.map_flags.len() {
+                target.map_flags.set(idx, flag != 0);
+            }
         }
 
-        game_scene.player1.equip.0 = self.equipment as u16;
-
-        game_scene.player1.x = self.pos_x;
-        game_scene.player1.y = self.pos_y;
-
-        game_scene.player1.control_mode = if self.control_mode == 1 { ControlMode::IronHead } else { ControlMode::Normal };
-        game_scene.player1.direction = self.direction;
-        game_scene.player1.life = self.life;
-        game_scene.player1.max_life = self.max_life;
-        game_scene.player1.stars = clamp(self.stars, 0, 3) as u8;
-
-        game_scene.player2 = game_scene.player1.clone();
-        game_scene.inventory_player2 = game_scene.inventory_player1.clone();
-
-        game_scene.player1.cond.0 = 0x80;
+        restore_bits(&self.flags, target.game_flags);
+        *target.play_time = self.counter;
+
+        let player1 = &mut *target.player1;
+        player1.equip.0 = self.equipment as u16;
+        player1.x = self.pos_x;
+        player1.y = self.pos_y;
+        player1.control_mode = if self.control_mode == 1 { ControlMode::IronHead } else { ControlMode::Normal };
+        player1.direction = self.direction;
+        player1.life = self.life;
+        player1.max_life = self.max_life;
+        player1.stars = clamp(self.stars, 0, 3) as u8;
+
+        *target.player2 = target.player1.clone();
+        *target.inventory_player2 = target.inventory_player1.clone();
+
+        target.player1.cond.0 = 0x80;
+
+        if let Some(ext) = &self.ext_data {
+            // skip flags outlive game-over restarts, so only add the saved ones
+            merge_bits(&ext.skip_flags, target.skip_flags);
+
+            if let Some(p2) = &ext.player2 {
+                let player2 = &mut *target.player2;
+                player2.cond.0 = 0x80;
+                player2.appearance = PlayerAppearance::YellowQuote;
+                player2.equip.0 = p2.equipment as u16;
+                player2.x = p2.pos_x;
+                player2.y = p2.pos_y;
+                player2.direction = p2.direction;
+                player2.life = p2.life;
+                player2.max_life = p2.max_life;
+                player2.stars = clamp(p2.stars, 0, 3) as u8;
+
+                *target.inventory_player2 = Inventory::new();
+                restore_inventory(target.inventory_player2, &p2.weapon_data, &p2.items, Some(&p2.item_amounts));
+                target.inventory_player2.current_weapon = p2.current_weapon as u16;
+                target.inventory_player2.current_item = p2.current_item as u16;
+            }
+        }
     }
 
     pub fn dump(state: &mut SharedGameState, game_scene: &mut GameScene) -> GameProfile {
-        let current_map = game_scene.stage_id as u32;
         let current_song = state.sound_manager.current_song() as u32;
-        let pos_x = game_scene.player1.x as i32;
-        let pos_y = game_scene.player1.y as i32;
-        let direction = game_scene.player1.direction;
-        let max_life = game_scene.player1.max_life;
-        let stars = game_scene.player1.stars as u16;
-        let life = game_scene.player1.life;
-        let current_weapon = game_scene.inventory_player1.current_weapon as u32;
-        let current_item = game_scene.inventory_player1.current_item as u32;
-        let equipment = game_scene.player1.equip.0 as u32;
-        let control_mode = game_scene.player1.control_mode as u32;
-        let counter = 0; // TODO
-        let mut weapon_data = [
-            WeaponData { weapon_id: 0, level: 0, exp: 0, max_ammo: 0, ammo: 0 },
-            WeaponData { weapon_id: 0, level: 0, exp: 0, max_ammo: 0, ammo: 0 },
-            WeaponData { weapon_id: 0, level: 0, exp: 0, max_ammo: 0, ammo: 0 },
-            WeaponData { weapon_id: 0, level: 0, exp: 0, max_ammo: 0, ammo: 0 },
-            WeaponData { weapon_id: 0, level: 0, exp: 0, max_ammo: 0, ammo: 0 },
-            WeaponData { weapon_id: 0, level: 0, exp: 0, max_ammo: 0, ammo: 0 },
-            WeaponData { weapon_id: 0, level: 0, exp: 0, max_ammo: 0, ammo: 0 },
-            WeaponData { weapon_id: 0, level: 0, exp: 0, max_ammo: 0, ammo: 0 },
-        ];
+
+        GameProfile::dump_state(game_scene.stage_id as u32, current_song, &ProfileState {
+            player1: &mut game_scene.player1,
+            player2: &mut game_scene.player2,
+            inventory_player1: &mut game_scene.inventory_player1,
+            inventory_player2: &mut game_scene.inventory_player2,
+            teleporter_slots: &mut state.teleporter_slots,
+            map_flags: &mut state.map_flags,
+            game_flags: &mut state.game_flags,
+            skip_flags: &mut state.skip_flags,
+            play_time: &mut state.play_time,
+        })
+    }
+
+    pub fn dump_state(current_map: u32, current_song: u32, source: &ProfileState) -> GameProfile {
+        let mut weapon_data = [WeaponData::default(); 8];
         let mut items = [0u32; 32];
-        let mut teleporter_slots = [
-            TeleporterSlotData { index: 0, event_num: 0 },
-            TeleporterSlotData { index: 0, event_num: 0 },
-            TeleporterSlotData { index: 0, event_num: 0 },
-            TeleporterSlotData { index: 0, event_num: 0 },
-            TeleporterSlotData { index: 0, event_num: 0 },
-            TeleporterSlotData { index: 0, event_num: 0 },
-            TeleporterSlotData { index: 0, event_num: 0 },
-            TeleporterSlotData { index: 0, event_num: 0 },
-        ];
-
-        for (idx, weap) in weapon_data.iter_mut().enumerate() {
-            if let Some(weapon) = game_scene.inventory_player1.get_weapon(idx) {
-                weap.weapon_id = weapon.wtype as u32;
-                weap.level = weapon.level as u32;
-                weap.exp = weapon.experience as u32;
-                weap.max_ammo = weapon.max_ammo as u32;
-                weap.ammo = weapon.ammo as u32;
-            }
-        }
+        let mut item_amounts = [0u16; 32];
+        store_inventory(source.inventory_player1, &mut weapon_data, &mut items, &mut item_amounts);
 
-        for (idx, item) in items.iter_mut().enumerate() {
-            if let Some(sitem) = game_scene.inventory_player1.get_item_idx(idx) {
-                *item = sitem.0 as u32;
-            }
+        let mut teleporter_slots = [TeleporterSlotData::default(); 8];
+        for (slot, &(index, event_num)) in teleporter_slots.iter_mut().zip(source.teleporter_slots.iter()) {
+            slot.index = index as u32;
+            slot.event_num = event_num as u32;
         }
 
-        for (idx, slot) in teleporter_slots.iter_mut().enumerate() {
-            if let Some(&(index, event_num)) = state.teleporter_slots.get(idx) {
-                slot.index = index as u32;
-                slot.event_num = event_num as u32;
-            }
+        let mut map_flags = [0u8; 0x80];
+        for (idx, flag) in map_flags.iter_mut().enumerate() {
+            *flag = if let Some(true) = source.map_flags.get(idx) { 1 } else { 0 };
         }
 
-        let mut bidx = 0;
         let mut flags = [0u8; 1000];
-        for bits in state.game_flags.as_slice() {
-            let bytes = bits.to_le_bytes();
-            for b in bytes.iter() {
-                if let Some(out) = flags.get_mut(bidx) {
-                    *out = *b;
-                }
-                bidx += 1;
-            }
-        }
+        store_bits(source.game_flags, &mut flags);
+
+        let mut skip_flags = [0u8; 8];
+        store_bits(source.skip_flags, &mut skip_flags);
+
+        let player2 = if source.player2.cond.alive() {
+            let mut p2 = Player2Data {
+                pos_x: source.player2.x,
+                pos_y: source.player2.y,
+                direction: source.player2.direction,
+                max_life: source.player2.max_life,
+                stars: source.player2.stars as u16,
+                life: source.player2.life,
+                equipment: source.player2.equip.0 as u32,
+                current_weapon: source.inventory_player2.current_weapon as u32,
+                current_item: source.inventory_player2.current_item as u32,
+                weapon_data: [WeaponData::default(); 8],
+                items: [0u32; 32],
+                item_amounts: [0u16; 32],
+            };
+            store_inventory(source.inventory_player2, &mut p2.weapon_data, &mut p2.items, &mut p2.item_amounts);
+
+            Some(p2)
+        } else {
+            None
+        };
 
         GameProfile {
             current_map,
             current_song,
-            pos_x,
-            pos_y,
-            direction,
-            max_life,
-            stars,
-            life,
-            current_weapon,
-            current_item,
-            equipment,
-            control_mode,
-            counter,
+            pos_x: source.player1.x,
+            pos_y: source.player1.y,
+            direction: source.player1.direction,
+            max_life: source.player1.max_life,
+            stars: source.player1.stars as u16,
+            life: source.player1.life,
+            current_weapon: source.inventory_player1.current_weapon as u32,
+            current_item: source.inventory_player1.current_item as u32,
+            equipment: source.player1.equip.0 as u32,
+            control_mode: source.player1.control_mode as u32,
+            counter: *source.play_time,
             weapon_data,
             items,
             teleporter_slots,
+            map_flags,
             flags,
+            cs_plus_data: None,
+            ext_data: Some(ExtendedProfileData {
+                item_amounts,
+                skip_flags,
+                player2,
+            }),
         }
     }
 
@@ -221,13 +301,7 @@ impl GameProfile {
         data.write_u32::<LE>(self.control_mode)?;
         data.write_u32::<LE>(self.counter)?;
 
-        for weapon in self.weapon_data.iter() {
-            data.write_u32::<LE>(weapon.weapon_id)?;
-            data.write_u32::<LE>(weapon.level)?;
-            data.write_u32::<LE>(weapon.exp)?;
-            data.write_u32::<LE>(weapon.max_ammo)?;
-            data.write_u32::<LE>(weapon.ammo)?;
-        }
+        write_weapons(&self.weapon_data, &mut data)?;
 
         for item in self.items.iter().copied() {
             data.write_u32::<LE>(item)?;
@@ -238,12 +312,91 @@ impl GameProfile {
             data.write_u32::<LE>(slot.event_num)?;
         }
 
-        let something = [0u8; 0x80];
-        data.write(&something)?;
+        data.write(&self.map_flags)?;
 
         data.write_u32::<BE>(0x464c4147)?;
         data.write(&self.flags)?;
 
+        if let Some(cs_plus_data) = self.cs_plus_data {
+            data.write_u32::<LE>(cs_plus_data.unknown)?;
+            data.write_u64::<LE>(cs_plus_data.timestamp)?;
+            data.write_u8(cs_plus_data.difficulty)?;
+            data.write(&cs_plus_data.reserved)?;
+        } else if let Some(ext_data) = &self.ext_data {
+            data.write_u32::<BE>(EXT_SIGNATURE)?;
+            data.write_u32::<LE>(EXT_VERSION)?;
+
+            for amount in ext_data.item_amounts.iter().copied() {
+                data.write_u16::<LE>(amount)?;
+            }
+
+            data.write_all(&ext_data.skip_flags)?;
+
+            if let Some(p2) = &ext_data.player2 {
+                data.write_u8(1)?;
+                data.write_i32::<LE>(p2.pos_x)?;
+                data.write_i32::<LE>(p2.pos_y)?;
+                data.write_u32::<LE>(p2.direction as u32)?;
+                data.write_u16::<LE>(p2.max_life)?;
+                data.write_u16::<LE>(p2.stars)?;
+                data.write_u16::<LE>(p2.life)?;
+                data.write_u32::<LE>(p2.equipment)?;
+                data.write_u32::<LE>(p2.current_weapon)?;
+                data.write_u32::<LE>(p2.current_item)?;
+                write_weapons(&p2.weapon_data, &mut data)?;
+
+                for item in p2.items.iter().copied() {
+                    data.write_u32::<LE>(item)?;
+                }
+
+                for amount in p2.item_amounts.iter().copied() {
+                    data.write_u16::<LE>(amount)?;
+                }
+            } else {
+                data.write_u8(0)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns true if given profile file is a Cave Story+ multi-slot one.
+    pub fn is_cs_plus_save(data: &[u8]) -> bool {
+        !data.is_empty() && data.len() % CS_PLUS_PROFILE_SIZE == 0
+    }
+
+    /// Loads all slots from a Cave Story+ profile file, empty slots are returned as `None`.
+    pub fn load_cs_plus_save(data: &[u8]) -> GameResult<Vec<Option<GameProfile>>> {
+        if !GameProfile::is_cs_plus_save(data) {
+            return Err(ResourceLoadError(str!("Invalid CS+ profile size")));
+        }
+
+        let mut profiles = Vec::with_capacity(data.len() / CS_PLUS_PROFILE_SIZE);
+        for slot in data.chunks(CS_PLUS_PROFILE_SIZE) {
+            if slot.iter().all(|&b| b == 0) {
+                profiles.push(None);
+            } else {
+                profiles.push(Some(GameProfile::load_from_save(slot)?));
+            }
+        }
+
+        Ok(profiles)
+    }
+
+    /// Writes a Cave Story+ profile file, empty slots are filled with zeroes.
+    pub fn write_cs_plus_save<W: io::Write>(profiles: &[Option<GameProfile>], mut data: W) -> GameResult {
+        for profile in profiles.iter() {
+            if let Some(profile) = profile {
+                let mut buf = Vec::with_capacity(CS_PLUS_PROFILE_SIZE);
+                profile.write_save(&mut buf)?;
+                buf.resize(CS_PLUS_PROFILE_SIZE, 0);
+
+                data.write_all(&buf)?;
+            } else {
+                data.write_all(&[0u8; CS_PLUS_PROFILE_SIZE])?;
+            }
+        }
+
         Ok(())
     }
 
@@ -267,35 +420,9 @@ impl GameProfile {
         let equipment = data.read_u32::<LE>()?;
         let control_mode = data.read_u32::<LE>()?;
         let counter = data.read_u32::<LE>()?;
-        let mut weapon_data = [
-            WeaponData { weapon_id: 0, level: 0, exp: 0, max_ammo: 0, ammo: 0 },
-            WeaponData { weapon_id: 0, level: 0, exp: 0, max_ammo: 0, ammo: 0 },
-            WeaponData { weapon_id: 0, level: 0, exp: 0, max_ammo: 0, ammo: 0 },
-            WeaponData { weapon_id: 0, level: 0, exp: 0, max_ammo: 0, ammo: 0 },
-            WeaponData { weapon_id: 0, level: 0, exp: 0, max_ammo: 0, ammo: 0 },
-            WeaponData { weapon_id: 0, level: 0, exp: 0, max_ammo: 0, ammo: 0 },
-            WeaponData { weapon_id: 0, level: 0, exp: 0, max_ammo: 0, ammo: 0 },
-            WeaponData { weapon_id: 0, level: 0, exp: 0, max_ammo: 0, ammo: 0 },
-        ];
+        let weapon_data = read_weapons(&mut data)?;
         let mut items = [0u32; 32];
-        let mut teleporter_slots = [
-            TeleporterSlotData { index: 0, event_num: 0 },
-            TeleporterSlotData { index: 0, event_num: 0 },
-            TeleporterSlotData { index: 0, event_num: 0 },
-            TeleporterSlotData { index: 0, event_num: 0 },
-            TeleporterSlotData { index: 0, event_num: 0 },
-            TeleporterSlotData { index: 0, event_num: 0 },
-            TeleporterSlotData { index: 0, event_num: 0 },
-            TeleporterSlotData { index: 0, event_num: 0 },
-        ];
-
-        for weap in weapon_data.iter_mut() {
-            weap.weapon_id = data.read_u32::<LE>()?;
-            weap.level = data.read_u32::<LE>()?;
-            weap.exp = data.read_u32::<LE>()?;
-            weap.max_ammo = data.read_u32::<LE>()?;
-            weap.ammo = data.read_u32::<LE>()?;
-        }
+        let mut teleporter_slots = [TeleporterSlotData::default(); 8];
 
         for item in items.iter_mut() {
             *item = data.read_u32::<LE>()?;
@@ -306,8 +433,8 @@ impl GameProfile {
             slot.event_num = data.read_u32::<LE>()?;
         }
 
-        let mut something = [0u8; 0x80];
-        data.read_exact(&mut something)?;
+        let mut map_flags = [0u8; 0x80];
+        data.read_exact(&mut map_flags)?;
 
         if data.read_u32::<BE>()? != 0x464c4147 {
             return Err(ResourceLoadError(str!("Invalid FLAG signature")));
@@ -316,6 +443,36 @@ impl GameProfile {
         let mut flags = [0u8; 1000];
         data.read_exact(&mut flags)?;
 
+        // CS+ or doukutsu-rs extension data might follow the flags
+        let mut tag = [0u8; 4];
+        let mut cs_plus_data = None;
+        let mut ext_data = None;
+
+        if data.read_exact(&mut tag).is_ok() {
+            if u32::from_be_bytes(tag) == EXT_SIGNATURE {
+                ext_data = Some(GameProfile::read_ext_data(&mut data)?);
+            } else {
+                let mut extra = [0u8; CS_PLUS_PROFILE_SIZE - PROFILE_SIZE];
+                extra[..4].copy_from_slice(&tag);
+
+                if data.read_exact(&mut extra[4..]).is_ok() {
+                    let mut extra = &extra[..];
+                    let unknown = extra.read_u32::<LE>()?;
+                    let timestamp = extra.read_u64::<LE>()?;
+                    let difficulty = extra.read_u8()?;
+                    let mut reserved = [0u8; 15];
+                    extra.read_exact(&mut reserved)?;
+
+                    cs_plus_data = Some(CSPlusProfileData {
+                        unknown,
+                        timestamp,
+                        difficulty,
+                        reserved,
+                    });
+                }
+            }
+        }
+
         Ok(GameProfile {
             current_map,
             current_song,
@@ -333,7 +490,439 @@ impl GameProfile {
             weapon_data,
             items,
             teleporter_slots,
+            map_flags,
             flags,
+            cs_plus_data,
+            ext_data,
+        })
+    }
+
+    fn read_ext_data<R: io::Read>(mut data: R) -> GameResult<ExtendedProfileData> {
+        let version = data.read_u32::<LE>()?;
+        if version != EXT_VERSION {
+            return Err(ResourceLoadError(format!("Unsupported extended profile version: {}", version)));
+        }
+
+        let mut item_amounts = [0u16; 32];
+        for amount in item_amounts.iter_mut() {
+            *amount = data.read_u16::<LE>()?;
+        }
+
+        let mut skip_flags = [0u8; 8];
+        data.read_exact(&mut skip_flags)?;
+
+        let player2 = if data.read_u8()? != 0 {
+            let pos_x = data.read_i32::<LE>()?;
+            let pos_y = data.read_i32::<LE>()?;
+            let direction = data.read_u32::<LE>()?;
+            let max_life = data.read_u16::<LE>()?;
+            let stars = data.read_u16::<LE>()?;
+            let life = data.read_u16::<LE>()?;
+            let equipment = data.read_u32::<LE>()?;
+            let current_weapon = data.read_u32::<LE>()?;
+            let current_item = data.read_u32::<LE>()?;
+            let weapon_data = read_weapons(&mut data)?;
+
+            let mut items = [0u32; 32];
+            for item in items.iter_mut() {
+                *item = data.read_u32::<LE>()?;
+            }
+
+            let mut item_amounts = [0u16; 32];
+            for amount in item_amounts.iter_mut() {
+                *amount = data.read_u16::<LE>()?;
+            }
+
+            Some(Player2Data {
+                pos_x,
+                pos_y,
+                direction: Direction::from_int(direction as usize).unwrap_or(Direction::Left),
+                max_life,
+                stars,
+                life,
+                equipment,
+                current_weapon,
+                current_item,
+                weapon_data,
+                items,
+                item_amounts,
+            })
+        } else {
+            None
+        };
+
+        Ok(ExtendedProfileData {
+            item_amounts,
+            skip_flags,
+            player2,
         })
     }
 }
+
+fn write_weapons<W: io::Write>(weapon_data: &[WeaponData; 8], mut data: W) -> GameResult {
+    for weapon in weapon_data.iter() {
+        data.write_u32::<LE>(weapon.weapon_id)?;
+        data.write_u32::<LE>(weapon.level)?;
+        data.write_u32::<LE>(weapon.exp)?;
+        data.write_u32::<LE>(weapon.max_ammo)?;
+        data.write_u32::<LE>(weapon.ammo)?;
+    }
+
+    Ok(())
+}
+
+fn read_weapons<R: io::Read>(mut data: R) -> GameResult<[WeaponData; 8]> {
+    let mut weapon_data = [WeaponData::default(); 8];
+
+    for weap in weapon_data.iter_mut() {
+        weap.weapon_id = data.read_u32::<LE>()?;
+        weap.level = data.read_u32::<LE>()?;
+        weap.exp = data.read_u32::<LE>()?;
+        weap.max_ammo = data.read_u32::<LE>()?;
+        weap.ammo = data.read_u32::<LE>()?;
+    }
+
+    Ok(weapon_data)
+}
+
+fn store_inventory(inventory: &Inventory, weapon_data: &mut [WeaponData; 8], items: &mut [u32; 32], item_amounts: &mut [u16; 32]) {
+    for (idx, weap) in weapon_data.iter_mut().enumerate() {
+        if let Some(weapon) = inventory.get_weapon(idx) {
+            weap.weapon_id = weapon.wtype as u32;
+            weap.level = weapon.level as u32;
+            weap.exp = weapon.experience as u32;
+            weap.max_ammo = weapon.max_ammo as u32;
+            weap.ammo = weapon.ammo as u32;
+        } else {
+            *weap = WeaponData::default();
+        }
+    }
+
+    for (idx, (item, amount)) in items.iter_mut().zip(item_amounts.iter_mut()).enumerate() {
+        let sitem = inventory.get_item_idx(idx);
+        *item = sitem.map_or(0, |sitem| sitem.0 as u32);
+        *amount = sitem.map_or(0, |sitem| sitem.1);
+    }
+}
+
+/// Adds stored weapons and items to the inventory, items without a stored amount get one.
+fn restore_inventory(inventory: &mut Inventory, weapon_data: &[WeaponData; 8], items: &[u32; 32], item_amounts: Option<&[u16; 32]>) {
+    for weapon in weapon_data.iter() {
+        if weapon.weapon_id == 0 { continue; }
+        let weapon_type: Option<WeaponType> = FromPrimitive::from_u8(weapon.weapon_id as u8);
+
+        if let Some(wtype) = weapon_type {
+            let w = inventory.add_weapon(wtype, weapon.max_ammo as u16);
+            w.ammo = weapon.ammo as u16;
+            w.level = match weapon.level {
+                2 => { WeaponLevel::Level2 }
+                3 => { WeaponLevel::Level3 }
+                _ => { WeaponLevel::Level1 }
+            };
+            w.experience = weapon.exp as u16;
+        }
+    }
+
+    for (idx, item) in items.iter().copied().enumerate() {
+        if item == 0 { break; }
+
+        inventory.add_item(item as u16);
+
+        if let Some(&amount) = item_amounts.and_then(|amounts| amounts.get(idx)) {
+            if let Some(sitem) = inventory.get_item(item as u16) {
+                sitem.1 = amount.max(1);
+            }
+        }
+    }
+}
+
+fn store_bits(bits: &BitVec, bytes: &mut [u8]) {
+    for (idx, byte) in bytes.iter_mut().enumerate() {
+        *byte = 0;
+
+        for bit in 0..8 {
+            if let Some(true) = bits.get(idx * 8 + bit) {
+                *byte |= 1 << bit;
+            }
+        }
+    }
+}
+
+fn restore_bits(bytes: &[u8], bits: &mut BitVec) {
+    for (idx, &byte) in bytes.iter().enumerate() {
+        for bit in 0..8 {
+            if idx * 8 + bit < bits.len() {
+                bits.set(idx * 8 + bit, byte & (1 << bit) != 0);
+            }
+        }
+    }
+}
+
+fn merge_bits(bytes: &[u8], bits: &mut BitVec) {
+    for (idx, &byte) in bytes.iter().enumerate() {
+        for bit in 0..8 {
+            if idx * 8 + bit < bits.len() && byte & (1 << bit) != 0 {
+                bits.set(idx * 8 + bit, true);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+fn test_profile(cs_plus_data: Option<CSPlusProfileData>) -> GameProfile {
+    let weapon = |weapon_id| WeaponData { weapon_id, level: 2, exp: 15, max_ammo: 100, ammo: 37 };
+    let slot = |index| TeleporterSlotData { index, event_num: 1000 + index };
+
+    let mut items = [0u32; 32];
+    items[0] = 2;
+    items[1] = 35;
+
+    let mut map_flags = [0u8; 0x80];
+    map_flags[13] = 1;
+
+    let mut flags = [0u8; 1000];
+    flags[0] = 0b1010_0101;
+    flags[999] = 0xff;
+
+    GameProfile {
+        current_map: 13,
+        current_song: 8,
+        pos_x: 37 * 16 * 0x200,
+        pos_y: 11 * 16 * 0x200,
+        direction: Direction::Right,
+        max_life: 15,
+        stars: 3,
+        life: 12,
+        current_weapon: 1,
+        current_item: 2,
+        equipment: 0b1_0000_0010,
+        control_mode: 0,
+        counter: 123456,
+        weapon_data: [weapon(2), weapon(3), weapon(0), weapon(0), weapon(0), weapon(0), weapon(0), weapon(0)],
+        items,
+        teleporter_slots: [slot(1), slot(2), slot(0), slot(0), slot(0), slot(0), slot(0), slot(0)],
+        map_flags,
+        flags,
+        cs_plus_data,
+        ext_data: None,
+    }
+}
+
+#[test]
+fn test_freeware_profile_round_trip() {
+    let mut buf = Vec::new();
+    test_profile(None).write_save(&mut buf).unwrap();
+    assert_eq!(buf.len(), PROFILE_SIZE);
+
+    let profile = GameProfile::load_from_save(&buf[..]).unwrap();
+    assert!(profile.cs_plus_data.is_none());
+
+    let mut buf2 = Vec::new();
+    profile.write_save(&mut buf2).unwrap();
+    assert_eq!(buf, buf2);
+}
+
+#[test]
+fn test_cs_plus_profile_round_trip() {
+    let cs_plus_data = CSPlusProfileData {
+        unknown: 0x1234,
+        timestamp: 1_600_000_000,
+        difficulty: 2,
+        reserved: [7; 15],
+    };
+
+    let profiles = vec![Some(test_profile(Some(cs_plus_data))), None, Some(test_profile(Some(CSPlusProfileData::default())))];
+    let mut buf = Vec::new();
+    GameProfile::write_cs_plus_save(&profiles, &mut buf).unwrap();
+    assert_eq!(buf.len(), CS_PLUS_PROFILE_SIZE * 3);
+    assert!(GameProfile::is_cs_plus_save(&buf));
+
+    let loaded = GameProfile::load_cs_plus_save(&buf).unwrap();
+    assert_eq!(loaded.len(), 3);
+    assert!(loaded[1].is_none());
+
+    let data = loaded[0].as_ref().unwrap().cs_plus_data.unwrap();
+    assert_eq!(data.timestamp, 1_600_000_000);
+    assert_eq!(data.difficulty, 2);
+
+    let mut buf2 = Vec::new();
+    GameProfile::write_cs_plus_save(&loaded, &mut buf2).unwrap();
+    assert_eq!(buf, buf2);
+}
+
+#[test]
+fn test_profile_game_state_round_trip() {
+    let constants = EngineConstants::defaults();
+
+    let mut player1 = Player::new(&constants);
+    player1.cond.set_alive(true);
+    player1.x = 37 * 16 * 0x200;
+    player1.y = 11 * 16 * 0x200;
+    player1.direction = Direction::Right;
+    player1.life = 12;
+    player1.max_life = 15;
+    player1.stars = 3;
+    player1.equip.0 = 0b1_0000_0010;
+    player1.control_mode = ControlMode::IronHead;
+
+    let mut player2 = Player::new(&constants);
+    player2.cond.set_alive(true);
+    player2.x = 40 * 16 * 0x200;
+    player2.y = 12 * 16 * 0x200;
+    player2.direction = Direction::Left;
+    player2.life = 3;
+    player2.max_life = 9;
+    player2.stars = 1;
+    player2.equip.0 = 0b100;
+
+    let mut inventory_player1 = Inventory::new();
+    let polar_star = inventory_player1.add_weapon(WeaponType::PolarStar, 0);
+    polar_star.level = WeaponLevel::Level3;
+    polar_star.experience = 7;
+    let missile = inventory_player1.add_weapon(WeaponType::MissileLauncher, 10);
+    missile.ammo = 4;
+    inventory_player1.add_item(2);
+    inventory_player1.add_item(35);
+    inventory_player1.add_item(35);
+    inventory_player1.add_item(35);
+    inventory_player1.current_weapon = 1;
+    inventory_player1.current_item = 1;
+
+    let mut inventory_player2 = Inventory::new();
+    inventory_player2.add_weapon(WeaponType::Fireball, 0).experience = 3;
+    inventory_player2.add_item(9);
+    inventory_player2.add_item(9);
+
+    let mut teleporter_slots = vec![(3, 1003), (1, 1001)];
+    let mut map_flags = bitvec::bitvec![0; 128];
+    map_flags.set(13, true);
+    map_flags.set(127, true);
+    let mut game_flags = bitvec::bitvec![0; 8000];
+    game_flags.set(0, true);
+    game_flags.set(5, true);
+    game_flags.set(7999, true);
+    let mut skip_flags = bitvec::bitvec![0; 64];
+    skip_flags.set(2, true);
+    skip_flags.set(63, true);
+    let mut play_time = 123456;
+
+    let profile = GameProfile::dump_state(13, 8, &ProfileState {
+        player1: &mut player1,
+        player2: &mut player2,
+        inventory_player1: &mut inventory_player1,
+        inventory_player2: &mut inventory_player2,
+        teleporter_slots: &mut teleporter_slots,
+        map_flags: &mut map_flags,
+        game_flags: &mut game_flags,
+        skip_flags: &mut skip_flags,
+        play_time: &mut play_time,
+    });
+
+    let mut buf = Vec::new();
+    profile.write_save(&mut buf).unwrap();
+    assert!(buf.len() > PROFILE_SIZE);
+    assert!(!GameProfile::is_cs_plus_save(&buf));
+
+    let profile = GameProfile::load_from_save(&buf[..]).unwrap();
+    assert!(profile.cs_plus_data.is_none());
+    assert_eq!(profile.current_map, 13);
+    assert_eq!(profile.current_song, 8);
+
+    let mut loaded_player1 = Player::new(&constants);
+    let mut loaded_player2 = Player::new(&constants);
+    let mut loaded_inventory_player1 = Inventory::new();
+    let mut loaded_inventory_player2 = Inventory::new();
+    let mut loaded_teleporter_slots = vec![(9, 9)];
+    let mut loaded_map_flags = bitvec::bitvec![1; 128];
+    let mut loaded_game_flags = bitvec::bitvec![1; 8000];
+    let mut loaded_skip_flags = bitvec::bitvec![0; 64];
+    let mut loaded_play_time = 0;
+
+    profile.apply_state(&mut ProfileState {
+        player1: &mut loaded_player1,
+        player2: &mut loaded_player2,
+        inventory_player1: &mut loaded_inventory_player1,
+        inventory_player2: &mut loaded_inventory_player2,
+        teleporter_slots: &mut loaded_teleporter_slots,
+        map_flags: &mut loaded_map_flags,
+        game_flags: &mut loaded_game_flags,
+        skip_flags: &mut loaded_skip_flags,
+        play_time: &mut loaded_play_time,
+    });
+
+    for (a, b) in [(&player1, &loaded_player1), (&player2, &loaded_player2)].iter() {
+        assert!(b.cond.alive());
+        assert_eq!((a.x, a.y, a.direction, a.life, a.max_life, a.stars, a.equip.0),
+                   (b.x, b.y, b.direction, b.life, b.max_life, b.stars, b.equip.0));
+    }
+    assert_eq!(loaded_player1.control_mode, ControlMode::IronHead);
+
+    for (a, b) in [(&inventory_player1, &loaded_inventory_player1), (&inventory_player2, &loaded_inventory_player2)].iter() {
+        assert_eq!(a.get_weapon_count(), b.get_weapon_count());
+        for idx in 0..a.get_weapon_count() {
+            let (a, b) = (a.get_weapon(idx).unwrap(), b.get_weapon(idx).unwrap());
+            assert_eq!((a.wtype, a.level, a.experience, a.ammo, a.max_ammo), (b.wtype, b.level, b.experience, b.ammo, b.max_ammo));
+        }
+
+        assert_eq!(a.get_item_count(), b.get_item_count());
+        for idx in 0..a.get_item_count() {
+            let (a, b) = (a.get_item_idx(idx).unwrap(), b.get_item_idx(idx).unwrap());
+            assert_eq!((a.0, a.1), (b.0, b.1));
+        }
+
+        assert_eq!((a.current_weapon, a.current_item), (b.current_weapon, b.current_item));
+    }
+
+    assert_eq!(loaded_teleporter_slots, teleporter_slots);
+    assert_eq!(loaded_map_flags, map_flags);
+    assert_eq!(loaded_game_flags, game_flags);
+    assert_eq!(loaded_skip_flags, skip_flags);
+    assert_eq!(loaded_play_time, play_time);
+
+    // item ids in the original layout are left intact for other readers
+    assert_eq!(&buf[0xd8..0xe0], &[2, 0, 0, 0, 35, 0, 0, 0]);
+}
+
+#[test]
+fn test_profile_load_keeps_skip_flags() {
+    let constants = EngineConstants::defaults();
+
+    let mut skip_flags = bitvec::bitvec![0; 64];
+    skip_flags.set(2, true);
+
+    let profile = GameProfile::dump_state(13, 8, &ProfileState {
+        player1: &mut Player::new(&constants),
+        player2: &mut Player::new(&constants),
+        inventory_player1: &mut Inventory::new(),
+        inventory_player2: &mut Inventory::new(),
+        teleporter_slots: &mut Vec::new(),
+        map_flags: &mut bitvec::bitvec![0; 128],
+        game_flags: &mut bitvec::bitvec![0; 8000],
+        skip_flags: &mut skip_flags,
+        play_time: &mut 0,
+    });
+
+    let mut buf = Vec::new();
+    profile.write_save(&mut buf).unwrap();
+    let profile = GameProfile::load_from_save(&buf[..]).unwrap();
+
+    // set after the profile was saved, e.g. before a game over
+    let mut live_skip_flags = bitvec::bitvec![0; 64];
+    live_skip_flags.set(5, true);
+
+    profile.apply_state(&mut ProfileState {
+        player1: &mut Player::new(&constants),
+        player2: &mut Player::new(&constants),
+        inventory_player1: &mut Inventory::new(),
+        inventory_player2: &mut Inventory::new(),
+        teleporter_slots: &mut Vec::new(),
+        map_flags: &mut bitvec::bitvec![0; 128],
+        game_flags: &mut bitvec::bitvec![0; 8000],
+        skip_flags: &mut live_skip_flags,
+        play_time: &mut 0,
+    });
+
+    assert!(live_skip_flags[2]);
+    assert!(live_skip_flags[5]);
+    assert!(!live_skip_flags[3]);
+}
@@ -0,0 +1,216 @@
+use std::io;
+
+use byteorder::{LE, ReadBytesExt, WriteBytesExt};
+use ggez::{Context, filesystem, GameResult};
+use ggez::filesystem::OpenOptions;
+use ggez::GameError::ResourceLoadError;
+
+use crate::profile::GameProfile;
+use crate::str;
+
+const REPLAY_MAGIC: &[u8; 8] = b"DRSRPLY\0";
+const REPLAY_VERSION: u32 = 1;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ReplayState {
+    None,
+    Recording,
+    Playback,
+}
+
+/// Player 1 inputs recorded since the game was started from a profile.
+pub struct Replay {
+    pub state: ReplayState,
+    /// Stage the recording was started in.
+    pub stage_id: u32,
+    /// Seed of `game_rng` used for the first stage.
+    pub rng_seed: i32,
+    /// Raw profile data the recording was started from.
+    pub profile: Vec<u8>,
+    /// `KeyState` bitfield for each tick.
+    pub keylist: Vec<u16>,
+    tick: usize,
+    seed_pending: bool,
+}
+
+impl Replay {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Replay {
+        Replay {
+            state: ReplayState::None,
+            stage_id: 0,
+            rng_seed: 0,
+            profile: Vec::new(),
+            keylist: Vec::new(),
+            tick: 0,
+            seed_pending: false,
+        }
+    }
+
+    /// Returns the path of the replay file.
+    pub fn get_replay_filename() -> String {
+        str!("/Replay.dat")
+    }
+
+    pub fn start_recording(&mut self, profile: &GameProfile, rng_seed: i32) -> GameResult {
+        self.profile.clear();
+        profile.write_save(&mut self.profile)?;
+        self.stage_id = profile.current_map;
+        self.rng_seed = rng_seed;
+        self.keylist.clear();
+        self.tick = 0;
+        self.seed_pending = true;
+        self.state = ReplayState::Recording;
+
+        Ok(())
+    }
+
+    /// Rewinds the replay and returns the profile it was started from.
+    pub fn start_playback(&mut self) -> GameResult<GameProfile> {
+        let profile = GameProfile::load_from_save(&self.profile[..])?;
+
+        self.tick = 0;
+        self.seed_pending = true;
+        self.state = ReplayState::Playback;
+
+        Ok(profile)
+    }
+
+    /// Returns the recorded seed once, when the first stage of replay is initialized.
+    pub fn take_seed(&mut self) -> Option<i32> {
+        if self.state != ReplayState::None && self.seed_pending {
+            self.seed_pending = false;
+            return Some(self.rng_seed);
+        }
+
+        None
+    }
+
+    pub fn record(&mut self, keys: u16) {
+        if self.state == ReplayState::Recording {
+            self.keylist.push(keys);
+        }
+    }
+
+    /// Returns inputs for next tick, playback is stopped after the last one.
+    pub fn next_keys(&mut self) -> u16 {
+        if self.state != ReplayState::Playback {
+            return 0;
+        }
+
+        if let Some(&keys) = self.keylist.get(self.tick) {
+            self.tick += 1;
+            keys
+        } else {
+            log::info!("Replay finished after {} ticks.", self.tick);
+            self.state = ReplayState::None;
+            0
+        }
+    }
+
+    pub fn stop(&mut self) {
+        self.state = ReplayState::None;
+    }
+
+    pub fn load(&mut self, ctx: &mut Context) -> GameResult {
+        let data = filesystem::user_open(ctx, Replay::get_replay_filename())?;
+        self.read_replay(data)
+    }
+
+    pub fn save(&self, ctx: &mut Context) -> GameResult {
+        let data = filesystem::open_options(ctx, Replay::get_replay_filename(),
+                                            OpenOptions::new().write(true).create(true).truncate(true))?;
+        self.write_replay(data)
+    }
+
+    pub fn write_replay<W: io::Write>(&self, mut data: W) -> GameResult {
+        data.write_all(REPLAY_MAGIC)?;
+        data.write_u32::<LE>(REPLAY_VERSION)?;
+        data.write_u32::<LE>(self.stage_id)?;
+        data.write_i32::<LE>(self.rng_seed)?;
+
+        data.write_u32::<LE>(self.profile.len() as u32)?;
+        data.write_all(&self.profile)?;
+
+        data.write_u32::<LE>(self.keylist.len() as u32)?;
+        for &keys in self.keylist.iter() {
+            data.write_u16::<LE>(keys)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn read_replay<R: io::Read>(&mut self, mut data: R) -> GameResult {
+        let mut magic = [0u8; 8];
+        data.read_exact(&mut magic)?;
+
+        if &magic != REPLAY_MAGIC {
+            return Err(ResourceLoadError(str!("Invalid replay file.")));
+        }
+
+        let version = data.read_u32::<LE>()?;
+        if version != REPLAY_VERSION {
+            return Err(ResourceLoadError(format!("Unsupported replay version: {}", version)));
+        }
+
+        self.stage_id = data.read_u32::<LE>()?;
+        self.rng_seed = data.read_i32::<LE>()?;
+
+        let profile_len = data.read_u32::<LE>()? as usize;
+        self.profile = vec![0u8; profile_len];
+        data.read_exact(&mut self.profile)?;
+
+        let key_count = data.read_u32::<LE>()? as usize;
+        self.keylist = Vec::with_capacity(key_count);
+        for _ in 0..key_count {
+            self.keylist.push(data.read_u16::<LE>()?);
+        }
+
+        self.tick = 0;
+        self.seed_pending = false;
+        self.state = ReplayState::None;
+
+        Ok(())
+    }
+}
+
+#[test]
+fn test_replay_round_trip() {
+    let mut replay = Replay::new();
+    replay.stage_id = 12;
+    replay.rng_seed = -1234567;
+    replay.profile = vec![1, 2, 3, 4, 5];
+    replay.keylist = vec![0, 0x41, 0x1fff, 0x1000];
+
+    let mut buf = Vec::new();
+    replay.write_replay(&mut buf).unwrap();
+
+    let mut loaded = Replay::new();
+    loaded.read_replay(&buf[..]).unwrap();
+
+    assert_eq!(loaded.stage_id, replay.stage_id);
+    assert_eq!(loaded.rng_seed, replay.rng_seed);
+    assert_eq!(loaded.profile, replay.profile);
+    assert_eq!(loaded.keylist, replay.keylist);
+
+    assert!(Replay::new().read_replay(&buf[1..]).is_err());
+}
+
+#[test]
+fn test_replay_playback_stops_at_end() {
+    let mut replay = Replay::new();
+    replay.state = ReplayState::Recording;
+    replay.seed_pending = true;
+    replay.rng_seed = 42;
+    replay.record(3);
+    replay.record(7);
+
+    assert_eq!(replay.take_seed(), Some(42));
+    assert_eq!(replay.take_seed(), None);
+
+    replay.state = ReplayState::Playback;
+    assert_eq!(replay.next_keys(), 3);
+    assert_eq!(replay.next_keys(), 7);
+    assert_eq!(replay.next_keys(), 0);
+    assert_eq!(replay.state, ReplayState::None);
+}
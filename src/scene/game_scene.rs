@@ -10,10 +10,16 @@ use crate::caret::CaretType;
 use crate::common::{Direction, FadeDirection, FadeState, fix9_scale, interpolate_fix9_scale, Rect};
 use crate::components::boss_life_bar::BossLifeBar;
 use crate::components::draw_common::{Alignment, draw_number};
+use crate::components::falling_island::FallingIsland;
 use crate::components::hud::HUD;
+use crate::components::inventory::InventoryUI;
+use crate::components::map_system::MapSystem;
 use crate::components::stage_select::StageSelect;
+use crate::credit_script::{CreditScriptVM, IllustrationState};
 use crate::entity::GameEntity;
 use crate::frame::{Frame, UpdateTarget};
+use crate::input::dummy_player_controller::DummyPlayerController;
+use crate::input::replay_player_controller::ReplayController;
 use crate::input::touch_controls::TouchControlType;
 use crate::inventory::{Inventory, TakeExperienceResult};
 use crate::npc::boss::BossNPC;
@@ -21,6 +27,7 @@ use crate::npc::list::NPCList;
 use crate::npc::NPC;
 use crate::physics::PhysicalEntity;
 use crate::player::{Player, PlayerAppearance, TargetPlayer};
+use crate::replay::ReplayState;
 use crate::rng::XorShift;
 use crate::scene::Scene;
 use crate::scene::title_scene::TitleScene;
@@ -36,6 +43,9 @@ pub struct GameScene {
     pub stage: Stage,
     pub boss_life_bar: BossLifeBar,
     pub stage_select: StageSelect,
+    pub inventory_ui: InventoryUI,
+    pub map_system: MapSystem,
+    pub falling_island: FallingIsland,
     pub hud_player1: HUD,
     pub hud_player2: HUD,
     pub frame: Frame,
@@ -79,12 +89,15 @@ impl GameScene {
         Ok(Self {
             tick: 0,
             stage,
-            player1: Player::new(state),
-            player2: Player::new(state),
+            player1: Player::new(&state.constants),
+            player2: Player::new(&state.constants),
             inventory_player1: Inventory::new(),
             inventory_player2: Inventory::new(),
             boss_life_bar: BossLifeBar::new(),
             stage_select: StageSelect::new(),
+            inventory_ui: InventoryUI::new(),
+            map_system: MapSystem::new(),
+            falling_island: FallingIsland::new(),
             hud_player1: HUD::new(Alignment::Left),
             hud_player2: HUD::new(Alignment::Right),
             frame: Frame {
@@ -176,7 +189,20 @@ impl GameScene {
             BackgroundType::Black => {
                 graphics::clear(ctx, Color::from_rgb(0, 0, 32));
             }
-            BackgroundType::Autoscroll => {}
+            BackgroundType::Autoscroll => {
+                graphics::clear(ctx, Color::from_rgb(0, 0, 32));
+
+                let off_x = (self.tick as usize * 6) % batch.width();
+                let count_x = state.canvas_size.0 as usize / batch.width() + 2;
+                let count_y = state.canvas_size.1 as usize / batch.height() + 1;
+
+                for y in 0..count_y {
+                    for x in 0..count_x {
+                        batch.add((x * batch.width()) as f32 - off_x as f32,
+                                  (y * batch.height()) as f32);
+                    }
+                }
+            }
             BackgroundType::OutsideWind | BackgroundType::Outside => {
                 graphics::clear(ctx, Color::from_rgb(0, 0, 0));
 
@@ -368,6 +394,22 @@ impl GameScene {
         Ok(())
     }
 
+    fn draw_screen_effects(&self, state: &mut SharedGameState, ctx: &mut Context) -> GameResult {
+        for effect in state.screen_effects.iter() {
+            let color = [1.0, 1.0, 1.0, effect.alpha()];
+
+            for rect in effect.rects(&self.frame, state.canvas_size) {
+                state.texture_set.draw_rect(Rect::new((rect.left as f32 * state.scale) as isize,
+                                                      (rect.top as f32 * state.scale) as isize,
+                                                      (rect.right as f32 * state.scale) as isize,
+                                                      (rect.bottom as f32 * state.scale) as isize),
+                                            color, ctx)?;
+            }
+        }
+
+        Ok(())
+    }
+
     fn draw_black_bars(&self, _state: &mut SharedGameState, _ctx: &mut Context) -> GameResult {
         Ok(())
     }
@@ -649,6 +691,82 @@ impl GameScene {
         [0x02, 0x04, 0x60, 0x61, 0x62, 0x64, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0xa0, 0xa1, 0xa2, 0xa3].contains(&tile)
     }
 
+    /// Draws the credit lines with their cast sprites and the illustration set by `<SIL`.
+    fn draw_credits(&self, state: &mut SharedGameState, ctx: &mut Context) -> GameResult {
+        // the credits were laid out for a 320 pixels wide screen
+        let offset_x = ((state.canvas_size.0 - 320.0) / 2.0).floor();
+
+        if state.creditscript_vm.illustration_state != IllustrationState::Hidden {
+            let name = format!("Resource/BITMAP/Credit{:02}", state.creditscript_vm.illustration);
+            let x = offset_x + (state.creditscript_vm.illustration_pos / 0x200) as f32;
+
+            let batch = state.texture_set.get_or_load_batch(ctx, &state.constants, &name)?;
+            batch.add_rect(x, 0.0, &Rect::new(0, 0, 160, 240));
+            batch.draw(ctx)?;
+        }
+
+        if state.creditscript_vm.lines.is_empty() {
+            return Ok(());
+        }
+
+        let batch = state.texture_set.get_or_load_batch(ctx, &state.constants, "casts")?;
+        let mut rect = Rect::new(0, 0, 0, 0);
+
+        for line in state.creditscript_vm.lines.iter() {
+            rect.left = (line.cast_id % 13) * 24;
+            rect.top = (line.cast_id / 13) * 24;
+            rect.right = rect.left + 24;
+            rect.bottom = rect.top + 24;
+
+            batch.add_rect(offset_x + (line.pos_x / 0x200) as f32 - 24.0, (line.pos_y / 0x200) as f32 - 8.0, &rect);
+        }
+
+        batch.draw(ctx)?;
+
+        for line in state.creditscript_vm.lines.iter() {
+            state.font.draw_text(line.text.chars(), offset_x + (line.pos_x / 0x200) as f32, (line.pos_y / 0x200) as f32,
+                                 &state.constants, &mut state.texture_set, ctx)?;
+        }
+
+        Ok(())
+    }
+
+    /// Draws the water surface at the level set by water level NPC.
+    fn draw_water_level(&self, state: &mut SharedGameState, ctx: &mut Context) -> GameResult {
+        if self.stage.data.background_type != BackgroundType::Water {
+            return Ok(());
+        }
+
+        let batch = state.texture_set.get_or_load_batch(ctx, &state.constants, &self.tex_background_name)?;
+        let (frame_x, frame_y) = self.frame.xy_interpolated(state.frame_time, state.scale);
+        let water_y = fix9_scale(state.water_level, state.scale) - frame_y;
+        let off_x = frame_x % 32.0;
+        let count_x = state.canvas_size.0 as usize / 32 + 2;
+
+        for y in 0..32 {
+            let pos_y = water_y + (y * 32) as f32;
+            if pos_y < -32.0 {
+                continue;
+            }
+            if pos_y > state.canvas_size.1 {
+                break;
+            }
+
+            for x in 0..count_x {
+                let pos_x = (x * 32) as f32 - off_x;
+
+                batch.add_rect(pos_x, pos_y, &Rect::new_size(0, 16, 32, 32));
+                if y == 0 {
+                    batch.add_rect(pos_x, pos_y, &Rect::new_size(0, 0, 32, 16));
+                }
+            }
+        }
+
+        batch.draw(ctx)?;
+
+        Ok(())
+    }
+
     fn draw_water(&self, state: &mut SharedGameState, ctx: &mut Context) -> GameResult {
         let (frame_x, frame_y) = self.frame.xy_interpolated(state.frame_time, state.scale);
 
@@ -925,6 +1043,9 @@ impl GameScene {
 
                         npc.shock = 8;
 
+                        npc = unsafe { self.boss.parts.get_unchecked_mut(i) };
+                        npc.shock = 8;
+
                         npc = unsafe { self.boss.parts.get_unchecked_mut(0) };
                         npc.shock = 8;
                     }
@@ -994,7 +1115,7 @@ impl GameScene {
 
 
         for npc in self.npc_list.iter_alive() {
-            npc.tick(state, ([&mut self.player1, &mut self.player2], &self.npc_list, &mut self.stage, &self.bullet_manager))?;
+            npc.tick(state, ([&mut self.player1, &mut self.player2], &self.npc_list, &mut self.stage, &mut self.bullet_manager))?;
         }
         self.boss.tick(state, ([&mut self.player1, &mut self.player2], &self.npc_list, &mut self.stage, &self.bullet_manager))?;
 
@@ -1056,11 +1177,11 @@ impl GameScene {
 
         if state.control_flags.control_enabled() {
             if let Some(weapon) = self.inventory_player1.get_current_weapon_mut() {
-                weapon.shoot_bullet(&self.player1, TargetPlayer::Player1, &mut self.bullet_manager, state);
+                weapon.shoot_bullet(&mut self.player1, TargetPlayer::Player1, &mut self.bullet_manager, state);
             }
 
             if let Some(weapon) = self.inventory_player2.get_current_weapon_mut() {
-                weapon.shoot_bullet(&self.player2, TargetPlayer::Player2, &mut self.bullet_manager, state);
+                weapon.shoot_bullet(&mut self.player2, TargetPlayer::Player2, &mut self.bullet_manager, state);
             }
 
             self.hud_player1.tick(state, (&self.player1, &mut self.inventory_player1))?;
@@ -1068,6 +1189,8 @@ impl GameScene {
             self.boss_life_bar.tick(state, (&self.npc_list, &self.boss))?;
         }
 
+        self.hud_player1.nikumaru_counter.tick(state, &self.player1)?;
+
         Ok(())
     }
 
@@ -1137,12 +1260,25 @@ impl Scene for GameScene {
             .wrapping_add(self.player1.y as i32)
             .wrapping_add(self.stage_id as i32)
             .rotate_right(7);
-        state.game_rng = XorShift::new(seed);
+        state.game_rng = XorShift::new(state.replay.take_seed().unwrap_or(seed));
+        state.water_level = 240 * 16 * 0x200;
         state.textscript_vm.set_scene_script(self.stage.load_text_script(&state.base_path, &state.constants, ctx)?);
         state.textscript_vm.suspend = false;
 
-        self.player1.controller = state.settings.create_player1_controller();
-        self.player2.controller = state.settings.create_player2_controller();
+        match state.replay.state {
+            ReplayState::Recording => {
+                self.player1.controller = Box::new(ReplayController::new_recorder(state.settings.create_player1_controller()));
+                self.player2.controller = Box::new(DummyPlayerController::new());
+            }
+            ReplayState::Playback => {
+                self.player1.controller = Box::new(ReplayController::new_playback());
+                self.player2.controller = Box::new(DummyPlayerController::new());
+            }
+            ReplayState::None => {
+                self.player1.controller = state.settings.create_player1_controller();
+                self.player2.controller = state.settings.create_player2_controller();
+            }
+        }
 
         let npcs = self.stage.load_npcs(&state.base_path, ctx)?;
         for npc_data in npcs.iter() {
@@ -1206,10 +1342,36 @@ impl Scene for GameScene {
             state.next_scene = Some(Box::new(TitleScene::new()));
         }
 
-        match state.textscript_vm.mode {
-            ScriptMode::Map if state.control_flags.tick_world() => self.tick_world(state)?,
-            ScriptMode::StageSelect => self.stage_select.tick(state, (&self.player1, &self.player2))?,
-            _ => {}
+        if self.map_system.visible() {
+            self.map_system.tick(state, (&self.player1, &self.player2))?;
+        } else if self.falling_island.visible() {
+            self.falling_island.tick(state, ())?;
+        } else {
+            match state.textscript_vm.mode {
+                ScriptMode::Map if state.control_flags.tick_world() => self.tick_world(state)?,
+                ScriptMode::StageSelect => self.stage_select.tick(state, (&self.player1, &self.player2))?,
+                ScriptMode::Inventory => self.inventory_ui.tick(state, (&self.player1, &mut self.inventory_player1))?,
+                _ => {}
+            }
+        }
+
+        if !self.intro_mode
+            && state.textscript_vm.mode == ScriptMode::Map
+            && state.textscript_vm.state == TextScriptExecutionState::Ended
+            && state.control_flags.control_enabled()
+            && !self.player1.cond.hidden()
+            && self.player1.controller.trigger_inventory() {
+            self.inventory_ui.init(state, &self.inventory_player1);
+        }
+
+        if !self.intro_mode
+            && state.textscript_vm.mode == ScriptMode::Map
+            && state.textscript_vm.state == TextScriptExecutionState::Ended
+            && state.control_flags.control_enabled()
+            && !self.map_system.visible()
+            && self.player1.equip.has_map()
+            && self.player1.controller.trigger_map() {
+            self.map_system.open(&self.stage, &self.player1);
         }
 
         if self.map_name_counter > 0 {
@@ -1232,11 +1394,18 @@ impl Scene for GameScene {
             _ => {}
         }
 
+        state.tick_screen_effects();
+
         TextScriptVM::run(state, self, ctx)?;
+        CreditScriptVM::run(state, ctx)?;
 
         #[cfg(feature = "scripting")]
             state.lua.scene_tick(self);
 
+        if !self.intro_mode {
+            state.play_time = state.play_time.wrapping_add(1);
+        }
+
         self.tick = self.tick.wrapping_add(1);
         Ok(())
     }
@@ -1310,6 +1479,7 @@ impl Scene for GameScene {
 
         self.draw_tiles(state, ctx, TileLayer::Foreground)?;
         self.draw_tiles(state, ctx, TileLayer::Snack)?;
+        self.draw_water_level(state, ctx)?;
         self.draw_carets(state, ctx)?;
         if state.settings.shader_effects
             && (self.stage.data.background_type == BackgroundType::Black
@@ -1321,8 +1491,11 @@ impl Scene for GameScene {
         state.game_canvas.draw(ctx, DrawParam::new()
             .scale(Vector2::new(1.0 / state.scale, 1.0 / state.scale)))?;
         self.draw_black_bars(state, ctx)?;
+        self.draw_screen_effects(state, ctx)?;
 
-        if state.control_flags.control_enabled() {
+        if state.control_flags.control_enabled()
+            && state.textscript_vm.mode != ScriptMode::Inventory
+            && !self.map_system.visible() {
             self.hud_player1.draw(state, ctx, &self.frame)?;
             self.hud_player2.draw(state, ctx, &self.frame)?;
             self.boss_life_bar.draw(state, ctx, &self.frame)?;
@@ -1357,6 +1530,17 @@ impl Scene for GameScene {
             self.stage_select.draw(state, ctx, &self.frame)?;
         }
 
+        if state.textscript_vm.mode == ScriptMode::Inventory {
+            self.inventory_ui.draw(state, ctx, &self.frame)?;
+        }
+
+        self.map_system.draw(state, ctx, &self.frame)?;
+        self.falling_island.draw(state, ctx, &self.frame)?;
+
+        if state.control_flags.credits_running() {
+            self.draw_credits(state, ctx)?;
+        }
+
         self.draw_fade(state, ctx)?;
         if self.map_name_counter > 0 {
             let map_name = if self.intro_mode {
@@ -1,4 +1,6 @@
 use ggez::{Context, filesystem, GameResult};
+
+use crate::credit_script::CreditScript;
 use crate::npc::NPCTable;
 use crate::scene::Scene;
 use crate::shared_game_state::SharedGameState;
@@ -38,6 +40,12 @@ impl Scene for LoadingScene {
             let stage_select_script = TextScript::load_from(stage_select_tsc, &state.constants)?;
             state.textscript_vm.set_stage_select_script(stage_select_script);
 
+            match filesystem::open(ctx, [&state.base_path, "/Credit.tsc"].join(""))
+                .and_then(|credit_tsc| CreditScript::load_from(credit_tsc, &state.constants)) {
+                Ok(credit_script) => state.creditscript_vm.set_script(credit_script),
+                Err(err) => log::warn!("Failed to load credits script: {}", err),
+            }
+
             state.start_intro(ctx)?;
         }
 
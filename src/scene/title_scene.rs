@@ -2,7 +2,7 @@ use ggez::{Context, GameResult, graphics};
 use ggez::graphics::Color;
 
 use crate::common::{Rect, VERSION_BANNER};
-use crate::menu::{Menu, MenuEntry, MenuSelectionResult};
+use crate::menu::{Menu, MenuEntry, MenuSaveInfo, MenuSelectionResult};
 use crate::scene::Scene;
 use crate::shared_game_state::{SharedGameState, TimingMode};
 use crate::input::combined_menu_controller::CombinedMenuController;
@@ -26,8 +26,13 @@ pub struct TitleScene {
     main_menu: Menu,
     option_menu: Menu,
     save_select_menu: Menu,
+    /// If set, selecting a save in save select menu deletes it.
+    delete_mode: bool,
 }
 
+/// Number of profile slots shown in save select menu.
+const SAVE_SLOTS: usize = 3;
+
 impl TitleScene {
     pub fn new() -> Self {
         Self {
@@ -36,8 +41,34 @@ impl TitleScene {
             current_menu: CurrentMenu::MainMenu,
             main_menu: Menu::new(0, 0, 100, 0),
             option_menu: Menu::new(0, 0, 180, 0),
-            save_select_menu: Menu::new(0, 0, 200, 0),
+            save_select_menu: Menu::new(0, 0, 240, 0),
+            delete_mode: false,
+        }
+    }
+
+    fn save_entry(slot: usize, state: &SharedGameState, ctx: &mut Context) -> MenuEntry {
+        match SharedGameState::read_profile(slot, ctx) {
+            Ok(Some(profile)) => {
+                return MenuEntry::SaveData(MenuSaveInfo {
+                    stage_name: state.stages.get(profile.current_map as usize)
+                        .map(|stage| stage.name.clone())
+                        .unwrap_or_default(),
+                    life: profile.life,
+                    max_life: profile.max_life,
+                    weapons: profile.weapon_data.iter()
+                        .map(|weapon| weapon.weapon_id)
+                        .filter(|&id| id != 0)
+                        .collect(),
+                    play_time: profile.counter,
+                });
+            }
+            Ok(None) => {}
+            Err(e) => {
+                log::warn!("Failed to load save slot {}: {}", slot, e);
+            }
         }
+
+        MenuEntry::NewSave
     }
 
     fn draw_background(&self, state: &mut SharedGameState, ctx: &mut Context) -> GameResult {
@@ -93,6 +124,7 @@ impl Scene for TitleScene {
         self.controller.add(state.settings.create_player1_controller());
         self.controller.add(state.settings.create_player2_controller());
 
+        state.sound_manager.stop_looping_sfx();
         state.sound_manager.play_song(24, &state.constants, ctx)?;
         self.main_menu.push_entry(MenuEntry::Active("New game".to_string()));
         self.main_menu.push_entry(MenuEntry::Active("Load game".to_string()));
@@ -121,9 +153,9 @@ impl Scene for TitleScene {
         self.option_menu.push_entry(MenuEntry::Disabled(DISCORD_LINK.to_owned()));
         self.option_menu.push_entry(MenuEntry::Active("Back".to_string()));
 
-        self.save_select_menu.push_entry(MenuEntry::NewSave);
-        self.save_select_menu.push_entry(MenuEntry::NewSave);
-        self.save_select_menu.push_entry(MenuEntry::NewSave);
+        for slot in 0..SAVE_SLOTS {
+            self.save_select_menu.push_entry(TitleScene::save_entry(slot, state, ctx));
+        }
         self.save_select_menu.push_entry(MenuEntry::Active("Delete a save".to_string()));
         self.save_select_menu.push_entry(MenuEntry::Active("Back".to_string()));
 
@@ -146,19 +178,15 @@ impl Scene for TitleScene {
         self.option_menu.x = ((state.canvas_size.0 - self.option_menu.width as f32) / 2.0).floor() as isize;
         self.option_menu.y = ((state.canvas_size.1 + 70.0 - self.option_menu.height as f32) / 2.0).floor() as isize;
 
+        self.save_select_menu.update_height();
+        self.save_select_menu.x = ((state.canvas_size.0 - self.save_select_menu.width as f32) / 2.0).floor() as isize;
+        self.save_select_menu.y = ((state.canvas_size.1 + 70.0 - self.save_select_menu.height as f32) / 2.0).floor() as isize;
+
         match self.current_menu {
             CurrentMenu::MainMenu => {
                 match self.main_menu.tick(&mut self.controller, state) {
-                    MenuSelectionResult::Selected(0, _) => {
-                        state.reset();
-                        state.sound_manager.play_song(0, &state.constants, ctx)?;
-                        self.tick = 1;
-                        self.current_menu = CurrentMenu::StartGame;
-                    }
-                    MenuSelectionResult::Selected(1, _) => {
-                        state.sound_manager.play_song(0, &state.constants, ctx)?;
-                        self.tick = 1;
-                        self.current_menu = CurrentMenu::LoadGame;
+                    MenuSelectionResult::Selected(0, _) | MenuSelectionResult::Selected(1, _) => {
+                        self.current_menu = CurrentMenu::SaveSelectMenu;
                     }
                     MenuSelectionResult::Selected(2, _) => {
                         self.current_menu = CurrentMenu::OptionMenu;
@@ -185,6 +213,7 @@ impl Scene for TitleScene {
                     MenuSelectionResult::Selected(1, toggle) => {
                         if let MenuEntry::Toggle(_, value) = toggle {
                             state.settings.shader_effects = !state.settings.shader_effects;
+                            state.settings.save(ctx)?;
 
                             *value = state.settings.shader_effects;
                         }
@@ -193,6 +222,7 @@ impl Scene for TitleScene {
                         if let MenuEntry::Toggle(_, value) = toggle {
                             state.settings.original_textures = !state.settings.original_textures;
                             state.reload_textures();
+                            state.settings.save(ctx)?;
 
                             *value = state.settings.original_textures;
                         }
@@ -201,6 +231,7 @@ impl Scene for TitleScene {
                         if let MenuEntry::Toggle(_, value) = toggle {
                             state.settings.seasonal_textures = !state.settings.seasonal_textures;
                             state.reload_textures();
+                            state.settings.save(ctx)?;
 
                             *value = state.settings.seasonal_textures;
                         }
@@ -216,6 +247,49 @@ impl Scene for TitleScene {
                     _ => {}
                 }
             }
+            CurrentMenu::SaveSelectMenu => {
+                match self.save_select_menu.tick(&mut self.controller, state) {
+                    MenuSelectionResult::Selected(slot, entry) if slot < SAVE_SLOTS => {
+                        match entry {
+                            MenuEntry::SaveData(_) if self.delete_mode => {
+                                if let Err(e) = state.delete_save(slot, ctx) {
+                                    log::warn!("Failed to delete save slot {}: {}", slot, e);
+                                } else {
+                                    *entry = MenuEntry::NewSave;
+                                }
+
+                                self.delete_mode = false;
+                            }
+                            MenuEntry::SaveData(_) => {
+                                state.save_slot = slot;
+                                state.sound_manager.play_song(0, &state.constants, ctx)?;
+                                self.tick = 1;
+                                self.current_menu = CurrentMenu::LoadGame;
+                            }
+                            MenuEntry::NewSave if !self.delete_mode => {
+                                state.reset();
+                                state.save_slot = slot;
+                                state.sound_manager.play_song(0, &state.constants, ctx)?;
+                                self.tick = 1;
+                                self.current_menu = CurrentMenu::StartGame;
+                            }
+                            _ => {}
+                        }
+                    }
+                    MenuSelectionResult::Selected(SAVE_SLOTS, _) => {
+                        self.delete_mode = !self.delete_mode;
+                    }
+                    MenuSelectionResult::Selected(_, _) | MenuSelectionResult::Canceled => {
+                        self.delete_mode = false;
+                        self.current_menu = CurrentMenu::MainMenu;
+                    }
+                    _ => {}
+                }
+
+                if let Some(MenuEntry::Active(text)) = self.save_select_menu.entries.get_mut(SAVE_SLOTS) {
+                    *text = if self.delete_mode { "Cancel deletion" } else { "Delete a save" }.to_string();
+                }
+            }
             CurrentMenu::StartGame => {
                 if self.tick == 10 {
                     state.start_new_game(ctx)?;
@@ -264,6 +338,7 @@ impl Scene for TitleScene {
         match self.current_menu {
             CurrentMenu::MainMenu => { self.main_menu.draw(state, ctx)?; }
             CurrentMenu::OptionMenu => { self.option_menu.draw(state, ctx)?; }
+            CurrentMenu::SaveSelectMenu => { self.save_select_menu.draw(state, ctx)?; }
             _ => {}
         }
 
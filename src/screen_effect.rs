@@ -0,0 +1,132 @@
+use crate::common::Rect;
+use crate::frame::Frame;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ScreenEffectType {
+    /// Blinking full screen flash, used by `<FLA` and lightning.
+    Flash,
+    /// Cross shaped flash expanding from a point, used when bosses explode.
+    Explosion,
+    /// Screen turns white and fades back, used on death.
+    WhiteOut,
+}
+
+pub struct ScreenEffect {
+    pub effect_type: ScreenEffectType,
+    pub x: i32,
+    pub y: i32,
+    action_num: u16,
+    counter: i32,
+    width: i32,
+    dead: bool,
+}
+
+impl ScreenEffect {
+    pub fn new(x: i32, y: i32, effect_type: ScreenEffectType) -> ScreenEffect {
+        ScreenEffect {
+            effect_type,
+            x,
+            y,
+            action_num: 0,
+            counter: 0,
+            width: 0,
+            dead: false,
+        }
+    }
+
+    pub fn is_dead(&self) -> bool {
+        self.dead
+    }
+
+    pub fn tick(&mut self, canvas_size: (f32, f32)) {
+        match self.effect_type {
+            ScreenEffectType::Flash => {
+                self.counter += 1;
+                if self.counter > 20 {
+                    self.dead = true;
+                }
+            }
+            ScreenEffectType::Explosion => {
+                match self.action_num {
+                    0 => {
+                        self.counter += 0x200;
+                        self.width += self.counter;
+
+                        if self.width > canvas_size.0 as i32 * 0x200 * 4 {
+                            self.action_num = 1;
+                            self.counter = 0;
+                            self.width = canvas_size.1 as i32 * 0x200;
+                        }
+                    }
+                    1 => {
+                        self.width -= self.width / 8;
+
+                        if self.width / 0x100 == 0 {
+                            self.dead = true;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            ScreenEffectType::WhiteOut => {
+                self.counter += 1;
+                if self.counter > 50 {
+                    self.dead = true;
+                }
+            }
+        }
+    }
+
+    /// Returns the opacity of the effect.
+    pub fn alpha(&self) -> f32 {
+        match self.effect_type {
+            // stays fully white for a bit before fading away
+            ScreenEffectType::WhiteOut => (1.0 - (self.counter - 10).max(0) as f32 / 40.0).max(0.0),
+            _ => 1.0,
+        }
+    }
+
+    /// Returns the areas of the screen covered by the effect, in canvas pixels.
+    pub fn rects(&self, frame: &Frame, canvas_size: (f32, f32)) -> Vec<Rect<isize>> {
+        let (width, height) = (canvas_size.0 as isize, canvas_size.1 as isize);
+        let mut rects = Vec::new();
+
+        if self.dead {
+            return rects;
+        }
+
+        match self.effect_type {
+            ScreenEffectType::Flash => {
+                if self.counter / 2 % 2 != 0 {
+                    rects.push(Rect::new(0, 0, width, height));
+                }
+            }
+            ScreenEffectType::Explosion => {
+                let x = (self.x - frame.x) as isize;
+                let y = (self.y - frame.y) as isize;
+                let size = self.width as isize;
+
+                let top = ((y - size) / 0x200).max(0);
+                let bottom = ((y + size) / 0x200).min(height);
+
+                if self.action_num == 0 {
+                    let left = ((x - size) / 0x200).max(0);
+                    let right = ((x + size) / 0x200).min(width);
+
+                    if left < right {
+                        rects.push(Rect::new(left, 0, right, height));
+                    }
+                }
+
+                if top < bottom {
+                    rects.push(Rect::new(0, top, width, bottom));
+                }
+            }
+            ScreenEffectType::WhiteOut => {
+                rects.push(Rect::new(0, 0, width, height));
+            }
+        }
+
+        rects
+    }
+}
@@ -1,5 +1,8 @@
-use ggez::{Context, GameResult};
-use serde::{Deserialize, Serialize};
+use ggez::{Context, filesystem, GameResult};
+use ggez::filesystem::OpenOptions;
+use ggez::GameError::InvalidValue;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde::de::Error;
 use winit::event::VirtualKeyCode;
 
 use crate::input::keyboard_player_controller::KeyboardController;
@@ -8,6 +11,7 @@ use crate::player::TargetPlayer;
 use crate::input::touch_player_controller::TouchPlayerController;
 
 #[derive(Serialize, Deserialize)]
+#[serde(default)]
 pub struct Settings {
     pub seasonal_textures: bool,
     pub original_textures: bool,
@@ -15,6 +19,7 @@ pub struct Settings {
     pub motion_interpolation: bool,
     pub touch_controls: bool,
     pub player1_key_map: PlayerKeyMap,
+    #[serde(default = "p2_default_keymap", deserialize_with = "deserialize_p2_keymap")]
     pub player2_key_map: PlayerKeyMap,
     #[serde(skip)]
     pub speed: f64,
@@ -27,10 +32,25 @@ pub struct Settings {
 }
 
 impl Settings {
-    pub fn load(_ctx: &mut Context) -> GameResult<Settings> {
+    pub fn load(ctx: &mut Context) -> GameResult<Settings> {
+        if let Ok(file) = filesystem::user_open(ctx, "/settings.yml") {
+            match serde_yaml::from_reader::<_, Settings>(file) {
+                Ok(settings) => return Ok(settings),
+                Err(err) => log::warn!("Failed to deserialize settings: {}", err),
+            }
+        }
+
         Ok(Settings::default())
     }
 
+    pub fn save(&self, ctx: &mut Context) -> GameResult {
+        let file = filesystem::open_options(ctx, "/settings.yml", OpenOptions::new().write(true).create(true).truncate(true))?;
+        serde_yaml::to_writer(file, self)
+            .map_err(|err| InvalidValue(format!("Failed to serialize settings: {}", err)))?;
+
+        Ok(())
+    }
+
     pub fn create_player1_controller(&self) -> Box<dyn PlayerController> {
         if self.touch_controls {
             return Box::new(TouchPlayerController::new());
@@ -63,6 +83,7 @@ impl Default for Settings {
 }
 
 #[derive(Serialize, Deserialize)]
+#[serde(default)]
 pub struct PlayerKeyMap {
     pub left: VirtualKeyCode,
     pub up: VirtualKeyCode,
@@ -77,6 +98,26 @@ pub struct PlayerKeyMap {
     pub map: VirtualKeyCode,
 }
 
+impl Default for PlayerKeyMap {
+    fn default() -> Self {
+        p1_default_keymap()
+    }
+}
+
+/// Fills keys missing from player 2 key map with player 2 defaults rather than player 1 ones.
+fn deserialize_p2_keymap<'de, D: Deserializer<'de>>(deserializer: D) -> Result<PlayerKeyMap, D::Error> {
+    let overrides = serde_yaml::Mapping::deserialize(deserializer)?;
+
+    let mut keymap = serde_yaml::to_value(p2_default_keymap()).map_err(D::Error::custom)?;
+    if let serde_yaml::Value::Mapping(keys) = &mut keymap {
+        for (key, value) in overrides {
+            keys.insert(key, value);
+        }
+    }
+
+    serde_yaml::from_value(keymap).map_err(D::Error::custom)
+}
+
 fn p1_default_keymap() -> PlayerKeyMap {
     PlayerKeyMap {
         left: VirtualKeyCode::Left,
@@ -108,3 +149,30 @@ fn p2_default_keymap() -> PlayerKeyMap {
         map: VirtualKeyCode::Y,
     }
 }
+
+#[test]
+fn test_partial_settings() {
+    let settings: Settings = serde_yaml::from_str("
+shader_effects: false
+player1_key_map:
+  jump: C
+player2_key_map:
+  left: J
+").unwrap();
+
+    assert!(!settings.shader_effects);
+    assert!(settings.seasonal_textures);
+    assert!(settings.motion_interpolation);
+
+    assert_eq!(settings.player1_key_map.jump, VirtualKeyCode::C);
+    assert_eq!(settings.player1_key_map.shoot, VirtualKeyCode::X);
+    assert_eq!(settings.player1_key_map.left, VirtualKeyCode::Left);
+
+    assert_eq!(settings.player2_key_map.left, VirtualKeyCode::J);
+    assert_eq!(settings.player2_key_map.right, VirtualKeyCode::Slash);
+    assert_eq!(settings.player2_key_map.jump, VirtualKeyCode::B);
+
+    let settings: Settings = serde_yaml::from_str("original_textures: true").unwrap();
+    assert!(settings.original_textures);
+    assert_eq!(settings.player2_key_map.left, VirtualKeyCode::Comma);
+}
@@ -1,5 +1,6 @@
+use std::io::Read;
 use std::ops::Div;
-use std::time::Instant;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
 use bitvec::vec::BitVec;
 use chrono::{Datelike, Local};
@@ -11,13 +12,16 @@ use num_traits::clamp;
 use crate::bmfont_renderer::BMFontRenderer;
 use crate::caret::{Caret, CaretType};
 use crate::common::{ControlFlags, Direction, FadeState};
+use crate::credit_script::CreditScriptVM;
 use crate::engine_constants::EngineConstants;
 use crate::input::touch_controls::TouchControls;
 use crate::npc::NPCTable;
 use crate::profile::GameProfile;
+use crate::replay::{Replay, ReplayState};
 use crate::rng::XorShift;
 use crate::scene::game_scene::GameScene;
 use crate::scene::Scene;
+use crate::screen_effect::{ScreenEffect, ScreenEffectType};
 #[cfg(feature = "scripting")]
 use crate::scripting::LuaScriptingState;
 use crate::settings::Settings;
@@ -87,6 +91,9 @@ pub struct SharedGameState {
     pub timing_mode: TimingMode,
     pub control_flags: ControlFlags,
     pub game_flags: BitVec,
+    pub map_flags: BitVec,
+    /// Flags used to skip cutscenes, these are not cleared by `<INI` or on game over.
+    pub skip_flags: BitVec,
     pub fade_state: FadeState,
     /// RNG used by game state, using it for anything else might cause unintended side effects and break replays.
     pub game_rng: XorShift,
@@ -94,11 +101,22 @@ pub struct SharedGameState {
     pub effect_rng: XorShift,
     pub quake_counter: u16,
     pub teleporter_slots: Vec<(u16, u16)>,
+    /// Profile slot used by `<SVP` and `<LDP`.
+    pub save_slot: usize,
+    /// Ticks elapsed since the game was started, stored in the profile.
+    pub play_time: u32,
+    /// Player input recording, not cleared on game reset.
+    pub replay: Replay,
     pub carets: Vec<Caret>,
+    pub screen_effects: Vec<ScreenEffect>,
     pub touch_controls: TouchControls,
     pub base_path: String,
     pub npc_table: NPCTable,
     pub npc_super_pos: (i32, i32),
+    /// Y position of the water surface set by water level NPC, everything below it is submerged.
+    pub water_level: i32,
+    pub npc_curly_target: (i32, i32),
+    pub npc_curly_counter: u16,
     pub stages: Vec<StageData>,
     pub frame_time: f64,
     pub scale: f32,
@@ -110,6 +128,7 @@ pub struct SharedGameState {
     pub screen_size: (f32, f32),
     pub next_scene: Option<Box<dyn Scene>>,
     pub textscript_vm: TextScriptVM,
+    pub creditscript_vm: CreditScriptVM,
     pub season: Season,
     pub constants: EngineConstants,
     pub font: BMFontRenderer,
@@ -163,16 +182,25 @@ impl SharedGameState {
             timing_mode: TimingMode::_50Hz,
             control_flags: ControlFlags(0),
             game_flags: bitvec::bitvec![0; 8000],
+            map_flags: bitvec::bitvec![0; 128],
+            skip_flags: bitvec::bitvec![0; 64],
             fade_state: FadeState::Hidden,
             game_rng: XorShift::new(0),
             effect_rng: XorShift::new(Instant::now().elapsed().as_nanos() as i32),
             quake_counter: 0,
             teleporter_slots: Vec::with_capacity(8),
+            save_slot: 0,
+            play_time: 0,
+            replay: Replay::new(),
             carets: Vec::with_capacity(32),
+            screen_effects: Vec::new(),
             touch_controls: TouchControls::new(),
             base_path: str!(base_path),
             npc_table: NPCTable::new(),
             npc_super_pos: (0, 0),
+            water_level: 0,
+            npc_curly_target: (0, 0),
+            npc_curly_counter: 0,
             stages: Vec::with_capacity(96),
             frame_time: 0.0,
             scale,
@@ -184,6 +212,7 @@ impl SharedGameState {
             canvas_size,
             next_scene: None,
             textscript_vm: TextScriptVM::new(),
+            creditscript_vm: CreditScriptVM::new(),
             season,
             constants,
             font,
@@ -239,9 +268,72 @@ impl SharedGameState {
         Ok(())
     }
 
+    /// Returns the path of profile file for given save slot.
+    pub fn get_save_filename(slot: usize) -> String {
+        if slot == 0 {
+            str!("/Profile.dat")
+        } else {
+            format!("/Profile{}.dat", slot)
+        }
+    }
+
+    /// Reads all slots from Profile.dat if it's a Cave Story+ multi-slot save.
+    fn read_cs_plus_profiles(ctx: &mut Context) -> GameResult<Option<Vec<Option<GameProfile>>>> {
+        if let Ok(mut data) = filesystem::user_open(ctx, "/Profile.dat") {
+            let mut buf = Vec::new();
+            data.read_to_end(&mut buf)?;
+
+            if GameProfile::is_cs_plus_save(&buf) {
+                return Ok(Some(GameProfile::load_cs_plus_save(&buf)?));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn write_cs_plus_profiles(profiles: &[Option<GameProfile>], ctx: &mut Context) -> GameResult {
+        if let Ok(data) = filesystem::open_options(ctx, "/Profile.dat", OpenOptions::new().write(true).create(true).truncate(true)) {
+            GameProfile::write_cs_plus_save(profiles, data)?;
+        } else {
+            log::warn!("Cannot open save file.");
+        }
+
+        Ok(())
+    }
+
+    /// Reads the profile from given save slot, returns `None` if the slot is empty.
+    pub fn read_profile(slot: usize, ctx: &mut Context) -> GameResult<Option<GameProfile>> {
+        if let Some(mut profiles) = SharedGameState::read_cs_plus_profiles(ctx)? {
+            return Ok(if slot < profiles.len() { profiles.swap_remove(slot) } else { None });
+        }
+
+        if let Ok(data) = filesystem::user_open(ctx, SharedGameState::get_save_filename(slot)) {
+            return Ok(Some(GameProfile::load_from_save(data)?));
+        }
+
+        Ok(None)
+    }
+
     pub fn save_game(&mut self, game_scene: &mut GameScene, ctx: &mut Context) -> GameResult {
-        if let Ok(data) = filesystem::open_options(ctx, "/Profile.dat", OpenOptions::new().write(true).create(true)) {
-            let profile = GameProfile::dump(self, game_scene);
+        let mut profile = GameProfile::dump(self, game_scene);
+
+        if let Some(mut profiles) = SharedGameState::read_cs_plus_profiles(ctx)? {
+            if profiles.len() <= self.save_slot {
+                profiles.resize_with(self.save_slot + 1, || None);
+            }
+
+            let mut cs_plus_data = profiles[self.save_slot].as_ref()
+                .and_then(|profile| profile.cs_plus_data)
+                .unwrap_or_default();
+            cs_plus_data.timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+            profile.cs_plus_data = Some(cs_plus_data);
+            profiles[self.save_slot] = Some(profile);
+
+            return SharedGameState::write_cs_plus_profiles(&profiles, ctx);
+        }
+
+        if let Ok(data) = filesystem::open_options(ctx, SharedGameState::get_save_filename(self.save_slot),
+                                                   OpenOptions::new().write(true).create(true).truncate(true)) {
             profile.write_save(data)?;
         } else {
             log::warn!("Cannot open save file.");
@@ -251,39 +343,97 @@ impl SharedGameState {
     }
 
     pub fn load_or_start_game(&mut self, ctx: &mut Context) -> GameResult {
-        if let Ok(data) = filesystem::user_open(ctx, "/Profile.dat") {
-            match GameProfile::load_from_save(data) {
-                Ok(profile) => {
-                    self.reset();
-                    let mut next_scene = GameScene::new(self, ctx, profile.current_map as usize)?;
-
-                    profile.apply(self, &mut next_scene, ctx);
-
-                    #[cfg(feature = "scripting")]
-                        self.lua.reload_scripts(ctx)?;
-
-                    self.next_scene = Some(Box::new(next_scene));
-                    return Ok(());
-                }
-                Err(e) => {
-                    log::warn!("Failed to load save game, starting new one: {}", e);
-                }
+        match SharedGameState::read_profile(self.save_slot, ctx) {
+            Ok(Some(profile)) => {
+                self.reset();
+                let mut next_scene = GameScene::new(self, ctx, profile.current_map as usize)?;
+
+                profile.apply(self, &mut next_scene, ctx);
+
+                #[cfg(feature = "scripting")]
+                    self.lua.reload_scripts(ctx)?;
+
+                self.next_scene = Some(Box::new(next_scene));
+                return Ok(());
+            }
+            Ok(None) => {
+                log::warn!("No save game found, starting new one...");
+            }
+            Err(e) => {
+                log::warn!("Failed to load save game, starting new one: {}", e);
             }
-        } else {
-            log::warn!("No save game found, starting new one...");
         }
 
         self.start_new_game(ctx)
     }
 
+    pub fn delete_save(&mut self, slot: usize, ctx: &mut Context) -> GameResult {
+        if let Some(mut profiles) = SharedGameState::read_cs_plus_profiles(ctx)? {
+            if let Some(profile) = profiles.get_mut(slot) {
+                *profile = None;
+            }
+
+            return SharedGameState::write_cs_plus_profiles(&profiles, ctx);
+        }
+
+        filesystem::delete(ctx, SharedGameState::get_save_filename(slot))
+    }
+
+    /// Restarts current game from its profile and records player 1 inputs from there on.
+    pub fn start_replay_recording(&mut self, game_scene: &mut GameScene, ctx: &mut Context) -> GameResult {
+        let profile = GameProfile::dump(self, game_scene);
+        let seed = self.effect_rng.next_u64();
+
+        self.replay.start_recording(&profile, seed)?;
+        self.start_replay_scene(profile, ctx)
+    }
+
+    /// Loads the replay file and starts playing it back.
+    pub fn start_replay_playback(&mut self, ctx: &mut Context) -> GameResult {
+        self.replay.load(ctx)?;
+
+        let profile = self.replay.start_playback()?;
+        self.start_replay_scene(profile, ctx)
+    }
+
+    fn start_replay_scene(&mut self, profile: GameProfile, ctx: &mut Context) -> GameResult {
+        self.reset();
+        let mut next_scene = GameScene::new(self, ctx, profile.current_map as usize)?;
+
+        profile.apply(self, &mut next_scene, ctx);
+
+        #[cfg(feature = "scripting")]
+            self.lua.reload_scripts(ctx)?;
+
+        self.next_scene = Some(Box::new(next_scene));
+
+        Ok(())
+    }
+
+    /// Stops the replay, recorded inputs are written to the replay file.
+    pub fn stop_replay(&mut self, ctx: &mut Context) -> GameResult {
+        let recording = self.replay.state == ReplayState::Recording;
+        self.replay.stop();
+
+        if recording {
+            self.replay.save(ctx)?;
+        }
+
+        Ok(())
+    }
+
     pub fn reset(&mut self) {
         self.control_flags.0 = 0;
+        self.sound_manager.stop_looping_sfx();
         self.game_flags = bitvec::bitvec![0; 8000];
+        self.map_flags = bitvec::bitvec![0; 128];
         self.fade_state = FadeState::Hidden;
         self.game_rng = XorShift::new(0);
         self.teleporter_slots.clear();
+        self.play_time = 0;
         self.quake_counter = 0;
         self.carets.clear();
+        self.screen_effects.clear();
         self.textscript_vm.set_mode(ScriptMode::Map);
         self.textscript_vm.suspend = true;
     }
@@ -311,6 +461,20 @@ impl SharedGameState {
         self.carets.push(Caret::new(x, y, ctype, direct, &self.constants));
     }
 
+    pub fn tick_screen_effects(&mut self) {
+        let canvas_size = self.canvas_size;
+
+        for effect in self.screen_effects.iter_mut() {
+            effect.tick(canvas_size);
+        }
+
+        self.screen_effects.retain(|e| !e.is_dead());
+    }
+
+    pub fn create_screen_effect(&mut self, x: i32, y: i32, effect_type: ScreenEffectType) {
+        self.screen_effects.push(ScreenEffect::new(x, y, effect_type));
+    }
+
     pub fn set_speed(&mut self, value: f64) {
         self.settings.speed = clamp(value, 0.1, 3.0);
         self.frame_time = 0.0;
@@ -106,6 +106,42 @@ impl SoundManager {
         let _ = self.tx.send(PlaybackMessage::PlaySample(id));
     }
 
+    /// Plays specified sound effect in a loop until it's stopped with `stop_sfx`.
+    pub fn loop_sfx(&mut self, id: u8) {
+        let _ = self.tx.send(PlaybackMessage::LoopSample(id));
+    }
+
+    pub fn stop_sfx(&mut self, id: u8) {
+        let _ = self.tx.send(PlaybackMessage::StopSample(id));
+    }
+
+    /// Changes the sample rate specified looping sound effect is played back at, which alters its pitch.
+    pub fn set_sfx_sample_rate(&mut self, id: u8, sample_rate: f32) {
+        let _ = self.tx.send(PlaybackMessage::SetSampleRate(id, sample_rate));
+    }
+
+    /// Stops all looping sound effects.
+    pub fn stop_looping_sfx(&mut self) {
+        let _ = self.tx.send(PlaybackMessage::StopLoops);
+    }
+
+    /// Loops stream sounds at given frequency, used by `<SSS` and the Core.
+    pub fn play_stream_sfx(&mut self, freq: u16) {
+        // same frequency conversion as DirectSound version of the game
+        let freq = freq as f32;
+        self.set_sfx_sample_rate(40, freq * 10.0 + 100.0);
+        self.set_sfx_sample_rate(41, (freq + 100.0) * 10.0 + 100.0);
+        self.loop_sfx(40);
+        self.loop_sfx(41);
+    }
+
+    /// Stops stream and propeller sounds.
+    pub fn stop_stream_sfx(&mut self) {
+        self.stop_sfx(40);
+        self.stop_sfx(41);
+        self.stop_sfx(58);
+    }
+
     pub fn play_song(&mut self, song_id: usize, constants: &EngineConstants, ctx: &mut Context) -> GameResult {
         if self.current_song_id == song_id {
             return Ok(());
@@ -137,6 +173,23 @@ impl SoundManager {
         Ok(())
     }
 
+    /// Fades out current BGM, it can be brought back with `restore_state`.
+    pub fn fade_out(&mut self) -> GameResult {
+        if self.current_song_id == 0 {
+            return Ok(());
+        }
+
+        log::info!("Fading out BGM");
+
+        self.prev_song_id = self.current_song_id;
+        self.current_song_id = 0;
+
+        self.tx.send(PlaybackMessage::SaveState)?;
+        self.tx.send(PlaybackMessage::FadeOut)?;
+
+        Ok(())
+    }
+
     pub fn save_state(&mut self) -> GameResult {
         self.tx.send(PlaybackMessage::SaveState)?;
         self.prev_song_id = self.current_song_id;
@@ -167,8 +220,13 @@ impl SoundManager {
 
 enum PlaybackMessage {
     Stop,
+    FadeOut,
     PlaySong(Box<Song>),
     PlaySample(u8),
+    LoopSample(u8),
+    StopSample(u8),
+    SetSampleRate(u8, f32),
+    StopLoops,
     SetSpeed(f32),
     SaveState,
     RestoreState,
@@ -189,6 +247,8 @@ fn run<T>(rx: Receiver<PlaybackMessage>, bank: SoundBank,
     let mut state = PlaybackState::Stopped;
     let mut saved_state: Option<SavedPlaybackState> = None;
     let mut speed = 1.0;
+    let mut bgm_volume = 1.0f32;
+    let mut bgm_fading = false;
     let mut org_engine = PlaybackEngine::new(Song::empty(), &bank);
     let mut pixtone = PixTonePlayback::new();
     pixtone.create_samples();
@@ -197,6 +257,8 @@ fn run<T>(rx: Receiver<PlaybackMessage>, bank: SoundBank,
     org_engine.set_sample_rate(sample_rate as usize);
     org_engine.loops = usize::MAX;
 
+    // BGM fades out over about a second
+    let fade_step = 1.0 / sample_rate;
     let buf_size = sample_rate as usize * 10 / 1000;
     let mut bgm_buf = vec![0x8080; buf_size];
     let mut pxt_buf = vec![0x8000; buf_size];
@@ -218,6 +280,8 @@ fn run<T>(rx: Receiver<PlaybackMessage>, bank: SoundBank,
                         }
 
                         org_engine.start_song(*song, &bank);
+                        bgm_volume = 1.0;
+                        bgm_fading = false;
 
                         for i in &mut bgm_buf[0..frames] { *i = 0x8080 };
                         frames = org_engine.render_to(&mut bgm_buf);
@@ -228,6 +292,18 @@ fn run<T>(rx: Receiver<PlaybackMessage>, bank: SoundBank,
                     Ok(PlaybackMessage::PlaySample(id)) => {
                         pixtone.play_sfx(id);
                     }
+                    Ok(PlaybackMessage::LoopSample(id)) => {
+                        pixtone.loop_sfx(id);
+                    }
+                    Ok(PlaybackMessage::StopSample(id)) => {
+                        pixtone.stop_sfx(id);
+                    }
+                    Ok(PlaybackMessage::SetSampleRate(id, rate)) => {
+                        pixtone.set_sample_rate(id, rate);
+                    }
+                    Ok(PlaybackMessage::StopLoops) => {
+                        pixtone.stop_loops();
+                    }
                     Ok(PlaybackMessage::Stop) => {
                         if state == PlaybackState::Stopped {
                             saved_state = None;
@@ -235,6 +311,9 @@ fn run<T>(rx: Receiver<PlaybackMessage>, bank: SoundBank,
 
                         state = PlaybackState::Stopped;
                     }
+                    Ok(PlaybackMessage::FadeOut) => {
+                        bgm_fading = true;
+                    }
                     Ok(PlaybackMessage::SetSpeed(new_speed)) => {
                         assert!(new_speed > 0.0);
                         speed = new_speed;
@@ -252,6 +331,9 @@ fn run<T>(rx: Receiver<PlaybackMessage>, bank: SoundBank,
                                 org_engine.set_position(0);
                             }
 
+                            bgm_volume = 1.0;
+                            bgm_fading = false;
+
                             for i in &mut bgm_buf[0..frames] { *i = 0x8080 };
                             frames = org_engine.render_to(&mut bgm_buf);
                             bgm_index = 0;
@@ -279,6 +361,21 @@ fn run<T>(rx: Receiver<PlaybackMessage>, bank: SoundBank,
                         ((sample & 0xff) << 8, sample & 0xff00)
                     }
                 };
+                let (org_sample_l, org_sample_r) = if bgm_fading {
+                    bgm_volume -= fade_step;
+
+                    if bgm_volume <= 0.0 {
+                        bgm_volume = 1.0;
+                        bgm_fading = false;
+                        state = PlaybackState::Stopped;
+                        (0x8000, 0x8000)
+                    } else {
+                        let fade = |sample: u16| ((((sample ^ 0x8000) as i16) as f32 * bgm_volume) as i16) as u16 ^ 0x8000;
+                        (fade(org_sample_l), fade(org_sample_r))
+                    }
+                } else {
+                    (org_sample_l, org_sample_r)
+                };
                 let pxt_sample: u16 = pxt_buf[pxt_index];
 
                 if pxt_index < (pxt_buf.len() - 1) {
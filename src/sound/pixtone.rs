@@ -211,12 +211,22 @@ impl PixToneParameters {
     }
 }
 
+/// Sample rate PixTone sounds are synthesized at.
+const BASE_SAMPLE_RATE: f32 = 22050.0;
+
 #[derive(Copy, Clone, PartialEq)]
 pub struct PlaybackState(u8, f32, u32);
 
+/// State of a looping sound, (id, position).
+#[derive(Copy, Clone, PartialEq)]
+pub struct LoopingState(u8, f32);
+
 pub struct PixTonePlayback {
     pub samples: HashMap<u8, Vec<i16>>,
     pub playback_state: Vec<PlaybackState>,
+    pub looping_state: Vec<LoopingState>,
+    /// Playback sample rates overridden at runtime, used for pitch control.
+    pub sample_rates: HashMap<u8, f32>,
 }
 
 impl PixTonePlayback {
@@ -224,6 +234,8 @@ impl PixTonePlayback {
         PixTonePlayback {
             samples: HashMap::new(),
             playback_state: vec![],
+            looping_state: vec![],
+            sample_rates: HashMap::new(),
         }
     }
 
@@ -248,13 +260,38 @@ impl PixTonePlayback {
         self.playback_state.push(PlaybackState(id, 0.0, tag));
     }
 
+    /// Starts playing specified sound in a loop, does nothing if it's already looping.
+    pub fn loop_sfx(&mut self, id: u8) {
+        if !self.looping_state.iter().any(|state| state.0 == id) {
+            self.looping_state.push(LoopingState(id, 0.0));
+        }
+    }
+
+    /// Stops all instances of specified sound, including looping ones.
+    pub fn stop_sfx(&mut self, id: u8) {
+        self.playback_state.retain(|state| state.0 != id);
+        self.looping_state.retain(|state| state.0 != id);
+        self.sample_rates.remove(&id);
+    }
+
+    /// Stops all looping sounds and resets their sample rates.
+    pub fn stop_loops(&mut self) {
+        self.looping_state.clear();
+        self.sample_rates.clear();
+    }
+
+    /// Sets the sample rate used by looping instances of specified sound.
+    pub fn set_sample_rate(&mut self, id: u8, sample_rate: f32) {
+        self.sample_rates.insert(id, sample_rate);
+    }
+
     pub fn mix(&mut self, dst: &mut [u16], sample_rate: f32) {
         let mut scan = VecMutScan::new(&mut self.playback_state);
-        let delta = 22050.0 / sample_rate;
 
         while let Some(item) = scan.next() {
             let mut state = *item;
             let mut remove = false;
+            let delta = BASE_SAMPLE_RATE / sample_rate;
 
             if let Some(sample) = self.samples.get(&state.0) {
                 if sample.is_empty() {
@@ -289,5 +326,32 @@ impl PixTonePlayback {
                 }
             }
         }
+
+        for state in self.looping_state.iter_mut() {
+            let delta = self.sample_rates.get(&state.0).copied().unwrap_or(BASE_SAMPLE_RATE) / sample_rate;
+
+            if let Some(sample) = self.samples.get(&state.0) {
+                if sample.is_empty() {
+                    continue;
+                }
+
+                let len = sample.len();
+
+                for result in dst.iter_mut() {
+                    // wrap around the neighbouring samples too, so the loop point doesn't click
+                    let pos = state.1 as usize % len;
+                    let s1 = (sample[pos] as f32) / 32768.0;
+                    let s2 = (sample[(pos + 1) % len] as f32) / 32768.0;
+                    let s3 = (sample[(pos + 2) % len] as f32) / 32768.0;
+                    let s4 = (sample[(pos + len - 1) % len] as f32) / 32768.0;
+
+                    let s = cubic_interp(s1, s2, s4, s3, state.1.fract()) * 32768.0;
+                    let sam = (*result ^ 0x8000) as i16;
+                    *result = sam.saturating_add(s as i16) as u16 ^ 0x8000;
+
+                    state.1 = (state.1 + delta) % len as f32;
+                }
+            }
+        }
     }
 }
@@ -1,5 +1,5 @@
 use std::cmp::Ordering;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::io;
 use std::io::Cursor;
 use std::io::Seek;
@@ -17,6 +17,7 @@ use num_traits::{clamp, FromPrimitive};
 
 use crate::bitfield;
 use crate::common::{Direction, FadeDirection, FadeState};
+use crate::components::falling_island::FallingIslandMode;
 use crate::encoding::{read_cur_shift_jis, read_cur_wtf8};
 use crate::engine_constants::EngineConstants;
 use crate::entity::GameEntity;
@@ -25,6 +26,7 @@ use crate::npc::NPC;
 use crate::player::{ControlMode, TargetPlayer};
 use crate::scene::game_scene::GameScene;
 use crate::scene::title_scene::TitleScene;
+use crate::screen_effect::ScreenEffectType;
 use crate::shared_game_state::SharedGameState;
 use crate::str;
 use crate::weapon::WeaponType;
@@ -144,7 +146,7 @@ pub enum OpCode {
     /// <FL+xxxx, Sets the flag xxxx to true
     #[strum(serialize = "FL+")]
     FLp,
-    /// <MP-xxxx, Sets the map xxxx to true
+    /// <MP+xxxx, Sets the map flag for stage xxxx
     #[strum(serialize = "MP+")]
     MPp,
     /// <SK-xxxx, Sets the skip flag xxx to false
@@ -178,7 +180,7 @@ pub enum OpCode {
     /// <TAMxxxx:yyyy:zzzz, Trades weapon xxxx for weapon yyyy with zzzz ammo
     TAM,
 
-    /// <UNJxxxx, Jumps to event xxxx if no damage has been taken
+    /// <UNJxxxx, Jumps to event xxxx if no damage has been taken since last <UNJ
     UNJ,
     /// <NCJxxxx:yyyy, Jumps to event xxxx if NPC of type yyyy is alive
     NCJ,
@@ -355,6 +357,8 @@ pub enum TextScriptExecutionState {
     WaitStanding(u16, u32),
     WaitConfirmation(u16, u32, u16, u8, ConfirmSelection),
     WaitFade(u16, u32),
+    MapSystem(u16, u32),
+    FallingIsland(u16, u32),
     SaveProfile(u16, u32),
     LoadProfile,
     Reset,
@@ -379,6 +383,10 @@ pub struct TextScriptVM {
     pub line_1: Vec<char>,
     pub line_2: Vec<char>,
     pub line_3: Vec<char>,
+    /// Values printed by `<NUM`, set by `<IT+` and `<AM+`.
+    pub numbers: [u16; 4],
+    /// Digits queued by `<NUM`, typed before the rest of the message.
+    pub pending_chars: VecDeque<char>,
 }
 
 impl Default for TextScriptVM {
@@ -461,6 +469,8 @@ impl TextScriptVM {
             line_1: Vec::with_capacity(24),
             line_2: Vec::with_capacity(24),
             line_3: Vec::with_capacity(24),
+            numbers: [0; 4],
+            pending_chars: VecDeque::new(),
         }
     }
 
@@ -487,6 +497,50 @@ impl TextScriptVM {
         self.clear_text_box();
     }
 
+    /// Appends a character to the text box, moving to the next line on line feeds.
+    pub fn append_char(&mut self, chr: char) {
+        match chr {
+            '\n' if self.current_line == TextScriptLine::Line1 => {
+                self.current_line = TextScriptLine::Line2;
+            }
+            '\n' if self.current_line == TextScriptLine::Line2 => {
+                self.current_line = TextScriptLine::Line3;
+            }
+            '\n' => {
+                self.line_1.clear();
+                self.line_1.append(&mut self.line_2);
+                self.line_2.append(&mut self.line_3);
+            }
+            '\r' => {}
+            _ if self.current_line == TextScriptLine::Line1 => {
+                self.line_1.push(chr);
+            }
+            _ if self.current_line == TextScriptLine::Line2 => {
+                self.line_2.push(chr);
+            }
+            _ if self.current_line == TextScriptLine::Line3 => {
+                self.line_3.push(chr);
+            }
+            _ => {}
+        }
+    }
+
+    /// Stores a value printed by `<NUM0000`, the quantity given by last `<IT+` or `<AM+`.
+    pub fn store_number(&mut self, value: u16) {
+        self.numbers[0] = value;
+    }
+
+    /// Queues digits of a stored number for the typewriter, returns the number of queued digits.
+    pub fn queue_number(&mut self, index: usize) -> u32 {
+        if let Some(num) = self.numbers.get(index).copied() {
+            let digits = num.to_string();
+            self.pending_chars.extend(digits.chars());
+            return digits.len() as u32;
+        }
+
+        0
+    }
+
     pub fn clear_text_box(&mut self) {
         self.flags.0 = 0;
         self.face = 0;
@@ -495,6 +549,7 @@ impl TextScriptVM {
         self.line_1.clear();
         self.line_2.clear();
         self.line_3.clear();
+        self.pending_chars.clear();
     }
 
     pub fn set_mode(&mut self, mode: ScriptMode) {
@@ -536,32 +591,12 @@ impl TextScriptVM {
                         let mut cursor = Cursor::new(bytecode);
                         cursor.seek(SeekFrom::Start(ip as u64))?;
 
-                        let chr = std::char::from_u32(read_cur_varint(&mut cursor)? as u32).unwrap_or('\u{fffd}');
+                        let chr = match state.textscript_vm.pending_chars.pop_front() {
+                            Some(chr) => chr,
+                            None => std::char::from_u32(read_cur_varint(&mut cursor)? as u32).unwrap_or('\u{fffd}'),
+                        };
 
-                        match chr {
-                            '\n' if state.textscript_vm.current_line == TextScriptLine::Line1 => {
-                                state.textscript_vm.current_line = TextScriptLine::Line2;
-                            }
-                            '\n' if state.textscript_vm.current_line == TextScriptLine::Line2 => {
-                                state.textscript_vm.current_line = TextScriptLine::Line3;
-                            }
-                            '\n' => {
-                                state.textscript_vm.line_1.clear();
-                                state.textscript_vm.line_1.append(&mut state.textscript_vm.line_2);
-                                state.textscript_vm.line_2.append(&mut state.textscript_vm.line_3);
-                            }
-                            '\r' => {}
-                            _ if state.textscript_vm.current_line == TextScriptLine::Line1 => {
-                                state.textscript_vm.line_1.push(chr);
-                            }
-                            _ if state.textscript_vm.current_line == TextScriptLine::Line2 => {
-                                state.textscript_vm.line_2.push(chr);
-                            }
-                            _ if state.textscript_vm.current_line == TextScriptLine::Line3 => {
-                                state.textscript_vm.line_3.push(chr);
-                            }
-                            _ => {}
-                        }
+                        state.textscript_vm.append_char(chr);
 
                         if remaining > 1 {
                             let ticks = if state.textscript_vm.flags.fast()
@@ -649,6 +684,18 @@ impl TextScriptVM {
                     }
                     break;
                 }
+                TextScriptExecutionState::MapSystem(event, ip) => {
+                    if !game_scene.map_system.visible() {
+                        state.textscript_vm.state = TextScriptExecutionState::Running(event, ip);
+                    }
+                    break;
+                }
+                TextScriptExecutionState::FallingIsland(event, ip) => {
+                    if !game_scene.falling_island.visible() {
+                        state.textscript_vm.state = TextScriptExecutionState::Running(event, ip);
+                    }
+                    break;
+                }
 
                 TextScriptExecutionState::SaveProfile(event, ip) => {
                     state.save_game(game_scene, ctx)?;
@@ -858,6 +905,74 @@ impl TextScriptVM {
                             exec_state = TextScriptExecutionState::Running(event, cursor.position() as u32);
                         }
                     }
+                    OpCode::SKp | OpCode::SKm => {
+                        let flag_num = read_cur_varint(&mut cursor)? as usize;
+
+                        if flag_num < state.skip_flags.len() {
+                            state.skip_flags.set(flag_num, op == OpCode::SKp);
+                        }
+
+                        exec_state = TextScriptExecutionState::Running(event, cursor.position() as u32);
+                    }
+                    OpCode::SKJ => {
+                        let flag_num = read_cur_varint(&mut cursor)? as usize;
+                        let event_num = read_cur_varint(&mut cursor)? as u16;
+
+                        if let Some(true) = state.skip_flags.get(flag_num) {
+                            exec_state = TextScriptExecutionState::Running(event_num, 0);
+                        } else {
+                            exec_state = TextScriptExecutionState::Running(event, cursor.position() as u32);
+                        }
+                    }
+                    OpCode::MPp => {
+                        let stage_id = read_cur_varint(&mut cursor)? as usize;
+
+                        if stage_id < state.map_flags.len() {
+                            state.map_flags.set(stage_id, true);
+                        }
+
+                        exec_state = TextScriptExecutionState::Running(event, cursor.position() as u32);
+                    }
+                    OpCode::MPJ => {
+                        let event_num = read_cur_varint(&mut cursor)? as u16;
+
+                        if let Some(true) = state.map_flags.get(game_scene.stage_id) {
+                            exec_state = TextScriptExecutionState::Running(event_num, 0);
+                        } else {
+                            exec_state = TextScriptExecutionState::Running(event, cursor.position() as u32);
+                        }
+                    }
+                    OpCode::CRE => {
+                        state.control_flags.set_credits_running(true);
+                        state.creditscript_vm.start();
+
+                        exec_state = TextScriptExecutionState::Running(event, cursor.position() as u32);
+                    }
+                    OpCode::SIL => {
+                        let illustration_id = read_cur_varint(&mut cursor)? as u16;
+                        state.creditscript_vm.set_illustration(illustration_id);
+
+                        exec_state = TextScriptExecutionState::Running(event, cursor.position() as u32);
+                    }
+                    OpCode::CIL => {
+                        state.creditscript_vm.hide_illustration();
+
+                        exec_state = TextScriptExecutionState::Running(event, cursor.position() as u32);
+                    }
+                    OpCode::STC => {
+                        let new_record = game_scene.hud_player1.nikumaru_counter.save_counter(state, &game_scene.player1, ctx)?;
+
+                        if new_record {
+                            log::info!("New Nikumaru counter record: {} ticks", game_scene.hud_player1.nikumaru_counter.tick);
+                        }
+
+                        exec_state = TextScriptExecutionState::Running(event, cursor.position() as u32);
+                    }
+                    OpCode::MLP => {
+                        game_scene.map_system.open(&game_scene.stage, &game_scene.player1);
+
+                        exec_state = TextScriptExecutionState::MapSystem(event, cursor.position() as u32);
+                    }
                     OpCode::ITJ => {
                         let item_id = read_cur_varint(&mut cursor)? as u16;
                         let event_num = read_cur_varint(&mut cursor)? as u16;
@@ -1042,6 +1157,7 @@ impl TextScriptVM {
                         new_scene.intro_mode = game_scene.intro_mode;
                         new_scene.inventory_player1 = game_scene.inventory_player1.clone();
                         new_scene.inventory_player2 = game_scene.inventory_player2.clone();
+                        new_scene.hud_player1.nikumaru_counter.tick = game_scene.hud_player1.nikumaru_counter.tick;
                         new_scene.player1 = game_scene.player1.clone();
                         new_scene.player1.vel_x = 0;
                         new_scene.player1.vel_y = 0;
@@ -1108,6 +1224,7 @@ impl TextScriptVM {
                         let mode: Option<ControlMode> = FromPrimitive::from_u8(control_mode);
                         if let Some(mode) = mode {
                             game_scene.player1.control_mode = mode;
+                            game_scene.player2.control_mode = mode;
                         }
 
                         exec_state = TextScriptExecutionState::Running(event, cursor.position() as u32);
@@ -1149,7 +1266,7 @@ impl TextScriptVM {
                         exec_state = TextScriptExecutionState::Running(event, cursor.position() as u32);
                     }
                     OpCode::FMU => {
-                        state.sound_manager.play_song(0, &state.constants, ctx)?;
+                        state.sound_manager.fade_out()?;
 
                         exec_state = TextScriptExecutionState::Running(event, cursor.position() as u32);
                     }
@@ -1165,6 +1282,29 @@ impl TextScriptVM {
 
                         exec_state = TextScriptExecutionState::Running(event, cursor.position() as u32);
                     }
+                    OpCode::FLA => {
+                        state.create_screen_effect(0, 0, ScreenEffectType::Flash);
+
+                        exec_state = TextScriptExecutionState::Running(event, cursor.position() as u32);
+                    }
+                    OpCode::SSS => {
+                        let freq = read_cur_varint(&mut cursor)? as u16;
+
+                        state.sound_manager.play_stream_sfx(freq);
+
+                        exec_state = TextScriptExecutionState::Running(event, cursor.position() as u32);
+                    }
+                    OpCode::SPS => {
+                        // propeller sound
+                        state.sound_manager.loop_sfx(58);
+
+                        exec_state = TextScriptExecutionState::Running(event, cursor.position() as u32);
+                    }
+                    OpCode::CSS | OpCode::CPS => {
+                        state.sound_manager.stop_stream_sfx();
+
+                        exec_state = TextScriptExecutionState::Running(event, cursor.position() as u32);
+                    }
                     OpCode::DNP => {
                         let event_num = read_cur_varint(&mut cursor)? as u16;
 
@@ -1308,7 +1448,7 @@ impl TextScriptVM {
                                     npc.direction = direction;
                                 }
 
-                                npc.tick(state, ([&mut game_scene.player1, &mut game_scene.player2], &game_scene.npc_list, &mut game_scene.stage, &game_scene.bullet_manager))?;
+                                npc.tick(state, ([&mut game_scene.player1, &mut game_scene.player2], &game_scene.npc_list, &mut game_scene.stage, &mut game_scene.bullet_manager))?;
                             }
                         }
 
@@ -1389,6 +1529,9 @@ impl TextScriptVM {
                             game_scene.inventory_player2.add_item(item_id);
                         }
 
+                        let amount = game_scene.inventory_player1.get_item(item_id).map_or(0, |item| item.1);
+                        state.textscript_vm.store_number(amount);
+
                         exec_state = TextScriptExecutionState::Running(event, cursor.position() as u32);
                     }
                     OpCode::IpN => {
@@ -1418,6 +1561,8 @@ impl TextScriptVM {
                         let max_ammo = read_cur_varint(&mut cursor)? as u16;
                         let weapon_type: Option<WeaponType> = FromPrimitive::from_u8(weapon_id);
 
+                        state.textscript_vm.store_number(max_ammo);
+
                         if let Some(wtype) = weapon_type {
                             game_scene.inventory_player1.add_weapon(wtype, max_ammo);
                             game_scene.inventory_player2.add_weapon(wtype, max_ammo);
@@ -1483,42 +1628,65 @@ impl TextScriptVM {
 
                         exec_state = TextScriptExecutionState::LoadProfile;
                     }
-                    // unimplemented opcodes
-                    // Zero operands
-                    OpCode::CIL | OpCode::CPS | OpCode::KE2 |
-                    OpCode::CRE | OpCode::CSS | OpCode::FLA | OpCode::MLP |
-                    OpCode::SPS | OpCode::FR2 |
-                    OpCode::STC | OpCode::HM2 => {
-                        log::warn!("unimplemented opcode: {:?}", op);
+                    OpCode::UNJ => {
+                        let event_num = read_cur_varint(&mut cursor)? as u16;
+                        let damaged = game_scene.player1.was_damaged || game_scene.player2.was_damaged;
 
-                        exec_state = TextScriptExecutionState::Running(event, cursor.position() as u32);
+                        game_scene.player1.was_damaged = false;
+                        game_scene.player2.was_damaged = false;
+
+                        if !damaged {
+                            exec_state = TextScriptExecutionState::Running(event_num, 0);
+                        } else {
+                            exec_state = TextScriptExecutionState::Running(event, cursor.position() as u32);
+                        }
                     }
-                    // One operand codes
-                    OpCode::NUM | OpCode::MPp | OpCode::SKm | OpCode::SKp |
-                    OpCode::UNJ | OpCode::MPJ | OpCode::XX1 | OpCode::SIL |
-                    OpCode::SSS | OpCode::ACH => {
-                        let par_a = read_cur_varint(&mut cursor)?;
+                    OpCode::XX1 => {
+                        let mode = read_cur_varint(&mut cursor)?;
 
-                        log::warn!("unimplemented opcode: {:?} {}", op, par_a);
+                        game_scene.falling_island.start(if mode == 0 { FallingIslandMode::Crash } else { FallingIslandMode::Saved });
 
-                        exec_state = TextScriptExecutionState::Running(event, cursor.position() as u32);
+                        exec_state = TextScriptExecutionState::FallingIsland(event, cursor.position() as u32);
                     }
-                    // Two operand codes
-                    OpCode::SKJ => {
-                        let par_a = read_cur_varint(&mut cursor)?;
-                        let par_b = read_cur_varint(&mut cursor)?;
+                    OpCode::NUM => {
+                        let index = read_cur_varint(&mut cursor)? as usize;
 
-                        log::warn!("unimplemented opcode: {:?} {} {}", op, par_a, par_b);
+                        let len = state.textscript_vm.queue_number(index);
 
-                        exec_state = TextScriptExecutionState::Running(event, cursor.position() as u32);
+                        if state.textscript_vm.flags.render() && len > 0 {
+                            exec_state = TextScriptExecutionState::Msg(event, cursor.position() as u32, len, 4);
+                        } else {
+                            state.textscript_vm.pending_chars.clear();
+                            exec_state = TextScriptExecutionState::Running(event, cursor.position() as u32);
+                        }
                     }
-                    // Three operand codes
                     OpCode::TAM => {
+                        let old_weapon_id = read_cur_varint(&mut cursor)? as u8;
+                        let new_weapon_id = read_cur_varint(&mut cursor)? as u8;
+                        let max_ammo = read_cur_varint(&mut cursor)? as u16;
+                        let old_weapon_type: Option<WeaponType> = FromPrimitive::from_u8(old_weapon_id);
+                        let new_weapon_type: Option<WeaponType> = FromPrimitive::from_u8(new_weapon_id);
+
+                        if let (Some(old_wtype), Some(new_wtype)) = (old_weapon_type, new_weapon_type) {
+                            game_scene.inventory_player1.trade_weapon(old_wtype, new_wtype, max_ammo);
+                            game_scene.inventory_player2.trade_weapon(old_wtype, new_wtype, max_ammo);
+                        }
+
+                        exec_state = TextScriptExecutionState::Running(event, cursor.position() as u32);
+                    }
+                    // unimplemented opcodes
+                    // Zero operands
+                    OpCode::KE2 | OpCode::FR2 |
+                    OpCode::HM2 => {
+                        log::warn!("unimplemented opcode: {:?}", op);
+
+                        exec_state = TextScriptExecutionState::Running(event, cursor.position() as u32);
+                    }
+                    // One operand codes
+                    OpCode::ACH => {
                         let par_a = read_cur_varint(&mut cursor)?;
-                        let par_b = read_cur_varint(&mut cursor)?;
-                        let par_c = read_cur_varint(&mut cursor)?;
 
-                        log::warn!("unimplemented opcode: {:?} {} {} {}", op, par_a, par_b, par_c);
+                        log::warn!("unimplemented opcode: {:?} {}", op, par_a);
 
                         exec_state = TextScriptExecutionState::Running(event, cursor.position() as u32);
                     }
@@ -1966,3 +2134,24 @@ fn test_varint() {
         assert_eq!(result, n);
     }
 }
+
+#[test]
+fn test_num_after_item_and_ammo() {
+    let mut vm = TextScriptVM::new();
+
+    // <IT+0035<NUM0000, with one item held
+    vm.store_number(1);
+    assert_eq!(vm.queue_number(0), 1);
+    assert_eq!(vm.pending_chars.iter().collect::<String>(), "1");
+    assert!(vm.line_1.is_empty());
+
+    // <AM+0005:0100<NUM0000
+    vm.clear_text_box();
+    assert!(vm.pending_chars.is_empty());
+    vm.store_number(100);
+    assert_eq!(vm.queue_number(0), 3);
+    assert_eq!(vm.pending_chars.iter().collect::<String>(), "100");
+
+    assert_eq!(vm.queue_number(4), 0);
+    assert_eq!(vm.pending_chars.len(), 3);
+}
@@ -58,6 +58,9 @@ pub struct Weapon {
     pub experience: u16,
     pub ammo: u16,
     pub max_ammo: u16,
+    counter1: u16,
+    counter2: u16,
+    empty_counter: u16,
 }
 
 impl Weapon {
@@ -68,6 +71,9 @@ impl Weapon {
             experience,
             ammo,
             max_ammo,
+            counter1: 0,
+            counter2: 0,
+            empty_counter: 0,
         }
     }
 
@@ -84,6 +90,12 @@ impl Weapon {
         false
     }
 
+    pub fn refill_ammo(&mut self, ammo: u16) {
+        if self.max_ammo != 0 {
+            self.ammo = self.ammo.saturating_add(ammo).min(self.max_ammo);
+        }
+    }
+
     fn shoot_bullet_snake(&mut self, player: &Player, player_id: TargetPlayer, bullet_manager: &mut BulletManager, state: &mut SharedGameState) {
         if player.controller.trigger_shoot() && bullet_manager.count_bullets_multi([1, 2, 3], player_id) < 4 {
             let btype = match self.level {
@@ -258,7 +270,581 @@ impl Weapon {
         }
     }
 
-    pub fn shoot_bullet(&mut self, player: &Player, player_id: TargetPlayer, bullet_manager: &mut BulletManager, state: &mut SharedGameState) {
+    fn shoot_bullet_machine_gun(&mut self, player: &mut Player, player_id: TargetPlayer, bullet_manager: &mut BulletManager, state: &mut SharedGameState) {
+        if bullet_manager.count_bullets_multi([10, 11, 12], player_id) > 4 {
+            return;
+        }
+
+        let btype = match self.level {
+            WeaponLevel::Level1 => { 10 }
+            WeaponLevel::Level2 => { 11 }
+            WeaponLevel::Level3 => { 12 }
+            WeaponLevel::None => { unreachable!() }
+        };
+
+        if !player.controller.shoot() {
+            // fire immediately on the next press
+            self.counter1 = 6;
+
+            // ammo regenerates while the trigger is released
+            self.counter2 += 1;
+            if (player.equip.has_turbocharge() && self.counter2 > 1) || self.counter2 > 4 {
+                self.counter2 = 0;
+                self.refill_ammo(1);
+            }
+
+            return;
+        }
+
+        self.counter1 += 1;
+        if self.counter1 < 6 {
+            return;
+        }
+
+        self.counter1 = 0;
+
+        if !self.consume_ammo(1) {
+            state.sound_manager.play_sfx(37);
+            if self.empty_counter == 0 {
+                state.create_caret(player.x, player.y, CaretType::Empty, Direction::Left);
+                self.empty_counter = 50;
+            }
+            return;
+        }
+
+        if player.up {
+            if self.level == WeaponLevel::Level3 {
+                player.vel_y += 0x100;
+            }
+
+            match player.direction {
+                Direction::Left => {
+                    bullet_manager.create_bullet(player.x - 3 * 0x200, player.y - 8 * 0x200, btype, player_id, Direction::Up, &state.constants);
+                    state.create_caret(player.x - 3 * 0x200, player.y - 8 * 0x200, CaretType::Shoot, Direction::Left);
+                }
+                Direction::Right => {
+                    bullet_manager.create_bullet(player.x + 3 * 0x200, player.y - 8 * 0x200, btype, player_id, Direction::Up, &state.constants);
+                    state.create_caret(player.x + 3 * 0x200, player.y - 8 * 0x200, CaretType::Shoot, Direction::Left);
+                }
+                _ => {}
+            }
+        } else if player.down {
+            // shooting downwards at level 3 lets the player hover
+            if self.level == WeaponLevel::Level3 {
+                if player.vel_y > 0 {
+                    player.vel_y /= 2;
+                }
+
+                if player.vel_y > -0x400 {
+                    player.vel_y -= 0x200;
+                    if player.vel_y < -0x400 {
+                        player.vel_y = -0x400;
+                    }
+                }
+            }
+
+            match player.direction {
+                Direction::Left => {
+                    bullet_manager.create_bullet(player.x - 3 * 0x200, player.y + 8 * 0x200, btype, player_id, Direction::Bottom, &state.constants);
+                    state.create_caret(player.x - 3 * 0x200, player.y + 8 * 0x200, CaretType::Shoot, Direction::Left);
+                }
+                Direction::Right => {
+                    bullet_manager.create_bullet(player.x + 3 * 0x200, player.y + 8 * 0x200, btype, player_id, Direction::Bottom, &state.constants);
+                    state.create_caret(player.x + 3 * 0x200, player.y + 8 * 0x200, CaretType::Shoot, Direction::Left);
+                }
+                _ => {}
+            }
+        } else {
+            match player.direction {
+                Direction::Left => {
+                    bullet_manager.create_bullet(player.x - 12 * 0x200, player.y + 3 * 0x200, btype, player_id, Direction::Left, &state.constants);
+                    state.create_caret(player.x - 12 * 0x200, player.y + 3 * 0x200, CaretType::Shoot, Direction::Left);
+                }
+                Direction::Right => {
+                    bullet_manager.create_bullet(player.x + 12 * 0x200, player.y + 3 * 0x200, btype, player_id, Direction::Right, &state.constants);
+                    state.create_caret(player.x + 12 * 0x200, player.y + 3 * 0x200, CaretType::Shoot, Direction::Right);
+                }
+                _ => {}
+            }
+        }
+
+        if self.level == WeaponLevel::Level3 {
+            state.sound_manager.play_sfx(49);
+        } else {
+            state.sound_manager.play_sfx(32);
+        }
+    }
+
+    fn shoot_bullet_missile_launcher(&mut self, player: &Player, player_id: TargetPlayer, bullet_manager: &mut BulletManager, state: &mut SharedGameState) {
+        let (btype, bullet_types, explosion_types) = if self.wtype == WeaponType::SuperMissileLauncher {
+            (27 + self.level as u16, [28, 29, 30], [31, 32, 33])
+        } else {
+            (12 + self.level as u16, [13, 14, 15], [16, 17, 18])
+        };
+
+        let max_bullets = match self.level {
+            WeaponLevel::Level1 => { 1 }
+            WeaponLevel::Level2 => { 2 }
+            WeaponLevel::Level3 => { 4 }
+            WeaponLevel::None => { unreachable!() }
+        };
+
+        if bullet_manager.count_bullets_multi(bullet_types, player_id)
+            + bullet_manager.count_bullets_multi(explosion_types, player_id) >= max_bullets {
+            return;
+        }
+
+        if !player.controller.trigger_shoot() {
+            return;
+        }
+
+        if !self.consume_ammo(1) {
+            state.sound_manager.play_sfx(37);
+            state.create_caret(player.x, player.y, CaretType::Empty, Direction::Left);
+            return;
+        }
+
+        let spread = self.level == WeaponLevel::Level3;
+
+        if player.up {
+            match player.direction {
+                Direction::Left => {
+                    bullet_manager.create_bullet(player.x - 0x200, player.y - 8 * 0x200, btype, player_id, Direction::Up, &state.constants);
+                    state.create_caret(player.x - 0x200, player.y - 8 * 0x200, CaretType::Shoot, Direction::Left);
+
+                    if spread {
+                        bullet_manager.create_bullet(player.x + 3 * 0x200, player.y, btype, player_id, Direction::Up, &state.constants);
+                        bullet_manager.create_bullet(player.x - 3 * 0x200, player.y, btype, player_id, Direction::Up, &state.constants);
+                    }
+                }
+                Direction::Right => {
+                    bullet_manager.create_bullet(player.x + 0x200, player.y - 8 * 0x200, btype, player_id, Direction::Up, &state.constants);
+                    state.create_caret(player.x + 0x200, player.y - 8 * 0x200, CaretType::Shoot, Direction::Left);
+
+                    if spread {
+                        bullet_manager.create_bullet(player.x - 3 * 0x200, player.y, btype, player_id, Direction::Up, &state.constants);
+                        bullet_manager.create_bullet(player.x + 3 * 0x200, player.y, btype, player_id, Direction::Up, &state.constants);
+                    }
+                }
+                _ => {}
+            }
+        } else if player.down {
+            match player.direction {
+                Direction::Left => {
+                    bullet_manager.create_bullet(player.x - 0x200, player.y + 8 * 0x200, btype, player_id, Direction::Bottom, &state.constants);
+                    state.create_caret(player.x - 0x200, player.y + 8 * 0x200, CaretType::Shoot, Direction::Left);
+
+                    if spread {
+                        bullet_manager.create_bullet(player.x + 3 * 0x200, player.y, btype, player_id, Direction::Bottom, &state.constants);
+                        bullet_manager.create_bullet(player.x - 3 * 0x200, player.y, btype, player_id, Direction::Bottom, &state.constants);
+                    }
+                }
+                Direction::Right => {
+                    bullet_manager.create_bullet(player.x + 0x200, player.y + 8 * 0x200, btype, player_id, Direction::Bottom, &state.constants);
+                    state.create_caret(player.x + 0x200, player.y + 8 * 0x200, CaretType::Shoot, Direction::Left);
+
+                    if spread {
+                        bullet_manager.create_bullet(player.x - 3 * 0x200, player.y, btype, player_id, Direction::Bottom, &state.constants);
+                        bullet_manager.create_bullet(player.x + 3 * 0x200, player.y, btype, player_id, Direction::Bottom, &state.constants);
+                    }
+                }
+                _ => {}
+            }
+        } else {
+            let off_y = if spread { 0x200 } else { 0 };
+
+            match player.direction {
+                Direction::Left => {
+                    bullet_manager.create_bullet(player.x - 6 * 0x200, player.y + off_y, btype, player_id, Direction::Left, &state.constants);
+                    state.create_caret(player.x - 12 * 0x200, player.y + off_y, CaretType::Shoot, Direction::Left);
+
+                    if spread {
+                        bullet_manager.create_bullet(player.x, player.y - 8 * 0x200, btype, player_id, Direction::Left, &state.constants);
+                        bullet_manager.create_bullet(player.x + 4 * 0x200, player.y - 0x200, btype, player_id, Direction::Left, &state.constants);
+                    }
+                }
+                Direction::Right => {
+                    bullet_manager.create_bullet(player.x + 6 * 0x200, player.y + off_y, btype, player_id, Direction::Right, &state.constants);
+                    state.create_caret(player.x + 12 * 0x200, player.y + off_y, CaretType::Shoot, Direction::Right);
+
+                    if spread {
+                        bullet_manager.create_bullet(player.x, player.y - 8 * 0x200, btype, player_id, Direction::Right, &state.constants);
+                        bullet_manager.create_bullet(player.x - 4 * 0x200, player.y - 0x200, btype, player_id, Direction::Right, &state.constants);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        state.sound_manager.play_sfx(32);
+    }
+
+    fn shoot_bullet_bubbler(&mut self, player: &Player, player_id: TargetPlayer, bullet_manager: &mut BulletManager, state: &mut SharedGameState) {
+        if self.level == WeaponLevel::Level1 {
+            if bullet_manager.count_bullets_multi([19, 20, 21], player_id) > 3 {
+                return;
+            }
+
+            if !player.controller.trigger_shoot() {
+                self.counter2 += 1;
+                if self.counter2 > 20 {
+                    self.counter2 = 0;
+                    self.refill_ammo(1);
+                }
+
+                return;
+            }
+        } else {
+            if bullet_manager.count_bullets_multi([19, 20, 21], player_id) > 15 {
+                return;
+            }
+
+            if !player.controller.shoot() {
+                // fire immediately on the next press
+                self.counter1 = 6;
+
+                self.counter2 += 1;
+                if self.counter2 > 1 {
+                    self.counter2 = 0;
+                    self.refill_ammo(1);
+                }
+
+                return;
+            }
+
+            self.counter1 += 1;
+            if self.counter1 < 7 {
+                return;
+            }
+
+            self.counter1 = 0;
+        }
+
+        let btype = match self.level {
+            WeaponLevel::Level1 => { 19 }
+            WeaponLevel::Level2 => { 20 }
+            WeaponLevel::Level3 => { 21 }
+            WeaponLevel::None => { unreachable!() }
+        };
+
+        if !self.consume_ammo(1) {
+            state.sound_manager.play_sfx(37);
+            state.create_caret(player.x, player.y, CaretType::Empty, Direction::Left);
+            return;
+        }
+
+        let (off_x, off_y, caret_off_y) = if self.level == WeaponLevel::Level1 {
+            (0x200, 2 * 0x200, 2 * 0x200)
+        } else {
+            (3 * 0x200, 8 * 0x200, 16 * 0x200)
+        };
+
+        if player.up {
+            match player.direction {
+                Direction::Left => {
+                    bullet_manager.create_bullet(player.x - off_x, player.y - off_y, btype, player_id, Direction::Up, &state.constants);
+                    state.create_caret(player.x - off_x, player.y - caret_off_y, CaretType::Shoot, Direction::Left);
+                }
+                Direction::Right => {
+                    bullet_manager.create_bullet(player.x + off_x, player.y - off_y, btype, player_id, Direction::Up, &state.constants);
+                    state.create_caret(player.x + off_x, player.y - caret_off_y, CaretType::Shoot, Direction::Left);
+                }
+                _ => {}
+            }
+        } else if player.down {
+            match player.direction {
+                Direction::Left => {
+                    bullet_manager.create_bullet(player.x - off_x, player.y + off_y, btype, player_id, Direction::Bottom, &state.constants);
+                    state.create_caret(player.x - off_x, player.y + caret_off_y, CaretType::Shoot, Direction::Left);
+                }
+                Direction::Right => {
+                    bullet_manager.create_bullet(player.x + off_x, player.y + off_y, btype, player_id, Direction::Bottom, &state.constants);
+                    state.create_caret(player.x + off_x, player.y + caret_off_y, CaretType::Shoot, Direction::Left);
+                }
+                _ => {}
+            }
+        } else {
+            match player.direction {
+                Direction::Left => {
+                    bullet_manager.create_bullet(player.x - 6 * 0x200, player.y + 3 * 0x200, btype, player_id, Direction::Left, &state.constants);
+                    state.create_caret(player.x - 12 * 0x200, player.y + 3 * 0x200, CaretType::Shoot, Direction::Left);
+                }
+                Direction::Right => {
+                    bullet_manager.create_bullet(player.x + 6 * 0x200, player.y + 3 * 0x200, btype, player_id, Direction::Right, &state.constants);
+                    state.create_caret(player.x + 12 * 0x200, player.y + 3 * 0x200, CaretType::Shoot, Direction::Right);
+                }
+                _ => {}
+            }
+        }
+
+        state.sound_manager.play_sfx(48);
+    }
+
+    fn shoot_bullet_blade(&mut self, player: &Player, player_id: TargetPlayer, bullet_manager: &mut BulletManager, state: &mut SharedGameState) {
+        if bullet_manager.count_bullets_multi([25, 26, 27], player_id) > 0 {
+            return;
+        }
+
+        if !player.controller.trigger_shoot() {
+            return;
+        }
+
+        let btype = match self.level {
+            WeaponLevel::Level1 => { 25 }
+            WeaponLevel::Level2 => { 26 }
+            WeaponLevel::Level3 => { 27 }
+            WeaponLevel::None => { unreachable!() }
+        };
+
+        // the blade is swung from behind the player
+        if player.up {
+            match player.direction {
+                Direction::Left => {
+                    bullet_manager.create_bullet(player.x - 0x200, player.y + 4 * 0x200, btype, player_id, Direction::Up, &state.constants);
+                }
+                Direction::Right => {
+                    bullet_manager.create_bullet(player.x + 0x200, player.y + 4 * 0x200, btype, player_id, Direction::Up, &state.constants);
+                }
+                _ => {}
+            }
+        } else if player.down {
+            match player.direction {
+                Direction::Left => {
+                    bullet_manager.create_bullet(player.x - 0x200, player.y - 6 * 0x200, btype, player_id, Direction::Bottom, &state.constants);
+                }
+                Direction::Right => {
+                    bullet_manager.create_bullet(player.x + 0x200, player.y - 6 * 0x200, btype, player_id, Direction::Bottom, &state.constants);
+                }
+                _ => {}
+            }
+        } else {
+            match player.direction {
+                Direction::Left => {
+                    bullet_manager.create_bullet(player.x + 6 * 0x200, player.y - 3 * 0x200, btype, player_id, Direction::Left, &state.constants);
+                }
+                Direction::Right => {
+                    bullet_manager.create_bullet(player.x - 6 * 0x200, player.y - 3 * 0x200, btype, player_id, Direction::Right, &state.constants);
+                }
+                _ => {}
+            }
+        }
+
+        state.sound_manager.play_sfx(34);
+    }
+
+    fn shoot_bullet_nemesis(&mut self, player: &Player, player_id: TargetPlayer, bullet_manager: &mut BulletManager, state: &mut SharedGameState) {
+        if bullet_manager.count_bullets_multi([34, 35, 36], player_id) > 1 {
+            return;
+        }
+
+        if !player.controller.trigger_shoot() {
+            return;
+        }
+
+        let btype = match self.level {
+            WeaponLevel::Level1 => { 34 }
+            WeaponLevel::Level2 => { 35 }
+            WeaponLevel::Level3 => { 36 }
+            WeaponLevel::None => { unreachable!() }
+        };
+
+        if !self.consume_ammo(1) {
+            state.sound_manager.play_sfx(37);
+            state.create_caret(player.x, player.y, CaretType::Empty, Direction::Left);
+            return;
+        }
+
+        if player.up {
+            match player.direction {
+                Direction::Left => {
+                    bullet_manager.create_bullet(player.x - 0x200, player.y - 12 * 0x200, btype, player_id, Direction::Up, &state.constants);
+                    state.create_caret(player.x - 0x200, player.y - 12 * 0x200, CaretType::Shoot, Direction::Left);
+                }
+                Direction::Right => {
+                    bullet_manager.create_bullet(player.x + 0x200, player.y - 12 * 0x200, btype, player_id, Direction::Up, &state.constants);
+                    state.create_caret(player.x + 0x200, player.y - 12 * 0x200, CaretType::Shoot, Direction::Left);
+                }
+                _ => {}
+            }
+        } else if player.down {
+            match player.direction {
+                Direction::Left => {
+                    bullet_manager.create_bullet(player.x - 0x200, player.y + 12 * 0x200, btype, player_id, Direction::Bottom, &state.constants);
+                    state.create_caret(player.x - 0x200, player.y + 12 * 0x200, CaretType::Shoot, Direction::Left);
+                }
+                Direction::Right => {
+                    bullet_manager.create_bullet(player.x + 0x200, player.y + 12 * 0x200, btype, player_id, Direction::Bottom, &state.constants);
+                    state.create_caret(player.x + 0x200, player.y + 12 * 0x200, CaretType::Shoot, Direction::Left);
+                }
+                _ => {}
+            }
+        } else {
+            match player.direction {
+                Direction::Left => {
+                    bullet_manager.create_bullet(player.x - 22 * 0x200, player.y + 3 * 0x200, btype, player_id, Direction::Left, &state.constants);
+                    state.create_caret(player.x - 16 * 0x200, player.y + 3 * 0x200, CaretType::Shoot, Direction::Left);
+                }
+                Direction::Right => {
+                    bullet_manager.create_bullet(player.x + 22 * 0x200, player.y + 3 * 0x200, btype, player_id, Direction::Right, &state.constants);
+                    state.create_caret(player.x + 16 * 0x200, player.y + 3 * 0x200, CaretType::Shoot, Direction::Right);
+                }
+                _ => {}
+            }
+        }
+
+        match self.level {
+            WeaponLevel::Level1 => state.sound_manager.play_sfx(117),
+            WeaponLevel::Level2 => state.sound_manager.play_sfx(49),
+            WeaponLevel::Level3 => state.sound_manager.play_sfx(60),
+            WeaponLevel::None => unreachable!(),
+        }
+    }
+
+    fn shoot_bullet_spur(&mut self, player: &mut Player, player_id: TargetPlayer, bullet_manager: &mut BulletManager, state: &mut SharedGameState) {
+        // the spur levels up while charging and drops back to level 1 once the trigger is released
+        let level = self.level;
+        let mut release = false;
+
+        if player.controller.shoot() {
+            self.add_charge(if player.equip.has_turbocharge() { 3 } else { 2 }, player, state);
+
+            self.counter1 += 1;
+            if (self.counter1 / 2) % 2 != 0 {
+                match level {
+                    WeaponLevel::Level1 => state.sound_manager.play_sfx(59),
+                    WeaponLevel::Level2 => state.sound_manager.play_sfx(60),
+                    WeaponLevel::Level3 => {
+                        if !self.is_max_charge(state) {
+                            state.sound_manager.play_sfx(61);
+                        }
+                    }
+                    WeaponLevel::None => unreachable!(),
+                }
+            }
+        } else {
+            if self.counter1 > 0 {
+                release = true;
+            }
+
+            self.counter1 = 0;
+        }
+
+        // counter2 remembers whether the charge has been maxed out
+        if self.is_max_charge(state) {
+            if self.counter2 == 0 {
+                self.counter2 = 1;
+                state.sound_manager.play_sfx(65);
+            }
+        } else {
+            self.counter2 = 0;
+        }
+
+        if !player.controller.shoot() {
+            self.level = WeaponLevel::Level1;
+            self.experience = 0;
+        }
+
+        let btype = match level {
+            WeaponLevel::Level1 => {
+                release = false;
+                6
+            }
+            WeaponLevel::Level2 => { 37 }
+            WeaponLevel::Level3 => {
+                if self.counter2 == 1 { 39 } else { 38 }
+            }
+            WeaponLevel::None => { unreachable!() }
+        };
+
+        if bullet_manager.count_bullets_multi([37, 38, 39], player_id) > 0
+            || bullet_manager.count_bullets_multi([40, 41, 42], player_id) > 0 {
+            return;
+        }
+
+        if !player.controller.trigger_shoot() && !release {
+            return;
+        }
+
+        if !self.consume_ammo(1) {
+            state.sound_manager.play_sfx(37);
+            return;
+        }
+
+        if player.up {
+            match player.direction {
+                Direction::Left => {
+                    bullet_manager.create_bullet(player.x - 0x200, player.y - 8 * 0x200, btype, player_id, Direction::Up, &state.constants);
+                    state.create_caret(player.x - 0x200, player.y - 8 * 0x200, CaretType::Shoot, Direction::Left);
+                }
+                Direction::Right => {
+                    bullet_manager.create_bullet(player.x + 0x200, player.y - 8 * 0x200, btype, player_id, Direction::Up, &state.constants);
+                    state.create_caret(player.x + 0x200, player.y - 8 * 0x200, CaretType::Shoot, Direction::Left);
+                }
+                _ => {}
+            }
+        } else if player.down {
+            match player.direction {
+                Direction::Left => {
+                    bullet_manager.create_bullet(player.x - 0x200, player.y + 8 * 0x200, btype, player_id, Direction::Bottom, &state.constants);
+                    state.create_caret(player.x - 0x200, player.y + 8 * 0x200, CaretType::Shoot, Direction::Left);
+                }
+                Direction::Right => {
+                    bullet_manager.create_bullet(player.x + 0x200, player.y + 8 * 0x200, btype, player_id, Direction::Bottom, &state.constants);
+                    state.create_caret(player.x + 0x200, player.y + 8 * 0x200, CaretType::Shoot, Direction::Left);
+                }
+                _ => {}
+            }
+        } else {
+            match player.direction {
+                Direction::Left => {
+                    bullet_manager.create_bullet(player.x - 6 * 0x200, player.y + 3 * 0x200, btype, player_id, Direction::Left, &state.constants);
+                    state.create_caret(player.x - 12 * 0x200, player.y + 3 * 0x200, CaretType::Shoot, Direction::Left);
+                }
+                Direction::Right => {
+                    bullet_manager.create_bullet(player.x + 6 * 0x200, player.y + 3 * 0x200, btype, player_id, Direction::Right, &state.constants);
+                    state.create_caret(player.x + 12 * 0x200, player.y + 3 * 0x200, CaretType::Shoot, Direction::Right);
+                }
+                _ => {}
+            }
+        }
+
+        match btype {
+            6 => state.sound_manager.play_sfx(49),
+            37 => state.sound_manager.play_sfx(62),
+            38 => state.sound_manager.play_sfx(63),
+            _ => state.sound_manager.play_sfx(64),
+        }
+    }
+
+    fn add_charge(&mut self, exp: u16, player: &mut Player, state: &SharedGameState) {
+        let lvl_table = state.constants.weapon.level_table[self.wtype as usize];
+
+        self.experience += exp;
+
+        if self.level == WeaponLevel::Level3 {
+            if self.experience > lvl_table[2] {
+                self.experience = lvl_table[2];
+
+                if player.equip.has_whimsical_star() && player.stars < 3 {
+                    player.stars += 1;
+                }
+            }
+        } else if self.experience > lvl_table[self.level as usize - 1] {
+            self.level = self.level.next();
+            self.experience = 0;
+        }
+    }
+
+    fn is_max_charge(&self, state: &SharedGameState) -> bool {
+        self.level == WeaponLevel::Level3
+            && self.experience == state.constants.weapon.level_table[self.wtype as usize][2]
+    }
+
+    pub fn shoot_bullet(&mut self, player: &mut Player, player_id: TargetPlayer, bullet_manager: &mut BulletManager, state: &mut SharedGameState) {
+        if self.empty_counter > 0 {
+            self.empty_counter -= 1;
+        }
+
         if !player.cond.alive() || player.cond.hidden() {
             return;
         }
@@ -268,13 +854,13 @@ impl Weapon {
             WeaponType::Snake => self.shoot_bullet_snake(player, player_id, bullet_manager, state),
             WeaponType::PolarStar => self.shoot_bullet_polar_star(player, player_id, bullet_manager, state),
             WeaponType::Fireball => self.shoot_bullet_fireball(player, player_id, bullet_manager, state),
-            WeaponType::MachineGun => {}
-            WeaponType::MissileLauncher => {}
-            WeaponType::Bubbler => {}
-            WeaponType::Blade => {}
-            WeaponType::SuperMissileLauncher => {}
-            WeaponType::Nemesis => {}
-            WeaponType::Spur => {}
+            WeaponType::MachineGun => self.shoot_bullet_machine_gun(player, player_id, bullet_manager, state),
+            WeaponType::MissileLauncher => self.shoot_bullet_missile_launcher(player, player_id, bullet_manager, state),
+            WeaponType::Bubbler => self.shoot_bullet_bubbler(player, player_id, bullet_manager, state),
+            WeaponType::Blade => self.shoot_bullet_blade(player, player_id, bullet_manager, state),
+            WeaponType::SuperMissileLauncher => self.shoot_bullet_missile_launcher(player, player_id, bullet_manager, state),
+            WeaponType::Nemesis => self.shoot_bullet_nemesis(player, player_id, bullet_manager, state),
+            WeaponType::Spur => self.shoot_bullet_spur(player, player_id, bullet_manager, state),
         }
     }
 }